@@ -0,0 +1,23 @@
+//! prints a centered multi-line title, and a right-aligned footer
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let ncd = NcDirect::new()?;
+
+    ncd.styles_set(NCSTYLE_BOLD)?;
+    ncd.puttext(
+        NCALIGN_CENTER,
+        "┏━━━━━━━━━━━━━━━━━━━━┓\n\
+         ┃ libnotcurses-sys   ┃\n\
+         ┃ direct mode banner ┃\n\
+         ┗━━━━━━━━━━━━━━━━━━━━┛",
+    )?;
+    ncd.styles_set(NCSTYLE_NONE)?;
+
+    ncd.puttext(NCALIGN_RIGHT, "\nv2.1.2\nright aligned")?;
+
+    ncd.flush()?;
+    ncd.stop()?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! prints a report of the capabilities of the terminal, for diagnostics
+//!
+//! the report is not styled when piped, e.g. to a file
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let ncd = NcDirect::new()?;
+    let caps = ncd.capabilities();
+
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let report = format![
+        "terminal:     {}\n\
+         styling:      {}\n\
+         truecolor:    {}\n\
+         palette size: {}\n\
+         images:       {}\n\
+         utf-8:        {}\n\
+         size:         {}x{}\n",
+        yes_no(caps.tty),
+        yes_no(caps.styling),
+        yes_no(caps.truecolor),
+        caps.palette_size,
+        yes_no(caps.images),
+        yes_no(caps.utf8),
+        caps.cols,
+        caps.rows,
+    ];
+    ncd.putstr(0, &report)?;
+
+    ncd.flush()?;
+    ncd.stop()?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! colors the output only when it's interactive
+//!
+//! Try piping it to a file, setting `NO_COLOR=1`,
+//! or passing `--color=always` or `--color=never`.
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let ncd = NcDirect::new()?;
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--color=always" => ncd.set_color_mode(NcColorMode::Always),
+            "--color=never" => ncd.set_color_mode(NcColorMode::Never),
+            "--color=auto" => ncd.set_color_mode(NcColorMode::Auto),
+            _ => (),
+        }
+    }
+
+    ncd.set_fg_rgb(0x44dd44)?;
+    ncd.styles_set(NCSTYLE_BOLD)?;
+    ncd.putstr(0, "ok")?;
+    ncd.styles_set(NCSTYLE_NONE)?;
+    ncd.set_fg_default()?;
+    ncd.putstr(
+        0,
+        &format![": is_tty {}, styling {}\n", ncd.is_tty(), ncd.styling_p()],
+    )?;
+
+    ncd.flush()?;
+    ncd.stop()?;
+    Ok(())
+}
@@ -2,12 +2,15 @@
 //!
 //! Explore cursor functions in direct mode
 //!
+//! The context is stopped by `NcDirect::with` even if any step fails.
 
 use libnotcurses_sys::*;
 
 fn main() -> NcResult<()> {
-    let ncd = NcDirect::new()?;
+    NcDirect::with(cursor)
+}
 
+fn cursor(ncd: &mut NcDirect) -> NcResult<()> {
     let cols = ncd.dim_x();
     let rows = ncd.dim_y();
     println!("terminal size (rows, cols): {}, {}", rows, cols);
@@ -23,7 +26,9 @@ fn main() -> NcResult<()> {
     ncd.putstr(0, &format!(" ({},{})\n", cy, cx))?;
     sleep![1];
 
-    let sentence = vec!["And", "now", "I", "will", "clear", "the", "screen", ".", ".", "."];
+    let sentence = vec![
+        "And", "now", "I", "will", "clear", "the", "screen", ".", ".", ".",
+    ];
     for word in sentence {
         ncd.putstr(0, &format!["{} ", word])?;
         fsleep![ncd, 0, 150];
@@ -35,6 +40,5 @@ fn main() -> NcResult<()> {
     ncd.clear()?;
     sleep![1];
 
-    ncd.stop()?;
     Ok(())
 }
@@ -0,0 +1,29 @@
+//! prints sections of CLI output separated by gradient dividers
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let ncd = NcDirect::new()?;
+    let cols = ncd.dim_x();
+
+    let sections = [
+        ("Build", "compiling 42 crates\nfinished in 12.3s"),
+        ("Tests", "128 passed\n0 failed"),
+        ("Lints", "no warnings"),
+    ];
+    for (title, body) in sections.iter() {
+        // a larger length is clipped to the terminal width
+        ncd.colored_rule(cols * 2, 0x40a0ff, 0xff40a0)?;
+        ncd.putstr(0, "\n")?;
+        ncd.styles_set(NCSTYLE_BOLD)?;
+        ncd.putstr(0, &format!["{}\n", title])?;
+        ncd.styles_set(NCSTYLE_NONE)?;
+        ncd.putstr(0, &format!["{}\n", body])?;
+    }
+    ncd.rule(cols)?;
+    ncd.putstr(0, "\n")?;
+
+    ncd.flush()?;
+    ncd.stop()?;
+    Ok(())
+}
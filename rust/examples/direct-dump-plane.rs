@@ -0,0 +1,55 @@
+//! Example 'direct-dump-plane'
+//!
+//! Composes a colored table in a plane and dumps it below a prompt,
+//! in direct mode.
+//!
+//! NOTE: This example uses the Rust style with methods.
+
+use libnotcurses_sys::*;
+
+const ROWS: [(&str, &str, NcRgb); 4] = [
+    ("red", "ff0000", 0xff4040),
+    ("green", "00ff00", 0x40ff40),
+    ("blue", "0000ff", 0x4040ff),
+    ("yellow", "ffff00", 0xffff40),
+];
+
+fn main() -> NcResult<()> {
+    let ncd = NcDirect::new()?;
+
+    // Direct mode has no standard plane, so we borrow the one that results
+    // from rendering a frame, and draw the table over the image.
+    let plane = match ncd.render_frame("image-16x16.png", NCBLIT_1x1, NCSCALE_NONE) {
+        Ok(plane) => plane,
+        Err(nc_error) => {
+            ncd.stop()?;
+            return Err(NcError::with_msg(
+                nc_error.int,
+                "ERROR: ncdirect_render_frame(). Make sure you \
+                are running this example from the examples folder",
+            ));
+        }
+    };
+    plane.erase();
+
+    plane.set_styles(NCSTYLE_BOLD);
+    plane.set_fg_rgb(0xffffff);
+    plane.set_bg_rgb(0x303030);
+    plane.putstr_yx(0, 0, "color   rgb     ")?;
+    plane.set_styles(NCSTYLE_NONE);
+
+    for (row, (name, hex, rgb)) in ROWS.iter().enumerate() {
+        let y = row as NcDimension + 1;
+        plane.set_fg_rgb(*rgb);
+        plane.set_bg_rgb(0x101010);
+        plane.putstr_yx(y, 0, &format!["{:<8}{:<8}", name, hex])?;
+    }
+
+    ncd.putstr(0, "Here is the table:\n")?;
+    ncd.dump(plane)?;
+    ncd.putstr(0, "Done.\n")?;
+
+    plane.destroy()?;
+    ncd.stop()?;
+    Ok(())
+}
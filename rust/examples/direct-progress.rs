@@ -0,0 +1,39 @@
+//! Example 'direct-progress'
+//!
+//! Draws a progress bar as wide as the terminal, adapting its width
+//! when the terminal is resized while it runs.
+//!
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let ncd = NcDirect::new()?;
+    ncd.detect_resize();
+    let mut cols = ncd.dim_x();
+
+    for step in 0..=200_u32 {
+        if let Some((_, new_cols)) = ncd.detect_resize() {
+            cols = new_cols;
+            // start the bar afresh on a new line
+            ncd.putstr(0, "\n")?;
+        }
+
+        // room for the brackets and the percentage
+        let width = cols.saturating_sub(8);
+        let filled = width * step / 200;
+        let bar = format![
+            "\r[{}{}] {:>3}%",
+            "#".repeat(filled as usize),
+            " ".repeat((width - filled) as usize),
+            step / 2
+        ];
+        ncd.putstr(0, &bar)?;
+        ncd.flush()?;
+        sleep![0, 30];
+    }
+    ncd.putstr(0, "\n")?;
+    ncd.flush()?;
+
+    ncd.stop()?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! Example 'direct-spinner'
+//!
+//! Updates a spinner in place, flushing explicitly after each frame.
+//!
+
+use libnotcurses_sys::*;
+
+const FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+
+fn main() -> NcResult<()> {
+    let ncd = NcDirect::new()?;
+
+    ncd.putstr(0, "working ")?;
+    ncd.flush()?;
+
+    for n in 0..40 {
+        ncd.putstr(0, FRAMES[n % FRAMES.len()])?;
+        // without flushing, the frame could still be in the buffer during the sleep
+        ncd.flush()?;
+        sleep![0, 80];
+        ncd.cursor_left(1)?;
+    }
+    ncd.putstr(0, "done\n")?;
+    ncd.flush()?;
+
+    ncd.stop()?;
+    Ok(())
+}
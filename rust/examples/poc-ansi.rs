@@ -0,0 +1,24 @@
+//! renders colored text piped from another tool, honoring its SGR sequences
+//!
+//! usage: ls --color=always | cargo run --example poc-ansi
+
+use std::io::Read;
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let mut input = String::new();
+    if std::io::stdin().read_to_string(&mut input).is_err() || input.is_empty() {
+        input = "\x1b[1;34mdir\x1b[0m  \x1b[1;32mexecutable\x1b[0m  \x1b[38;5;208mfile\x1b[0m\n"
+            .to_string();
+    }
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.set_scrolling(true);
+    stdplane.putansi(&input)?;
+
+    rsleep![nc, 3];
+    nc.stop()?;
+    Ok(())
+}
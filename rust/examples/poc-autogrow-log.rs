@@ -0,0 +1,29 @@
+//! appends log lines to an autogrowing plane, which is moved up to keep
+//! showing the latest ones
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    let log = NcPlane::new_bound(nc.stdplane()?, 0, 0, 1, tcols)?;
+    log.set_autogrow(true);
+
+    for n in 0..60 {
+        log.putstrln(&format![
+            "[{:03}] event received, {} bytes",
+            n,
+            n * 37 % 1000
+        ])?;
+
+        // the plane keeps growing, scroll the view to its bottom
+        let (rows, _) = log.dim_yx();
+        log.move_yx((trows as NcOffset - rows as NcOffset).min(0), 0)?;
+        rsleep![nc, 0, 50];
+    }
+    sleep![1];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! a small dashboard over a tiled image background
+//!
+//! usage: cargo run --example poc-backdrop -- <image>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let dashboard = NcPlane::new_bound(nc.stdplane()?, 1, 2, 12, 50)?;
+
+    let visual = NcVisual::from_file(&file)?;
+    let backdrop = dashboard.set_base_visual(nc, visual, NCBLIT_2x1, NcBackdropFit::Tile)?;
+    // dim the image, so that the text stands out
+    backdrop.restyle_region(0, 0, -1, -1, NCSTYLE_DIM)?;
+
+    dashboard.set_fg_rgb(0xffffff);
+    dashboard.on_styles(NCSTYLE_BOLD);
+    dashboard.putstr_yx(1, 2, "status")?;
+    dashboard.off_styles(NCSTYLE_BOLD);
+    for (row, (name, value)) in [("cpu", "12%"), ("memory", "3.1 GiB"), ("uptime", "4 days")]
+        .iter()
+        .enumerate()
+    {
+        dashboard.putstr_yx(3 + row as NcDimension, 4, &format!["{:<10}{}", name, value])?;
+    }
+
+    rsleep![nc, 4];
+    backdrop.destroy()?;
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! rings the bell and flashes the screen on an invalid keypress
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, "press a digit (q to quit)")?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == 'q' {
+            break;
+        } else if key.is_ascii_digit() {
+            stdplane.putstr_yx(2, 0, &format!["you pressed {}", key])?;
+            nc.render()?;
+        } else {
+            nc.bell()?;
+            nc.visual_bell()?;
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! renders an image with the best blitter the terminal supports
+//!
+//! usage: cargo run --example poc-best-blitter -- <image>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let blitter = nc.best_blitter();
+    let visual = NcVisual::from_file(&file)?;
+    let options =
+        NcVisualOptions::with_plane(stdplane, NCSCALE_SCALE, 1, 0, 0, 0, 0, 0, blitter, 0);
+    visual.render(nc, &options)?;
+
+    let name = Notcurses::str_blitter(blitter);
+    stdplane.putstr_yx(0, 0, &format!["blitter: {} (press q to quit)", name])?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    while nc.getc_nblocking(&mut input) != 'q' {}
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
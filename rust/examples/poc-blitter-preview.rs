@@ -0,0 +1,44 @@
+//! a "choose your blitter" screen, comparing an image rendered with each
+//! blitter supported by the terminal
+//!
+//! usage: cargo run --example poc-blitter-preview -- <image>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, "choose your blitter (press q to quit)")?;
+
+    let visual = NcVisual::from_file(&file)?;
+    let previews = visual.render_all_blitters(nc)?;
+
+    // arrange the previews side by side, wrapping into new rows as needed
+    let (_rows, cols) = stdplane.dim_yx();
+    let (mut y, mut x, mut row_height) = (2, 0, 0);
+    for (blitter, plane) in previews {
+        let (prows, pcols) = plane.dim_yx();
+        let width = pcols.max(10);
+        if x > 0 && x + width > cols {
+            y += row_height + 2;
+            x = 0;
+            row_height = 0;
+        }
+        stdplane.putstr_yx(y, x, &Notcurses::str_blitter(blitter))?;
+        plane.move_yx(y as NcOffset + 1, x as NcOffset)?;
+        x += width + 2;
+        row_height = row_height.max(prows);
+    }
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    while nc.getc_nblocking(&mut input) != 'q' {}
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
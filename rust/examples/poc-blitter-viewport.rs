@@ -0,0 +1,45 @@
+//! adapts the blitter of an image to the size of the viewport,
+//! resize the terminal to try it
+
+use libnotcurses_sys::*;
+
+// the size in pixels of the image
+const IMG_FILE: &str = "image-16x16.png";
+const IMG_W: NcDimension = 16;
+const IMG_H: NcDimension = 16;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let visual = NcVisual::from_file(IMG_FILE)?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let (rows, cols) = nc.term_dim_yx();
+        let blitter = stdplane.choose_blitter_for(rows - 1, cols, IMG_W, IMG_H)?;
+
+        stdplane.erase();
+        let options =
+            NcVisualOptions::with_plane(stdplane, NCSCALE_SCALE, 1, 0, 0, 0, 0, 0, blitter, 0);
+        visual.render(nc, &options)?;
+        stdplane.putstr_yx(
+            0,
+            0,
+            &format![
+                "{}x{} cells: {} (press q to quit)",
+                cols,
+                rows - 1,
+                Notcurses::str_blitter(blitter)
+            ],
+        )?;
+        nc.render()?;
+
+        if nc.getc_nblocking(&mut input) == 'q' {
+            break;
+        }
+    }
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
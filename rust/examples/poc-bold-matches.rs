@@ -0,0 +1,36 @@
+//! bolds the matches of a pattern over some colored log lines,
+//! keeping the colors they were written with
+
+use libnotcurses_sys::*;
+
+const LINES: &[(&str, NcRgb)] = &[
+    ("INFO  connected to db-primary", 0x60a0ff),
+    ("WARN  db-replica lagging by 3s", 0xffc040),
+    ("INFO  cache warmed in 120ms", 0x60a0ff),
+    ("ERROR db-replica unreachable", 0xff4040),
+];
+
+const PATTERN: &str = "db-";
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    for (y, (line, rgb)) in LINES.iter().enumerate() {
+        stdplane.set_fg_rgb(*rgb);
+        stdplane.putstr_yx(y as NcDimension + 1, 2, line)?;
+    }
+    stdplane.set_fg_default();
+    rsleep![nc, 1];
+
+    for (y, (line, _)) in LINES.iter().enumerate() {
+        for (x, matched) in line.match_indices(PATTERN) {
+            stdplane.cursor_move_yx(y as NcDimension + 1, x as NcDimension + 2)?;
+            stdplane.putstr_stained_styled(matched, NCSTYLE_BOLD | NCSTYLE_UNDERLINE)?;
+        }
+    }
+    rsleep![nc, 2];
+
+    nc.stop()?;
+    Ok(())
+}
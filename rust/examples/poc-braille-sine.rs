@@ -0,0 +1,25 @@
+//! draws a high resolution sine curve using braille
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let (rows, cols) = stdplane.dim_yx();
+    let plane = NcPlane::new_bound(stdplane, 1, 0, rows - 1, cols)?;
+    plane.set_fg_rgb(0x40d0a0);
+
+    let mut canvas = plane.braille_canvas(cols * 2, (rows - 1) * 4);
+    let (width, height) = canvas.size();
+    let amplitude = (height - 1) as f64 / 2.0;
+    for x in 0..width {
+        let angle = x as f64 / width as f64 * std::f64::consts::PI * 4.0;
+        let y = amplitude - angle.sin() * amplitude;
+        canvas.set_pixel(x, y.round() as NcDimension, true);
+    }
+    canvas.render()?;
+
+    rsleep![nc, 3];
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! clickable buttons within a plane, routed through its mouse areas
+//! (q to quit)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+
+    let panel = NcPlane::new_bound(nc.stdplane()?, 2, 4, 5, 40)?;
+    let buttons = ["[ Save ]", "[ Load ]", "[ Quit ]"];
+    let mut x = 1;
+    for (id, label) in buttons.iter().enumerate() {
+        panel.putstr_yx(1, x, label)?;
+        panel.add_mouse_area(1, x, 1, label.len() as NcDimension, id as u32)?;
+        x += label.len() as NcDimension + 2;
+    }
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == 'q' {
+            break;
+        }
+        if key != NCKEY_RELEASE {
+            continue;
+        }
+        match panel.hit_test_input(&input) {
+            Some(2) => break,
+            Some(id) => {
+                panel.putstr_yx(3, 1, &format!["clicked {:<20}", buttons[id as usize]])?;
+            }
+            None => {
+                panel.putstr_yx(3, 1, &format!["{:<28}", "missed"])?;
+            }
+        }
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
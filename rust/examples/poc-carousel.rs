@@ -0,0 +1,65 @@
+//! a circular carousel of items, which wraps around from the last one to the
+//! first, navigated with the arrow keys (q to quit)
+
+use core::ffi::c_void;
+
+use libnotcurses_sys::*;
+
+const ITEMS: [&str; 5] = ["apples", "bananas", "cherries", "dates", "elderberries"];
+
+unsafe extern "C" fn draw_item(tablet: *mut NcTablet, _drawfromtop: bool) -> NcIntResult {
+    let tablet = &mut *tablet;
+    let item = &*(tablet.userptr() as *const &str);
+    match tablet.plane() {
+        Some(plane) if plane.putstr_yx(0, 1, item).is_ok() => 1,
+        _ => 0,
+    }
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new_bound(nc.stdplane()?, 1, 2, 12, 24)?;
+
+    let (mut border, mut tablet, mut focused): (NcChannelPair, NcChannelPair, NcChannelPair) =
+        (0, 0, 0);
+    border.set_fg_rgb(0x4080c0);
+    tablet.set_fg_rgb(0x606060);
+    focused.set_fg_rgb(0xffd700);
+    let options = NcReelOptions::new()
+        .set_border_channels(border)
+        .set_tablet_channels(tablet)
+        .set_focused_channels(focused)
+        .set_tabletmask(NCBOXMASK_LEFT | NCBOXMASK_RIGHT)
+        .set_circular(true);
+    let reel = NcReel::with_options(plane, options)?;
+
+    let mut last = None;
+    for item in ITEMS.iter() {
+        let userptr = item as *const &str as *mut c_void;
+        last = Some(reel.add(last, None, draw_item, userptr)?);
+    }
+    reel.redraw()?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            NCKEY_DOWN | NCKEY_RIGHT => {
+                reel.next();
+            }
+            NCKEY_UP | NCKEY_LEFT => {
+                reel.prev();
+            }
+            _ => {
+                reel.offer_input(&input);
+            }
+        }
+        reel.redraw()?;
+        nc.render()?;
+    }
+
+    reel.destroy();
+    nc.stop()?;
+    Ok(())
+}
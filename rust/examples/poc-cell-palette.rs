@@ -0,0 +1,46 @@
+//! builds a palette of reusable styled cells, which are stamped
+//! on several planes without being tied to any of them
+
+use libnotcurses_sys::*;
+
+fn styled(egc: &str, stylemask: NcStyleMask, fg: NcRgb, bg: NcRgb) -> NcOwnedCell {
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(fg);
+    channels.set_bg_rgb(bg);
+    NcOwnedCell::new(egc, stylemask, channels)
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let palette = [
+        styled("▓", 0, 0x2e7d32, 0x1b5e20),
+        styled("≈", NCSTYLE_BOLD, 0x4fc3f7, 0x01579b),
+        styled("▲", 0, 0x9e9e9e, 0x424242),
+        styled("🌲", 0, 0x2e7d32, 0x1b5e20),
+        styled("🏠", 0, 0xffcc80, 0x1b5e20),
+    ];
+
+    let stdplane = nc.stdplane()?;
+    for (y, title) in ["forest", "lake", "mountains"].iter().enumerate() {
+        let y = y as NcDimension * 4 + 1;
+        let tile = NcPlane::new_bound(stdplane, y as NcOffset, 2, 3, 20)?;
+        stdplane.putstr_yx(y + 1, 24, title)?;
+        for row in 0..3 {
+            let mut x = 0;
+            while x < 20 {
+                let cell = match (y, row, x) {
+                    (1, 1, 8) => &palette[4],
+                    (1, _, _) if x < 18 && (x + row) % 5 == 0 => &palette[3],
+                    (5, _, _) => &palette[1],
+                    (9, 0, _) if x % 4 == 0 => &palette[2],
+                    _ => &palette[0],
+                };
+                x += tile.put_owned_cell(row, x, cell)?;
+            }
+        }
+    }
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! draws a grid where every cell has its own color, leaving the active
+//! channels and styles of the plane untouched
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 1, 2, 18, 64)?;
+    plane.set_styles(NCSTYLE_ITALIC);
+
+    for y in 0..16 {
+        for x in 0..32 {
+            let mut channels: NcChannelPair = 0;
+            channels.set_fg_rgb8(255 - y as u8 * 16, x as u8 * 8, y as u8 * 16);
+            channels.set_bg_rgb8(y as u8 * 4, 0, x as u8 * 2);
+            let style = if (x + y) % 2 == 0 {
+                NCSTYLE_BOLD
+            } else {
+                NCSTYLE_NONE
+            };
+            plane.putchar_yx_styled(y, x * 2, '◆', channels, style)?;
+        }
+    }
+
+    // written with the active (italic) style of the plane
+    plane.putstr_yx(17, 0, "each cell styled on its own")?;
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+//! animates a color wave across a line of text
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+
+    let text = "a wave of color rolls over this text, 波のように";
+    let len = plane.putstr_yx(1, 2, text)?;
+
+    for frame in 0..120_u32 {
+        plane.map_region(1, 2, 1, len, |_y, x, cell| {
+            let phase = ((x + frame) % 24) as f64 / 24.0 * std::f64::consts::PI * 2.0;
+            let level = ((phase.sin() + 1.0) * 127.5) as NcColor;
+            cell.set_fg_rgb8(level, 0x80, 0xff - level);
+        })?;
+        rsleep![nc, 0, 30];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
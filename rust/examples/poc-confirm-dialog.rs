@@ -0,0 +1,40 @@
+//! a confirmation dialog drawn with a text box
+//! (y or n to answer)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    let (rows, cols) = (9, 40);
+    let dialog = NcPlane::new_bound(
+        nc.stdplane()?,
+        (trows.saturating_sub(rows) / 2) as NcOffset,
+        (tcols.saturating_sub(cols) / 2) as NcOffset,
+        rows,
+        cols,
+    )?;
+    let mut border: NcChannelPair = 0;
+    border.set_fg_rgb(0xffc040);
+    dialog.text_box(
+        "Delete files",
+        "The 3 selected files will be deleted permanently, \
+        this can't be undone.\n\nProceed? (y/n)",
+        border,
+    )?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    let answer = loop {
+        match nc.getc_nblocking(&mut input) {
+            'y' => break true,
+            'n' => break false,
+            _ => (),
+        }
+    };
+
+    nc.stop()?;
+    println!["{}", if answer { "deleted" } else { "cancelled" }];
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! opens a small context menu at the position of a mouse click
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, "click anywhere (q to quit)")?;
+
+    // the menu is bound to an offset plane, to show the translation at work
+    let frame = NcPlane::new_bound(stdplane, 2, 2, 1, 1)?;
+    let menu = NcPlane::new_bound(frame, 0, 0, 3, 10)?;
+    menu.putstr_yx(0, 0, " open     ")?;
+    menu.putstr_yx(1, 0, " rename   ")?;
+    menu.putstr_yx(2, 0, " delete   ")?;
+    menu.move_bottom();
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == 'q' {
+            break;
+        } else if key == NCKEY_BUTTON1 {
+            menu.move_yx_abs(input.y, input.x)?;
+            menu.move_top();
+            nc.render()?;
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
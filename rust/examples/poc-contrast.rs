@@ -0,0 +1,35 @@
+//! labels over a gradient bar, always readable
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+
+    let (left, right): (NcChannel, NcChannel) = (0x000000, 0xffffff);
+    let mut ul: NcChannelPair = 0;
+    ul.set_fg_rgb(left);
+    ul.set_bg_rgb(left);
+    let mut ur: NcChannelPair = 0;
+    ur.set_fg_rgb(right);
+    ur.set_bg_rgb(right);
+    plane.cursor_move_yx(1, 0)?;
+    plane.gradient(&' ', 0, ul, ur, ul, ur, 1, 59)?;
+
+    // computed per cell, following the gradient
+    plane.cursor_move_yx(1, 2)?;
+    plane.putstr_contrasting("per-cell high contrast text over a gradient bar")?;
+
+    // computed once, from a single background color
+    for (row, bg) in [0x202020, 0x808080, 0xe0e0e0].iter().enumerate() {
+        let mut channels: NcChannelPair = 0;
+        channels.set_bg_rgb(*bg);
+        channels.set_fchannel(NcChannel::contrasting(*bg));
+        plane.set_channels(channels);
+        plane.putstr_yx(3 + row as NcDimension, 2, &format![" bg {:06x} ", bg])?;
+    }
+
+    rsleep![nc, 3];
+    nc.stop()?;
+    Ok(())
+}
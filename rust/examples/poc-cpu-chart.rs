@@ -0,0 +1,53 @@
+//! a mini-chart of the CPU usage over the last seconds (linux only)
+
+use libnotcurses_sys::*;
+
+/// Returns the (busy, total) CPU time since boot, from /proc/stat.
+fn cpu_times() -> (u64, u64) {
+    let stat = std::fs::read_to_string("/proc/stat").unwrap_or_default();
+    let times: Vec<u64> = stat
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    // the 4th and 5th times are idle and iowait
+    let idle = times.iter().skip(3).take(2).sum::<u64>();
+    let total = times.iter().sum::<u64>();
+    (total - idle, total)
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(1, 2, "CPU usage")?;
+    let chart = NcPlane::new_bound(stdplane, 2, 2, 4, 30)?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0x40c0ff);
+    channels.set_bg_rgb(0x101010);
+
+    let mut usage = vec![];
+    let mut last = cpu_times();
+    for _ in 0..60 {
+        sleep![0, 250];
+        let now = cpu_times();
+        let (busy, total) = (now.0 - last.0, now.1 - last.1);
+        last = now;
+        usage.push(if total > 0 {
+            busy as f64 / total as f64
+        } else {
+            0.0
+        });
+
+        // the latest values, scaled to the highest one
+        let start = usage.len().saturating_sub(chart.dim_x() as usize);
+        chart.draw_histogram(&usage[start..], channels, true)?;
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! cross-fades between two prepared screens
+
+use libnotcurses_sys::*;
+
+/// Fills the `plane` with a message over a solid background.
+fn screen(plane: &mut NcPlane, text: &str, fg: NcRgb, bg: NcRgb) -> NcResult<()> {
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(fg);
+    channels.set_bg_rgb(bg);
+    plane.fill(" ", channels, 0)?;
+    plane.set_fg_rgb(fg);
+    plane.set_bg_rgb(bg);
+    let (rows, _) = plane.dim_yx();
+    plane.putstr_aligned_at(rows / 2, NCALIGN_CENTER, text)?;
+    Ok(())
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let (rows, cols) = stdplane.dim_yx();
+
+    // the planes are created in their own piles, so they are never rendered
+    let morning = NcPlane::new(nc, 0, 0, rows, cols)?;
+    screen(morning, "good morning", 0x202020, 0xffd890)?;
+    let night = NcPlane::new(nc, 0, 0, rows, cols)?;
+    screen(night, "good night", 0xe0e0ff, 0x101030)?;
+
+    for (from, to) in [(&*morning, &*night), (&*night, &*morning)].iter() {
+        for step in 0..=30 {
+            NcPlane::crossfade(from, to, stdplane, step as f32 / 30.0)?;
+            rsleep![nc, 0, 40];
+        }
+        sleep![1];
+    }
+
+    morning.destroy()?;
+    night.destroy()?;
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! handles the notcurses input with crossterm style event handling code
+//! (q or Esc to quit)
+//!
+//! run with `cargo run --example poc-crossterm-input --features crossterm`
+
+use crossterm::event::{Event, KeyCode, KeyEvent, MouseEvent};
+use libnotcurses_sys::*;
+
+/// Event handling code as found in an existing crossterm application.
+///
+/// Returns None to quit.
+fn describe(event: Event) -> Option<String> {
+    match event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            ..
+        })
+        | Event::Key(KeyEvent {
+            code: KeyCode::Esc, ..
+        }) => None,
+        Event::Key(KeyEvent { code, modifiers }) => Some(format!["key {:?} {:?}", code, modifiers]),
+        Event::Mouse(MouseEvent {
+            kind, row, column, ..
+        }) => Some(format!["mouse {:?} at {}, {}", kind, row, column]),
+        Event::Resize(..) => Some("resized".to_string()),
+    }
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, "press some keys or click around (q to quit)")?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        nc.getc_nblocking(&mut input);
+        match describe(input.into()) {
+            Some(description) => {
+                stdplane.erase_region(2, 0, 1, -1)?;
+                stdplane.putstr_yx(2, 0, &description)?;
+                nc.render()?;
+            }
+            None => break,
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
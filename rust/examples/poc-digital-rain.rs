@@ -0,0 +1,46 @@
+//! the classic digital rain effect, with falling characters per column
+
+use libnotcurses_sys::*;
+
+/// A tiny xorshift pseudo-random number generator.
+fn random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (rows, cols) = nc.term_dim_yx();
+    let plane = NcPlane::new(nc, 0, 0, rows, cols)?;
+
+    let glyphs: Vec<char> = "ｱｲｳｴｵｶｷｸｹｺ0123456789".chars().collect();
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+
+    for frame in 0..400_u64 {
+        // fade the trails
+        plane.map_region(0, 0, rows, cols, |_, _, cell| {
+            let g = cell.channels.fg_g();
+            cell.channels.set_fg_rgb8(0, g.saturating_sub(24), 0);
+        })?;
+
+        plane.column_animate(
+            |_, _| {
+                if random(&mut seed) % 3 == 0 {
+                    return None;
+                }
+                let ch = glyphs[(random(&mut seed) % glyphs.len() as u64) as usize];
+                let mut channels: NcChannelPair = 0;
+                channels.set_fg_rgb8(180, 255, 180);
+                channels.set_bg_rgb8(0, 0, 0);
+                Some((ch, channels))
+            },
+            frame,
+        )?;
+        rsleep![nc, 0, 40];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
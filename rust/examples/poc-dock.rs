@@ -0,0 +1,42 @@
+//! drags a floating panel over a sidebar, and docks it there
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let sidebar = NcPlane::new_bound(stdplane, 0, 0, 16, 24)?;
+    let mut grey: NcChannelPair = 0;
+    grey.set_bg_rgb(0x303030);
+    sidebar.fill(" ", grey, 0)?;
+    sidebar.putstr_yx(0, 1, "sidebar")?;
+
+    let panel = NcPlane::new_bound(stdplane, 6, 50, 5, 16)?;
+    let mut blue: NcChannelPair = 0;
+    blue.set_bg_rgb(0x204080);
+    panel.fill(" ", blue, 0)?;
+    panel.putstr_yx(0, 1, "floating panel")?;
+    rsleep![nc, 1];
+
+    // dragged towards the sidebar
+    for x in (20..50).rev() {
+        panel.move_yx(6, x)?;
+        rsleep![nc, 0, 30];
+    }
+
+    // reparented, kept within the sidebar, and raised, in a single call
+    panel.dock_into(sidebar, NcDockPosition::Top)?;
+    panel.putstr_yx(0, 1, "docked panel  ")?;
+    rsleep![nc, 1];
+
+    // moving the sidebar moves the docked panel along
+    for y in 0..6 {
+        sidebar.move_yx(y, 0)?;
+        rsleep![nc, 0, 100];
+    }
+    rsleep![nc, 1];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! double buffering with two piles: while one is shown, the next frame
+//! is drawn and rendered in the other one
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    let mut buffers = [
+        NcPlane::new(nc, 0, 0, trows, tcols)?,
+        NcPlane::new(nc, 0, 0, trows, tcols)?,
+    ];
+
+    let draw = |plane: &mut NcPlane, frame: u32| -> NcResult<()> {
+        plane.erase();
+        let x = frame % tcols;
+        plane.putstr_yx(trows / 2, x, "●")?;
+        plane.putstr_yx(0, 0, &format!["frame {}", frame])?;
+        Ok(())
+    };
+
+    draw(buffers[0], 0)?;
+    nc.render_pile(buffers[0])?;
+    for frame in 1..200 {
+        let back = frame as usize % 2;
+        let front = 1 - back;
+        // shows the frame rendered in the last iteration
+        nc.rasterize_pile(buffers[front])?;
+        // and prepares the next one in the other pile
+        draw(buffers[back], frame)?;
+        nc.render_pile(buffers[back])?;
+        sleep![0, 20];
+    }
+
+    for plane in buffers.iter_mut() {
+        plane.destroy()?;
+    }
+    nc.stop()?;
+    Ok(())
+}
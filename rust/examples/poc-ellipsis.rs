@@ -0,0 +1,26 @@
+//! renders a table of long filenames, truncated to fit their column
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let files = [
+        ("README.md", "4.1K"),
+        ("a_very_long_filename_that_does_not_fit.tar.gz", "112M"),
+        ("写真_2021年の夏休み_海辺の家族.jpg", "3.2M"),
+        ("notes.txt", "210B"),
+    ];
+
+    let name_cols = 24;
+    for (row, (name, size)) in files.iter().enumerate() {
+        stdplane.cursor_move_yx(row as NcDimension, 0)?;
+        stdplane.putstr_ellipsis(name_cols, name)?;
+        stdplane.putstr_yx(row as NcDimension, name_cols + 2, size)?;
+    }
+
+    rsleep![nc, 3, 0];
+    nc.stop()?;
+    Ok(())
+}
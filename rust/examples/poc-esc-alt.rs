@@ -0,0 +1,39 @@
+//! tells a lone Esc apart from an Esc followed by another key, which some
+//! terminals send for Alt combinations (q to quit)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+    plane.putstr_yx(0, 0, "press Esc, Alt+key, or any key (q to quit)")?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let c = nc.getc_nblocking(&mut input);
+        let description = match c {
+            'q' => break,
+            NCKEY_ESC => {
+                // give the rest of an escape sequence the time to arrive
+                sleep![0, 50];
+                match nc.peek_input()? {
+                    Some(next) if next.id != NCKEY_ESC as u32 => {
+                        let next_c = nc.getc_nblock(&mut input);
+                        format!["Alt+{}", next_c]
+                    }
+                    _ => "lone Esc".to_string(),
+                }
+            }
+            c if input.alt => format!["Alt+{} (reported by notcurses)", c],
+            c => format!["key {}", c],
+        };
+        plane.erase();
+        plane.putstr_yx(0, 0, "press Esc, Alt+key, or any key (q to quit)")?;
+        plane.putstr_yx(2, 0, &description)?;
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! flattens a scene made of three layers (a sky, some hills and a label
+//! with a transparent background) into a single plane
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (rows, cols) = (12, 40);
+
+    // the layers live in their own piles, so they are not rendered
+    let sky = NcPlane::new(nc, 0, 0, rows, cols)?;
+    for y in 0..rows {
+        let t = y as f64 / rows as f64;
+        let lerp = |from: f64, to: f64| (from + (to - from) * t) as NcColor;
+        sky.set_bg_rgb8(lerp(16.0, 240.0), lerp(32.0, 128.0), lerp(96.0, 64.0));
+        sky.putstr_yx(y, 0, &" ".repeat(cols as usize))?;
+    }
+
+    let hills = NcPlane::new(nc, 6, 0, rows - 6, cols)?;
+    let mut green: NcChannelPair = 0;
+    green.set_fg_rgb(0x206020);
+    hills.set_channels(green);
+    for x in 0..cols {
+        let height = (3.0 + 2.0 * (x as f64 / 5.0).sin()) as NcDimension;
+        for y in (rows - 6 - height)..(rows - 6) {
+            hills.putstr_yx(y, x, "█")?;
+        }
+    }
+
+    let label = NcPlane::new(nc, 2, 12, 1, 16)?;
+    label.set_bg_alpha(NCCELL_ALPHA_TRANSPARENT)?;
+    label.set_fg_rgb(0xffffff);
+    label.putstr_yx(0, 0, "a flat landscape")?;
+
+    // the flattened scene is shown in the standard pile
+    let scene = NcPlane::new_bound(nc.stdplane()?, 0, 0, rows, cols)?;
+    NcPlane::composite(scene, &[&*sky, &*hills, &*label])?;
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
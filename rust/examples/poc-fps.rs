@@ -0,0 +1,28 @@
+//! renders a live FPS readout in the top right corner
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let mut rate = NcStatsRate::new(nc);
+
+    for frame in 0..300_u32 {
+        stdplane.putstr_yx(0, 0, &format!["frame {}", frame])?;
+        nc.render()?;
+
+        if rate.elapsed().as_millis() >= 500 {
+            rate.update(nc);
+            let readout = format![
+                "{:6.1} fps {:8.0} B/s",
+                rate.fps(),
+                rate.throughput_bytes_per_sec()
+            ];
+            stdplane.putstr_aligned_at(0, NCALIGN_RIGHT, &readout)?;
+        }
+        sleep![0, 10];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
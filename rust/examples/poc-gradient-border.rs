@@ -0,0 +1,53 @@
+//! highlights the focused panel with a glowing gradient border,
+//! press tab to switch the focus and q to quit
+
+use libnotcurses_sys::*;
+
+fn channels(fg: NcRgb) -> NcChannelPair {
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(fg);
+    channels.set_bg_rgb(0x101010);
+    channels
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let (rows, cols) = stdplane.dim_yx();
+    let width = cols / 2;
+    let left = NcPlane::new_bound(stdplane, 0, 0, rows, width)?;
+    let right = NcPlane::new_bound(stdplane, 0, width as NcOffset, rows, cols - width)?;
+    left.putstr_yx(1, 2, "left panel")?;
+    right.putstr_yx(1, 2, "right panel")?;
+
+    let glow = (
+        channels(0xff00ff),
+        channels(0x00ffff),
+        channels(0x0080ff),
+        channels(0xffff00),
+    );
+    let dim = channels(0x404040);
+
+    let mut focus_left = true;
+    let mut input = NcInput::new_empty();
+    loop {
+        let (focused, unfocused) = if focus_left {
+            (&mut *left, &mut *right)
+        } else {
+            (&mut *right, &mut *left)
+        };
+        focused.perimeter_gradient(glow.0, glow.1, glow.2, glow.3, NCSTYLE_BOLD, 0)?;
+        unfocused.perimeter_gradient(dim, dim, dim, dim, NCSTYLE_NONE, 0)?;
+        nc.render()?;
+
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            '\t' => focus_left = !focus_left,
+            _ => (),
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
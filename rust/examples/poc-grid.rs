@@ -0,0 +1,24 @@
+//! builds a character grid, cell by cell
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+
+    let glyphs = ['·', '+', '#', '字'];
+    for row in 0..8 {
+        plane.cursor_move_yx(row, 0)?;
+        for col in 0..16 {
+            let ch = glyphs[((row + col) % glyphs.len() as u32) as usize];
+            let mut cell = NcCell::with_char(ch, plane);
+            cell.set_fg_rgb(0x406080 + row * 0x100000 + col * 0x0800);
+            plane.putc(&cell)?;
+        }
+        plane.putchar('|')?;
+    }
+
+    rsleep![nc, 2];
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! moves a ship with the arrow keys, faster while a key is held
+//! (q to quit)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, "arrows to move, hold to speed up, q to quit")?;
+    let ship = NcPlane::new_bound(stdplane, trows as NcOffset / 2, tcols as NcOffset / 2, 1, 1)?;
+    ship.putchar('▲')?;
+    nc.render()?;
+
+    let mut tracker = NcKeyRepeatTracker::new();
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == 'q' {
+            break;
+        }
+        let (dy, dx) = match key {
+            NCKEY_UP => (-1, 0),
+            NCKEY_DOWN => (1, 0),
+            NCKEY_LEFT => (0, -1),
+            NCKEY_RIGHT => (0, 1),
+            _ => continue,
+        };
+        // a press moves one cell, a held key accelerates
+        let speed = match tracker.process(&input) {
+            Some(NcKeyRepeat::Repeated) => 3,
+            _ => 1,
+        };
+        let (y, x) = ship.yx();
+        let y = (y + dy * speed).max(1).min(trows as NcOffset - 1);
+        let x = (x + dx * speed).max(0).min(tcols as NcOffset - 1);
+        ship.move_yx(y, x)?;
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! colors the keywords, numbers and strings of some lines of code
+
+use libnotcurses_sys::*;
+
+const CODE: &[&str] = &[
+    "fn main() {",
+    "    let answer = 42;",
+    "    let name = \"world\";",
+    "    if answer > 0 {",
+    "        println!(\"hello {}\", name);",
+    "    }",
+    "}",
+];
+
+const KEYWORDS: &[&str] = &["fn", "let", "if", "else", "return"];
+
+fn classify(token: &str) -> (NcChannelPair, NcStyleMask) {
+    let mut channels: NcChannelPair = 0;
+    let trimmed = token.trim_end_matches(|c| c == ';' || c == ',' || c == ')');
+    if KEYWORDS.contains(&token) {
+        channels.set_fg_rgb(0xc678dd);
+        (channels, NCSTYLE_BOLD)
+    } else if trimmed.parse::<f64>().is_ok() {
+        channels.set_fg_rgb(0xd19a66);
+        (channels, 0)
+    } else if token.contains('"') {
+        channels.set_fg_rgb(0x98c379);
+        (channels, NCSTYLE_ITALIC)
+    } else {
+        (channels, 0)
+    }
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+
+    for (y, line) in CODE.iter().enumerate() {
+        plane.cursor_move_yx(y as NcDimension + 1, 2)?;
+        plane.putstr_tokens(line, classify)?;
+    }
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! a clock that only renders when the displayed second changes (q to quit)
+
+use libnotcurses_sys::*;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let mut tracker = NcDamageTracker::new(nc.stdplane()?);
+
+    let mut input = NcInput::new_empty();
+    let (mut renders, mut loops) = (0u64, 0u64);
+    let mut last_second = u64::MAX;
+    loop {
+        loops += 1;
+        let second = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        if second != last_second {
+            last_second = second;
+            let (h, m, s) = (second / 3600 % 24, second / 60 % 60, second % 60);
+            tracker.putstr_yx(1, 2, &format!["UTC {:02}:{:02}:{:02}", h, m, s])?;
+        }
+
+        match nc.getc_nblock(&mut input) {
+            'q' => break,
+            NCKEY_RESIZE => tracker.mark_all_dirty(),
+            _ => (),
+        }
+
+        if tracker.is_dirty() {
+            renders += 1;
+            tracker.putstr_yx(3, 2, &format!["{} renders in {} loops", renders, loops])?;
+        }
+        tracker.render_damaged()?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! renders an indexed-color sprite, cycling its 8 color palette
+
+use libnotcurses_sys::*;
+
+// a 16x16 invader, each digit is an index into the palette
+const SPRITE: &[&str] = &[
+    "0000000000000000",
+    "0000100000100000",
+    "0000010001000000",
+    "0000222222200000",
+    "0002233233220000",
+    "0022222222222000",
+    "0020222222202000",
+    "0020200000202000",
+    "0000044044000000",
+    "0000000000000000",
+    "0005555555550000",
+    "0056666666665000",
+    "0056777777765000",
+    "0056666666665000",
+    "0005555555550000",
+    "0000000000000000",
+];
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+
+    let data: Vec<u8> = SPRITE
+        .iter()
+        .flat_map(|row| row.bytes().map(|b| b - b'0'))
+        .collect();
+    let mut palette: [NcRgb; 8] = [
+        0x000000, 0xff4040, 0x40ff40, 0xffffff, 0xffd000, 0x4040ff, 0x8080ff, 0xc0c0ff,
+    ];
+
+    for _ in 0..24 {
+        let visual = NcVisual::from_palidx(&data, 16, 16, 16, 8, 1, &palette)?;
+        let options = NcVisualOptions::without_plane(1, 2, 0, 0, 0, 0, NCBLIT_2x1, 0);
+        let plane = visual.render(nc, &options)?;
+        rsleep![nc, 0, 150];
+        plane.destroy()?;
+        visual.destroy();
+
+        // rotate the colors of the body, keeping the background
+        palette[1..].rotate_left(1);
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! dispatches keybindings declared in a lookup table
+
+use std::collections::HashMap;
+
+use libnotcurses_sys::*;
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Save,
+    Open,
+    Find,
+    Help,
+    Redraw,
+    Quit,
+}
+
+fn main() -> NcResult<()> {
+    let mut bindings = HashMap::new();
+    bindings.insert(NcKeyCombo::key(NCKEY_UP), Action::Up);
+    bindings.insert(NcKeyCombo::key(NCKEY_DOWN), Action::Down);
+    bindings.insert(NcKeyCombo::key(NCKEY_LEFT), Action::Left);
+    bindings.insert(NcKeyCombo::key(NCKEY_RIGHT), Action::Right);
+    bindings.insert(NcKeyCombo::key(NCKEY_HOME), Action::Top);
+    bindings.insert(NcKeyCombo::key(NCKEY_END), Action::Bottom);
+    bindings.insert(NcKeyCombo::ctrl('s'), Action::Save);
+    bindings.insert(NcKeyCombo::ctrl('o'), Action::Open);
+    bindings.insert(NcKeyCombo::ctrl('f'), Action::Find);
+    bindings.insert(NcKeyCombo::key(NCKEY_F01), Action::Help);
+    bindings.insert(NcKeyCombo::ctrl('l'), Action::Redraw);
+    bindings.insert(NcKeyCombo::ctrl('q'), Action::Quit);
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, "press some keys (Ctrl+Q to quit)")?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        nc.getc_nblocking(&mut input);
+        let action = NcKeyCombo::from_input(&input).and_then(|combo| bindings.get(&combo));
+
+        stdplane.erase();
+        stdplane.putstr_yx(0, 0, "press some keys (Ctrl+Q to quit)")?;
+        match action {
+            Some(Action::Quit) => break,
+            Some(action) => stdplane.putstr_yx(2, 0, &format!["action: {:?}", action])?,
+            None => stdplane.putstr_yx(2, 0, "unbound key")?,
+        };
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
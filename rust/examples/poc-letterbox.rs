@@ -0,0 +1,39 @@
+//! plays a video letterboxed into a 4:3 plane
+//!
+//! usage: cargo run --example poc-letterbox -- <16:9 video>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let (trows, _) = nc.term_dim_yx();
+
+    // cells are about twice as tall as wide
+    let rows = trows - 2;
+    let cols = rows * 2 * 4 / 3;
+    let frame = NcPlane::new_bound(nc.stdplane()?, 1, 0, rows, cols)?;
+
+    let mut bars: NcChannelPair = 0;
+    bars.set_bg_rgb(0x202020);
+
+    let visual = NcVisual::from_file(&file)?;
+    let mut input = NcInput::new_empty();
+    loop {
+        visual.render_letterboxed(nc, frame, bars)?;
+        rsleep![nc, 0, 40];
+
+        if nc.getc_nblock(&mut input) == 'q' {
+            break;
+        }
+        // a still image just loops over its single frame
+        visual.decode_loop()?;
+    }
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! a centered "Loading…" message, with animated dots
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let splash = NcPlane::new_bound(nc.stdplane()?, 0, 0, nc.term_dim_yx().0, nc.term_dim_yx().1)?;
+
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0xe0c060);
+
+    for n in 0..12 {
+        splash.erase();
+        // the width stays the same, so the text doesn't shift
+        let dots = "…".repeat(n % 4) + &" ".repeat(3 - n % 4);
+        splash.center_text(&format!["Loading{}", dots], channels, NCSTYLE_BOLD)?;
+        rsleep![nc, 0, 300];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
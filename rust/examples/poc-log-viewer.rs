@@ -0,0 +1,41 @@
+//! a scrollable log viewer, retaining the lines scrolled off the top
+//!
+//! Up & Down scroll one line, PgUp & PgDown scroll a page,
+//! End goes to the newest line, and q quits.
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (rows, cols) = nc.term_dim_yx();
+    let plane = NcPlane::new(nc, 0, 0, rows, cols)?;
+    let mut log = NcScrollPlane::new(plane, 1000);
+
+    for n in 0..200 {
+        if n % 10 == 0 {
+            log.plane().set_fg_rgb(0xffcc00);
+        } else {
+            log.plane().set_fg_default();
+        }
+        log.push_line(&format!["[{:03}] something happened", n])?;
+    }
+    nc.render()?;
+
+    let page = rows as usize;
+    let mut input = NcInput::new_empty();
+    loop {
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            NCKEY_UP => log.scroll_back(1)?,
+            NCKEY_DOWN => log.scroll_forward(1)?,
+            NCKEY_PGUP => log.scroll_back(page)?,
+            NCKEY_PGDOWN => log.scroll_forward(page)?,
+            NCKEY_END => log.scroll_to_bottom()?,
+            _ => continue,
+        }
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
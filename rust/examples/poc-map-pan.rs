@@ -0,0 +1,54 @@
+//! pans around a large procedurally drawn map with the arrow keys (q to quit)
+
+use libnotcurses_sys::*;
+
+const MAP_ROWS: NcDimension = 120;
+const MAP_COLS: NcDimension = 400;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    // the content lives in its own pile, which is never rendered
+    let map = NcPlane::new(nc, 0, 0, MAP_ROWS, MAP_COLS)?;
+    for y in 0..MAP_ROWS {
+        for x in 0..MAP_COLS {
+            let height = ((x as f64 / 17.0).sin() + (y as f64 / 9.0).cos()) / 2.0;
+            let (glyph, rgb) = match height {
+                h if h < -0.3 => ('~', 0x2060c0),
+                h if h < 0.0 => ('.', 0xc0b060),
+                h if h < 0.5 => ('"', 0x40a040),
+                _ => ('^', 0x808080),
+            };
+            map.set_fg_rgb(rgb);
+            map.putchar_yx(y, x, glyph)?;
+        }
+    }
+
+    let view = NcPlane::new_bound(nc.stdplane()?, 1, 0, trows - 1, tcols)?;
+    let mut viewport = NcViewport::new(view, map)?;
+    let stdplane = nc.stdplane()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let (y, x) = viewport.offset_yx();
+        stdplane.erase();
+        stdplane.putstr_yx(0, 0, &format!["offset: {}, {}", y, x])?;
+        nc.render()?;
+
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            NCKEY_UP => viewport.scroll_by(-1, 0)?,
+            NCKEY_DOWN => viewport.scroll_by(1, 0)?,
+            NCKEY_LEFT => viewport.scroll_by(0, -2)?,
+            NCKEY_RIGHT => viewport.scroll_by(0, 2)?,
+            NCKEY_PGUP => viewport.scroll_by(-(trows as NcOffset), 0)?,
+            NCKEY_PGDOWN => viewport.scroll_by(trows as NcOffset, 0)?,
+            NCKEY_HOME => viewport.scroll_to(0, 0)?,
+            _ => (),
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! renders a help screen formatted with markup tags
+
+use libnotcurses_sys::*;
+
+const HELP: &[&str] = &[
+    "<b><u>Help</u></b>",
+    "",
+    "<b><fg=ffcc00>q</fg></b>      quit",
+    "<b><fg=ffcc00>h j k l</fg></b>  move <i>left, down, up, right</i>",
+    "<b><fg=ffcc00>/</fg></b>      search, <i>e.g.</i> /<u>pattern</u>",
+    "<b><fg=ffcc00>?</fg></b>      show this help",
+    "",
+    "<bg=600000><fg=ffffff> warning </fg></bg> unsaved changes are <b>lost</b> on quit",
+];
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 1, 2, HELP.len() as NcDimension, 60)?;
+
+    for (y, line) in HELP.iter().enumerate() {
+        plane.cursor_move_yx(y as NcDimension, 0)?;
+        plane.putmarkup(line)?;
+    }
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
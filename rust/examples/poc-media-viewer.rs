@@ -0,0 +1,47 @@
+//! opens any media file and shows it: images are displayed, animations loop
+//! and videos play once (space to pause, s to stop, q to quit)
+//!
+//! usage: cargo run --example poc-media-viewer -- <file>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+    let plane = NcPlane::new_bound(nc.stdplane()?, 1, 0, trows - 1, tcols)?;
+
+    let mut media = match nc.display_media(&file, plane) {
+        Ok(media) => media,
+        Err(err) => {
+            nc.stop()?;
+            eprintln!["{}", err];
+            return Ok(());
+        }
+    };
+    nc.stdplane()?
+        .putstr_yx(0, 0, &format!["{} ({:?})", file, media.kind()])?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        match nc.getc_nblock(&mut input) {
+            'q' => break,
+            ' ' if media.is_playing() => media.pause(),
+            ' ' => media.play()?,
+            's' => media.stop(),
+            _ => (),
+        }
+        if media.update(nc)? {
+            nc.render()?;
+        }
+        sleep![0, 5];
+    }
+
+    drop(media);
+    nc.stop()?;
+    Ok(())
+}
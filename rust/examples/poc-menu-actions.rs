@@ -0,0 +1,54 @@
+//! maps the menu items to the actions of an application
+
+use libnotcurses_sys::*;
+
+#[derive(Debug)]
+enum Action {
+    New,
+    Open,
+    Quit,
+    About,
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+    let plane = nc.stdplane()?;
+
+    let mut menu = NcActionMenu::new(
+        plane,
+        vec![
+            NcActionSection::new("File", NcInput::with_alt('f'))
+                .item("New", NcInput::with_ctrl('n'), Action::New)
+                .item("Open", NcInput::with_ctrl('o'), Action::Open)
+                .separator()
+                .item("Quit", NcInput::with_ctrl('q'), Action::Quit),
+            NcActionSection::new("Help", NcInput::with_alt('h')).item(
+                "About",
+                NcInput::with_ctrl('a'),
+                Action::About,
+            ),
+        ],
+    )?;
+    plane.putstr_yx(2, 0, "Alt+F opens the menu, Ctrl+Q quits")?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        nc.getc_nblocking(&mut input);
+        let action = menu.selected(&input);
+        match action {
+            Some(Action::Quit) => break,
+            Some(action) => {
+                let text = format!["last action: {:<10}", format!["{:?}", action]];
+                plane.putstr_yx(4, 0, &text)?;
+            }
+            None => (),
+        }
+        nc.render()?;
+    }
+
+    menu.destroy()?;
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! a menu bar with custom colors, placed at the bottom row
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+
+    let mut file_items = [
+        NcMenuItem::new("New", NcInput::with_ctrl('n')),
+        NcMenuItem::new("Open", NcInput::with_ctrl('o')),
+        NcMenuItem::new_empty(),
+        NcMenuItem::new("Quit", NcInput::with_ctrl('q')),
+    ];
+    let mut help_items = [NcMenuItem::new("About", NcInput::with_ctrl('a'))];
+
+    let mut sections = [
+        NcMenuSection::new("File", &mut file_items, NcInput::with_alt('f')),
+        NcMenuSection::new("Help", &mut help_items, NcInput::with_alt('h')),
+    ];
+
+    let mut header_channels: NcChannelPair = 0;
+    header_channels.set_fg_rgb(0xffcc00);
+    header_channels.set_bg_rgb(0x1a1a40);
+    let mut section_channels: NcChannelPair = 0;
+    section_channels.set_fg_rgb(0xe0e0e0);
+    section_channels.set_bg_rgb(0x303070);
+
+    let mopts = NcMenuOptions::new(&mut sections)
+        .set_header_channels(header_channels)
+        .set_section_channels(section_channels)
+        .set_bottom(true);
+
+    let plane = nc.stdplane()?;
+    let menu = NcMenu::new(plane, mopts)?;
+    menu.unroll(0)?;
+    rsleep![nc, 1];
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == NCKEY_BUTTON1 {
+            let mut shortcut = NcInput::new_empty();
+            if let Some(item) = menu.mouse_selected(&input, &mut shortcut) {
+                plane.putstr_yx(0, 0, &format!["clicked: {:<20}", item])?;
+            }
+        }
+        if key == 'q' {
+            break;
+        }
+        menu.offer_input(input);
+        nc.render()?;
+    }
+
+    menu.destroy()?;
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! assembles a mosaic from the quadrants of an image, swapped around
+//! (pass the path of another image to try it)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let visual = NcVisual::from_file(&path)?;
+    let options = NcVisualOptions::without_plane(0, 0, 0, 0, 0, 0, NCBLIT_2x1, 0);
+    let (px_rows, px_cols, _, _) = visual.geom(nc, &options)?;
+
+    // each quadrant, in pixels, and in cells with the 2x1 blitter
+    let (quad_h, quad_w) = (px_rows / 2, px_cols / 2);
+    let (cell_h, cell_w) = ((quad_h + 1) / 2, quad_w);
+
+    let plane = NcPlane::new_bound(nc.stdplane()?, 1, 2, cell_h * 2 + 1, cell_w * 2 + 2)?;
+    // the quadrants are placed diagonally opposite, with a gap between them
+    for &(qy, qx) in &[(0, 0), (0, 1), (1, 0), (1, 1)] {
+        let crop = NcRect::new(qy * quad_h, qx * quad_w, quad_h, quad_w);
+        let dst = ((1 - qy) * (cell_h + 1), (1 - qx) * (cell_w + 2));
+        visual.blit_region(plane, crop, dst, NCBLIT_2x1)?;
+    }
+
+    rsleep![nc, 3];
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! a plane that can be dragged with the mouse, double click to maximize it
+//! (q to quit)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    let (rows, cols) = (6, 24);
+    let window = NcPlane::new_bound(nc.stdplane()?, 2, 4, rows, cols)?;
+    let draw = |window: &mut NcPlane| -> NcResult<()> {
+        window.erase();
+        let mut channels: NcChannelPair = 0;
+        channels.set_bg_rgb(0x204080);
+        window.set_base(' ', 0, channels)?;
+        window.putstr_yx(0, 1, "drag me, double click")?;
+        Ok(())
+    };
+    draw(window)?;
+    nc.render()?;
+
+    let mut tracker = NcMouseTracker::new();
+    let mut grab = None;
+    let mut maximized = false;
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == 'q' {
+            break;
+        }
+        match tracker.process(&input) {
+            Some(NcMouseGesture::DragStart { y, x, .. }) => {
+                let (wy, wx) = window.yx();
+                grab = Some((y as NcOffset - wy, x as NcOffset - wx));
+            }
+            Some(NcMouseGesture::Drag { y, x, .. }) => {
+                if let Some((gy, gx)) = grab {
+                    window.move_yx(y as NcOffset - gy, x as NcOffset - gx)?;
+                }
+            }
+            Some(NcMouseGesture::DragEnd { y, x, .. }) => {
+                if let Some((gy, gx)) = grab.take() {
+                    window.move_yx(y as NcOffset - gy, x as NcOffset - gx)?;
+                }
+            }
+            Some(NcMouseGesture::DoubleClick { .. }) => {
+                maximized = !maximized;
+                if maximized {
+                    window.resize_simple(trows, tcols)?;
+                    window.move_yx(0, 0)?;
+                } else {
+                    window.resize_simple(rows, cols)?;
+                    window.move_yx(2, 4)?;
+                }
+                draw(window)?;
+            }
+            _ => continue,
+        }
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
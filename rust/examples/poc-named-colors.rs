@@ -0,0 +1,45 @@
+//! styles a status line from a config that sets its colors by name,
+//! reporting the entries with unknown colors
+
+use libnotcurses_sys::*;
+
+const CONFIG: &str = "
+    title.fg = White
+    title.bg = RebeccaPurple
+    status.fg = black
+    status.bg = lightgoldenrodyellow
+    warning.fg = orangered
+    warning.bg = bluish
+";
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let mut y = 0;
+    for line in CONFIG.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let (key, name) = match line.find('=') {
+            Some(i) => (line[..i].trim(), line[i + 1..].trim()),
+            None => continue,
+        };
+
+        let result = if key.ends_with(".fg") {
+            stdplane.set_fg_named(name)
+        } else {
+            stdplane.set_bg_named(name)
+        };
+        match result {
+            Ok(()) => stdplane.putstr_yx(y, 2, &format!["{:<12} {:<22}", key, name])?,
+            Err(err) => {
+                stdplane.set_fg_default();
+                stdplane.set_bg_default();
+                stdplane.putstr_yx(y, 2, &format!["{:<12} {}", key, err.msg])?
+            }
+        };
+        y += 1;
+    }
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! pre-renders a complex scene in an off-screen pile while a loading message
+//! is shown, and then moves it into the standard pile to show it at once
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let (rows, cols) = nc.term_dim_yx();
+
+    stdplane.putstr_aligned_at(rows / 2, NCALIGN_CENTER, "drawing the scene…")?;
+    nc.render()?;
+
+    // the new plane is the root of its own pile, which is not rendered
+    let offscreen = NcPlane::new(nc, 0, 0, rows, cols)?;
+    let scene = NcPlane::new_bound(offscreen, 0, 0, rows, cols)?;
+    for y in 0..rows {
+        for x in 0..cols {
+            let mut channels: NcChannelPair = 0;
+            channels.set_bg_rgb8((x * 255 / cols) as u8, (y * 255 / rows) as u8, 0x60);
+            scene.set_channels(channels);
+            scene.putchar_yx(y, x, ' ')?;
+        }
+        sleep![0, 10];
+    }
+    let label = NcPlane::new_bound(scene, rows as NcOffset / 2, 2, 1, 20)?;
+    label.putstr("the finished scene")?;
+
+    stdplane.erase();
+    scene.move_to_pile(stdplane)?;
+    nc.render()?;
+    sleep![2];
+
+    // and back off-screen
+    scene.move_to_pile(offscreen)?;
+    stdplane.putstr_aligned_at(rows / 2, NCALIGN_CENTER, "the scene is gone")?;
+    rsleep![nc, 1];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! animates a color with an elastic easing that overshoots the range of the
+//! components, which are clipped instead of wrapping to a wrong color
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let (from, to) = ((40.0, 40.0, 200.0), (250.0, 120.0, 20.0));
+    let frames = 90;
+    for frame in 0..=frames {
+        // an elastic ease out, overshooting the target before settling
+        let t = frame as f64 / frames as f64;
+        let e = 1.0 - (-6.0 * t).exp() * (12.0 * t).cos();
+        let lerp = |a: f64, b: f64| (a + (b - a) * e).round() as i32;
+
+        let clipped = stdplane.set_fg_rgb8_clipped(
+            lerp(from.0, to.0),
+            lerp(from.1, to.1),
+            lerp(from.2, to.2),
+        );
+        stdplane.putstr_yx(1, 2, "████████████████")?;
+        stdplane.set_fg_default();
+        stdplane.putstr_yx(2, 2, if clipped { "clipped " } else { "in range" })?;
+        rsleep![nc, 0, 30];
+    }
+    sleep![1];
+
+    nc.stop()?;
+    Ok(())
+}
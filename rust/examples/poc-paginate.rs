@@ -0,0 +1,44 @@
+//! paginates a long document across screens (any key for the next page)
+
+use libnotcurses_sys::*;
+
+const PARAGRAPH: &str = "Notcurses is a library facilitating complex TUIs on \
+    modern terminal emulators, supporting vivid colors, multimedia, threads, \
+    and Unicode to the maximum degree possible. Things can be done with \
+    Notcurses that simply can't be done with NCURSES. 漢字 and emoji 🦀 \
+    are wide glyphs, which are never split across the edge of the screen.";
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+    let (rows, _) = plane.dim_yx();
+
+    let document = [PARAGRAPH; 12].join("\n\n");
+    let mut rest = document.as_str();
+    let mut page = 1;
+    let mut input = NcInput::new_empty();
+    while !rest.is_empty() {
+        plane.erase();
+        // the last row is for the footer
+        for y in 0..rows - 1 {
+            plane.cursor_move_yx(y, 0)?;
+            let (_, consumed) = plane.putstr_partial(rest)?;
+            rest = &rest[consumed..];
+            if let Some(stripped) = rest.strip_prefix('\n') {
+                rest = stripped;
+            }
+            if rest.is_empty() {
+                break;
+            }
+        }
+        plane.putstr_yx(rows - 1, 0, &format!["-- page {} --", page])?;
+        nc.render()?;
+        if nc.getc_nblocking(&mut input) == 'q' {
+            break;
+        }
+        page += 1;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
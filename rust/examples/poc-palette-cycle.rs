@@ -0,0 +1,42 @@
+//! a gradient bar animated only by cycling the palette, without redrawing
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (_, tcols) = nc.term_dim_yx();
+    let cols = tcols.min(64);
+
+    // a fiery gradient in the entries 16 to 47
+    let colors: Vec<NcRgb> = (0..32)
+        .map(|i| {
+            let t = (i as f32 / 31.0 * core::f32::consts::PI).sin();
+            let r = 0x40 + (0xbf as f32 * t) as u32;
+            let g = (0xa0 as f32 * t * t) as u32;
+            (r << 16) | (g << 8)
+        })
+        .collect();
+    let palette = NcPalette::new(nc);
+    palette.set_range(16, &colors);
+    palette.r#use(nc)?;
+
+    // the cells are drawn once, with palette indexed backgrounds
+    let bar = NcPlane::new_bound(nc.stdplane()?, 1, 2, 3, cols)?;
+    for x in 0..cols {
+        let mut cell = NcCell::with_char(' ', bar);
+        cell.set_bg_palindex(16 + (x % 32) as NcPaletteIndex);
+        for y in 0..3 {
+            bar.putc_yx(y, x, &cell)?;
+        }
+    }
+
+    for _ in 0..96 {
+        palette.cycle(16..48, 1);
+        palette.r#use(nc)?;
+        rsleep![nc, 0, 50];
+    }
+
+    palette.free();
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! loads a color theme into the first 16 palette entries, and shows them
+//! (pass the path of another palette file to try it)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "solarized.palette".to_string());
+
+    let nc = Notcurses::new()?;
+    let palette = NcPalette::load(nc, &path)?;
+    palette.r#use(nc)?;
+
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 2, &format!["theme: {}", path])?;
+    for index in 0..16 {
+        let mut cell = NcCell::with_char(' ', stdplane);
+        cell.set_bg_palindex(index as NcPaletteIndex);
+        let (y, x) = (2 + index / 8 * 2, 2 + index % 8 * 6);
+        for dx in 0..5 {
+            stdplane.putc_yx(y, x + dx, &cell)?;
+        }
+        let (r, g, b) = palette.get_rgb8(index as NcPaletteIndex);
+        stdplane.putstr_yx(y + 1, x, &format!["{:02X}{:02X}{:02X}", r, g, b])?;
+    }
+    rsleep![nc, 3];
+
+    palette.free();
+    nc.stop()?;
+    Ok(())
+}
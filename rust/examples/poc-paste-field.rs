@@ -0,0 +1,45 @@
+//! a text field that receives a paste as a single event
+//! (ctrl+d to quit)
+//!
+//! The terminal input is read by the NcPasteDecoder, since the escape
+//! handling of notcurses would consume the paste markers.
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.paste_enable()?;
+
+    let field = NcPlane::new_bound(nc.stdplane()?, 1, 2, 4, 60)?;
+    let mut text = String::new();
+    let mut status = String::from("type or paste something");
+    let mut decoder = NcPasteDecoder::new();
+
+    'input: loop {
+        field.erase();
+        field.putstr_yx(0, 0, "> ")?;
+        field.putstr(&text)?;
+        field.putstr_yx(2, 0, &status)?;
+        nc.render()?;
+
+        for event in decoder.read_input(None)? {
+            match event {
+                NcInputEvent::Key('\u{4}') => break 'input,
+                NcInputEvent::Key('\u{7f}') => {
+                    text.pop();
+                }
+                NcInputEvent::Key(c) if !c.is_control() => text.push(c),
+                NcInputEvent::Key(_) => (),
+                NcInputEvent::Paste(paste) => {
+                    status = format!["pasted {} characters in one event", paste.chars().count()];
+                    text.extend(paste.chars().map(|c| if c.is_control() { ' ' } else { c }));
+                }
+            }
+        }
+        text.truncate(text.char_indices().nth(56).map_or(text.len(), |(i, _)| i));
+    }
+
+    nc.paste_disable()?;
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+//! prints the pixel graphics protocol detected at startup
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    // some terminals answer the queries only after the initialization
+    nc.render()?;
+    let pixel = nc.check_pixel_support()?;
+
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, &format!["pixel protocol: {:?}", pixel])?;
+    let blitter = if pixel == NcPixelImpl::None {
+        "using cell blitters"
+    } else {
+        "using pixel blitting"
+    };
+    stdplane.putstr_yx(1, 0, blitter)?;
+
+    rsleep![nc, 2, 0];
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! prints the plane hierarchy of a small layout, after stopping notcurses
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.set_name("std");
+
+    let (rows, cols) = stdplane.dim_yx();
+    let header = NcPlane::new_bound(stdplane, 0, 0, 1, cols)?;
+    header.set_name("header");
+    let sidebar = NcPlane::new_bound(stdplane, 1, 0, rows - 2, 20)?;
+    sidebar.set_name("sidebar");
+    let main = NcPlane::new_bound(stdplane, 1, 20, rows - 2, cols - 20)?;
+    main.set_name("main");
+    let dialog = NcPlane::new_bound(main, 2, 4, 6, 30)?;
+    dialog.set_name("dialog");
+    let _button = NcPlane::new_bound(dialog, 4, 20, 1, 8)?;
+    let _footer = NcPlane::new_bound(stdplane, rows as NcOffset - 1, 0, 1, cols)?;
+
+    // a separate pile
+    let offscreen = NcPlane::new(nc, 0, 0, 5, 5)?;
+    offscreen.set_name("offscreen");
+
+    let std_tree = stdplane.pile_tree_string();
+    let all_tree = nc.plane_tree_string();
+    nc.stop()?;
+
+    println!["standard pile:\n{}", std_tree];
+    println!["all the piles:\n{}", all_tree];
+    Ok(())
+}
@@ -0,0 +1,30 @@
+//! static text over a pulsing background
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let (rows, cols) = stdplane.dim_yx();
+    let plane = NcPlane::new_bound(stdplane, 0, 0, rows, cols)?;
+    plane.putstr_yx(rows / 2, cols / 2 - 8, "Notcurses pulses")?;
+
+    // a cycle of increasing and then decreasing blues
+    let colors: Vec<NcChannelPair> = (0..32)
+        .chain((0..32).rev())
+        .map(|i| {
+            let mut channels: NcChannelPair = 0;
+            channels.set_bg_rgb8(0, i * 2, i * 4 + 64);
+            channels
+        })
+        .collect();
+
+    for step in 0..colors.len() * 4 {
+        plane.animate_base(&colors, step)?;
+        rsleep![nc, 0, 30];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! paints a radial gradient on the standard plane
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let (rows, cols) = stdplane.dim_yx();
+
+    // cells are about twice as tall as they are wide
+    let (center_y, center_x) = (rows as f32 / 2.0, cols as f32 / 2.0);
+    let max_dist = (center_x * center_x + (center_y * 2.0).powi(2)).sqrt();
+
+    stdplane.paint_with(|y, x| {
+        let (dy, dx) = ((y as f32 - center_y) * 2.0, x as f32 - center_x);
+        let t = ((dy * dy + dx * dx).sqrt() / max_dist).min(1.0);
+        let level = (255.0 * (1.0 - t)) as NcColor;
+
+        let mut channels: NcChannelPair = 0;
+        channels.set_fg_rgb8(255, 255, 255);
+        channels.set_bg_rgb8(level, level / 2, 255 - level);
+        (channels, ' ', NCSTYLE_NONE)
+    })?;
+
+    rsleep![nc, 3, 0];
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! a search box pre-filled with the last query
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let last_query = "the last query";
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(1, 2, "search:")?;
+
+    let plane = NcPlane::new_bound(stdplane, 1, 10, 1, 30)?;
+    let mut options = NcReaderOptions::new();
+    options.flags = (NCREADER_OPTION_CURSOR | NCREADER_OPTION_HORSCROLL) as u64;
+    let reader = NcReader::with_options(plane, options)?;
+    reader.write_str(last_query)?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == NCKEY_ENTER {
+            break;
+        }
+        reader.offer_input(&input);
+        nc.render()?;
+    }
+
+    let query = reader.contents()?;
+    reader.destroy();
+    nc.stop()?;
+    println!("searching for: {}", query);
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! records several frames to a file, with their timestamps, and then
+//! replays them with the original timing
+
+use std::{
+    ffi::CString,
+    io::Write,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let path = std::env::temp_dir().join("poc-record-frames.rec");
+    let cpath = CString::new(path.to_str().unwrap()).unwrap();
+    let mode = CString::new("w+").unwrap();
+    let mut file = NcFile::from_libc(unsafe { libc::fopen(cpath.as_ptr(), mode.as_ptr()) });
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let options = NcRenderFileOptions::new()
+        .set_append(true)
+        .set_timestamp(true);
+    for n in 0..20 {
+        stdplane.putstr_yx(1, 2 + n, "*")?;
+        stdplane.putstr_yx(2, 2, &format!["recording frame {}", n])?;
+        nc.render()?;
+        nc.render_to_file_opts(&mut file, options)?;
+        sleep(Duration::from_millis(50 + 10 * n as u64));
+    }
+    let recording = file.read_string()?;
+    nc.stop()?;
+
+    // each frame is preceded by a line with its timestamp, in seconds
+    let start = Instant::now();
+    let mut first = None;
+    for frame in recording.split("#ncframe ").skip(1) {
+        let (timestamp, frame) = frame.split_at(frame.find('\n').unwrap_or(0));
+        let timestamp: f64 = timestamp.parse().unwrap_or(0.0);
+        let offset = Duration::from_secs_f64(timestamp - *first.get_or_insert(timestamp));
+        if let Some(wait) = offset.checked_sub(start.elapsed()) {
+            sleep(wait);
+        }
+        print!["{}", &frame[1..]];
+        std::io::stdout().flush().unwrap();
+    }
+    println!["\nreplayed {}", path.display()];
+    Ok(())
+}
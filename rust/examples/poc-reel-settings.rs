@@ -0,0 +1,67 @@
+//! a scrollable settings list, navigated with the arrow keys (q to quit)
+
+use core::ffi::c_void;
+
+use libnotcurses_sys::*;
+
+const SETTINGS: [&str; 8] = [
+    "theme: dark",
+    "font size: 14",
+    "line numbers: on",
+    "word wrap: off",
+    "tab width: 4",
+    "autosave: on",
+    "telemetry: off",
+    "language: en",
+];
+
+unsafe extern "C" fn draw_setting(tablet: *mut NcTablet, _drawfromtop: bool) -> NcIntResult {
+    let tablet = &mut *tablet;
+    let setting = &*(tablet.userptr() as *const &str);
+    match tablet.plane() {
+        Some(plane) if plane.putstr_yx(0, 1, setting).is_ok() => 1,
+        _ => 0,
+    }
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (trows, _) = nc.term_dim_yx();
+    let plane = NcPlane::new_bound(nc.stdplane()?, 1, 2, trows - 2, 30)?;
+
+    let mut focused: NcChannelPair = 0;
+    focused.set_fg_rgb(0xffd700);
+    let options = NcReelOptions::new().set_focused_channels(focused);
+    let reel = NcReel::with_options(plane, options)?;
+
+    let mut last = None;
+    for setting in SETTINGS.iter() {
+        let userptr = setting as *const &str as *mut c_void;
+        last = Some(reel.add(last, None, draw_setting, userptr)?);
+    }
+    reel.redraw()?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        match key {
+            'q' => break,
+            NCKEY_DOWN => {
+                reel.next();
+            }
+            NCKEY_UP => {
+                reel.prev();
+            }
+            _ => {
+                reel.offer_input(&input);
+            }
+        }
+        reel.redraw()?;
+        nc.render()?;
+    }
+
+    reel.destroy();
+    nc.stop()?;
+    Ok(())
+}
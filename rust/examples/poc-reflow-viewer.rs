@@ -0,0 +1,45 @@
+//! a document viewer that keeps its place when the terminal is resized
+//! (up/down/pgup/pgdown to scroll, resize the terminal to try it, q to quit)
+
+use libnotcurses_sys::*;
+
+const PARAGRAPH: &str = "Notcurses is a library facilitating complex TUIs on \
+    modern terminal emulators, supporting vivid colors, multimedia, threads, \
+    and Unicode to the maximum degree possible. Things can be done with \
+    Notcurses that simply can't be done with NCURSES.";
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let document: Vec<String> = (1..=20).map(|n| format!["§{}. {}", n, PARAGRAPH]).collect();
+
+    let (rows, cols) = stdplane.dim_yx();
+    let plane = NcPlane::new_bound(stdplane, 0, 0, rows, cols)?;
+    plane.set_reflow_text(document.join("\n\n"))?;
+    plane.set_reflow_anchored(true)?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        nc.render()?;
+
+        let page = plane.dim_y() as isize;
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            NCKEY_UP => plane.scroll_reflow(-1)?,
+            NCKEY_DOWN => plane.scroll_reflow(1)?,
+            NCKEY_PGUP => plane.scroll_reflow(-page)?,
+            NCKEY_PGDOWN => plane.scroll_reflow(page)?,
+            NCKEY_RESIZE => {
+                let (rows, cols) = nc.term_dim_yx();
+                plane.resize_simple(rows, cols)?;
+                plane.reflow()?;
+            }
+            _ => (),
+        }
+    }
+
+    plane.unset_reflow_text();
+    nc.stop()?;
+    Ok(())
+}
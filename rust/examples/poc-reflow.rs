@@ -0,0 +1,36 @@
+//! a paragraph that reflows as the terminal is resized, resize it to try it
+
+use libnotcurses_sys::*;
+
+const TEXT: &str = "Notcurses is a library facilitating complex TUIs on modern \
+    terminal emulators, supporting vivid colors, multimedia, threads, and \
+    Unicode to the maximum degree possible. Things can be done with Notcurses \
+    that simply can't be done with NCURSES.\n\n(press q to quit)";
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let (rows, cols) = stdplane.dim_yx();
+    let plane = NcPlane::new_bound(stdplane, 0, 0, rows, cols)?;
+    plane.set_reflow_text(TEXT.to_string())?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        nc.render()?;
+
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            NCKEY_RESIZE => {
+                let (rows, cols) = nc.term_dim_yx();
+                plane.resize_simple(rows, cols)?;
+                plane.reflow()?;
+            }
+            _ => (),
+        }
+    }
+
+    plane.unset_reflow_text();
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! skips the writeout of the frames made stale by a newer one
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    // a producer flags every 25ms that a newer frame is due
+    let newer = Arc::new(AtomicBool::new(false));
+    let producer = {
+        let newer = Arc::clone(&newer);
+        thread::spawn(move || {
+            for _ in 0..200 {
+                thread::sleep(Duration::from_millis(25));
+                newer.store(true, Ordering::Release);
+            }
+        })
+    };
+
+    let (mut written, mut skipped) = (0_u32, 0_u32);
+    for frame in 0..200_u32 {
+        newer.store(false, Ordering::Release);
+
+        // a frame that takes about as long to draw as the producer's period
+        for row in 0..10 {
+            stdplane.putstr_yx(row, 0, &format!["frame {} row {}", frame, row])?;
+            sleep![0, 3];
+        }
+        stdplane.putstr_yx(11, 0, &format!["written {} skipped {}", written, skipped])?;
+
+        if nc.render_interruptible(&newer)? {
+            written += 1;
+        } else {
+            skipped += 1;
+        }
+    }
+
+    producer.join().expect("producer thread");
+    rsleep![nc, 1];
+    nc.stop()?;
+    Ok(())
+}
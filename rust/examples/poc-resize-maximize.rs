@@ -0,0 +1,31 @@
+//! a plane that always fills the terminal, resize the terminal to try it
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let (rows, cols) = stdplane.dim_yx();
+    let plane = NcPlane::new_bound(stdplane, 0, 0, rows, cols)?;
+    plane.set_resize_maximize();
+
+    let mut channels: NcChannelPair = 0;
+    channels.set_bg_rgb(0x203040);
+    plane.set_base(' ', 0, channels)?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let (rows, cols) = plane.dim_yx();
+        plane.erase();
+        plane.putstr_yx(0, 0, &format!["{}x{} (press q to quit)", cols, rows])?;
+        nc.render()?;
+
+        if nc.getc_nblocking(&mut input) == 'q' {
+            break;
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! restyles the right half of a plane to bold, keeping its contents
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 1, 1, 6, 40)?;
+    for y in 0..6 {
+        plane.putstr_yx(y, 0, "the quick brown fox jumps over the lazy")?;
+    }
+    rsleep![nc, 1];
+
+    // -1 goes through the bottom and right edges
+    plane.restyle_region(0, 20, -1, -1, NCSTYLE_BOLD)?;
+    rsleep![nc, 1];
+
+    let mut blue: NcChannelPair = 0;
+    blue.set_bg_rgb(0x000080);
+    plane.stain_region(2, 0, 2, -1, blue, blue, blue, blue)?;
+    rsleep![nc, 1];
+
+    plane.erase_region(0, 0, -1, 10)?;
+    rsleep![nc, 2];
+
+    nc.stop()?;
+    Ok(())
+}
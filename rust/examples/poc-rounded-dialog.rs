@@ -0,0 +1,34 @@
+//! draws a dialog framed by a rounded box, over a double framed background
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let (rows, cols) = stdplane.dim_yx();
+
+    let mut frame: NcChannelPair = 0;
+    frame.set_fg_rgb(0x4080c0);
+    stdplane.cursor_move_yx(0, 0)?;
+    stdplane.double_box_sized(0, frame, rows, cols, 0)?;
+
+    let (dialog_rows, dialog_cols) = (7, 36);
+    let dialog = NcPlane::new_bound(
+        stdplane,
+        (rows.saturating_sub(dialog_rows) / 2) as NcOffset,
+        (cols.saturating_sub(dialog_cols) / 2) as NcOffset,
+        dialog_rows,
+        dialog_cols,
+    )?;
+    let mut border: NcChannelPair = 0;
+    border.set_fg_rgb(0xe0c040);
+    dialog.cursor_move_yx(0, 0)?;
+    dialog.rounded_box_sized(NCSTYLE_BOLD, border, dialog_rows, dialog_cols, 0)?;
+    dialog.putstr_aligned_at(0, NCALIGN_CENTER, " Save changes? ")?;
+    dialog.putstr_aligned_at(3, NCALIGN_CENTER, "Your document has unsaved edits.")?;
+    dialog.putstr_aligned_at(5, NCALIGN_CENTER, "[ Save ]   [ Discard ]")?;
+
+    rsleep![nc, 3];
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! computes the histogram of the foreground colors of each row of a plane
+
+use std::collections::BTreeMap;
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 1, 1, 6, 40)?;
+
+    // some highlighted code
+    let code: &[&[(NcRgb, &str)]] = &[
+        &[(0xcc66ff, "fn "), (0x66ccff, "main"), (0xcccccc, "() {")],
+        &[
+            (0xcc66ff, "    let "),
+            (0xcccccc, "x = "),
+            (0xffcc66, "42"),
+            (0xcccccc, ";"),
+        ],
+        &[(0x66cc66, "    // 字 wide glyphs count once")],
+        &[(0xcccccc, "}")],
+    ];
+    for (y, tokens) in code.iter().enumerate() {
+        plane.cursor_move_yx(y as NcDimension, 0)?;
+        for (rgb, token) in tokens.iter() {
+            plane.set_fg_rgb(*rgb);
+            plane.putstr(token)?;
+        }
+    }
+
+    let mut report = vec![];
+    for (y, cells) in plane.cell_rows() {
+        let mut histogram = BTreeMap::new();
+        for (_, cell) in cells.iter().filter(|(_, cell)| cell.gcluster != 0) {
+            *histogram.entry(cell.fg_rgb()).or_insert(0) += 1;
+        }
+        let counts: Vec<String> = histogram
+            .iter()
+            .map(|(rgb, count)| format!["{:06x}×{}", rgb, count])
+            .collect();
+        report.push(format!["row {}: {}", y, counts.join(" ")]);
+    }
+
+    let stdplane = nc.stdplane()?;
+    for (y, line) in report.iter().enumerate() {
+        stdplane.putstr_yx(8 + y as NcDimension, 1, line)?;
+    }
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! writes hebrew labels, right-aligned, next to their english translation
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let labels = [
+        ("welcome", "ברוכים הבאים"),
+        ("settings (2 items)", "הגדרות (2 פריטים)"),
+        ("version 2.1.2", "גרסה 2.1.2"),
+    ];
+    let cols = 24;
+    for (y, (english, hebrew)) in labels.iter().enumerate() {
+        let y = y as NcDimension * 2 + 1;
+        stdplane.putstr_yx(y, 2, english)?;
+        // right to left text is aligned to the right
+        // (the hebrew letters are one column wide)
+        let width = hebrew.chars().count() as NcDimension;
+        stdplane.cursor_move_yx(y, 2 + cols * 2 - width.min(cols))?;
+        stdplane.putstr_rtl(hebrew)?;
+    }
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
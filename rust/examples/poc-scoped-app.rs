@@ -0,0 +1,37 @@
+//! runs a whole app inside `Notcurses::with`, which stops the context
+//! afterwards, even when the app fails (press any key, or e to fail)
+
+use libnotcurses_sys::*;
+
+fn app(nc: &mut Notcurses) -> NcResult<u32> {
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(1, 2, "press any key to count it, q to quit, e to fail")?;
+    nc.render()?;
+
+    let mut count = 0;
+    let mut input = NcInput::new_empty();
+    loop {
+        match nc.getc_nblocking(&mut input) {
+            'q' => return Ok(count),
+            'e' => {
+                return Err(NcError::with_kind(
+                    NCRESULT_ERR,
+                    NcErrorKind::Other,
+                    "the app failed on purpose",
+                ))
+            }
+            _ => count += 1,
+        }
+        stdplane.putstr_yx(3, 2, &format!["keys pressed: {}", count])?;
+        nc.render()?;
+    }
+}
+
+fn main() {
+    let options = NotcursesOptions::with_flags(NCOPTION_SUPPRESS_BANNERS);
+    // the terminal is restored before printing the outcome
+    match Notcurses::with(options, app) {
+        Ok(count) => println!["{} keys pressed", count],
+        Err(err) => println!["error: {}", err],
+    }
+}
@@ -0,0 +1,55 @@
+//! draws a half-block picture, and saves it as a PNG screenshot
+//! when pressing s (q to quit)
+//!
+//! run with `cargo run --example poc-screenshot --features png`
+
+use libnotcurses_sys::*;
+
+const PATH: &str = "poc-screenshot.png";
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let picture = NcPlane::new_bound(nc.stdplane()?, 1, 2, 12, 48)?;
+
+    // each cell shows two pixels: the upper half with the foreground,
+    // and the lower half with the background
+    for y in 0..12 {
+        for x in 0..48 {
+            let mut channels: NcChannelPair = 0;
+            let pixel = |py: u32| {
+                let dist = ((py as f64 - 12.0).powi(2) + ((x as f64 - 24.0) / 2.0).powi(2)).sqrt();
+                let glow = (255.0 * (1.0 - dist / 14.0).max(0.0)) as u8;
+                (glow, glow / 2, 0x40)
+            };
+            let (r, g, b) = pixel(y * 2);
+            channels.set_fg_rgb8(r, g, b);
+            let (r, g, b) = pixel(y * 2 + 1);
+            channels.set_bg_rgb8(r, g, b);
+            picture.set_channels(channels);
+            picture.putstr_yx(y, x, "▀")?;
+        }
+    }
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(14, 2, "press s to save a screenshot, q to quit")?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            's' => {
+                let msg = match picture.save_png(PATH, NCBLIT_2x1) {
+                    Ok(()) => format!["saved {}", PATH],
+                    Err(err) => format!["error: {}", err],
+                };
+                stdplane.erase_region(15, 0, 1, -1)?;
+                stdplane.putstr_yx(15, 2, &msg)?;
+                nc.render()?;
+            }
+            _ => (),
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
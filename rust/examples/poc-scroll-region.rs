@@ -0,0 +1,25 @@
+//! a static title bar and status line around a scrolling log body
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let (rows, _cols) = stdplane.dim_yx();
+
+    stdplane.set_styles(NCSTYLE_REVERSE);
+    stdplane.putstr_yx(0, 0, " poc-scroll-region ")?;
+    stdplane.putstr_yx(rows - 1, 0, " the log scrolls between the bars ")?;
+    stdplane.set_styles(NCSTYLE_NONE);
+
+    let log = stdplane.set_scroll_region(1, rows - 2)?;
+    for n in 0..rows * 3 {
+        log.putstr(&format!["\nlog entry #{}", n])?;
+        rsleep![nc, 0, 50];
+    }
+    sleep![1];
+
+    log.destroy()?;
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! a scrollback log with a scrollbar, scrolled with the arrow keys
+//! (q to quit)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let log: Vec<String> = (0..120)
+        .map(|n| format!["{:>4} | request served in {} ms", n, n * 13 % 97])
+        .collect();
+
+    let (rows, cols) = (12, 44);
+    let pane = NcPlane::new_bound(nc.stdplane()?, 1, 2, rows, cols)?;
+    let mut bar: NcChannelPair = 0;
+    bar.set_fg_rgb(0x6080c0);
+
+    // starts at the bottom
+    let visible = rows as usize;
+    let mut offset = log.len() - visible;
+    let mut input = NcInput::new_empty();
+    loop {
+        pane.erase();
+        for (y, line) in log[offset..offset + visible].iter().enumerate() {
+            pane.putstr_yx(y as NcDimension, 0, line)?;
+        }
+        pane.draw_scrollbar(NcScrollbarSide::Right, log.len(), visible, offset, bar)?;
+        nc.render()?;
+
+        match nc.getc_nblocking(&mut input) {
+            'q' => break,
+            NCKEY_UP => offset = offset.saturating_sub(1),
+            NCKEY_DOWN => offset = (offset + 1).min(log.len() - visible),
+            NCKEY_PGUP => offset = offset.saturating_sub(visible),
+            NCKEY_PGDOWN => offset = (offset + visible).min(log.len() - visible),
+            _ => (),
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
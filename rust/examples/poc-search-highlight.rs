@@ -0,0 +1,52 @@
+//! highlights all the occurrences of a search term in a text
+
+use libnotcurses_sys::*;
+
+const TEXT: &[&str] = &[
+    "It is a truth universally acknowledged, that a single man",
+    "in possession of a good fortune, must be in want of a wife.",
+    "However little known the feelings or views of such a man may",
+    "be on his first entering a neighbourhood, this truth is so",
+    "well fixed in the minds of the surrounding families, that he",
+    "is considered the rightful property of some one or other of",
+    "their daughters.",
+];
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 1, 2, TEXT.len() as NcDimension + 2, 64)?;
+    for (y, line) in TEXT.iter().enumerate() {
+        plane.putstr_yx(y as NcDimension, 0, line)?;
+    }
+    rsleep![nc, 1];
+
+    let mut highlight: NcChannelPair = 0;
+    highlight.set_fg_rgb(0x000000);
+    highlight.set_bg_rgb(0xffd000);
+
+    for term in &["truth", "man", "of"] {
+        plane.stain_region(0, 0, -1, -1, 0, 0, 0, 0)?;
+
+        // the text is ASCII, so byte offsets are columns
+        let mut coords = Vec::new();
+        for (y, line) in TEXT.iter().enumerate() {
+            for (x, _) in line.match_indices(term) {
+                for i in 0..term.len() {
+                    coords.push((y as NcDimension, (x + i) as NcDimension));
+                }
+            }
+        }
+        plane.color_cells(&coords, highlight)?;
+        let found = coords.len() / term.len();
+        plane.erase_region(TEXT.len() as NcDimension + 1, 0, 1, -1)?;
+        plane.putstr_yx(
+            TEXT.len() as NcDimension + 1,
+            0,
+            &format!["\"{}\": {} matches", term, found],
+        )?;
+        rsleep![nc, 2];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! shows some settings of the terminal in a table
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (rows, cols) = nc.term_dim_yx();
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+
+    let table = NcTable::new(&["Setting", "Value"])
+        .align(1, NCALIGN_RIGHT)
+        .row(&["terminal size", &format!["{}x{}", cols, rows]])
+        .row(&["colors", &nc.palette_size().to_string()])
+        .row(&["true color", yes_no(nc.cantruecolor())])
+        .row(&["UTF-8", yes_no(nc.canutf8())])
+        .row(&["sextants", yes_no(nc.cansextant())])
+        .row(&["best blitter", &Notcurses::str_blitter(nc.best_blitter())]);
+
+    // the plane is sized to fit the table
+    let (table_rows, table_cols) = table.dims()?;
+    let plane = NcPlane::new_bound(nc.stdplane()?, 1, 2, table_rows, table_cols.min(cols - 2))?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0xa0d0ff);
+    plane.draw_table(&table, channels)?;
+
+    rsleep![nc, 3];
+    nc.stop()?;
+    Ok(())
+}
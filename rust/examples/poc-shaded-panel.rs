@@ -0,0 +1,32 @@
+//! draws a panel with a shaded background and a drop shadow
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let shadow = NcPlane::new(nc, 3, 6, 10, 40)?;
+    let panel = NcPlane::new(nc, 2, 4, 10, 40)?;
+
+    let mut dark: NcChannelPair = 0;
+    dark.set_fg_rgb(0x202020);
+    dark.set_bg_rgb(0x000000);
+    shadow.fill("▒", dark, 0)?;
+
+    let mut shade: NcChannelPair = 0;
+    shade.set_fg_rgb(0x4060a0);
+    shade.set_bg_rgb(0x102040);
+    panel.fill("░", shade, 0)?;
+
+    // a solid title bar across the top row
+    let mut title: NcChannelPair = 0;
+    title.set_fg_rgb(0xffffff);
+    title.set_bg_rgb(0x4060a0);
+    panel.fill_region(0, 0, 1, -1, " ", title, NCSTYLE_BOLD)?;
+    panel.set_channels(title);
+    panel.set_styles(NCSTYLE_BOLD);
+    panel.putstr_yx(0, 1, "shaded panel")?;
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! shows a photo fitted into a sidebar preview pane, next to its details
+//!
+//! usage: cargo run --example poc-sidebar-preview -- <image>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    let sidebar_cols = (tcols / 3).max(1);
+    let sidebar = NcPlane::new_bound(nc.stdplane()?, 0, 0, trows, sidebar_cols)?;
+    sidebar.perimeter_rounded(0, 0, 0)?;
+
+    let blitter = nc.best_blitter();
+    let visual = NcVisual::from_file(&file)?;
+    visual.decode()?;
+    let (rows, cols) = visual.resize_within(nc, trows - 2, sidebar_cols - 2, blitter)?;
+
+    // centered inside the border of the pane
+    let preview = NcPlane::new_bound(
+        sidebar,
+        (1 + (trows - 2 - rows) / 2) as NcOffset,
+        (1 + (sidebar_cols - 2 - cols) / 2) as NcOffset,
+        rows,
+        cols,
+    )?;
+    let options = NcVisualOptions::with_plane(preview, NCSCALE_NONE, 0, 0, 0, 0, 0, 0, blitter, 0);
+    visual.render(nc, &options)?;
+
+    let details = NcPlane::new_bound(
+        nc.stdplane()?,
+        1,
+        sidebar_cols as NcOffset + 2,
+        3,
+        tcols - sidebar_cols - 2,
+    )?;
+    details.putstrln(&format!["file: {}", file])?;
+    details.putstrln(&format!["preview: {}x{} cells", cols, rows])?;
+    nc.render()?;
+    sleep![3];
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
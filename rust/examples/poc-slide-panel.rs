@@ -0,0 +1,38 @@
+//! slides a panel in from the left with easing, and back out
+
+use std::time::{Duration, Instant};
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (trows, _) = nc.term_dim_yx();
+    let width: NcOffset = 30;
+
+    let panel = NcPlane::new_bound(nc.stdplane()?, 1, -width, trows - 2, width as NcDimension)?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_bg_rgb(0x303050);
+    panel.set_base(' ', 0, channels)?;
+    panel.putstr_yx(1, 2, "a sliding panel")?;
+
+    let ms = Duration::from_millis;
+    let x = NcTimeline::new()
+        .keyframe(ms(0), -width as f64, NcEasing::Linear)
+        .keyframe(ms(600), 0.0, NcEasing::EaseOut)
+        .keyframe(ms(2000), 0.0, NcEasing::Linear)
+        .keyframe(ms(2800), -width as f64, NcEasing::EaseInOut);
+
+    let start = Instant::now();
+    loop {
+        let elapsed = start.elapsed();
+        panel.move_yx(1, x.value_at(elapsed).round() as NcOffset)?;
+        nc.render()?;
+        if x.is_finished(elapsed) {
+            break;
+        }
+        sleep![0, 16];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
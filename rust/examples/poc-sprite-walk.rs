@@ -0,0 +1,46 @@
+//! animates a character walking across the screen, from a sprite sheet
+
+use libnotcurses_sys::*;
+
+// a sprite sheet of 4 frames of 6x8 pixels, each digit is a palette index
+const SHEET: &[&str] = &[
+    "001100001100001100001100",
+    "001100001100001100001100",
+    "022220022220022220022220",
+    "202202022220202202022220",
+    "002200002200002200002200",
+    "020020002200020020002200",
+    "200002002200020020020200",
+    "300003003300030030033000",
+];
+const FRAME_W: NcDimension = 6;
+const FRAME_H: NcDimension = 8;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (_, cols) = nc.term_dim_yx();
+
+    let data: Vec<u8> = SHEET
+        .iter()
+        .flat_map(|row| row.bytes().map(|b| b - b'0'))
+        .collect();
+    let palette: [NcRgb; 4] = [0x000000, 0xffd0a0, 0x4080ff, 0x804020];
+    let (sheet_h, sheet_w) = (SHEET.len() as NcDimension, SHEET[0].len() as NcDimension);
+    let visual = NcVisual::from_palidx(&data, sheet_h, sheet_w, sheet_w, 4, 1, &palette)?;
+
+    let mut sheet = NcSpriteSheet::from_visual(nc, visual, FRAME_W, FRAME_H)?;
+    sheet.set_blitter(NCBLIT_2x1);
+
+    // with the 2x1 blitter, each cell shows 2 pixels vertically
+    let stage = NcPlane::new_bound(nc.stdplane()?, 1, 0, FRAME_H / 2, cols)?;
+    let frames = sheet.frames();
+    for step in 0..cols.saturating_sub(FRAME_W) {
+        stage.erase();
+        stage.blit_sprite(&mut sheet, step as usize % frames, 0, step)?;
+        rsleep![nc, 0, 120];
+    }
+
+    sheet.visual().destroy();
+    nc.stop()?;
+    Ok(())
+}
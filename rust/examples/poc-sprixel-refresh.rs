@@ -0,0 +1,39 @@
+//! keeps an image on screen across terminal resizes
+//!
+//! usage: cargo run --example poc-sprixel-refresh -- <image>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+
+    let blitter = if nc.cansixel() {
+        NCBLIT_SIXEL
+    } else {
+        NCBLIT_DEFAULT
+    };
+    let visual = NcVisual::from_file(&file)?;
+    let options =
+        NcVisualOptions::with_plane(stdplane, NCSCALE_SCALE, 0, 0, 0, 0, 0, 0, blitter, 0);
+    visual.render(nc, &options)?;
+    nc.render()?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == NCKEY_RESIZE {
+            nc.sprixel_refresh()?;
+        } else if key == 'q' {
+            break;
+        }
+    }
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
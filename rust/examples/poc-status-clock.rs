@@ -0,0 +1,45 @@
+//! a right-aligned clock in a status bar, erased precisely before each update
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use libnotcurses_sys::*;
+
+fn clock() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format![
+        " {:02}:{:02}:{:02} UTC ",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    ]
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let (rows, _cols) = stdplane.dim_yx();
+    let bar = rows - 1;
+
+    stdplane.putstr_aligned_at(bar, NCALIGN_LEFT, " poc-status-clock ")?;
+    stdplane.putstr_aligned_at(bar, NCALIGN_CENTER, "(press q to quit)")?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let placement = stdplane.putstr_aligned_at(bar, NCALIGN_RIGHT, &clock())?;
+        nc.render()?;
+        sleep![0, 500];
+
+        // erase just the clock, leaving the rest of the status bar intact
+        stdplane.putstr_yx(bar, placement.start, &" ".repeat(placement.cols as usize))?;
+
+        if nc.getc_nblock(&mut input) == 'q' {
+            break;
+        }
+    }
+
+    nc.stop()?;
+    Ok(())
+}
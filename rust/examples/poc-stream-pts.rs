@@ -0,0 +1,44 @@
+//! plays a video logging the timing of each frame, the hook needed to
+//! synchronize an external audio player
+//!
+//! usage: cargo run --example poc-stream-pts -- <video>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "image-16x16.png".to_string());
+
+    let nc = Notcurses::new()?;
+    let (trows, tcols) = nc.term_dim_yx();
+    let video = NcPlane::new_bound(nc.stdplane()?, 0, 0, trows - 1, tcols)?;
+    let log = NcPlane::new_bound(nc.stdplane()?, (trows - 1) as NcOffset, 0, 1, tcols)?;
+
+    let visual = NcVisual::from_file(&file)?;
+    let options =
+        NcVisualOptions::with_plane(video, NCSCALE_SCALE, 0, 0, 0, 0, 0, 0, NCBLIT_2x1, 0);
+
+    visual.stream(nc, 1.0, &options, |_, _, info| {
+        let duration = info
+            .duration
+            .map_or("-".to_string(), |d| format!["{:>4}ms", d.as_millis()]);
+        log.erase();
+        let _ = log.putstr_yx(
+            0,
+            0,
+            &format![
+                "frame {:>5}  pts {:>8.3}s  duration {}",
+                info.index,
+                info.pts.as_secs_f32(),
+                duration
+            ],
+        );
+        // an audio player would be told to seek to `info.pts` here
+        true
+    })?;
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
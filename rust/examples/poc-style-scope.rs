@@ -0,0 +1,38 @@
+//! draws a styled status badge, restoring the caller's style afterwards
+
+use libnotcurses_sys::*;
+
+/// Draws a badge at `y`, `x`, without leaking its style into the plane.
+fn badge(
+    plane: &mut NcPlane,
+    y: NcDimension,
+    x: NcDimension,
+    label: &str,
+    ok: bool,
+) -> NcResult<()> {
+    let mut scope = plane.style_scope();
+    scope.set_bg_rgb(if ok { 0x206020 } else { 0x802020 });
+    scope.set_fg_rgb(0xffffff);
+    scope.set_styles(NCSTYLE_BOLD);
+    scope.putstr_yx(y, x, &format![" {} ", label])?;
+    Ok(())
+}
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 1, 2, 4, 40)?;
+    plane.set_fg_rgb(0xa0a0a0);
+    plane.set_styles(NCSTYLE_ITALIC);
+
+    plane.putstr_yx(0, 0, "build:")?;
+    badge(plane, 0, 7, "passing", true)?;
+    plane.putstr_yx(1, 0, "deploy:")?;
+    badge(plane, 1, 8, "failed", false)?;
+
+    // still grey and italic
+    plane.putstr_yx(3, 0, "the caller's style is intact")?;
+    rsleep![nc, 3];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! a box tweening its colors back and forth
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+
+    let (mut from, mut to): (NcChannelPair, NcChannelPair) = (0, 0);
+    from.set_fg_rgb(0xffffff);
+    from.set_bg_rgb(0x000080);
+    to.set_fg_rgb(0x000000);
+    to.set_bg_rgb(0xffa000);
+
+    let steps = 60;
+    for step in 0..steps * 4 {
+        // back and forth
+        let t = (step % (steps * 2)) as f32 / steps as f32;
+        let t = if t > 1.0 { 2.0 - t } else { t };
+
+        plane.set_channels(NcChannelPair::lerp(from, to, t));
+        for row in 0..5 {
+            plane.putstr_yx(1 + row, 2, "                              ")?;
+        }
+        plane.putstr_yx(3, 4, &format!["t = {:.2}", t])?;
+        rsleep![nc, 0, 25];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! draw with the mouse, each stroke can be undone with u
+//! (q to quit)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+    let (trows, tcols) = nc.term_dim_yx();
+
+    let canvas = NcPlane::new_bound(nc.stdplane()?, 1, 0, trows - 1, tcols)?;
+    nc.stdplane()?
+        .putstr_yx(0, 0, "drag to draw, u to undo the last stroke, q to quit")?;
+    nc.render()?;
+
+    let mut tracker = NcMouseTracker::new();
+    let mut undo: Option<NcPlaneSnapshot> = None;
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        match key {
+            'q' => break,
+            'u' => {
+                // single level undo
+                if let Some(snapshot) = undo.take() {
+                    canvas.restore(&snapshot)?;
+                }
+            }
+            _ => match tracker.process(&input) {
+                Some(NcMouseGesture::DragStart { y, x, .. })
+                | Some(NcMouseGesture::Click { y, x, .. }) => {
+                    undo = Some(canvas.snapshot()?);
+                    if y >= 1 {
+                        canvas.putchar_yx(y - 1, x, '█')?;
+                    }
+                }
+                Some(NcMouseGesture::Drag { y, x, .. })
+                | Some(NcMouseGesture::DragEnd { y, x, .. }) => {
+                    if y >= 1 {
+                        canvas.putchar_yx(y - 1, x, '█')?;
+                    }
+                }
+                _ => continue,
+            },
+        }
+        nc.render()?;
+    }
+
+    nc.stop()?;
+    Ok(())
+}
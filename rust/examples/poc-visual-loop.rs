@@ -0,0 +1,33 @@
+//! loops an animation (e.g. a GIF) until a key is pressed
+//!
+//! usage: cargo run --example poc-visual-loop -- <file>
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let file = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "../../data/notcursesII.mkv".to_string());
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let visual = NcVisual::from_file(&file)?;
+    let options =
+        NcVisualOptions::with_plane(stdplane, NCSCALE_SCALE, 0, 0, 0, 0, 0, 0, NCBLIT_DEFAULT, 0);
+
+    let mut input = NcInput::new_empty();
+    loop {
+        visual.render(nc, &options)?;
+        nc.render()?;
+        sleep![0, 40];
+
+        if nc.getc_nblock(&mut input) != '\u{0}' {
+            break;
+        }
+        visual.decode_loop()?;
+    }
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
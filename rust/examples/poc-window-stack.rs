@@ -0,0 +1,52 @@
+//! overlapping windows, a click raises the topmost one under the pointer
+//! (q to quit)
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.mouse_enable()?;
+    let stdplane: *const NcPlane = nc.stdplane()?;
+
+    for (i, bg) in [0x803030, 0x308030, 0x303080].iter().enumerate() {
+        let offset = i as NcOffset * 3;
+        let window = NcPlane::new_bound(nc.stdplane()?, 1 + offset, 2 + offset * 2, 6, 20)?;
+        let mut channels: NcChannelPair = 0;
+        channels.set_bg_rgb(*bg);
+        window.set_base(' ', 0, channels)?;
+        window.putstr_yx(0, 1, &format!["window {}", i])?;
+    }
+    nc.render()?;
+
+    let mut tracker = NcMouseTracker::new();
+    let mut input = NcInput::new_empty();
+    loop {
+        let key = nc.getc_nblocking(&mut input);
+        if key == 'q' {
+            break;
+        }
+        if let Some(NcMouseGesture::Click { y, x, .. }) = tracker.process(&input) {
+            let (y, x) = (y as NcOffset, x as NcOffset);
+            // the windows are bound to the standard plane, at the origin,
+            // so their relative coordinates are also absolute ones
+            let hit = nc.planes_top_to_bottom().into_iter().find(|plane| {
+                let (py, px) = plane.yx();
+                let (rows, cols) = plane.dim_yx();
+                !core::ptr::eq(&**plane, stdplane)
+                    && y >= py
+                    && y < py + rows as NcOffset
+                    && x >= px
+                    && x < px + cols as NcOffset
+            });
+            if let Some(window) = hit {
+                window.move_top();
+            }
+            nc.render()?;
+        }
+        sleep![0, 10];
+    }
+
+    nc.mouse_disable()?;
+    nc.stop()?;
+    Ok(())
+}
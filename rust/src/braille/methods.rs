@@ -0,0 +1,93 @@
+//! `NcBrailleCanvas` methods and associated functions.
+
+use crate::{NcBrailleCanvas, NcDimension, NcPlane, NcResult, NCBRAILLE_BLANK};
+
+/// The braille dot bit for each pixel of a cell, indexed by `[y][x]`.
+const DOT_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Returns the braille pattern [char] for the given `dots` bitmask,
+/// where bit 0 is dot 1 and bit 7 is dot 8.
+#[inline]
+pub(crate) fn braille_from_dots(dots: u8) -> char {
+    core::char::from_u32(NCBRAILLE_BLANK as u32 + dots as u32).unwrap_or(NCBRAILLE_BLANK)
+}
+
+/// # `NcBrailleCanvas` Constructors
+impl<'a> NcBrailleCanvas<'a> {
+    /// New NcBrailleCanvas of `width` x `height` pixels, drawn on `plane`,
+    /// with all the pixels off.
+    pub fn new(plane: &'a mut NcPlane, width: NcDimension, height: NcDimension) -> Self {
+        Self {
+            plane,
+            width,
+            height,
+            pixels: vec![false; (width * height) as usize],
+        }
+    }
+}
+
+/// # `NcBrailleCanvas` Methods
+impl<'a> NcBrailleCanvas<'a> {
+    /// Returns the size of the canvas in pixels, as `(width, height)`.
+    pub fn size(&self) -> (NcDimension, NcDimension) {
+        (self.width, self.height)
+    }
+
+    /// Returns the size of the canvas in cells, as `(rows, cols)`.
+    pub fn dim_yx(&self) -> (NcDimension, NcDimension) {
+        ((self.height + 3) / 4, (self.width + 1) / 2)
+    }
+
+    /// Turns the pixel at `x`, `y` on or off.
+    ///
+    /// Coordinates outside the canvas are ignored.
+    pub fn set_pixel(&mut self, x: NcDimension, y: NcDimension, on: bool) {
+        if x < self.width && y < self.height {
+            self.pixels[(y * self.width + x) as usize] = on;
+        }
+    }
+
+    /// Returns true if the pixel at `x`, `y` is on.
+    ///
+    /// Coordinates outside the canvas are always off.
+    pub fn pixel(&self, x: NcDimension, y: NcDimension) -> bool {
+        x < self.width && y < self.height && self.pixels[(y * self.width + x) as usize]
+    }
+
+    /// Turns all the pixels off.
+    pub fn clear(&mut self) {
+        for pixel in self.pixels.iter_mut() {
+            *pixel = false;
+        }
+    }
+
+    /// Returns the braille pattern [char] for the cell at `row`, `col`.
+    pub fn egc_at(&self, row: NcDimension, col: NcDimension) -> char {
+        let mut dots = 0;
+        for (dy, bits) in DOT_BITS.iter().enumerate() {
+            for (dx, bit) in bits.iter().enumerate() {
+                if self.pixel(col * 2 + dx as NcDimension, row * 4 + dy as NcDimension) {
+                    dots |= bit;
+                }
+            }
+        }
+        braille_from_dots(dots)
+    }
+
+    /// Draws the canvas on its plane, starting at the plane's origin,
+    /// using the plane's current style.
+    ///
+    /// Cells falling outside the plane are not drawn.
+    pub fn render(&mut self) -> NcResult<()> {
+        let (rows, cols) = self.dim_yx();
+        let (plane_rows, plane_cols) = self.plane.dim_yx();
+        let mut buf = [0u8; 4];
+        for row in 0..rows.min(plane_rows) {
+            for col in 0..cols.min(plane_cols) {
+                let egc = self.egc_at(row, col);
+                self.plane.putstr_yx(row, col, egc.encode_utf8(&mut buf))?;
+            }
+        }
+        Ok(())
+    }
+}
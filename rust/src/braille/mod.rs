@@ -0,0 +1,40 @@
+//! `NcBrailleCanvas`
+
+mod methods;
+
+#[cfg(test)]
+mod test;
+
+use crate::{NcDimension, NcPlane};
+
+/// A monochrome pixel canvas drawn on an [NcPlane] using braille patterns.
+///
+/// Each [NcCell][crate::NcCell] packs 2x4 pixels, so a canvas of
+/// `width` x `height` pixels covers `width / 2` x `height / 4` cells,
+/// rounded up.
+///
+/// This is a higher level alternative to blitting with
+/// [NCBLIT_BRAILLE][crate::NCBLIT_BRAILLE], that allows drawing directly
+/// in pixel coordinates.
+///
+/// Created with NcPlane.[braille_canvas()][NcPlane#method.braille_canvas].
+///
+/// ## Diagram
+///
+/// Pixels inside a cell, and the braille dots they map to:
+///
+/// ```txt
+/// (0,0) dot 1   (1,0) dot 4
+/// (0,1) dot 2   (1,1) dot 5
+/// (0,2) dot 3   (1,2) dot 6
+/// (0,3) dot 7   (1,3) dot 8
+/// ```
+pub struct NcBrailleCanvas<'a> {
+    pub(crate) plane: &'a mut NcPlane,
+    pub(crate) width: NcDimension,
+    pub(crate) height: NcDimension,
+    pub(crate) pixels: Vec<bool>,
+}
+
+/// The first codepoint of the Unicode braille patterns block (no dots).
+pub const NCBRAILLE_BLANK: char = '\u{2800}';
@@ -0,0 +1,51 @@
+//! Test `NcBrailleCanvas` methods and associated functions.
+
+use crate::{NcBrailleCanvas, NcPlane, Notcurses};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn dots() {
+    use crate::braille::methods::braille_from_dots;
+    assert_eq!['⠀', braille_from_dots(0)];
+    assert_eq!['⠁', braille_from_dots(0x01)];
+    assert_eq!['⣿', braille_from_dots(0xff)];
+}
+
+#[test]
+#[serial]
+fn pixel_patterns() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 2)?;
+    let mut canvas = NcBrailleCanvas::new(plane, 3, 5);
+    assert_eq![(2, 2), canvas.dim_yx()];
+
+    // left column
+    for y in 0..4 {
+        canvas.set_pixel(0, y, true);
+    }
+    assert_eq!['⡇', canvas.egc_at(0, 0)];
+
+    // top right dot
+    canvas.set_pixel(1, 0, true);
+    assert_eq!['⡏', canvas.egc_at(0, 0)];
+
+    // the next cells
+    canvas.set_pixel(2, 0, true);
+    canvas.set_pixel(0, 4, true);
+    assert_eq!['⠁', canvas.egc_at(0, 1)];
+    assert_eq!['⠁', canvas.egc_at(1, 0)];
+
+    // out of bounds pixels are ignored
+    canvas.set_pixel(3, 0, true);
+    canvas.set_pixel(0, 5, true);
+    assert_eq![false, canvas.pixel(3, 0)];
+
+    canvas.render()?;
+    canvas.clear();
+    assert_eq!['⠀', canvas.egc_at(0, 0)];
+
+    nc.stop()?;
+    Ok(())
+}
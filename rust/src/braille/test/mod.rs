@@ -0,0 +1,4 @@
+//! `NcBrailleCanvas` tests
+
+#[cfg(test)]
+mod methods;
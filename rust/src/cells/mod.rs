@@ -64,8 +64,12 @@
 mod test;
 
 mod methods;
+mod owned;
 mod reimplemented;
+mod style;
+pub use owned::NcOwnedCell;
 pub use reimplemented::*;
+pub use style::NcStyle;
 
 // NcCell
 /// A coordinate on an [`NcPlane`][crate::NcPlane] storing 128 bits of data.
@@ -355,6 +359,8 @@ pub type NcEgcBackstop = u8;
 /// 11111111 11111111
 /// ```
 ///
+/// See also [NcStyle] for a typed set of styles.
+///
 /// `type in C:  uint16_t`
 ///
 pub type NcStyleMask = u16;
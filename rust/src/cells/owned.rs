@@ -0,0 +1,56 @@
+//! `NcOwnedCell`
+
+use crate::{NcCell, NcChannelPair, NcPlane, NcStyleMask};
+
+/// An [NcCell] that owns its [NcEgc][crate::NcEgc], instead of referencing
+/// the egcpool of an [NcPlane].
+///
+/// An NcCell holding a multi-byte EGC is only valid together with the plane
+/// it was loaded on, and must be released on it. An NcOwnedCell can instead
+/// be stored and passed around freely, and put on any plane, with
+/// [put_owned_cell()][NcPlane#method.put_owned_cell], which loads its EGC
+/// into the egcpool of that plane.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NcOwnedCell {
+    /// The extended grapheme cluster, which may be empty.
+    pub egc: String,
+    /// The styles.
+    pub stylemask: NcStyleMask,
+    /// The foreground and background channels.
+    pub channels: NcChannelPair,
+}
+
+/// # `NcOwnedCell` Constructors
+impl NcOwnedCell {
+    /// New NcOwnedCell.
+    pub fn new(egc: &str, stylemask: NcStyleMask, channels: NcChannelPair) -> Self {
+        Self {
+            egc: egc.to_string(),
+            stylemask,
+            channels,
+        }
+    }
+
+    /// New NcOwnedCell from a `cell` loaded on the `plane`.
+    ///
+    /// The `cell` is not released.
+    pub fn from_plane_cell(plane: &NcPlane, cell: &NcCell) -> Self {
+        let egc = crate::rstring![crate::cell_extended_gcluster(plane, cell)];
+        Self::new(egc, cell.stylemask, cell.channels)
+    }
+}
+
+/// # `NcOwnedCell` Methods
+impl NcOwnedCell {
+    /// Returns a new [NcCell] loaded on the `plane`, with the EGC,
+    /// styles and channels of this NcOwnedCell.
+    ///
+    /// The returned cell must be released on the same plane with
+    /// [cell_release()][crate::cell_release].
+    pub fn to_plane_cell(&self, plane: &mut NcPlane) -> NcCell {
+        let mut cell = NcCell::with_str(plane, &self.egc);
+        cell.stylemask = self.stylemask;
+        cell.channels = self.channels;
+        cell
+    }
+}
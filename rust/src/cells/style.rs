@@ -0,0 +1,190 @@
+//! `NcStyle`
+
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+use crate::{
+    NcStyleMask, Notcurses, NCSTYLE_BLINK, NCSTYLE_BOLD, NCSTYLE_DIM, NCSTYLE_INVIS,
+    NCSTYLE_ITALIC, NCSTYLE_MASK, NCSTYLE_NONE, NCSTYLE_PROTECT, NCSTYLE_REVERSE, NCSTYLE_STANDOUT,
+    NCSTYLE_STRUCK, NCSTYLE_UNDERLINE,
+};
+
+/// A set of styling attributes, wrapping an [NcStyleMask].
+///
+/// Styles are combined with the bitwise operators, e.g.
+/// `NcStyle::BOLD | NcStyle::ITALIC`, and converted to and from the raw
+/// [NcStyleMask] with [From] and [from_bits()][NcStyle#method.from_bits].
+///
+/// The methods taking an [NcStyleMask] take its [bits()][NcStyle#method.bits].
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NcStyle(NcStyleMask);
+
+/// # `NcStyle` constants
+impl NcStyle {
+    /// No styles.
+    pub const NONE: NcStyle = NcStyle(NCSTYLE_NONE);
+    ///
+    pub const BLINK: NcStyle = NcStyle(NCSTYLE_BLINK);
+    ///
+    pub const BOLD: NcStyle = NcStyle(NCSTYLE_BOLD);
+    ///
+    pub const DIM: NcStyle = NcStyle(NCSTYLE_DIM);
+    ///
+    pub const INVIS: NcStyle = NcStyle(NCSTYLE_INVIS);
+    ///
+    pub const ITALIC: NcStyle = NcStyle(NCSTYLE_ITALIC);
+    ///
+    pub const PROTECT: NcStyle = NcStyle(NCSTYLE_PROTECT);
+    ///
+    pub const REVERSE: NcStyle = NcStyle(NCSTYLE_REVERSE);
+    ///
+    pub const STANDOUT: NcStyle = NcStyle(NCSTYLE_STANDOUT);
+    ///
+    pub const STRUCK: NcStyle = NcStyle(NCSTYLE_STRUCK);
+    ///
+    pub const UNDERLINE: NcStyle = NcStyle(NCSTYLE_UNDERLINE);
+    /// All the styles.
+    pub const ALL: NcStyle = NcStyle(NCSTYLE_MASK);
+}
+
+/// # `NcStyle` constructors
+impl NcStyle {
+    /// New NcStyle from a raw [NcStyleMask].
+    ///
+    /// Returns None if any of the bits doesn't correspond to a style.
+    pub fn from_bits(bits: NcStyleMask) -> Option<Self> {
+        if bits & !NCSTYLE_MASK == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// New NcStyle from a raw [NcStyleMask], dropping the bits that don't
+    /// correspond to a style.
+    pub fn from_bits_truncate(bits: NcStyleMask) -> Self {
+        Self(bits & NCSTYLE_MASK)
+    }
+
+    /// New NcStyle with the styles supported by the terminal.
+    ///
+    /// *C style function: [notcurses_supported_styles()][crate::notcurses_supported_styles].*
+    pub fn supported(nc: &Notcurses) -> Self {
+        Self::from_bits_truncate(nc.supported_styles())
+    }
+}
+
+/// # `NcStyle` methods
+impl NcStyle {
+    /// Returns the raw [NcStyleMask].
+    pub fn bits(&self) -> NcStyleMask {
+        self.0
+    }
+
+    /// Returns true if no style is set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == NCSTYLE_NONE
+    }
+
+    /// Returns true if all the `other` styles are set.
+    pub fn contains(&self, other: NcStyle) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if any of the `other` styles is set.
+    pub fn intersects(&self, other: NcStyle) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Sets the `other` styles.
+    pub fn insert(&mut self, other: NcStyle) {
+        self.0 |= other.0;
+    }
+
+    /// Unsets the `other` styles.
+    pub fn remove(&mut self, other: NcStyle) {
+        self.0 &= !other.0;
+    }
+
+    /// Returns the names of the set styles, e.g. `["bold", "italic"]`.
+    ///
+    /// The names are returned in the order of the style bits.
+    pub fn names(&self) -> Vec<&'static str> {
+        STYLE_NAMES
+            .iter()
+            .filter(|(style, _)| self.contains(*style))
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    /// Returns the styles not supported by the terminal.
+    pub fn unsupported(&self, nc: &Notcurses) -> NcStyle {
+        *self - Self::supported(nc)
+    }
+}
+
+/// The name of each style, in the order of the style bits.
+const STYLE_NAMES: [(NcStyle, &str); 10] = [
+    (NcStyle::PROTECT, "protect"),
+    (NcStyle::INVIS, "invis"),
+    (NcStyle::BOLD, "bold"),
+    (NcStyle::DIM, "dim"),
+    (NcStyle::BLINK, "blink"),
+    (NcStyle::REVERSE, "reverse"),
+    (NcStyle::UNDERLINE, "underline"),
+    (NcStyle::STANDOUT, "standout"),
+    (NcStyle::ITALIC, "italic"),
+    (NcStyle::STRUCK, "struck"),
+];
+
+impl From<NcStyle> for NcStyleMask {
+    fn from(style: NcStyle) -> NcStyleMask {
+        style.0
+    }
+}
+
+impl BitOr for NcStyle {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for NcStyle {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for NcStyle {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for NcStyle {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Sub for NcStyle {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl SubAssign for NcStyle {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 &= !rhs.0;
+    }
+}
+
+impl Not for NcStyle {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self(!self.0 & NCSTYLE_MASK)
+    }
+}
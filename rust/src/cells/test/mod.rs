@@ -3,5 +3,11 @@
 #[cfg(test)]
 mod methods;
 
+#[cfg(test)]
+mod owned;
+
 #[cfg(test)]
 mod reimplemented;
+
+#[cfg(test)]
+mod style;
@@ -0,0 +1,55 @@
+//! Test `NcOwnedCell` methods.
+
+use crate::{NcCell, NcOwnedCell, NcPlane, Notcurses, NCSTYLE_BOLD, NCSTYLE_ITALIC};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn round_trip_between_planes() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let from = NcPlane::new(nc, 0, 0, 2, 10)?;
+    let to = NcPlane::new(nc, 0, 0, 2, 10)?;
+
+    // a multi-codepoint EGC, stored in the egcpool of the plane
+    let family = "👩\u{200d}👩\u{200d}👧";
+    let mut cell = NcCell::with_str(from, family);
+    cell.stylemask = NCSTYLE_BOLD;
+    cell.channels = 0x00ff0000_00000040;
+    let owned = NcOwnedCell::from_plane_cell(from, &cell);
+    unsafe { crate::cell_release(from, &mut cell) };
+    assert_eq![
+        NcOwnedCell::new(family, NCSTYLE_BOLD, 0x00ff0000_00000040),
+        owned
+    ];
+
+    // the owned cell outlives the plane it was extracted from
+    from.destroy()?;
+
+    assert![to.put_owned_cell(1, 2, &owned)? > 0];
+    assert_eq![owned, to.owned_cell_at(1, 2)?];
+
+    let ascii = NcOwnedCell::new("x", NCSTYLE_ITALIC, 0);
+    assert_eq![1, to.put_owned_cell(0, 0, &ascii)?];
+    assert_eq![ascii, to.owned_cell_at(0, 0)?];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn base_cell() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 2)?;
+
+    let base = NcOwnedCell::new("·", NCSTYLE_BOLD, 0x00112233_00445566);
+    plane.set_base_owned_cell(&base)?;
+    let mut cell = NcCell::new();
+    plane.base(&mut cell)?;
+    assert_eq![base, NcOwnedCell::from_plane_cell(plane, &cell)];
+    unsafe { crate::cell_release(plane, &mut cell) };
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Test `NcStyle` methods and operators.
+
+use crate::{
+    NcStyle, NcStyleMask, NCSTYLE_BOLD, NCSTYLE_ITALIC, NCSTYLE_MASK, NCSTYLE_NONE,
+    NCSTYLE_UNDERLINE,
+};
+
+#[test]
+fn flag_arithmetic() {
+    let style = NcStyle::BOLD | NcStyle::ITALIC;
+    assert![style.contains(NcStyle::BOLD)];
+    assert![style.contains(NcStyle::BOLD | NcStyle::ITALIC)];
+    assert![!style.contains(NcStyle::BOLD | NcStyle::UNDERLINE)];
+    assert![style.intersects(NcStyle::BOLD | NcStyle::UNDERLINE)];
+    assert![!style.intersects(NcStyle::UNDERLINE)];
+
+    assert_eq![NcStyle::ITALIC, style - NcStyle::BOLD];
+    assert_eq![NcStyle::BOLD, style & NcStyle::BOLD];
+    assert![(!style).contains(NcStyle::UNDERLINE)];
+    assert![!(!style).intersects(style)];
+    assert_eq![NcStyle::ALL, style | !style];
+
+    let mut style = NcStyle::default();
+    assert![style.is_empty()];
+    style.insert(NcStyle::UNDERLINE);
+    style |= NcStyle::BOLD;
+    assert_eq![NcStyle::UNDERLINE | NcStyle::BOLD, style];
+    style.remove(NcStyle::UNDERLINE);
+    assert_eq![NcStyle::BOLD, style];
+    style -= NcStyle::BOLD;
+    assert_eq![NcStyle::NONE, style];
+}
+
+#[test]
+fn raw_mask_round_trip() {
+    let mask = NCSTYLE_BOLD | NCSTYLE_UNDERLINE;
+    let style = NcStyle::from_bits(mask).unwrap();
+    assert_eq![mask, style.bits()];
+    assert_eq![mask, NcStyleMask::from(style)];
+    assert_eq![NcStyle::BOLD | NcStyle::UNDERLINE, style];
+
+    assert_eq![Some(NcStyle::NONE), NcStyle::from_bits(NCSTYLE_NONE)];
+    assert_eq![NCSTYLE_MASK, NcStyle::ALL.bits()];
+    assert_eq![NCSTYLE_ITALIC, NcStyle::ITALIC.into()];
+
+    // bits outside the mask
+    if NCSTYLE_MASK != NcStyleMask::MAX {
+        let invalid = !NCSTYLE_MASK;
+        assert_eq![None, NcStyle::from_bits(invalid | NCSTYLE_BOLD)];
+        assert_eq![
+            NcStyle::BOLD,
+            NcStyle::from_bits_truncate(invalid | NCSTYLE_BOLD)
+        ];
+    }
+}
+
+#[test]
+fn names() {
+    let mask = NCSTYLE_ITALIC | NCSTYLE_BOLD | NCSTYLE_UNDERLINE;
+    assert_eq![
+        vec!["bold", "underline", "italic"],
+        NcStyle::from_bits_truncate(mask).names()
+    ];
+    assert_eq![10, NcStyle::ALL.names().len()];
+    assert![NcStyle::NONE.names().is_empty()];
+}
@@ -1,6 +1,8 @@
 //! `NcChannel*` methods and associated functions.
 
-use crate::{NcAlphaBits, NcChannel, NcChannelPair, NcColor, NcPaletteIndex, NcRgb};
+use crate::{
+    NcAlphaBits, NcChannel, NcChannelPair, NcColor, NcPaletteIndex, NcRgb, NCCELL_BG_PALETTE,
+};
 
 /// Enables the [NcChannel] methods.
 pub trait NcChannelMethods {
@@ -28,6 +30,12 @@ pub trait NcChannelMethods {
 
     fn fcombine(&self, bchannel: NcChannel) -> NcChannelPair;
     fn bcombine(&self, fchannel: NcChannel) -> NcChannelPair;
+
+    fn contrasting(bchannel: NcChannel) -> NcChannel;
+
+    fn from_name(name: &str) -> Option<NcChannel>;
+
+    fn lerp(from: NcChannel, to: NcChannel, t: f32) -> NcChannel;
 }
 
 /// Enables the [NcChannelPair] methods.
@@ -75,6 +83,8 @@ pub trait NcChannelPairMethods {
     fn set_bg_palindex(&mut self, index: NcPaletteIndex);
 
     fn combine(fchannel: NcChannel, bchannel: NcChannel) -> NcChannelPair;
+
+    fn lerp(from: NcChannelPair, to: NcChannelPair, t: f32) -> NcChannelPair;
 }
 
 // NcChannel -------------------------------------------------------------------
@@ -239,6 +249,97 @@ impl NcChannelMethods for NcChannel {
     fn palindex_p(&self) -> bool {
         crate::channel_palindex_p(*self)
     }
+
+    // Contrast
+
+    /// Returns a new NcChannel, either black or white, whichever contrasts
+    /// the most against the RGB color of the `bchannel` background.
+    ///
+    /// The relative luminance of the background is calculated as in WCAG 2.0,
+    /// so mid-grey backgrounds get black text.
+    ///
+    /// This computes the color once. For backgrounds that vary per cell,
+    /// like gradients, use [NCCELL_ALPHA_HIGHCONTRAST][crate::NCCELL_ALPHA_HIGHCONTRAST]
+    /// instead, e.g. with NcPlane.[putstr_contrasting()][crate::NcPlane#method.putstr_contrasting].
+    ///
+    /// *(No equivalent C style function)*
+    fn contrasting(bchannel: NcChannel) -> NcChannel {
+        fn linear(component: NcColor) -> f64 {
+            let c = component as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        let (r, g, b) = bchannel.rgb8();
+        let luminance = 0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b);
+
+        // contrast ratios against black (0.0) & white (1.0) luminances
+        let against_black = (luminance + 0.05) / 0.05;
+        let against_white = 1.05 / (luminance + 0.05);
+
+        let mut channel: NcChannel = 0;
+        if against_black >= against_white {
+            channel.set(0x000000);
+        } else {
+            channel.set(0xffffff);
+        }
+        channel
+    }
+
+    // Named colors
+
+    /// Returns a new NcChannel with the RGB color of a standard CSS color
+    /// `name`, like `"red"`, `"cornflowerblue"` or `"rebeccapurple"`.
+    ///
+    /// The name is matched case-insensitively. Returns `None` if it's unknown.
+    ///
+    /// *(No equivalent C style function)*
+    fn from_name(name: &str) -> Option<NcChannel> {
+        crate::channel::named_rgb(name).map(|rgb| {
+            let mut channel: NcChannel = 0;
+            channel.set(rgb);
+            channel
+        })
+    }
+
+    // Interpolation
+
+    /// Linearly interpolates the RGB colors of two [NcChannel]s,
+    /// by `t` in the range `0.0..=1.0`.
+    ///
+    /// Returns exactly `from` when `t` is 0, and `to` when `t` is 1.
+    /// Otherwise the alpha is taken from `from`.
+    ///
+    /// If either channel uses the default color or a palette index, which
+    /// can't be interpolated, it returns the nearest endpoint instead.
+    ///
+    /// *(No equivalent C style function)*
+    fn lerp(from: NcChannel, to: NcChannel, t: f32) -> NcChannel {
+        let t = t.max(0.0).min(1.0);
+        if t == 0.0 {
+            return from;
+        }
+        if t == 1.0 {
+            return to;
+        }
+        let rgb_p = |c: NcChannel| !c.default_p() && c & NCCELL_BG_PALETTE == 0;
+        if !rgb_p(from) || !rgb_p(to) {
+            return if t < 0.5 { from } else { to };
+        }
+        let component = |a: NcColor, b: NcColor| {
+            let (a, b) = (a as f32, b as f32);
+            (a + (b - a) * t).round() as NcColor
+        };
+        let mut channel = from;
+        channel.set_rgb8(
+            component(from.r(), to.r()),
+            component(from.g(), to.g()),
+            component(from.b(), to.b()),
+        );
+        channel
+    }
 }
 
 // NcChannelPair ---------------------------------------------------------------
@@ -538,4 +639,19 @@ impl NcChannelPairMethods for NcChannelPair {
     fn combine(fchannel: NcChannel, bchannel: NcChannel) -> NcChannelPair {
         crate::channels_combine(fchannel, bchannel)
     }
+
+    // Interpolation
+
+    /// Linearly interpolates the foreground and background RGB colors of two
+    /// [NcChannelPair]s, by `t` in the range `0.0..=1.0`.
+    ///
+    /// See [NcChannel::lerp][NcChannelMethods#tymethod.lerp] for the details.
+    ///
+    /// *(No equivalent C style function)*
+    fn lerp(from: NcChannelPair, to: NcChannelPair, t: f32) -> NcChannelPair {
+        crate::channels_combine(
+            NcChannel::lerp(from.fchannel(), to.fchannel(), t),
+            NcChannel::lerp(from.bchannel(), to.bchannel(), t),
+        )
+    }
 }
@@ -63,8 +63,10 @@
 #[cfg(test)]
 mod test;
 
+mod helpers;
 mod methods;
 mod reimplemented;
+pub(crate) use helpers::*;
 pub use methods::{NcChannelMethods, NcChannelPairMethods};
 pub use reimplemented::*;
 
@@ -1,10 +1,109 @@
 //! Test `NcChannel*` methods and associated functions.
 
-// use crate::{NcChannel, NcChannelPair};
-//
-// use serial_test::serial;
-//
-// #[test]
-// #[serial]
-// fn () {
-// }
+use crate::{
+    NcChannel, NcChannelMethods, NcChannelPair, NcChannelPairMethods, NCCELL_BGDEFAULT_MASK,
+    NCCELL_BG_PALETTE,
+};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn contrasting() {
+    // dark backgrounds get white
+    assert_eq![0xffffff, NcChannel::contrasting(0x000000).rgb()];
+    assert_eq![0xffffff, NcChannel::contrasting(0x0000ff).rgb()];
+    assert_eq![0xffffff, NcChannel::contrasting(0x404040).rgb()];
+
+    // light backgrounds get black
+    assert_eq![0x000000, NcChannel::contrasting(0xffffff).rgb()];
+    assert_eq![0x000000, NcChannel::contrasting(0xffff00).rgb()];
+
+    // mid-grey is closer to black by luminance
+    assert_eq![0x000000, NcChannel::contrasting(0x808080).rgb()];
+
+    // the result is never using the default color
+    assert_eq![false, NcChannel::contrasting(0x808080).default_p()];
+}
+
+#[test]
+#[serial]
+fn from_name() {
+    assert_eq![Some(0xff0000), NcChannel::from_name("red").map(|c| c.rgb())];
+    assert_eq![
+        Some(0x6495ed),
+        NcChannel::from_name("cornflowerblue").map(|c| c.rgb())
+    ];
+    assert_eq![
+        Some(0x663399),
+        NcChannel::from_name("rebeccapurple").map(|c| c.rgb())
+    ];
+    assert_eq![
+        Some(0x000000),
+        NcChannel::from_name("black").map(|c| c.rgb())
+    ];
+
+    // case-insensitive, grey & gray are both fine
+    assert_eq![
+        Some(0x6495ed),
+        NcChannel::from_name("CornflowerBlue").map(|c| c.rgb())
+    ];
+    assert_eq![
+        NcChannel::from_name("darkgray"),
+        NcChannel::from_name("DARKGREY")
+    ];
+
+    // never using the default color
+    assert_eq![
+        Some(false),
+        NcChannel::from_name("white").map(|c| c.default_p())
+    ];
+
+    // unknown names
+    assert_eq![None, NcChannel::from_name("reddish")];
+    assert_eq![None, NcChannel::from_name("")];
+    assert_eq![None, NcChannel::from_name("#ff0000")];
+}
+
+#[test]
+#[serial]
+fn lerp() {
+    let (mut from, mut to): (NcChannel, NcChannel) = (0, 0);
+    from.set(0x000000);
+    to.set(0xff40c8);
+
+    // endpoints are exact
+    assert_eq![from, NcChannel::lerp(from, to, 0.0)];
+    assert_eq![to, NcChannel::lerp(from, to, 1.0)];
+    assert_eq![from, NcChannel::lerp(from, to, -1.0)];
+    assert_eq![to, NcChannel::lerp(from, to, 2.0)];
+
+    // the midpoint averages each component
+    assert_eq![0x802064, NcChannel::lerp(from, to, 0.5).rgb()];
+    assert_eq![false, NcChannel::lerp(from, to, 0.5).default_p()];
+
+    // default and palette-indexed endpoints can't be interpolated
+    let default: NcChannel = 0;
+    assert_eq![default, NcChannel::lerp(default, to, 0.25)];
+    assert_eq![to, NcChannel::lerp(default, to, 0.75)];
+    let palindex: NcChannel = NCCELL_BG_PALETTE | NCCELL_BGDEFAULT_MASK | 7;
+    assert_eq![from, NcChannel::lerp(from, palindex, 0.25)];
+    assert_eq![palindex, NcChannel::lerp(from, palindex, 0.75)];
+}
+
+#[test]
+#[serial]
+fn pair_lerp() {
+    let (mut from, mut to): (NcChannelPair, NcChannelPair) = (0, 0);
+    from.set_fg_rgb(0x102030);
+    from.set_bg_rgb(0xffffff);
+    to.set_fg_rgb(0x302010);
+    to.set_bg_rgb(0x000000);
+
+    assert_eq![from, NcChannelPair::lerp(from, to, 0.0)];
+    assert_eq![to, NcChannelPair::lerp(from, to, 1.0)];
+
+    let mid = NcChannelPair::lerp(from, to, 0.5);
+    assert_eq![0x202020, mid.fg_rgb()];
+    assert_eq![0x808080, mid.bg_rgb()];
+}
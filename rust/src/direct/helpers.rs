@@ -0,0 +1,151 @@
+use core::ptr::null_mut;
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{NcAlign, NcColorMode, NcDimension, NcDirect, NcResult, FILE_LIBC, NCRESULT_ERR};
+
+/// The state kept by the Rust bindings for each [NcDirect], which is opaque.
+///
+/// It's created when the context is initialized, and dropped when it stops.
+#[derive(Debug, Clone, Copy)]
+struct DirectState {
+    // the output file passed to ncdirect_init(), or null for stdout
+    out: *mut FILE_LIBC,
+    color_mode: NcColorMode,
+    // the dimensions last seen by NcDirect::detect_resize()
+    dims: Option<(NcDimension, NcDimension)>,
+}
+
+impl Default for DirectState {
+    fn default() -> Self {
+        Self {
+            out: null_mut(),
+            color_mode: NcColorMode::Auto,
+            dims: None,
+        }
+    }
+}
+
+thread_local! {
+    // the state of each NcDirect, by its address
+    static DIRECT_STATE: RefCell<HashMap<usize, DirectState>> = RefCell::new(HashMap::new());
+}
+
+/// Helper function that returns the state of the `ncd`.
+fn read_direct_state(ncd: *const NcDirect) -> DirectState {
+    DIRECT_STATE.with(|states| {
+        states
+            .borrow()
+            .get(&(ncd as usize))
+            .copied()
+            .unwrap_or_default()
+    })
+}
+
+/// Helper function that updates the state of the `ncd` with `f`.
+fn update_direct_state(ncd: *const NcDirect, f: impl FnOnce(&mut DirectState)) {
+    DIRECT_STATE.with(|states| f(states.borrow_mut().entry(ncd as usize).or_default()));
+}
+
+/// Helper function that starts tracking the state of a new `ncd`,
+/// which writes to the `out` file, or to stdout if it's null.
+pub(crate) fn init_direct_state(ncd: *const NcDirect, out: *mut FILE_LIBC) {
+    DIRECT_STATE.with(|states| {
+        states.borrow_mut().insert(
+            ncd as usize,
+            DirectState {
+                out,
+                ..Default::default()
+            },
+        )
+    });
+}
+
+/// Helper function that forgets the state of the `ncd`, when it stops.
+pub(crate) fn forget_direct_state(ncd: *const NcDirect) {
+    DIRECT_STATE.with(|states| states.borrow_mut().remove(&(ncd as usize)));
+}
+
+/// Helper function that returns the output file of the `ncd`,
+/// or null if it's stdout.
+pub(crate) fn direct_output(ncd: *const NcDirect) -> *mut FILE_LIBC {
+    read_direct_state(ncd).out
+}
+
+/// Helper function that returns the [NcColorMode] of the `ncd`.
+pub(crate) fn color_mode(ncd: *const NcDirect) -> NcColorMode {
+    read_direct_state(ncd).color_mode
+}
+
+/// Helper function that sets the [NcColorMode] of the `ncd`.
+pub(crate) fn set_color_mode(ncd: *const NcDirect, mode: NcColorMode) {
+    update_direct_state(ncd, |state| state.color_mode = mode);
+}
+
+/// Helper function that decides whether colors & styles must be emitted,
+/// in the color `mode`, depending on whether the output `is_tty`, and
+/// whether the `NO_COLOR` environment variable is set.
+pub(crate) fn styling_enabled(mode: NcColorMode, is_tty: bool, no_color: bool) -> bool {
+    match mode {
+        NcColorMode::Always => true,
+        NcColorMode::Never => false,
+        NcColorMode::Auto => is_tty && !no_color,
+    }
+}
+
+/// Helper function that caches the `dims` of the `ncd`, and returns them if
+/// they changed since the last call, or None if they didn't, or if there
+/// was no previous call.
+pub(crate) fn dims_changed(
+    ncd: *const NcDirect,
+    dims: (NcDimension, NcDimension),
+) -> Option<(NcDimension, NcDimension)> {
+    let mut changed = None;
+    update_direct_state(ncd, |state| {
+        if let Some(previous) = state.dims.replace(dims) {
+            if previous != dims {
+                changed = Some(dims);
+            }
+        }
+    });
+    changed
+}
+
+/// Helper function that splits `text` in lines, each one clipped to `cols`
+/// columns, and returns them along with their offset from the left edge,
+/// according to `align`.
+pub(crate) fn aligned_lines(
+    text: &str,
+    align: NcAlign,
+    cols: NcDimension,
+) -> NcResult<Vec<(NcDimension, &str)>> {
+    let mut lines = vec![];
+    for line in text.lines() {
+        let (line, width) = crate::plane::fitting_prefix(line, cols)?;
+        let offset = match align {
+            crate::NCALIGN_LEFT => 0,
+            crate::NCALIGN_CENTER => (cols - width) / 2,
+            crate::NCALIGN_RIGHT => cols - width,
+            _ => {
+                return Err(crate::NcError::with_kind(
+                    NCRESULT_ERR,
+                    crate::NcErrorKind::InvalidInput,
+                    "invalid alignment",
+                ))
+            }
+        };
+        lines.push((offset, line));
+    }
+    Ok(lines)
+}
+
+/// Helper function that clips the `len` of a horizontal rule starting at the
+/// column `x` to the `cols` of the terminal.
+pub(crate) fn rule_len(len: NcDimension, cols: NcDimension, x: NcDimension) -> NcDimension {
+    len.min(cols.saturating_sub(x))
+}
+
+/// Helper function that returns true if RGB colors are supported, given the
+/// `palette_size` of the terminal, and the `COLORTERM` environment variable.
+pub(crate) fn truecolor_support(palette_size: u32, colorterm: Option<&str>) -> bool {
+    palette_size >= 1 << 24 || matches![colorterm, Some("truecolor") | Some("24bit")]
+}
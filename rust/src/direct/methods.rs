@@ -4,9 +4,10 @@ use core::ptr::{null, null_mut};
 
 use crate::ffi::sigset_t;
 use crate::{
-    cstring, error, error_ref_mut, NcAlign, NcBlitter, NcChannelPair, NcColor, NcDimension,
-    NcDirect, NcDirectFlags, NcEgc, NcError, NcInput, NcPaletteIndex, NcPlane, NcResult, NcRgb,
-    NcScale, NcStyleMask, NcTime, NCRESULT_ERR,
+    cstring, error, error_ref_mut, printf, rstring, NcAlign, NcBlitter, NcChannelPair,
+    NcChannelPairMethods, NcColor, NcColorMode, NcDimension, NcDirect, NcDirectCapabilities,
+    NcDirectFlags, NcEgc, NcError, NcErrorKind, NcFile, NcInput, NcPaletteIndex, NcPlane, NcResult,
+    NcRgb, NcScale, NcStyleMask, NcTime, NCRESULT_ERR,
 };
 
 /// # `NcDirect` constructors and destructors
@@ -31,9 +32,32 @@ impl NcDirect {
     /// - [NCDIRECT_OPTION_INHIBIT_CBREAK][crate::NCDIRECT_OPTION_INHIBIT_CBREAK]
     /// - [NCDIRECT_OPTION_INHIBIT_SETLOCALE][crate::NCDIRECT_OPTION_INHIBIT_SETLOCALE]
     ///
+    /// Note that notcurses resets the styles on initialization and on
+    /// [stop()][NcDirect#method.stop], writing the terminal's reset sequences
+    /// to the standard output, even when it's not a terminal.
+    ///
     /// *C style function: [ncdirect_init()][crate::ncdirect_init].*
     pub fn with_flags<'a>(flags: NcDirectFlags) -> NcResult<&'a mut NcDirect> {
         let res = unsafe { crate::ncdirect_init(null(), null_mut(), flags) };
+        if !res.is_null() {
+            crate::direct::init_direct_state(res, null_mut());
+        }
+        error_ref_mut![res, "Initializing NcDirect"]
+    }
+
+    /// New NcDirect with optional flags, that writes to `file` instead of
+    /// the standard output.
+    ///
+    /// The `file` must outlive the NcDirect, and is not closed when it stops.
+    ///
+    /// See [with_flags()][NcDirect#method.with_flags] for the `flags`.
+    ///
+    /// *C style function: [ncdirect_init()][crate::ncdirect_init].*
+    pub fn with_file<'a>(file: &mut NcFile, flags: NcDirectFlags) -> NcResult<&'a mut NcDirect> {
+        let res = unsafe { crate::ncdirect_init(null(), file.as_nc_ptr(), flags) };
+        if !res.is_null() {
+            crate::direct::init_direct_state(res, file.as_libc_ptr());
+        }
         error_ref_mut![res, "Initializing NcDirect"]
     }
 
@@ -41,8 +65,36 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_stop()][crate::ncdirect_stop].*
     pub fn stop(&mut self) -> NcResult<()> {
+        crate::direct::forget_direct_state(self);
         error![unsafe { crate::ncdirect_stop(self) }]
     }
+
+    /// Initializes a new NcDirect with the default options, and calls the
+    /// closure `f` with it, stopping it afterwards.
+    ///
+    /// The context is stopped whether the closure returns successfully or
+    /// with an error, and also when it panics, while unwinding.
+    /// Each call manages its own context, so they can be nested.
+    ///
+    /// Returns the result of the closure, or the error stopping the context.
+    /// The closure must not stop the context itself.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with<F, T>(f: F) -> NcResult<T>
+    where
+        F: FnOnce(&mut NcDirect) -> NcResult<T>,
+    {
+        let ncd: *mut NcDirect = Self::new()?;
+        let guard = crate::notcurses::ScopeGuard::new(|| {
+            let _ = unsafe { &mut *ncd }.stop();
+        });
+        let res = f(unsafe { &mut *ncd });
+        guard.disarm();
+        let stopped = unsafe { &mut *ncd }.stop();
+        let value = res?;
+        stopped?;
+        Ok(value)
+    }
 }
 
 /// ## NcDirect methods: clear, flush, render
@@ -54,13 +106,44 @@ impl NcDirect {
         error![unsafe { crate::ncdirect_clear(self) }]
     }
 
-    /// Forces a flush.
+    /// Forces a flush of the output stream.
+    ///
+    /// Output is buffered, so it may not be immediately visible. Call this
+    /// when it must appear right away, e.g. before a blocking read, or when
+    /// updating something in place. The [fsleep!][crate::fsleep] macro
+    /// flushes and then sleeps, which is convenient for animations.
+    ///
+    /// Returns an error if the output stream can't be flushed,
+    /// e.g. if it has been closed.
     ///
     /// *C style function: [ncdirect_flush()][crate::ncdirect_flush].*
     pub fn flush(&self) -> NcResult<()> {
         error![unsafe { crate::ncdirect_flush(self) }]
     }
 
+    /// Writes the contents of an [NcPlane] starting at the current cursor
+    /// position, preserving each cell's [NcStyleMask] and colors.
+    ///
+    /// Every row of the plane is terminated with a newline, so a plane taller
+    /// than the remaining terminal space will scroll the output, just like
+    /// [render_image()][NcDirect#method.render_image] does.
+    ///
+    /// The styles and colors are reset to their defaults afterwards.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn dump(&mut self, plane: &NcPlane) -> NcResult<()> {
+        for row in dump_rows(plane)? {
+            for (egc, stylemask, channels) in row {
+                self.styles_set(stylemask)?;
+                self.putstr(channels, &egc)?;
+            }
+            // channels of 0 select the default colors
+            self.styles_set(crate::NCSTYLE_NONE)?;
+            self.putstr(0, "\n")?;
+        }
+        self.flush()
+    }
+
     /// Takes the result of [render_frame()][NcDirect#method.render_frame]
     /// and writes it to the output.
     ///
@@ -123,11 +206,17 @@ impl NcDirect {
 }
 
 /// ## NcDirect methods: `NcPaletteIndex`, `NcRgb`, `NcStyleMask` & default color
+///
+/// The setters do nothing when [styling_p()][NcDirect#method.styling_p]
+/// is false, e.g. when the output is piped to a file.
 impl NcDirect {
     /// Sets the foreground [NcPaletteIndex].
     ///
     /// *C style function: [ncdirect_set_fg_palindex()][crate::ncdirect_set_fg_palindex].*
     pub fn set_fg_palindex(&mut self, index: NcPaletteIndex) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_set_fg_palindex(self, index as i32) }]
     }
 
@@ -135,6 +224,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_bg_palindex()][crate::ncdirect_set_bg_palindex].*
     pub fn set_bg_palindex(&mut self, index: NcPaletteIndex) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_set_bg_palindex(self, index as i32) }]
     }
 
@@ -147,7 +239,11 @@ impl NcDirect {
     pub fn palette_size(&self) -> NcResult<u32> {
         let res = unsafe { crate::ncdirect_palette_size(self) };
         if res == 1 {
-            return Err(NcError::with_msg(1, "No color support"));
+            return Err(NcError::with_kind(
+                1,
+                NcErrorKind::Unsupported,
+                "No color support",
+            ));
         }
         Ok(res)
     }
@@ -156,6 +252,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_fg_rgb()][crate::ncdirect_set_fg_rgb].*
     pub fn set_fg_rgb(&mut self, rgb: NcRgb) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_set_fg_rgb(self, rgb) }]
     }
 
@@ -163,6 +262,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_bg_rgb()][crate::ncdirect_set_bg_rgb].*
     pub fn set_bg_rgb(&mut self, rgb: NcRgb) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_set_bg_rgb(self, rgb) }]
     }
 
@@ -170,6 +272,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_fg_rgb8()][crate::ncdirect_set_fg_rgb8].*
     pub fn set_fg_rgb8(&mut self, red: NcColor, green: NcColor, blue: NcColor) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![crate::ncdirect_set_fg_rgb8(self, red, green, blue)]
     }
 
@@ -177,6 +282,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_bg_rgb()][crate::ncdirect_set_bg_rgb].*
     pub fn set_bg_rgb8(&mut self, red: NcColor, green: NcColor, blue: NcColor) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![crate::ncdirect_set_bg_rgb8(self, red, green, blue)]
     }
 
@@ -184,6 +292,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_off_styles()][crate::ncdirect_off_styles].*
     pub fn styles_off(&mut self, stylebits: NcStyleMask) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         let res = unsafe { crate::ncdirect_off_styles(self, stylebits.into()) };
         error![res]
     }
@@ -192,6 +303,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_on_styles()][crate::ncdirect_on_styles].*
     pub fn styles_on(&mut self, stylebits: NcStyleMask) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_on_styles(self, stylebits.into()) }]
     }
 
@@ -199,6 +313,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_styles()][crate::ncdirect_set_styles].*
     pub fn styles_set(&mut self, stylebits: NcStyleMask) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_set_styles(self, stylebits.into()) }]
     }
 
@@ -206,6 +323,9 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_fg_default()][crate::ncdirect_set_fg_default].*
     pub fn set_fg_default(&mut self) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_set_fg_default(self) }]
     }
 
@@ -213,12 +333,82 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_set_bg_default()][crate::ncdirect_set_bg_default].*
     pub fn set_bg_default(&mut self) -> NcResult<()> {
+        if !self.styling_p() {
+            return Ok(());
+        }
         error![unsafe { crate::ncdirect_set_bg_default(self) }]
     }
 }
 
 /// ## NcDirect methods: capabilities, cursor, dimensions
 impl NcDirect {
+    /// Returns true if the output is a terminal.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn is_tty(&self) -> bool {
+        let out = crate::direct::direct_output(self);
+        let fd = if out.is_null() {
+            libc::STDOUT_FILENO
+        } else {
+            unsafe { libc::fileno(out) }
+        };
+        unsafe { libc::isatty(fd) == 1 }
+    }
+
+    /// Returns a summary of the capabilities of the terminal.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn capabilities(&self) -> NcDirectCapabilities {
+        let (tty, styling) = (self.is_tty(), self.styling_p());
+        let palette_size = if styling {
+            unsafe { crate::ncdirect_palette_size(self) }
+        } else {
+            0
+        };
+        let colorterm = std::env::var("COLORTERM").ok();
+        let dim = |d: i32| d.max(0) as NcDimension;
+        NcDirectCapabilities {
+            tty,
+            styling,
+            truecolor: styling
+                && crate::direct::truecolor_support(palette_size, colorterm.as_deref()),
+            palette_size,
+            images: tty && self.canopen_images(),
+            utf8: self.canutf8(),
+            rows: dim(unsafe { crate::ncdirect_dim_y(self) }),
+            cols: dim(unsafe { crate::ncdirect_dim_x(self) }),
+        }
+    }
+
+    /// Returns the current [NcColorMode].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn color_mode(&self) -> NcColorMode {
+        crate::direct::color_mode(self)
+    }
+
+    /// Sets the [NcColorMode] of this NcDirect, e.g. from a `--color`
+    /// command line flag.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_color_mode(&mut self, mode: NcColorMode) {
+        crate::direct::set_color_mode(self, mode);
+    }
+
+    /// Returns true if the color & style setters emit their escape sequences.
+    ///
+    /// With [NcColorMode::Auto], that's when the output is a terminal
+    /// and the `NO_COLOR` environment variable is not set.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn styling_p(&self) -> bool {
+        crate::direct::styling_enabled(
+            self.color_mode(),
+            self.is_tty(),
+            std::env::var_os("NO_COLOR").is_some(),
+        )
+    }
+
     /// Can we load images?
     ///
     /// Requires being built against FFmpeg/OIIO.
@@ -355,6 +545,23 @@ impl NcDirect {
         let x = unsafe { crate::ncdirect_dim_x(self) as NcDimension };
         (y, x)
     }
+
+    /// Returns the new number of rows and columns if they changed since the
+    /// last call, or None otherwise.
+    ///
+    /// Direct mode doesn't deliver [NCKEY_RESIZE][crate::NCKEY_RESIZE]
+    /// events, so this is meant to be polled, e.g. before redrawing a
+    /// progress bar. The first call only records the current dimensions,
+    /// and returns None.
+    ///
+    /// A terminal that doesn't report its size changes is never detected
+    /// as resized.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn detect_resize(&mut self) -> Option<(NcDimension, NcDimension)> {
+        let dims = self.dim_yx();
+        crate::direct::dims_changed(self, dims)
+    }
 }
 
 /// ## NcDirect methods: I/O
@@ -442,9 +649,37 @@ impl NcDirect {
     ///
     /// *C style function: [ncdirect_putstr()][crate::ncdirect_putstr].*
     pub fn putstr(&mut self, channels: NcChannelPair, string: &str) -> NcResult<()> {
+        if !self.styling_p() {
+            let out = crate::direct::direct_output(self);
+            let res = if out.is_null() {
+                printf!["%s", cstring![string]]
+            } else {
+                unsafe { libc::fputs(cstring![string], out) }
+            };
+            error![res, (), "NcDirect.putstr()", NcErrorKind::Io]
+        }
         error![unsafe { crate::ncdirect_putstr(self, channels, cstring![string]) }]
     }
 
+    /// Outputs the multi-line `text`, aligning each line to the width of the
+    /// terminal according to `align`, and returns the number of lines written.
+    ///
+    /// Each line starts at the left edge, is padded with spaces up to its
+    /// aligned position, and ends with a newline. Lines wider than the
+    /// terminal are clipped.
+    ///
+    /// The text is written with the default colors.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn puttext(&mut self, align: NcAlign, text: &str) -> NcResult<NcDimension> {
+        let lines = crate::direct::aligned_lines(text, align, self.dim_x())?;
+        for (offset, line) in &lines {
+            let padding = " ".repeat(*offset as usize);
+            self.putstr(0, &format!["{}{}\n", padding, line])?;
+        }
+        Ok(lines.len() as NcDimension)
+    }
+
     /// Draws a box with its upper-left corner at the current cursor position,
     /// having dimensions `ylen` * `xlen`.
     ///
@@ -561,4 +796,85 @@ impl NcDirect {
     ) -> NcResult<()> {
         error![unsafe { crate::ncdirect_vline_interp(self, &(*egc as i8), len as i32, h1, h2) }]
     }
+
+    /// Draws a horizontal rule of `len` columns with the `─` glyph, in the
+    /// default colors, starting at the current cursor position.
+    ///
+    /// The rule is clipped at the right edge of the terminal.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn rule(&mut self, len: NcDimension) -> NcResult<()> {
+        self.rule_interp(len, 0, 0)
+    }
+
+    /// Draws a horizontal rule of `len` columns with the `─` glyph, starting
+    /// at the current cursor position, fading its foreground color from
+    /// `from` to `to`.
+    ///
+    /// The rule is clipped at the right edge of the terminal.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn colored_rule(&mut self, len: NcDimension, from: NcRgb, to: NcRgb) -> NcResult<()> {
+        let (mut h1, mut h2): (NcChannelPair, NcChannelPair) = (0, 0);
+        h1.set_fg_rgb(from);
+        h2.set_fg_rgb(to);
+        self.rule_interp(len, h1, h2)
+    }
+
+    /// Draws the clipped horizontal rule of [rule()][NcDirect#method.rule]
+    /// and [colored_rule()][NcDirect#method.colored_rule].
+    fn rule_interp(
+        &mut self,
+        len: NcDimension,
+        h1: NcChannelPair,
+        h2: NcChannelPair,
+    ) -> NcResult<()> {
+        // the cursor position can't be queried if the output isn't a terminal
+        let x = self.cursor_yx().map_or(0, |(_, x)| x);
+        let len = crate::direct::rule_len(len, self.dim_x(), x);
+        if len == 0 {
+            return Ok(());
+        }
+        error![unsafe { crate::ncdirect_hline_interp(self, cstring!["─"], len as i32, h1, h2) }]
+    }
+}
+
+/// Extracts the glyphs, styles and channels of every row of `plane`,
+/// skipping the right halves of wide glyphs, and replacing empty cells
+/// with spaces.
+pub(crate) fn dump_rows(
+    plane: &NcPlane,
+) -> NcResult<Vec<Vec<(String, NcStyleMask, NcChannelPair)>>> {
+    let (rows, cols) = plane.dim_yx();
+    let mut dump = Vec::with_capacity(rows as usize);
+    for y in 0..rows {
+        let mut row = Vec::with_capacity(cols as usize);
+        for x in 0..cols {
+            let mut stylemask = 0;
+            let mut channels = 0;
+            let egc = unsafe {
+                crate::ncplane_at_yx(plane, y as i32, x as i32, &mut stylemask, &mut channels)
+            };
+            if egc.is_null() {
+                return Err(NcError::with_kind(
+                    NCRESULT_ERR,
+                    NcErrorKind::NullReturn,
+                    "NcDirect.dump()",
+                ));
+            }
+            let string = rstring![egc].to_string();
+            unsafe { libc::free(egc as *mut libc::c_void) };
+            if string.is_empty() {
+                if channels & crate::NCCELL_WIDEASIAN_MASK as NcChannelPair != 0 {
+                    // right half of a wide glyph
+                    continue;
+                }
+                row.push((" ".to_string(), stylemask, channels));
+            } else {
+                row.push((string, stylemask, channels));
+            }
+        }
+        dump.push(row);
+    }
+    Ok(dump)
 }
@@ -61,11 +61,15 @@
 //W+ ncdirect_getc_nblock
 //W+ ncdirect_getc_nblocking
 
+use crate::NcDimension;
+
 #[cfg(test)]
 mod test;
 
+mod helpers;
 mod methods;
 mod reimplemented;
+pub(crate) use helpers::*;
 pub use reimplemented::*;
 
 /// Minimal notcurses instances for styling text
@@ -91,3 +95,42 @@ pub const NCDIRECT_OPTION_INHIBIT_CBREAK: NcDirectFlags =
 ///
 pub const NCDIRECT_OPTION_INHIBIT_SETLOCALE: NcDirectFlags =
     crate::bindings::ffi::NCDIRECT_OPTION_INHIBIT_SETLOCALE as NcDirectFlags;
+
+/// A summary of the capabilities of the terminal of an [`NcDirect`],
+/// returned by [`capabilities()`][NcDirect#method.capabilities].
+///
+/// When the output is not a terminal, or the styling is disabled by the
+/// [`NcColorMode`], there's no color nor image support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NcDirectCapabilities {
+    /// Whether the output is a terminal.
+    pub tty: bool,
+    /// Whether the color & style setters emit their escape sequences.
+    pub styling: bool,
+    /// Whether RGB colors are supported, instead of being downsampled.
+    pub truecolor: bool,
+    /// The number of simultaneous colors supported, or 0 if none.
+    pub palette_size: u32,
+    /// Whether images can be loaded and displayed.
+    pub images: bool,
+    /// Whether the encoding is UTF-8.
+    pub utf8: bool,
+    /// The number of rows of the terminal, or 0 if unknown.
+    pub rows: NcDimension,
+    /// The number of columns of the terminal, or 0 if unknown.
+    pub cols: NcDimension,
+}
+
+/// Whether the [`NcDirect`] color & style setters emit their escape sequences.
+///
+/// Each NcDirect has its own mode, which starts as [`Auto`][NcColorMode::Auto].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcColorMode {
+    /// Only when the output is a terminal,
+    /// and the `NO_COLOR` environment variable is not set (the default).
+    Auto,
+    /// Always, like a `--color=always` flag.
+    Always,
+    /// Never, like a `--color=never` flag.
+    Never,
+}
@@ -0,0 +1,282 @@
+//! Test `NcDirect` methods and associated functions.
+
+use crate::direct::methods::dump_rows;
+use crate::{
+    NcColorMode, NcDirect, NcPlane, Notcurses, NCALIGN_CENTER, NCALIGN_LEFT, NCALIGN_RIGHT,
+};
+use serial_test::serial;
+
+#[test]
+fn aligned_lines() -> crate::NcResult<()> {
+    use crate::direct::aligned_lines;
+
+    let text = "title\nsub\n\nwider than ten";
+    assert_eq![
+        vec![(0, "title"), (0, "sub"), (0, ""), (0, "wider than")],
+        aligned_lines(text, NCALIGN_LEFT, 10)?
+    ];
+    assert_eq![
+        vec![(2, "title"), (3, "sub"), (5, ""), (0, "wider than")],
+        aligned_lines(text, NCALIGN_CENTER, 10)?
+    ];
+    assert_eq![
+        vec![(5, "title"), (7, "sub"), (10, ""), (0, "wider than")],
+        aligned_lines(text, NCALIGN_RIGHT, 10)?
+    ];
+
+    // wide characters take two columns
+    assert_eq![
+        vec![(3, "字字")],
+        aligned_lines("字字", NCALIGN_CENTER, 10)?
+    ];
+    assert![aligned_lines(text, crate::NCALIGN_UNALIGNED, 10).is_err()];
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn detect_resize() -> crate::NcResult<()> {
+    let ncd = NcDirect::new()?;
+    // the first call only records the dimensions
+    assert_eq![None, ncd.detect_resize()];
+    assert_eq![None, ncd.detect_resize()];
+    ncd.stop()?;
+    Ok(())
+}
+
+#[test]
+fn dims_changed() {
+    use crate::direct::{dims_changed, forget_direct_state};
+
+    let ncd = 0x1000 as *const NcDirect;
+    assert_eq![None, dims_changed(ncd, (24, 80))];
+    assert_eq![None, dims_changed(ncd, (24, 80))];
+    assert_eq![Some((30, 100)), dims_changed(ncd, (30, 100))];
+    assert_eq![None, dims_changed(ncd, (30, 100))];
+
+    // other contexts are tracked independently
+    let other = 0x2000 as *const NcDirect;
+    assert_eq![None, dims_changed(other, (10, 10))];
+    assert_eq![Some((24, 80)), dims_changed(ncd, (24, 80))];
+
+    forget_direct_state(ncd);
+    assert_eq![None, dims_changed(ncd, (50, 50))];
+}
+
+#[test]
+#[serial]
+fn dump_contains_glyphs() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 4)?;
+    plane.putstr_yx(0, 0, "ab")?;
+    plane.putstr_yx(1, 0, "cd")?;
+
+    let rows = dump_rows(plane)?;
+    assert_eq![2, rows.len()];
+    let text: Vec<String> = rows
+        .iter()
+        .map(|row| row.iter().map(|(egc, _, _)| egc.as_str()).collect())
+        .collect();
+    assert_eq!["ab  ", text[0]];
+    assert_eq!["cd  ", text[1]];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn flush() -> crate::NcResult<()> {
+    let ncd = NcDirect::new()?;
+    ncd.putstr(0, "")?;
+    ncd.flush()?;
+    ncd.stop()?;
+    Ok(())
+}
+
+#[test]
+fn styling_enabled() {
+    use crate::direct::styling_enabled;
+
+    // auto: only on a terminal, without NO_COLOR
+    assert![styling_enabled(NcColorMode::Auto, true, false)];
+    assert![!styling_enabled(NcColorMode::Auto, false, false)];
+    assert![!styling_enabled(NcColorMode::Auto, true, true)];
+    // forced
+    assert![styling_enabled(NcColorMode::Always, false, true)];
+    assert![!styling_enabled(NcColorMode::Never, true, false)];
+}
+
+#[test]
+#[serial]
+fn setters_noop_without_styling() -> crate::NcResult<()> {
+    use crate::{NcFile, NCSTYLE_BOLD};
+
+    // a context writing to a pipe, which is not a terminal
+    let mut fds = [0; 2];
+    assert_eq![0, unsafe { libc::pipe(fds.as_mut_ptr()) }];
+    unsafe { libc::fcntl(fds[0], libc::F_SETFL, libc::O_NONBLOCK) };
+    let mode = b"w\0".as_ptr() as *const libc::c_char;
+    let mut file = NcFile::from_libc(unsafe { libc::fdopen(fds[1], mode) });
+
+    // flushes the context, and returns what was written to the pipe since
+    let written = |ncd: &mut NcDirect| -> crate::NcResult<Vec<u8>> {
+        ncd.flush()?;
+        let (mut bytes, mut buf) = (vec![], [0_u8; 1024]);
+        loop {
+            let n = unsafe { libc::read(fds[0], buf.as_mut_ptr() as *mut _, buf.len()) };
+            if n <= 0 {
+                return Ok(bytes);
+            }
+            bytes.extend_from_slice(&buf[..n as usize]);
+        }
+    };
+
+    let ncd = NcDirect::with_file(&mut file, 0)?;
+    assert![!ncd.is_tty()];
+    assert_eq![NcColorMode::Auto, ncd.color_mode()];
+    assert![!ncd.styling_p()];
+    written(ncd)?;
+
+    // only the text is written
+    ncd.set_fg_rgb(0x112233)?;
+    ncd.set_bg_palindex(3)?;
+    ncd.styles_set(NCSTYLE_BOLD)?;
+    ncd.set_fg_default()?;
+    ncd.putstr(0, "plain")?;
+    assert_eq![b"plain".to_vec(), written(ncd)?];
+
+    // forcing the colors, e.g. with --color=always
+    ncd.set_color_mode(NcColorMode::Always);
+    assert![ncd.styling_p()];
+    ncd.styles_set(NCSTYLE_BOLD)?;
+    ncd.putstr(0, "bold")?;
+    let styled = written(ncd)?;
+    assert![styled.len() > 4 && styled.ends_with(b"bold")];
+
+    // the mode belongs to each context
+    let other = NcDirect::with_file(&mut file, 0)?;
+    assert_eq![NcColorMode::Auto, other.color_mode()];
+    other.stop()?;
+
+    ncd.set_color_mode(NcColorMode::Never);
+    assert![!ncd.styling_p()];
+    ncd.stop()?;
+    drop(file);
+    unsafe { libc::close(fds[0]) };
+    Ok(())
+}
+
+#[test]
+fn rule_len() {
+    use crate::direct::rule_len;
+
+    assert_eq![20, rule_len(20, 80, 0)];
+    // clipped to the terminal width
+    assert_eq![80, rule_len(200, 80, 0)];
+    assert_eq![70, rule_len(200, 80, 10)];
+    assert_eq![0, rule_len(5, 80, 90)];
+}
+
+#[test]
+#[serial]
+fn rule() -> crate::NcResult<()> {
+    let ncd = NcDirect::new()?;
+    let cols = ncd.dim_x();
+    ncd.rule(cols + 20)?;
+    ncd.putstr(0, "\n")?;
+    ncd.colored_rule(cols + 20, 0xff0000, 0x0000ff)?;
+    ncd.putstr(0, "\n")?;
+    ncd.stop()?;
+    Ok(())
+}
+
+#[test]
+fn truecolor_support() {
+    use crate::direct::truecolor_support;
+
+    assert![truecolor_support(1 << 24, None)];
+    assert![truecolor_support(256, Some("truecolor"))];
+    assert![truecolor_support(256, Some("24bit"))];
+    assert![!truecolor_support(256, Some("yes"))];
+    assert![!truecolor_support(8, None)];
+}
+
+#[test]
+#[serial]
+fn capabilities() -> crate::NcResult<()> {
+    let ncd = NcDirect::new()?;
+    let caps = ncd.capabilities();
+    assert_eq![ncd.is_tty(), caps.tty];
+    assert_eq![ncd.canutf8(), caps.utf8];
+    if caps.tty {
+        assert_eq![ncd.dim_yx(), (caps.rows, caps.cols)];
+    } else {
+        // no colors nor images for a pipe
+        assert![!caps.images];
+    }
+    if !caps.styling {
+        assert_eq![(0, false), (caps.palette_size, caps.truecolor)];
+    }
+    ncd.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn with() -> crate::NcResult<()> {
+    use crate::{NcError, NcErrorKind, NCRESULT_ERR};
+
+    // the local modes of the terminal, which the context puts in cbreak mode
+    // while running, and restores when stopped
+    let lflag = || {
+        use std::os::unix::io::AsRawFd;
+        let tty = std::fs::File::open("/dev/tty").ok()?;
+        let mut termios = unsafe { core::mem::zeroed::<libc::termios>() };
+        match unsafe { libc::tcgetattr(tty.as_raw_fd(), &mut termios) } {
+            0 => Some(termios.c_lflag),
+            _ => None,
+        }
+    };
+    let before = lflag();
+    let cbreak = before.map(|l| l & !(libc::ICANON | libc::ECHO));
+    // only observable when the terminal starts in canonical mode
+    let canonical = before.map_or(false, |l| l & libc::ICANON != 0);
+    let running = || !canonical || lflag() == cbreak;
+    let stopped = || lflag() == before;
+
+    let value = NcDirect::with(|ncd| {
+        assert![running()];
+        ncd.putstr(0, "")?;
+        Ok(42)
+    })?;
+    assert_eq![42, value];
+    assert![stopped()];
+
+    let res: crate::NcResult<()> = NcDirect::with(|_ncd| {
+        Err(NcError::with_kind(
+            NCRESULT_ERR,
+            NcErrorKind::Other,
+            "failing closure",
+        ))
+    });
+    assert_eq![NcErrorKind::Other, res.unwrap_err().kind()];
+    assert![stopped()];
+
+    let res = std::panic::catch_unwind(|| {
+        NcDirect::with(|_ncd| -> crate::NcResult<()> { panic!["in closure"] })
+    });
+    assert![res.is_err()];
+    assert![stopped()];
+
+    // nested scopes manage their own contexts
+    NcDirect::with(|outer| {
+        let inner = NcDirect::with(|inner| Ok(inner as *const NcDirect))?;
+        assert_ne![inner, &*outer as *const NcDirect];
+        // the outer context is still running
+        assert![running()];
+        outer.putstr(0, "")
+    })?;
+    assert![stopped()];
+    Ok(())
+}
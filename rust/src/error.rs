@@ -22,12 +22,41 @@ pub const NCRESULT_ERR: i32 = -1;
 /// MAX value, for the functions that return [`NcIntResult`].
 pub const NCRESULT_MAX: i32 = i32::MAX;
 
+/// The kind of an [NcError], for the failures that can be classified.
+///
+/// Errors returned directly from the C API are usually of the
+/// [Other][NcErrorKind::Other] kind, since they carry no more information
+/// than the [NcIntResult].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NcErrorKind {
+    /// A coordinate or dimension lies outside of the valid bounds.
+    BoundsError,
+    /// The terminal doesn't support the requested capability.
+    Unsupported,
+    /// An input/output operation failed.
+    Io,
+    /// A function returned a null pointer.
+    NullReturn,
+    /// An argument has an invalid value.
+    InvalidInput,
+    /// Any other failure.
+    Other,
+}
+
+impl Default for NcErrorKind {
+    fn default() -> Self {
+        NcErrorKind::Other
+    }
+}
+
 /// The error type for the Rust methods API.
 #[derive(Debug, Clone, Default)]
 pub struct NcError {
     /// [NcIntResult].
     pub int: i32,
     pub msg: String,
+    // returned by kind()
+    kind: NcErrorKind,
 }
 
 impl fmt::Display for NcError {
@@ -55,8 +84,21 @@ impl NcError {
         Self {
             int,
             msg: msg.to_string(),
+            ..Default::default()
         }
     }
+    /// New NcError with message and [NcErrorKind].
+    pub fn with_kind(int: NcIntResult, kind: NcErrorKind, msg: &str) -> Self {
+        Self {
+            int,
+            msg: msg.to_string(),
+            kind,
+        }
+    }
+    /// Returns the [NcErrorKind].
+    pub fn kind(&self) -> NcErrorKind {
+        self.kind
+    }
 }
 
 /// The result type for the Rust methods API.
@@ -9,6 +9,11 @@ use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
 
 use libc::{c_long, c_void, fclose, feof, fread, fseek, ftell, SEEK_CUR, SEEK_END, SEEK_SET};
 
+use crate::{NcError, NcErrorKind, NcResult, NCRESULT_ERR};
+
+#[cfg(test)]
+mod test;
+
 /// See [NcFile]. Notcurses functions expects this type of `*FILE` (a struct)
 pub type FILE_NC = crate::ffi::_IO_FILE;
 
@@ -125,8 +130,57 @@ impl NcFile {
         self.read_to_end(buf)
     }
 
+    /// Reads the whole file from the start, and returns it as a String.
+    ///
+    /// Returns an error if the file is not readable (e.g. it was opened
+    /// in write only mode), or if its contents are not valid UTF-8.
+    pub fn read_string(&mut self) -> NcResult<String> {
+        if !self.is_readable() {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::Io,
+                "NcFile.read_string(): not readable",
+            ));
+        }
+        let mut buf = Vec::new();
+        if self.read_all(&mut buf).is_err() {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::Io,
+                "NcFile.read_string()",
+            ));
+        }
+        String::from_utf8(buf).map_err(|_| {
+            NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcFile.read_string(): not UTF-8",
+            )
+        })
+    }
+
+    /// Sets the position back to the start of the file.
+    pub fn rewind(&mut self) -> NcResult<()> {
+        if self.seek(SeekFrom::Start(0)).is_err() {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::Io,
+                "NcFile.rewind()",
+            ));
+        }
+        Ok(())
+    }
+
     // private methods --
 
+    /// Returns true if the file was opened for reading.
+    fn is_readable(&self) -> bool {
+        unsafe {
+            let flags = libc::fcntl(libc::fileno(self.as_libc_ptr()), libc::F_GETFL);
+            flags != -1 && flags & libc::O_ACCMODE != libc::O_WRONLY
+        }
+    }
+
     /// Converts a file pointer from the struct notcurses uses to the
     /// opaque enum type libc expects
     #[inline]
@@ -0,0 +1,48 @@
+//! Test `NcFile` methods.
+
+use std::ffi::CString;
+
+use crate::NcFile;
+
+/// Opens a new temporary file in `mode`, with the `contents` written.
+fn file_with(name: &str, mode: &str, contents: &str) -> NcFile {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, contents).unwrap();
+    let path = CString::new(path.to_str().unwrap()).unwrap();
+    let mode = CString::new(mode).unwrap();
+    let file = unsafe { libc::fopen(path.as_ptr(), mode.as_ptr()) };
+    assert![!file.is_null()];
+    NcFile::from_libc(file)
+}
+
+#[test]
+fn read_string() -> crate::NcResult<()> {
+    let mut file = file_with("ncfile-read-string", "r+", "");
+    let text = "known content ✓\n";
+    let written = unsafe {
+        libc::fwrite(
+            text.as_ptr() as *const libc::c_void,
+            1,
+            text.len(),
+            file.as_libc_ptr(),
+        )
+    };
+    assert_eq![text.len(), written];
+
+    file.rewind()?;
+    assert_eq![0, file.current_pos().unwrap()];
+    assert_eq![text, file.read_string()?];
+    // it always reads from the start
+    assert_eq![text, file.read_string()?];
+    Ok(())
+}
+
+#[test]
+fn read_string_write_only() {
+    let mut file = file_with("ncfile-write-only", "w", "");
+    assert![file.read_string().is_err()];
+    assert_eq![
+        Some(crate::NcErrorKind::Io),
+        file.read_string().err().map(|e| e.kind())
+    ];
+}
@@ -0,0 +1,4 @@
+//! `NcFile` tests.
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,94 @@
+//! Conversion of `NcInput` into `crossterm` events.
+//!
+//! Requires the `crossterm` feature.
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+use crate::{
+    nckey_mouse_p, NcInput, NCKEY_BACKSPACE, NCKEY_BUTTON1, NCKEY_BUTTON2, NCKEY_BUTTON3,
+    NCKEY_DEL, NCKEY_DOWN, NCKEY_END, NCKEY_ENTER, NCKEY_ESC, NCKEY_F00, NCKEY_F60, NCKEY_HOME,
+    NCKEY_INS, NCKEY_LEFT, NCKEY_PGDOWN, NCKEY_PGUP, NCKEY_RELEASE, NCKEY_RESIZE, NCKEY_RIGHT,
+    NCKEY_SCROLL_DOWN, NCKEY_SCROLL_UP, NCKEY_UP,
+};
+
+/// Converts an [NcInput] into the closest crossterm [Event].
+///
+/// - Keys are converted to [Event::Key], with the modifiers. A control
+///   combination with a letter uses the lowercase letter, like crossterm does.
+/// - Mouse events are converted to [Event::Mouse]. Notcurses reports the
+///   motion while a button is held as repeated presses, so they are all
+///   converted to [MouseEventKind::Down]. A release doesn't identify the
+///   button, so it's converted to a release of the left button.
+/// - [NCKEY_RESIZE] is converted to [Event::Resize], with 0 rows and columns,
+///   since notcurses doesn't report the new size along with the event.
+///
+/// The events crossterm can't represent, like the mouse buttons beyond the
+/// fifth or most of the keypad keys, are converted to a [KeyCode::Null] key,
+/// as well as the inputs whose id is not a valid codepoint.
+impl From<NcInput> for Event {
+    fn from(input: NcInput) -> Event {
+        let mut modifiers = KeyModifiers::empty();
+        if input.shift {
+            modifiers |= KeyModifiers::SHIFT;
+        }
+        if input.ctrl {
+            modifiers |= KeyModifiers::CONTROL;
+        }
+        if input.alt {
+            modifiers |= KeyModifiers::ALT;
+        }
+
+        let id = match core::char::from_u32(input.id) {
+            Some(id) => id,
+            None => return Event::Key(KeyEvent::new(KeyCode::Null, modifiers)),
+        };
+        if id == NCKEY_RESIZE {
+            return Event::Resize(0, 0);
+        }
+        if nckey_mouse_p(id) {
+            let kind = match id {
+                NCKEY_BUTTON1 => Some(MouseEventKind::Down(MouseButton::Left)),
+                NCKEY_BUTTON2 => Some(MouseEventKind::Down(MouseButton::Middle)),
+                NCKEY_BUTTON3 => Some(MouseEventKind::Down(MouseButton::Right)),
+                NCKEY_SCROLL_UP => Some(MouseEventKind::ScrollUp),
+                NCKEY_SCROLL_DOWN => Some(MouseEventKind::ScrollDown),
+                NCKEY_RELEASE => Some(MouseEventKind::Up(MouseButton::Left)),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                return Event::Mouse(MouseEvent {
+                    kind,
+                    column: input.x.max(0).min(u16::MAX as i32) as u16,
+                    row: input.y.max(0).min(u16::MAX as i32) as u16,
+                    modifiers,
+                });
+            }
+            return Event::Key(KeyEvent::new(KeyCode::Null, modifiers));
+        }
+
+        let code = match id {
+            NCKEY_UP => KeyCode::Up,
+            NCKEY_RIGHT => KeyCode::Right,
+            NCKEY_DOWN => KeyCode::Down,
+            NCKEY_LEFT => KeyCode::Left,
+            NCKEY_INS => KeyCode::Insert,
+            NCKEY_DEL => KeyCode::Delete,
+            NCKEY_BACKSPACE | '\x7f' => KeyCode::Backspace,
+            NCKEY_PGDOWN => KeyCode::PageDown,
+            NCKEY_PGUP => KeyCode::PageUp,
+            NCKEY_HOME => KeyCode::Home,
+            NCKEY_END => KeyCode::End,
+            NCKEY_ENTER | '\n' | '\r' => KeyCode::Enter,
+            NCKEY_ESC => KeyCode::Esc,
+            '\t' if input.shift => KeyCode::BackTab,
+            '\t' => KeyCode::Tab,
+            NCKEY_F00..=NCKEY_F60 => KeyCode::F((id as u32 - NCKEY_F00 as u32) as u8),
+            c if crate::nckey_supppuab_p(c) => KeyCode::Null,
+            c if input.ctrl && c.is_ascii_uppercase() => KeyCode::Char(c.to_ascii_lowercase()),
+            c => KeyCode::Char(c),
+        };
+        Event::Key(KeyEvent::new(code, modifiers))
+    }
+}
@@ -0,0 +1,143 @@
+//! `NcKeyCombo`
+
+use crate::NcInput;
+
+/// A key, plus its modifiers, for declaring keybindings.
+///
+/// It implements [Hash] and [Eq] so that it can be used as a map key,
+/// e.g. in a `HashMap<NcKeyCombo, Action>`, and can be matched against an
+/// [NcInput] with [matches()][NcKeyCombo#method.matches].
+///
+/// ## Ctrl + letter normalization
+///
+/// Terminals send Ctrl + letter as a control character (`0x01`..`0x1a`),
+/// independently of Shift. Notcurses reports it as the uppercase letter with
+/// the `ctrl` modifier set, but an [NcInput] could also carry the raw control
+/// character. Both forms are normalized to the uppercase letter plus `ctrl`,
+/// with `shift` ignored, so that `NcKeyCombo::ctrl('s')`,
+/// `NcKeyCombo::ctrl('S')` and `NcKeyCombo::ctrl_shift('s')` are all equal,
+/// and match either form.
+///
+/// Tab (`^I`), Enter (`^J`) and Return (`^M`) are never treated as
+/// Ctrl + letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NcKeyCombo {
+    key: char,
+    alt: bool,
+    shift: bool,
+    ctrl: bool,
+}
+
+/// # `NcKeyCombo` constructors
+impl NcKeyCombo {
+    /// New NcKeyCombo, expecting all the modifiers.
+    pub fn with_modifiers(key: char, alt: bool, shift: bool, ctrl: bool) -> Self {
+        let (key, ctrl) = match ctrl_letter(key) {
+            Some(letter) => (letter, true),
+            None => (key, ctrl),
+        };
+        if ctrl && key.is_ascii_alphabetic() {
+            Self {
+                key: key.to_ascii_uppercase(),
+                alt,
+                shift: false,
+                ctrl,
+            }
+        } else {
+            Self {
+                key,
+                alt,
+                shift,
+                ctrl,
+            }
+        }
+    }
+
+    /// New NcKeyCombo without modifiers.
+    pub fn key(key: char) -> Self {
+        Self::with_modifiers(key, false, false, false)
+    }
+
+    /// New NcKeyCombo with the alt key.
+    pub fn alt(key: char) -> Self {
+        Self::with_modifiers(key, true, false, false)
+    }
+
+    /// New NcKeyCombo with the shift key.
+    pub fn shift(key: char) -> Self {
+        Self::with_modifiers(key, false, true, false)
+    }
+
+    /// New NcKeyCombo with the ctrl key.
+    pub fn ctrl(key: char) -> Self {
+        Self::with_modifiers(key, false, false, true)
+    }
+
+    /// New NcKeyCombo with the alt & shift keys.
+    pub fn alt_shift(key: char) -> Self {
+        Self::with_modifiers(key, true, true, false)
+    }
+
+    /// New NcKeyCombo with the ctrl & alt keys.
+    pub fn ctrl_alt(key: char) -> Self {
+        Self::with_modifiers(key, true, false, true)
+    }
+
+    /// New NcKeyCombo with the ctrl & shift keys.
+    pub fn ctrl_shift(key: char) -> Self {
+        Self::with_modifiers(key, false, true, true)
+    }
+
+    /// New NcKeyCombo from an [NcInput].
+    ///
+    /// Returns None if the input id is not a valid [char].
+    pub fn from_input(input: &NcInput) -> Option<Self> {
+        let key = core::char::from_u32(input.id)?;
+        Some(Self::with_modifiers(
+            key,
+            input.alt,
+            input.shift,
+            input.ctrl,
+        ))
+    }
+}
+
+/// # `NcKeyCombo` methods
+impl NcKeyCombo {
+    /// Returns the key.
+    pub fn key_char(&self) -> char {
+        self.key
+    }
+
+    /// Returns true if the alt modifier is set.
+    pub fn alt_p(&self) -> bool {
+        self.alt
+    }
+
+    /// Returns true if the shift modifier is set.
+    pub fn shift_p(&self) -> bool {
+        self.shift
+    }
+
+    /// Returns true if the ctrl modifier is set.
+    pub fn ctrl_p(&self) -> bool {
+        self.ctrl
+    }
+
+    /// Returns true if the `input` corresponds to this key combination.
+    ///
+    /// The coordinates and sequence number of the input are not compared.
+    pub fn matches(&self, input: &NcInput) -> bool {
+        Self::from_input(input).map_or(false, |combo| combo == *self)
+    }
+}
+
+/// Returns the uppercase letter corresponding to a Ctrl + letter
+/// control character, except for Tab, Enter & Return.
+fn ctrl_letter(key: char) -> Option<char> {
+    match key {
+        '\t' | '\n' | '\r' => None,
+        '\u{1}'..='\u{1a}' => Some((key as u8 + b'A' - 1) as char),
+        _ => None,
+    }
+}
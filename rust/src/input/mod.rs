@@ -11,8 +11,21 @@
 
 use crate::NcDimension;
 
+#[cfg(test)]
+mod test;
+
+#[cfg(feature = "crossterm")]
+mod crossterm;
 mod keycodes;
+mod keycombo;
+mod mouse;
+mod paste;
+mod repeat;
 pub use keycodes::*;
+pub use keycombo::*;
+pub use mouse::*;
+pub use paste::*;
+pub use repeat::*;
 
 /// Reads and decodes input events
 ///
@@ -50,6 +63,13 @@ pub const fn ncinput_equal_p(n1: NcInput, n2: NcInput) -> bool {
     true
 }
 
+/// Compares two NcInput with [ncinput_equal_p].
+impl PartialEq for NcInput {
+    fn eq(&self, other: &Self) -> bool {
+        ncinput_equal_p(*self, *other)
+    }
+}
+
 /// New NcInput.
 impl NcInput {
     /// New empty NcInput.
@@ -0,0 +1,176 @@
+//! `NcMouseTracker`
+
+use std::time::{Duration, Instant};
+
+use crate::{NcDimension, NcInput, NCKEY_BUTTON1, NCKEY_BUTTON3, NCKEY_RELEASE};
+
+/// A mouse gesture, synthesized by an [NcMouseTracker].
+///
+/// The `button` is one of [NCKEY_BUTTON1], [NCKEY_BUTTON2][crate::NCKEY_BUTTON2]
+/// or [NCKEY_BUTTON3], and `y`, `x` are the cell coordinates of the event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcMouseGesture {
+    /// A button was pressed and released in place.
+    Click {
+        button: char,
+        y: NcDimension,
+        x: NcDimension,
+    },
+    /// A second click of the same button, in the same place, in time.
+    DoubleClick {
+        button: char,
+        y: NcDimension,
+        x: NcDimension,
+    },
+    /// The pointer moved beyond the drag threshold while the button was held.
+    ///
+    /// The coordinates are the ones where the button was pressed.
+    DragStart {
+        button: char,
+        y: NcDimension,
+        x: NcDimension,
+    },
+    /// The pointer moved while dragging.
+    Drag {
+        button: char,
+        y: NcDimension,
+        x: NcDimension,
+    },
+    /// The button was released while dragging.
+    DragEnd {
+        button: char,
+        y: NcDimension,
+        x: NcDimension,
+    },
+}
+
+/// Synthesizes [NcMouseGesture]s from the raw mouse events.
+///
+/// Notcurses reports a press of a button, the motion while it's held as
+/// repeated presses with new coordinates, and finally a release, which
+/// doesn't identify the button.
+///
+/// A press of another button while one is held abandons the first one,
+/// and a click of another button prevents a double click.
+#[derive(Debug, Clone)]
+pub struct NcMouseTracker {
+    double_click_interval: Duration,
+    drag_threshold: NcDimension,
+    // the button being held: (button, y, x where pressed, dragging)
+    pressed: Option<(char, NcDimension, NcDimension, bool)>,
+    // the last click: (button, y, x, when)
+    last_click: Option<(char, NcDimension, NcDimension, Instant)>,
+}
+
+impl Default for NcMouseTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # `NcMouseTracker` constructors & builder methods
+impl NcMouseTracker {
+    /// New NcMouseTracker, with a double click interval of 400 milliseconds,
+    /// and a drag threshold of 1 cell.
+    pub fn new() -> Self {
+        Self {
+            double_click_interval: Duration::from_millis(400),
+            drag_threshold: 1,
+            pressed: None,
+            last_click: None,
+        }
+    }
+
+    /// Sets the maximum interval between the clicks of a double click.
+    pub fn set_double_click_interval(mut self, interval: Duration) -> Self {
+        self.double_click_interval = interval;
+        self
+    }
+
+    /// Sets the distance in cells, along either axis, the pointer has to move
+    /// while a button is held to start dragging. The minimum is 1.
+    ///
+    /// The clicks of a double click must be closer than this.
+    pub fn set_drag_threshold(mut self, cells: NcDimension) -> Self {
+        self.drag_threshold = cells.max(1);
+        self
+    }
+}
+
+/// # `NcMouseTracker` methods
+impl NcMouseTracker {
+    /// Processes the `input`, and returns the gesture it completes, if any.
+    ///
+    /// Any input other than the buttons 1 to 3 and their release is ignored.
+    pub fn process(&mut self, input: &NcInput) -> Option<NcMouseGesture> {
+        self.process_at(input, Instant::now())
+    }
+
+    /// Processes the `input` as received at the instant `now`.
+    ///
+    /// See [process()][NcMouseTracker#method.process].
+    pub fn process_at(&mut self, input: &NcInput, now: Instant) -> Option<NcMouseGesture> {
+        let key = core::char::from_u32(input.id)?;
+        let (y, x) = (input.y.max(0) as NcDimension, input.x.max(0) as NcDimension);
+
+        if key == NCKEY_RELEASE {
+            let (button, press_y, press_x, dragging) = self.pressed.take()?;
+            if dragging {
+                return Some(NcMouseGesture::DragEnd { button, y, x });
+            }
+            return Some(self.click(button, press_y, press_x, now));
+        }
+        if key < NCKEY_BUTTON1 || key > NCKEY_BUTTON3 {
+            return None;
+        }
+
+        match self.pressed {
+            // the motion of the held button
+            Some((button, press_y, press_x, dragging)) if button == key => {
+                if dragging {
+                    Some(NcMouseGesture::Drag { button, y, x })
+                } else if self.far(press_y, press_x, y, x) {
+                    self.pressed = Some((button, press_y, press_x, true));
+                    Some(NcMouseGesture::DragStart {
+                        button,
+                        y: press_y,
+                        x: press_x,
+                    })
+                } else {
+                    None
+                }
+            }
+            // a new press
+            _ => {
+                self.pressed = Some((key, y, x, false));
+                None
+            }
+        }
+    }
+
+    /// Returns a click or a double click of the `button` at `y`, `x`.
+    fn click(
+        &mut self,
+        button: char,
+        y: NcDimension,
+        x: NcDimension,
+        now: Instant,
+    ) -> NcMouseGesture {
+        if let Some((last_button, last_y, last_x, when)) = self.last_click.take() {
+            if last_button == button
+                && !self.far(last_y, last_x, y, x)
+                && now.saturating_duration_since(when) <= self.double_click_interval
+            {
+                return NcMouseGesture::DoubleClick { button, y, x };
+            }
+        }
+        self.last_click = Some((button, y, x, now));
+        NcMouseGesture::Click { button, y, x }
+    }
+
+    /// Returns true if the two positions are at least the drag threshold apart.
+    fn far(&self, y0: NcDimension, x0: NcDimension, y1: NcDimension, x1: NcDimension) -> bool {
+        let distance = |a: NcDimension, b: NcDimension| if a > b { a - b } else { b - a };
+        distance(y0, y1).max(distance(x0, x1)) >= self.drag_threshold
+    }
+}
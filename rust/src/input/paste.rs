@@ -0,0 +1,222 @@
+//! `NcPasteDecoder`
+
+use crate::{NcError, NcErrorKind, NcResult, NcTime, NCRESULT_ERR};
+
+/// How long to wait for the rest of a marker, in milliseconds, before
+/// releasing its start as keys.
+const MARKER_DELAY_MS: libc::c_int = 50;
+
+/// The sequence that starts a bracketed paste.
+pub const NCPASTE_START: &str = "\x1b[200~";
+
+/// The sequence that ends a bracketed paste.
+pub const NCPASTE_END: &str = "\x1b[201~";
+
+/// An input event, decoded by an [NcPasteDecoder].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NcInputEvent {
+    /// A character received outside of a paste.
+    Key(char),
+    /// The text pasted between the [NCPASTE_START] and [NCPASTE_END] markers.
+    ///
+    /// The carriage returns are converted to newlines, and any other control
+    /// characters are kept.
+    Paste(String),
+}
+
+/// Decodes the bracketed pastes in the input, so that each one is delivered
+/// as a single [NcInputEvent::Paste].
+///
+/// The terminal only brackets the pastes once it's been enabled with
+/// [Notcurses.paste_enable()][crate::Notcurses#method.paste_enable].
+///
+/// The escape handling of this version of notcurses consumes the markers,
+/// so they never reach [getc()][crate::Notcurses#method.getc]. Instead,
+/// [read_input()][NcPasteDecoder#method.read_input] reads the terminal
+/// input directly, and must not be mixed with the notcurses input functions.
+///
+/// The paste end marker can't be part of the pasted text, the terminals
+/// usually filter it out of the clipboard contents.
+#[derive(Debug, Clone, Default)]
+pub struct NcPasteDecoder {
+    // the bytes received that don't complete a UTF-8 character yet
+    bytes: Vec<u8>,
+    // the characters received that could be the start of a marker
+    pending: String,
+    // the text pasted so far, while inside a paste
+    paste: Option<String>,
+}
+
+/// # `NcPasteDecoder` constructors
+impl NcPasteDecoder {
+    /// New NcPasteDecoder, outside of a paste.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// # `NcPasteDecoder` methods
+impl NcPasteDecoder {
+    /// Returns true while inside a paste.
+    pub fn is_pasting(&self) -> bool {
+        self.paste.is_some()
+    }
+
+    /// Processes the character `c`, and returns the events it completes,
+    /// in order.
+    ///
+    /// A character that could be part of a marker is held back until
+    /// it's known not to be, so an escape key press is only delivered with
+    /// the next character, or by [flush()][NcPasteDecoder#method.flush].
+    pub fn process(&mut self, c: char) -> Vec<NcInputEvent> {
+        let mut events = Vec::new();
+        self.pending.push(c);
+
+        let marker = if self.is_pasting() {
+            NCPASTE_END
+        } else {
+            NCPASTE_START
+        };
+        if marker.starts_with(&self.pending) {
+            if self.pending.len() == marker.len() {
+                self.pending.clear();
+                match self.paste.take() {
+                    Some(text) => events.push(NcInputEvent::Paste(normalize_newlines(&text))),
+                    None => self.paste = Some(String::new()),
+                }
+            }
+            return events;
+        }
+
+        // releases the characters up to the longest suffix that could still
+        // be the start of the marker
+        let keep = self
+            .pending
+            .char_indices()
+            .map(|(i, _)| i)
+            .skip(1)
+            .find(|&i| marker.starts_with(&self.pending[i..]))
+            .unwrap_or_else(|| self.pending.len());
+        let released: String = self.pending.drain(..keep).collect();
+        self.release(&released, &mut events);
+        events
+    }
+
+    /// Processes the raw input `bytes`, and returns the events they complete,
+    /// in order.
+    ///
+    /// The bytes of an incomplete UTF-8 character are kept for the next call,
+    /// and the invalid ones are replaced with `U+FFFD`.
+    pub fn process_bytes(&mut self, bytes: &[u8]) -> Vec<NcInputEvent> {
+        let mut events = Vec::new();
+        self.bytes.extend_from_slice(bytes);
+        loop {
+            let (valid, invalid) = match core::str::from_utf8(&self.bytes) {
+                Ok(text) => (text.len(), None),
+                Err(e) => (e.valid_up_to(), e.error_len()),
+            };
+            let chars: Vec<char> = String::from_utf8_lossy(&self.bytes[..valid])
+                .chars()
+                .collect();
+            for c in chars {
+                events.extend(self.process(c));
+            }
+            match invalid {
+                Some(len) => {
+                    self.bytes.drain(..valid + len);
+                    events.extend(self.process(core::char::REPLACEMENT_CHARACTER));
+                }
+                None => {
+                    self.bytes.drain(..valid);
+                    return events;
+                }
+            }
+        }
+    }
+
+    /// Reads the input from the terminal, waiting for it up to `time`,
+    /// or indefinitely if it's None, and returns the events it completes.
+    ///
+    /// It keeps reading while more input is immediately available, and
+    /// waits briefly for the rest of a marker that arrives split, so that
+    /// it's not delivered as keys. An unfinished paste stays open.
+    ///
+    /// Returns an empty list if no input arrived in time, or if the wait was
+    /// interrupted by a signal, and an error if the input is closed.
+    pub fn read_input(&mut self, time: Option<NcTime>) -> NcResult<Vec<NcInputEvent>> {
+        let ms = time.map_or(-1, |t| {
+            (t.tv_sec * 1000 + t.tv_nsec / 1_000_000) as libc::c_int
+        });
+        self.read_fd(libc::STDIN_FILENO, ms)
+    }
+
+    /// Reads the input from `fd`, waiting for it up to `ms` milliseconds,
+    /// or indefinitely if it's negative, and returns the events it completes.
+    ///
+    /// Used by [read_input()][NcPasteDecoder#method.read_input].
+    pub(crate) fn read_fd(
+        &mut self,
+        fd: libc::c_int,
+        ms: libc::c_int,
+    ) -> NcResult<Vec<NcInputEvent>> {
+        let mut events = Vec::new();
+        let mut buf = [0_u8; 1024];
+        let mut ms = ms;
+        loop {
+            let mut pollfd = libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            if unsafe { libc::poll(&mut pollfd, 1, ms) } <= 0 {
+                // no more input: releases what was held back as a marker
+                events.extend(self.flush());
+                return Ok(events);
+            }
+            let read = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut _, buf.len()) };
+            if read <= 0 {
+                events.extend(self.flush());
+                if events.is_empty() {
+                    return Err(NcError::with_kind(
+                        NCRESULT_ERR,
+                        NcErrorKind::Io,
+                        "NcPasteDecoder.read_input(): the input is closed",
+                    ));
+                }
+                return Ok(events);
+            }
+            events.extend(self.process_bytes(&buf[..read as usize]));
+            ms = if self.pending.is_empty() {
+                0
+            } else {
+                MARKER_DELAY_MS
+            };
+        }
+    }
+
+    /// Releases the characters held back as possible markers, and returns
+    /// the events they complete.
+    ///
+    /// It should be called when no more input is immediately available.
+    /// An unfinished paste stays open.
+    pub fn flush(&mut self) -> Vec<NcInputEvent> {
+        let mut events = Vec::new();
+        let released = core::mem::take(&mut self.pending);
+        self.release(&released, &mut events);
+        events
+    }
+
+    /// Adds the `released` characters to the paste, or to the `events`
+    /// as keys outside of a paste.
+    fn release(&mut self, released: &str, events: &mut Vec<NcInputEvent>) {
+        match self.paste {
+            Some(ref mut text) => text.push_str(released),
+            None => events.extend(released.chars().map(NcInputEvent::Key)),
+        }
+    }
+}
+
+/// Converts the carriage returns, alone or followed by a newline, to newlines.
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
@@ -0,0 +1,124 @@
+//! `NcKeyRepeatTracker`
+
+use std::time::{Duration, Instant};
+
+use crate::NcInput;
+
+/// A key event, classified by an [NcKeyRepeatTracker].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcKeyRepeat {
+    /// The key was pressed.
+    Pressed,
+    /// The key is being held, and the terminal auto-repeats it.
+    Repeated,
+}
+
+/// Tells apart the initial press of a key from its auto-repeats.
+///
+/// This version of notcurses doesn't report whether a key event is a repeat,
+/// so it's guessed from the timing of the identical events: the terminal
+/// waits some time before the first repeat, and then repeats the key at
+/// a steady rate, faster than it's possible to type.
+///
+/// An event is a repeat of the previous one if it's the same key, with the
+/// same modifiers, and it arrives:
+/// - after a press, within the delay window of the first repeat,
+///   (by default between 200 and 700 milliseconds),
+/// - after a repeat, within the repeat interval (by default 100 milliseconds).
+///
+/// So two separate presses of the same key are only recognized as such when
+/// they're faster than the start of the delay window, or slower than its end.
+/// The repeat interval doesn't apply after a press, since the terminal never
+/// repeats a key before the delay, so a fast double press is two presses.
+#[derive(Debug, Clone)]
+pub struct NcKeyRepeatTracker {
+    delay_min: Duration,
+    delay_max: Duration,
+    interval: Duration,
+    // the last key event: (id, alt, shift, ctrl, when, classification)
+    last: Option<(u32, bool, bool, bool, Instant, NcKeyRepeat)>,
+}
+
+impl Default for NcKeyRepeatTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # `NcKeyRepeatTracker` constructors & builder methods
+impl NcKeyRepeatTracker {
+    /// New NcKeyRepeatTracker, with a delay window of 200 to 700 milliseconds,
+    /// and a repeat interval of 100 milliseconds.
+    pub fn new() -> Self {
+        Self {
+            delay_min: Duration::from_millis(200),
+            delay_max: Duration::from_millis(700),
+            interval: Duration::from_millis(100),
+            last: None,
+        }
+    }
+
+    /// Sets the window of time after a press, in which an identical event
+    /// is the first repeat.
+    pub fn set_delay(mut self, min: Duration, max: Duration) -> Self {
+        self.delay_min = min;
+        self.delay_max = max.max(min);
+        self
+    }
+
+    /// Sets the maximum interval between identical events for the later one
+    /// to be a repeat.
+    pub fn set_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+/// # `NcKeyRepeatTracker` methods
+impl NcKeyRepeatTracker {
+    /// Processes the `input`, and returns whether it's a press or a repeat.
+    ///
+    /// Returns None for the mouse events and the empty inputs, which are
+    /// otherwise ignored.
+    pub fn process(&mut self, input: &NcInput) -> Option<NcKeyRepeat> {
+        self.process_at(input, Instant::now())
+    }
+
+    /// Processes the `input` as received at the instant `now`.
+    ///
+    /// See [process()][NcKeyRepeatTracker#method.process].
+    pub fn process_at(&mut self, input: &NcInput, now: Instant) -> Option<NcKeyRepeat> {
+        let key = core::char::from_u32(input.id)?;
+        if input.id == 0 || crate::nckey_mouse_p(key) {
+            return None;
+        }
+
+        let kind = match self.last {
+            Some((id, alt, shift, ctrl, when, last_kind))
+                if (id, alt, shift, ctrl) == (input.id, input.alt, input.shift, input.ctrl) =>
+            {
+                let gap = now.saturating_duration_since(when);
+                let is_repeat = match last_kind {
+                    NcKeyRepeat::Pressed => gap >= self.delay_min && gap <= self.delay_max,
+                    NcKeyRepeat::Repeated => gap <= self.interval,
+                };
+                if is_repeat {
+                    NcKeyRepeat::Repeated
+                } else {
+                    NcKeyRepeat::Pressed
+                }
+            }
+            _ => NcKeyRepeat::Pressed,
+        };
+        self.last = Some((input.id, input.alt, input.shift, input.ctrl, now, kind));
+        Some(kind)
+    }
+
+    /// Forgets the last key event, so that the next one is a press.
+    ///
+    /// It should be called when the keys could have been released without
+    /// notice, e.g. when the terminal loses the focus.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
@@ -0,0 +1,110 @@
+//! Test the conversion of `NcInput` into `crossterm` events.
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+use crate::{
+    NcInput, NCKEY_BUTTON1, NCKEY_BUTTON3, NCKEY_BUTTON8, NCKEY_CLS, NCKEY_ENTER, NCKEY_F05,
+    NCKEY_PGUP, NCKEY_RELEASE, NCKEY_RESIZE, NCKEY_SCROLL_DOWN, NCKEY_UP,
+};
+
+fn key(id: char) -> NcInput {
+    NcInput {
+        id: id as u32,
+        ..NcInput::new_empty()
+    }
+}
+
+fn mouse(id: char, y: i32, x: i32) -> NcInput {
+    NcInput {
+        id: id as u32,
+        y,
+        x,
+        ..NcInput::new_empty()
+    }
+}
+
+#[test]
+fn keys() {
+    let event = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+    let none = KeyModifiers::empty();
+
+    assert_eq![event(KeyCode::Char('x'), none), key('x').into()];
+    assert_eq![event(KeyCode::Char('字'), none), key('字').into()];
+    assert_eq![event(KeyCode::Up, none), key(NCKEY_UP).into()];
+    assert_eq![event(KeyCode::PageUp, none), key(NCKEY_PGUP).into()];
+    assert_eq![event(KeyCode::F(5), none), key(NCKEY_F05).into()];
+    assert_eq![event(KeyCode::Enter, none), key(NCKEY_ENTER).into()];
+    assert_eq![event(KeyCode::Tab, none), key('\t').into()];
+    assert_eq![event(KeyCode::Backspace, none), key('\x7f').into()];
+
+    // modifiers
+    assert_eq![
+        event(KeyCode::Char('c'), KeyModifiers::CONTROL),
+        NcInput::with_ctrl('C').into()
+    ];
+    assert_eq![
+        event(KeyCode::Char('x'), KeyModifiers::ALT),
+        NcInput::with_alt('x').into()
+    ];
+    assert_eq![
+        event(KeyCode::BackTab, KeyModifiers::SHIFT),
+        NcInput::with_shift('\t').into()
+    ];
+
+    // keys that crossterm can't represent
+    assert_eq![event(KeyCode::Null, none), key(NCKEY_CLS).into()];
+
+    // invalid codepoints, e.g. a lone surrogate, are not mouse releases
+    let invalid = NcInput {
+        id: 0xd800,
+        ..NcInput::with_ctrl('a')
+    };
+    assert_eq![event(KeyCode::Null, KeyModifiers::CONTROL), invalid.into()];
+}
+
+#[test]
+fn mouse_events() {
+    let event = |kind, row, column| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::empty(),
+        })
+    };
+
+    assert_eq![
+        event(MouseEventKind::Down(MouseButton::Left), 3, 7),
+        mouse(NCKEY_BUTTON1, 3, 7).into()
+    ];
+    assert_eq![
+        event(MouseEventKind::Down(MouseButton::Right), 0, 1),
+        mouse(NCKEY_BUTTON3, 0, 1).into()
+    ];
+    assert_eq![
+        event(MouseEventKind::ScrollDown, 2, 2),
+        mouse(NCKEY_SCROLL_DOWN, 2, 2).into()
+    ];
+    assert_eq![
+        event(MouseEventKind::Up(MouseButton::Left), 4, 5),
+        mouse(NCKEY_RELEASE, 4, 5).into()
+    ];
+    // negative coordinates are clamped
+    assert_eq![
+        event(MouseEventKind::Down(MouseButton::Left), 0, 0),
+        mouse(NCKEY_BUTTON1, -1, -1).into()
+    ];
+
+    // buttons that crossterm can't represent
+    assert_eq![
+        Event::Key(KeyEvent::new(KeyCode::Null, KeyModifiers::empty())),
+        mouse(NCKEY_BUTTON8, 1, 1).into()
+    ];
+}
+
+#[test]
+fn resize() {
+    assert_eq![Event::Resize(0, 0), key(NCKEY_RESIZE).into()];
+}
@@ -0,0 +1,87 @@
+//! Test `NcKeyCombo`.
+
+use std::collections::HashMap;
+
+use crate::{NcInput, NcKeyCombo, NCKEY_ENTER, NCKEY_F01};
+
+#[test]
+fn matches_modifiers() {
+    let combo = NcKeyCombo::alt_shift('x');
+    assert![combo.matches(&NcInput::with_all_args(
+        'x', None, None, true, true, false, 0
+    ))];
+    assert![!combo.matches(&NcInput::with_alt('x'))];
+    assert![!combo.matches(&NcInput::with_shift('x'))];
+    assert![!combo.matches(&NcInput::with_ctrl('x'))];
+
+    let combo = NcKeyCombo::key(NCKEY_F01);
+    assert![combo.matches(&NcInput::with_all_args(
+        NCKEY_F01, None, None, false, false, false, 0
+    ))];
+    assert![!combo.matches(&NcInput::with_alt(NCKEY_F01))];
+
+    // coordinates and sequence numbers are ignored
+    let combo = NcKeyCombo::alt('a');
+    assert![combo.matches(&NcInput::with_all_args(
+        'a',
+        Some(3),
+        Some(4),
+        true,
+        false,
+        false,
+        9
+    ))];
+}
+
+#[test]
+fn ctrl_normalization() {
+    let combo = NcKeyCombo::ctrl('s');
+    assert_eq![combo, NcKeyCombo::ctrl('S')];
+    assert_eq![combo, NcKeyCombo::ctrl_shift('s')];
+    assert_eq!['S', combo.key_char()];
+
+    // as reported by notcurses
+    assert![combo.matches(&NcInput::with_ctrl('S'))];
+    // the raw control character
+    assert![combo.matches(&NcInput::with_all_args(
+        '\u{13}', None, None, false, false, false, 0
+    ))];
+    // but not the plain letter
+    assert![!combo.matches(&NcInput::with_all_args(
+        's', None, None, false, false, false, 0
+    ))];
+
+    // tab, enter & return are not ctrl + letter
+    assert![!NcKeyCombo::key('\t').ctrl_p()];
+    assert![!NcKeyCombo::key('\n').ctrl_p()];
+    assert![
+        NcKeyCombo::key(NCKEY_ENTER).matches(&NcInput::with_all_args(
+            NCKEY_ENTER,
+            None,
+            None,
+            false,
+            false,
+            false,
+            0
+        ))
+    ];
+}
+
+#[test]
+fn map_key() {
+    let mut bindings = HashMap::new();
+    bindings.insert(NcKeyCombo::ctrl('s'), "save");
+    bindings.insert(NcKeyCombo::ctrl('q'), "quit");
+    bindings.insert(NcKeyCombo::key(NCKEY_F01), "help");
+
+    let input = NcInput::with_ctrl('S');
+    let combo = NcKeyCombo::from_input(&input).unwrap();
+    assert_eq![Some(&"save"), bindings.get(&combo)];
+    assert_eq![None, bindings.get(&NcKeyCombo::alt('s'))];
+}
+
+#[test]
+fn input_equality() {
+    assert![NcInput::with_ctrl('a') == NcInput::with_ctrl('a')];
+    assert![NcInput::with_ctrl('a') != NcInput::with_ctrl('b')];
+}
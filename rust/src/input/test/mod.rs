@@ -0,0 +1,16 @@
+//! `NcInput` tests.
+
+#[cfg(all(test, feature = "crossterm"))]
+mod crossterm;
+
+#[cfg(test)]
+mod keycombo;
+
+#[cfg(test)]
+mod mouse;
+
+#[cfg(test)]
+mod paste;
+
+#[cfg(test)]
+mod repeat;
@@ -0,0 +1,168 @@
+//! Test `NcMouseTracker`.
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    NcInput, NcMouseGesture, NcMouseTracker, NCKEY_BUTTON1, NCKEY_BUTTON3, NCKEY_RELEASE,
+    NCKEY_SCROLL_UP,
+};
+
+use NcMouseGesture::*;
+
+fn event(id: char, y: i32, x: i32) -> NcInput {
+    NcInput {
+        id: id as u32,
+        y,
+        x,
+        ..NcInput::new_empty()
+    }
+}
+
+#[test]
+fn click_and_double_click() {
+    let mut tracker = NcMouseTracker::new();
+    let t0 = Instant::now();
+    let ms = |ms| t0 + Duration::from_millis(ms);
+
+    assert_eq![None, tracker.process_at(&event(NCKEY_BUTTON1, 2, 3), ms(0))];
+    assert_eq![
+        Some(Click {
+            button: NCKEY_BUTTON1,
+            y: 2,
+            x: 3
+        }),
+        tracker.process_at(&event(NCKEY_RELEASE, 2, 3), ms(50))
+    ];
+    tracker.process_at(&event(NCKEY_BUTTON1, 2, 3), ms(200));
+    assert_eq![
+        Some(DoubleClick {
+            button: NCKEY_BUTTON1,
+            y: 2,
+            x: 3
+        }),
+        tracker.process_at(&event(NCKEY_RELEASE, 2, 3), ms(250))
+    ];
+
+    // a third click starts over
+    tracker.process_at(&event(NCKEY_BUTTON1, 2, 3), ms(300));
+    assert![matches![
+        tracker.process_at(&event(NCKEY_RELEASE, 2, 3), ms(350)),
+        Some(Click { .. })
+    ]];
+
+    // too late for a double click
+    tracker.process_at(&event(NCKEY_BUTTON1, 2, 3), ms(1000));
+    assert![matches![
+        tracker.process_at(&event(NCKEY_RELEASE, 2, 3), ms(1050)),
+        Some(Click { .. })
+    ]];
+
+    // too far for a double click
+    tracker.process_at(&event(NCKEY_BUTTON1, 2, 4), ms(1100));
+    assert![matches![
+        tracker.process_at(&event(NCKEY_RELEASE, 2, 4), ms(1150)),
+        Some(Click { .. })
+    ]];
+}
+
+#[test]
+fn configurable_thresholds() {
+    let mut tracker = NcMouseTracker::new()
+        .set_double_click_interval(Duration::from_millis(100))
+        .set_drag_threshold(3);
+    let t0 = Instant::now();
+    let ms = |ms| t0 + Duration::from_millis(ms);
+
+    // moving less than the drag threshold is still a click
+    tracker.process_at(&event(NCKEY_BUTTON1, 5, 5), ms(0));
+    assert_eq![
+        None,
+        tracker.process_at(&event(NCKEY_BUTTON1, 6, 7), ms(10))
+    ];
+    assert![matches![
+        tracker.process_at(&event(NCKEY_RELEASE, 6, 7), ms(20)),
+        Some(Click { y: 5, x: 5, .. })
+    ]];
+
+    // a double click near enough, but not in time
+    tracker.process_at(&event(NCKEY_BUTTON1, 6, 6), ms(150));
+    assert![matches![
+        tracker.process_at(&event(NCKEY_RELEASE, 6, 6), ms(160)),
+        Some(Click { .. })
+    ]];
+    tracker.process_at(&event(NCKEY_BUTTON1, 7, 7), ms(200));
+    assert![matches![
+        tracker.process_at(&event(NCKEY_RELEASE, 7, 7), ms(210)),
+        Some(DoubleClick { .. })
+    ]];
+}
+
+#[test]
+fn drag() {
+    let mut tracker = NcMouseTracker::new();
+    let b = NCKEY_BUTTON1;
+
+    assert_eq![None, tracker.process(&event(b, 1, 1))];
+    // no motion yet
+    assert_eq![None, tracker.process(&event(b, 1, 1))];
+    assert_eq![
+        Some(DragStart {
+            button: b,
+            y: 1,
+            x: 1
+        }),
+        tracker.process(&event(b, 1, 2))
+    ];
+    assert_eq![
+        Some(Drag {
+            button: b,
+            y: 2,
+            x: 4
+        }),
+        tracker.process(&event(b, 2, 4))
+    ];
+    assert_eq![
+        Some(DragEnd {
+            button: b,
+            y: 2,
+            x: 5
+        }),
+        tracker.process(&event(NCKEY_RELEASE, 2, 5))
+    ];
+
+    // a drag is not the first click of a double click
+    tracker.process(&event(b, 2, 5));
+    assert![matches![
+        tracker.process(&event(NCKEY_RELEASE, 2, 5)),
+        Some(Click { .. })
+    ]];
+}
+
+#[test]
+fn interleaved_buttons() {
+    let mut tracker = NcMouseTracker::new();
+
+    // a press of another button abandons the first one
+    tracker.process(&event(NCKEY_BUTTON1, 0, 0));
+    tracker.process(&event(NCKEY_BUTTON3, 0, 0));
+    assert_eq![
+        Some(Click {
+            button: NCKEY_BUTTON3,
+            y: 0,
+            x: 0
+        }),
+        tracker.process(&event(NCKEY_RELEASE, 0, 0))
+    ];
+
+    // a click of another button prevents a double click
+    tracker.process(&event(NCKEY_BUTTON1, 0, 0));
+    assert![matches![
+        tracker.process(&event(NCKEY_RELEASE, 0, 0)),
+        Some(Click { .. })
+    ]];
+
+    // a release without a press, the wheel, and keys are ignored
+    assert_eq![None, tracker.process(&event(NCKEY_RELEASE, 0, 0))];
+    assert_eq![None, tracker.process(&event(NCKEY_SCROLL_UP, 0, 0))];
+    assert_eq![None, tracker.process(&event('a', 0, 0))];
+}
@@ -0,0 +1,117 @@
+//! Test `NcPasteDecoder`.
+
+use crate::{NcInputEvent, NcPasteDecoder, NCPASTE_END, NCPASTE_START};
+
+use NcInputEvent::*;
+
+/// Feeds all the characters of `input` to the `decoder`, and returns the events.
+fn feed(decoder: &mut NcPasteDecoder, input: &str) -> Vec<NcInputEvent> {
+    input.chars().flat_map(|c| decoder.process(c)).collect()
+}
+
+#[test]
+fn single_paste_event() {
+    let mut decoder = NcPasteDecoder::new();
+    let input = format![
+        "a{}hello, world\r\nsecond line{}b",
+        NCPASTE_START, NCPASTE_END
+    ];
+    assert_eq![
+        vec![
+            Key('a'),
+            Paste("hello, world\nsecond line".to_string()),
+            Key('b')
+        ],
+        feed(&mut decoder, &input)
+    ];
+    assert![!decoder.is_pasting()];
+}
+
+#[test]
+fn control_characters_in_paste() {
+    let mut decoder = NcPasteDecoder::new();
+    // a tab, a lone carriage return, an escape sequence and a partial marker
+    let text = "\tx\ry\x1b[1mz\x1b[201";
+    let input = format!["{}{}{}", NCPASTE_START, text, NCPASTE_END];
+    assert_eq![
+        vec![Paste("\tx\ny\x1b[1mz\x1b[201".to_string())],
+        feed(&mut decoder, &input)
+    ];
+
+    // a start marker inside a paste is just text
+    let input = format!["{}{}{}", NCPASTE_START, NCPASTE_START, NCPASTE_END];
+    assert_eq![
+        vec![Paste(NCPASTE_START.to_string())],
+        feed(&mut decoder, &input)
+    ];
+}
+
+#[test]
+fn partial_markers() {
+    let mut decoder = NcPasteDecoder::new();
+    // the escape is held back until it's known not to start a marker
+    assert_eq![Vec::<NcInputEvent>::new(), decoder.process('\x1b')];
+    assert_eq![vec![Key('\x1b'), Key('q')], decoder.process('q')];
+
+    assert_eq![vec![Key('\x1b'), Key('[')], feed(&mut decoder, "\x1b[\x1b")];
+    assert_eq![vec![Key('\x1b')], decoder.flush()];
+
+    // an unfinished paste stays open
+    feed(&mut decoder, NCPASTE_START);
+    assert![decoder.is_pasting()];
+    assert![feed(&mut decoder, "text\x1b[20").is_empty()];
+    assert![decoder.flush().is_empty()];
+    assert![decoder.is_pasting()];
+    assert_eq![
+        vec![Paste("text\x1b[20".to_string())],
+        feed(&mut decoder, NCPASTE_END)
+    ];
+}
+
+#[test]
+fn process_bytes() {
+    let mut decoder = NcPasteDecoder::new();
+    // an incomplete character is kept for the next call
+    let bytes = "ñ字".as_bytes();
+    assert_eq![vec![Key('ñ')], decoder.process_bytes(&bytes[..3])];
+    assert_eq![vec![Key('字')], decoder.process_bytes(&bytes[3..])];
+
+    // an invalid byte is replaced
+    assert_eq![
+        vec![Key('a'), Key('\u{fffd}'), Key('b')],
+        decoder.process_bytes(b"a\xffb")
+    ];
+}
+
+#[test]
+fn read_split_marker() -> crate::NcResult<()> {
+    let mut fds = [0; 2];
+    assert_eq![0, unsafe { libc::pipe(fds.as_mut_ptr()) }];
+    let (rfd, wfd) = (fds[0], fds[1]);
+    let write = move |bytes: &[u8]| unsafe {
+        libc::write(wfd, bytes.as_ptr() as *const _, bytes.len());
+    };
+    let mut decoder = NcPasteDecoder::new();
+
+    // the rest of the start marker arrives in a later read
+    write(b"a\x1b[20");
+    let writer = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        write(b"0~text\x1b[201~");
+    });
+    assert_eq![
+        vec![Key('a'), Paste("text".to_string())],
+        decoder.read_fd(rfd, -1)?
+    ];
+    writer.join().unwrap();
+
+    // an escape that's not followed by the rest of a marker is a key
+    write(b"\x1b");
+    assert_eq![vec![Key('\x1b')], decoder.read_fd(rfd, 0)?];
+    assert![decoder.read_fd(rfd, 0)?.is_empty()];
+
+    unsafe { libc::close(wfd) };
+    assert![decoder.read_fd(rfd, 0).is_err()];
+    unsafe { libc::close(rfd) };
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! Test `NcKeyRepeatTracker`.
+
+use std::time::{Duration, Instant};
+
+use crate::{NcInput, NcKeyRepeat, NcKeyRepeatTracker, NCKEY_BUTTON1, NCKEY_UP};
+
+use NcKeyRepeat::*;
+
+fn key(id: char) -> NcInput {
+    NcInput {
+        id: id as u32,
+        ..NcInput::new_empty()
+    }
+}
+
+#[test]
+fn held_key() {
+    let mut tracker = NcKeyRepeatTracker::new();
+    let t0 = Instant::now();
+    let ms = |ms| t0 + Duration::from_millis(ms);
+
+    // the first repeat comes after the delay, the rest at a steady rate
+    assert_eq![Some(Pressed), tracker.process_at(&key(NCKEY_UP), ms(0))];
+    assert_eq![Some(Repeated), tracker.process_at(&key(NCKEY_UP), ms(500))];
+    assert_eq![Some(Repeated), tracker.process_at(&key(NCKEY_UP), ms(540))];
+    assert_eq![Some(Repeated), tracker.process_at(&key(NCKEY_UP), ms(580))];
+
+    // released, and pressed again later
+    assert_eq![Some(Pressed), tracker.process_at(&key(NCKEY_UP), ms(1500))];
+}
+
+#[test]
+fn separate_presses() {
+    let mut tracker = NcKeyRepeatTracker::new();
+    let t0 = Instant::now();
+    let ms = |ms| t0 + Duration::from_millis(ms);
+
+    // two fast presses, before the delay window
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(0))];
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(150))];
+
+    // the delay window only applies after a press
+    assert_eq![Some(Repeated), tracker.process_at(&key('a'), ms(450))];
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(750))];
+
+    // another key, or other modifiers, break the sequence
+    assert_eq![Some(Pressed), tracker.process_at(&key('b'), ms(780))];
+    assert_eq![
+        Some(Pressed),
+        tracker.process_at(&NcInput::with_ctrl('b'), ms(800))
+    ];
+
+    // after a reset the next event is a press
+    tracker.reset();
+    assert_eq![
+        Some(Pressed),
+        tracker.process_at(&NcInput::with_ctrl('b'), ms(820))
+    ];
+}
+
+#[test]
+fn fast_double_press() {
+    let mut tracker = NcKeyRepeatTracker::new();
+    let t0 = Instant::now();
+    let ms = |ms| t0 + Duration::from_millis(ms);
+
+    // presses faster than the repeat interval are not repeats, since the
+    // terminal doesn't repeat a key before the delay
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(0))];
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(40))];
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(80))];
+
+    // and the last one can still be held
+    assert_eq![Some(Repeated), tracker.process_at(&key('a'), ms(580))];
+    assert_eq![Some(Repeated), tracker.process_at(&key('a'), ms(620))];
+}
+
+#[test]
+fn ignored_events() {
+    let mut tracker = NcKeyRepeatTracker::new()
+        .set_delay(Duration::from_millis(300), Duration::from_millis(400))
+        .set_interval(Duration::from_millis(50));
+    let t0 = Instant::now();
+    let ms = |ms| t0 + Duration::from_millis(ms);
+
+    assert_eq![None, tracker.process_at(&key(NCKEY_BUTTON1), ms(0))];
+    assert_eq![None, tracker.process_at(&NcInput::new_empty(), ms(0))];
+
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(0))];
+    // the mouse events don't break the sequence
+    tracker.process_at(&key(NCKEY_BUTTON1), ms(100));
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(250))];
+    assert_eq![Some(Repeated), tracker.process_at(&key('a'), ms(600))];
+    assert_eq![Some(Repeated), tracker.process_at(&key('a'), ms(650))];
+    assert_eq![Some(Pressed), tracker.process_at(&key('a'), ms(701))];
+}
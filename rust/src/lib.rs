@@ -113,6 +113,7 @@ mod bindings;
 pub use bindings::*;
 
 mod r#box;
+mod braille;
 mod cells;
 mod channel;
 mod dimension;
@@ -131,10 +132,12 @@ mod resizecb;
 mod signal;
 mod stats;
 mod time;
+mod timeline;
 mod visual;
 mod widgets;
 
 pub use crate::input::*;
+pub use braille::*;
 pub use cells::*;
 pub use channel::*;
 pub use dimension::*;
@@ -153,5 +156,6 @@ pub use resizecb::*;
 pub use signal::*;
 pub use stats::*;
 pub use time::*;
+pub use timeline::*;
 pub use visual::*;
 pub use widgets::*;
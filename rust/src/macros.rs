@@ -4,7 +4,10 @@
 
 #[allow(unused_imports)]
 // enjoy briefer doc comments
-use crate::{notcurses_render, NcDirect, NcError, NcResult, Notcurses, NCRESULT_ERR, NCRESULT_OK};
+use crate::{
+    notcurses_render, NcDirect, NcError, NcErrorKind, NcResult, Notcurses, NCRESULT_ERR,
+    NCRESULT_OK,
+};
 
 // Sleep, Render & Flush Macros ------------------------------------------------
 
@@ -63,7 +66,7 @@ macro_rules! fsleep {
         sleep![$( $sleep_args ),+];
     };
     ($ncd:expr, $( $sleep_args:expr),+ ,) => {
-        rsleep![$ncd, $( $sleep_args ),* ]
+        fsleep![$ncd, $( $sleep_args ),* ]
     };
 }
 
@@ -115,17 +118,20 @@ macro_rules! printf {
 /// Returns Ok(`$ok`) if `$res` >= [NCRESULT_OK], otherwise returns
 /// Err([NcError]::[new][NcError#method.new](`$res`, `$msg`)).
 ///
-/// `$ok` & `$msg` are optional. By default they will be the unit
-/// type `()`, and an empty `&str` `""`, respectively.
+/// `$ok`, `$msg` & `$kind` are optional. By default they will be the unit
+/// type `()`, an empty `&str` `""`, and [NcErrorKind::Other], respectively.
 #[macro_export]
 macro_rules! error {
-    ($res:expr, $ok:expr, $msg:expr) => {
+    ($res:expr, $ok:expr, $msg:expr, $kind:expr) => {
         if $res >= crate::NCRESULT_OK {
             return Ok($ok);
         } else {
-            return Err(crate::NcError::with_msg($res, $msg));
+            return Err(crate::NcError::with_kind($res, $kind, $msg));
         }
     };
+    ($res:expr, $ok:expr, $msg:expr) => {
+        error![$res, $ok, $msg, crate::NcErrorKind::Other];
+    };
     ($res:expr, $ok:expr) => {
         error![$res, $ok, ""];
     };
@@ -139,7 +145,7 @@ macro_rules! error {
 ///
 /// In other words:
 /// Returns Ok(&*`$ptr`) if `$ptr` != `null()`, otherwise returns
-/// Err([NcError]]::[new][NcError#method.new]([NCRESULT_ERR], `$msg`)).
+/// Err([NcError]]::[with_kind][NcError#method.with_kind]([NCRESULT_ERR], [NcErrorKind::NullReturn], `$msg`)).
 ///
 /// `$msg` is optional. By default it will be an empty `&str` `""`.
 #[macro_export]
@@ -149,7 +155,11 @@ macro_rules! error_ref {
             #[allow(unused_unsafe)]
             return Ok(unsafe { &*$ptr });
         } else {
-            return Err(crate::NcError::with_msg(crate::NCRESULT_ERR, $msg));
+            return Err(crate::NcError::with_kind(
+                crate::NCRESULT_ERR,
+                crate::NcErrorKind::NullReturn,
+                $msg,
+            ));
         }
     };
     ($ptr:expr) => {
@@ -162,7 +172,7 @@ macro_rules! error_ref {
 ///
 /// In other words:
 /// Returns Ok(&mut *`$ptr`) if `$ptr` != `null_mut()`, otherwise returns
-/// Err([NcError]]::[new][NcError#method.new]([NCRESULT_ERR], `$msg`)).
+/// Err([NcError]]::[with_kind][NcError#method.with_kind]([NCRESULT_ERR], [NcErrorKind::NullReturn], `$msg`)).
 ///
 /// `$msg` is optional. By default it will be an empty `&str` `""`.
 #[macro_export]
@@ -172,7 +182,11 @@ macro_rules! error_ref_mut {
             #[allow(unused_unsafe)]
             return Ok(unsafe { &mut *$ptr });
         } else {
-            return Err(crate::NcError::with_msg(crate::NCRESULT_ERR, $msg));
+            return Err(crate::NcError::with_kind(
+                crate::NCRESULT_ERR,
+                crate::NcErrorKind::NullReturn,
+                $msg,
+            ));
         }
     };
     ($ptr:expr) => {
@@ -185,7 +199,7 @@ macro_rules! error_ref_mut {
 ///
 /// In other words:
 /// Returns Ok((&*`$str`).to_string()) if `$str` != `null()`, otherwise returns
-/// Err([NcError]]::[new][NcError#method.new]([NCRESULT_ERR], `$msg`)).
+/// Err([NcError]]::[with_kind][NcError#method.with_kind]([NCRESULT_ERR], [NcErrorKind::NullReturn], `$msg`)).
 ///
 /// `$msg` is optional. By default it will be an empty `&str` `""`.
 #[macro_export]
@@ -195,7 +209,11 @@ macro_rules! error_str {
             #[allow(unused_unsafe)]
             return Ok(unsafe { (&*$str).to_string() });
         } else {
-            return Err(crate::NcError::with_msg(crate::NCRESULT_ERR, $msg));
+            return Err(crate::NcError::with_kind(
+                crate::NCRESULT_ERR,
+                crate::NcErrorKind::NullReturn,
+                $msg,
+            ));
         }
     };
     ($str:expr) => {
@@ -1,4 +1,38 @@
-use crate::{notcurses_init, Notcurses, NotcursesOptions, NCOPTION_SUPPRESS_BANNERS};
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{
+    notcurses_init, NCBLIT_1x1, NCBLIT_2x2, NCBLIT_3x2, NcBlitter, NcError, NcErrorKind, NcInput,
+    NcResult, Notcurses, NotcursesOptions, NCBLIT_SIXEL, NCOPTION_SUPPRESS_BANNERS, NCRESULT_ERR,
+};
+
+thread_local! {
+    // the input event read by Notcurses::peek_input() and not yet consumed,
+    // by the address of the Notcurses.
+    //
+    // The C API has no way to push input back, nor room for it in the opaque
+    // Notcurses, so it's kept here, and forgotten by Notcurses::stop().
+    static INPUT_PUSHBACK: RefCell<HashMap<usize, (char, NcInput)>> = RefCell::new(HashMap::new());
+}
+
+/// Helper function that pushes back the input event `c` & `input` read from
+/// the `nc`, so that it's returned again by the next read.
+///
+/// It replaces any event already pushed back.
+pub(crate) fn push_back_input(nc: *const Notcurses, c: char, input: NcInput) {
+    INPUT_PUSHBACK.with(|pushback| pushback.borrow_mut().insert(nc as usize, (c, input)));
+}
+
+/// Helper function that returns the input event pushed back for the `nc`,
+/// without removing it.
+pub(crate) fn pushed_back_input(nc: *const Notcurses) -> Option<(char, NcInput)> {
+    INPUT_PUSHBACK.with(|pushback| pushback.borrow().get(&(nc as usize)).copied())
+}
+
+/// Helper function that removes and returns the input event pushed back
+/// for the `nc`.
+pub(crate) fn take_pushed_back_input(nc: *const Notcurses) -> Option<(char, NcInput)> {
+    INPUT_PUSHBACK.with(|pushback| pushback.borrow_mut().remove(&(nc as usize)))
+}
 
 /// Helper function for initializing Notcurses on C style tests.
 #[allow(dead_code)]
@@ -8,3 +42,69 @@ pub(crate) unsafe fn notcurses_init_test<'a>() -> &'a mut Notcurses {
         core::ptr::null_mut(),
     )
 }
+
+/// Helper function for choosing the best [NcBlitter] from the capabilities
+/// of the terminal.
+///
+/// Used by [Notcurses.best_blitter()][Notcurses#method.best_blitter].
+pub(crate) fn best_blitter_for(pixel: bool, sextant: bool, utf8: bool) -> NcBlitter {
+    if pixel {
+        NCBLIT_SIXEL
+    } else if !utf8 {
+        NCBLIT_1x1
+    } else if sextant {
+        NCBLIT_3x2
+    } else {
+        NCBLIT_2x2
+    }
+}
+
+/// Helper function that returns the line that precedes a frame written with
+/// [render_to_file_opts()][Notcurses#method.render_to_file_opts],
+/// for a `time` since the UNIX epoch.
+pub(crate) fn frame_header(time: core::time::Duration) -> String {
+    format!["#ncframe {}.{:03}\n", time.as_secs(), time.subsec_millis()]
+}
+
+/// Helper function that writes the `bytes` to the terminal, through stdout,
+/// and flushes it, returning an error with `msg` on failure.
+pub(crate) fn write_terminal(bytes: &[u8], msg: &str) -> NcResult<()> {
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    if stdout
+        .write_all(bytes)
+        .and_then(|_| stdout.flush())
+        .is_err()
+    {
+        return Err(NcError::with_kind(NCRESULT_ERR, NcErrorKind::Io, msg));
+    }
+    Ok(())
+}
+
+/// Helper guard that calls a function when dropped, unless it's disarmed.
+///
+/// Used to stop a context even when the code using it panics,
+/// e.g. by [Notcurses::with()][Notcurses#method.with].
+pub(crate) struct ScopeGuard<F: FnOnce()> {
+    f: Option<F>,
+}
+
+impl<F: FnOnce()> ScopeGuard<F> {
+    /// New guard, that calls `f` when dropped.
+    pub(crate) fn new(f: F) -> Self {
+        Self { f: Some(f) }
+    }
+
+    /// Disarms the guard, so that it doesn't call its function.
+    pub(crate) fn disarm(mut self) {
+        self.f = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.f.take() {
+            f();
+        }
+    }
+}
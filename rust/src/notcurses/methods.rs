@@ -1,12 +1,16 @@
 //! `Notcurses*` methods and associated functions.
 
 use core::ptr::{null, null_mut};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::{
-    cstring, error, error_ref_mut, notcurses_init, rstring, NcAlign, NcBlitter, NcChannelPair,
-    NcDimension, NcEgc, NcError, NcFile, NcInput, NcLogLevel, NcPlane, NcResult, NcScale,
+    cstring, error, error_ref_mut, notcurses_init, rstring, sleep, NcAlign, NcBlitter, NcCell,
+    NcChannelPair, NcDimension, NcEgc, NcError, NcErrorKind, NcFile, NcInput, NcLogLevel,
+    NcMediaHandle, NcPixelImpl, NcPlane, NcPlaneGeom, NcRenderFileOptions, NcResult, NcScale,
     NcSignalSet, NcStats, NcStyleMask, NcTime, Notcurses, NotcursesOptions,
-    NCOPTION_NO_ALTERNATE_SCREEN, NCOPTION_SUPPRESS_BANNERS, NCRESULT_ERR,
+    NCOPTION_NO_ALTERNATE_SCREEN, NCOPTION_SUPPRESS_BANNERS, NCRESULT_ERR, NCSTYLE_REVERSE,
 };
 
 /// # `NotcursesOptions` Constructors
@@ -83,6 +87,30 @@ impl NotcursesOptions {
     }
 }
 
+/// # `NcRenderFileOptions` Constructors
+impl NcRenderFileOptions {
+    /// New NcRenderFileOptions, truncating the file, without timestamps.
+    pub const fn new() -> Self {
+        Self {
+            append: false,
+            timestamp: false,
+        }
+    }
+
+    /// Sets whether to append the frame to the end of the file,
+    /// instead of truncating it.
+    pub fn set_append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    /// Sets whether to prefix the frame with a timestamp line.
+    pub fn set_timestamp(mut self, timestamp: bool) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+}
+
 /// # `Notcurses` Constructors
 impl Notcurses {
     /// Returns a Notcurses context (without banners).
@@ -122,6 +150,32 @@ impl Notcurses {
             loglevel, 0, 0, 0, 0, flags,
         ))
     }
+
+    /// Initializes a Notcurses context with `options`, and calls the closure
+    /// `f` with it, stopping it afterwards.
+    ///
+    /// The context is stopped whether the closure returns successfully or
+    /// with an error, and also when it panics, while unwinding.
+    ///
+    /// Returns the result of the closure, or the error stopping the context.
+    /// The closure must not stop the context itself.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with<F, T>(options: NotcursesOptions, f: F) -> NcResult<T>
+    where
+        F: FnOnce(&mut Notcurses) -> NcResult<T>,
+    {
+        let nc: *mut Notcurses = Self::with_options(options)?;
+        let guard = crate::notcurses::ScopeGuard::new(|| {
+            let _ = unsafe { &mut *nc }.stop();
+        });
+        let res = f(unsafe { &mut *nc });
+        guard.disarm();
+        let stopped = unsafe { &mut *nc }.stop();
+        let value = res?;
+        stopped?;
+        Ok(value)
+    }
 }
 
 /// # `Notcurses` methods
@@ -159,6 +213,56 @@ impl Notcurses {
         Some(egc)
     }
 
+    /// Rings the terminal bell.
+    ///
+    /// Whether it's audible, visual, or silent at all depends on the terminal
+    /// and its user configuration, which is out of our control. See also
+    /// [visual_bell][Notcurses#method.visual_bell].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn bell(&mut self) -> NcResult<()> {
+        crate::notcurses::write_terminal(b"\x07", "Notcurses.bell()")
+    }
+
+    /// Returns the highest resolution [NcBlitter] supported by the terminal.
+    ///
+    /// In order of preference: [NCBLIT_SIXEL][crate::NCBLIT_SIXEL] if pixel
+    /// graphics are available, [NCBLIT_3x2][crate::NCBLIT_3x2] if sextants
+    /// can be used, [NCBLIT_2x2][crate::NCBLIT_2x2] if UTF-8 is supported,
+    /// and [NCBLIT_1x1][crate::NCBLIT_1x1] for ASCII-only terminals.
+    ///
+    /// In order to get consistent results across terminals, just pass the
+    /// desired blitter instead.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn best_blitter(&self) -> NcBlitter {
+        crate::notcurses::best_blitter_for(self.cansixel(), self.cansextant(), self.canutf8())
+    }
+
+    /// Opens the media file at `path` and displays it in the `plane`, scaled
+    /// to fit, with the [best blitter][Notcurses#method.best_blitter].
+    ///
+    /// The file is decoded to detect its [NcMediaKind][crate::NcMediaKind]: a single frame is
+    /// an image, rendered once, and several frames are either an animation,
+    /// which loops, or a video, which plays once, depending on the extension.
+    ///
+    /// The time-based media start playing right away, and are advanced by
+    /// [NcMediaHandle.update()][NcMediaHandle#method.update].
+    /// Nothing is written to the terminal until the next render.
+    ///
+    /// Returns an error of kind [Unsupported][NcErrorKind::Unsupported] if
+    /// the file can't be decoded.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn display_media<'a>(
+        &mut self,
+        path: &str,
+        plane: &'a mut NcPlane,
+    ) -> NcResult<NcMediaHandle<'a>> {
+        let blitter = self.best_blitter();
+        NcMediaHandle::open(self, path, plane, blitter)
+    }
+
     /// Returns the bottommost [NcPlane], of which there is always at least one.
     ///
     /// *C style function: [notcurses_bottom()][crate::notcurses_bottom].*
@@ -211,6 +315,26 @@ impl Notcurses {
         unsafe { crate::notcurses_cansixel(self) }
     }
 
+    /// Returns the pixel graphics protocol supported by the terminal.
+    ///
+    /// The bundled C API doesn't yet provide `notcurses_check_pixel_support()`,
+    /// so only sixel support, as reported by [cansixel()][Notcurses#method.cansixel],
+    /// is detected, and either [NcPixelImpl::Sixel] or [NcPixelImpl::None]
+    /// is returned.
+    ///
+    /// Some terminals only answer the detection queries once the
+    /// initialization has completed, so it's best called after the first
+    /// [render()][Notcurses#method.render].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn check_pixel_support(&self) -> NcResult<NcPixelImpl> {
+        if self.cansixel() {
+            Ok(NcPixelImpl::Sixel)
+        } else {
+            Ok(NcPixelImpl::None)
+        }
+    }
+
     /// Returns true if we can reliably use Unicode 13 sextants.
     ///
     /// *C style function: [notcurses_cansextant()][crate::notcurses_cansextant].*
@@ -247,11 +371,25 @@ impl Notcurses {
     /// Enables the terminal's cursor, if supported, placing it at `y`, `x`.
     ///
     /// Immediate effect (no need for a call to notcurses_render()).
-    /// It is an error if `y`, `x` lies outside the standard plane.
+    /// It is an error of the [BoundsError][NcErrorKind::BoundsError] kind
+    /// if `y`, `x` lies outside the standard plane.
     ///
     /// *C style function: [notcurses_cursor_enable()][crate::notcurses_cursor_enable].*
     pub fn cursor_enable(&mut self, y: NcDimension, x: NcDimension) -> NcResult<()> {
-        error![unsafe { crate::notcurses_cursor_enable(self, y as i32, x as i32) }]
+        let (rows, cols) = self.term_dim_yx();
+        if y >= rows || x >= cols {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::BoundsError,
+                "Notcurses.cursor_enable(): out of bounds",
+            ));
+        }
+        error![
+            unsafe { crate::notcurses_cursor_enable(self, y as i32, x as i32) },
+            (),
+            "Notcurses.cursor_enable()",
+            NcErrorKind::Unsupported
+        ]
     }
 
     /// Dumps Notcurses state to the supplied `debugfp`.
@@ -270,6 +408,8 @@ impl Notcurses {
     ///
     /// *C style function: [notcurses_drop_planes()][crate::notcurses_drop_planes].*
     pub fn drop_planes(&mut self) {
+        let stdplane: *const NcPlane = self.stdplane_const();
+        crate::plane::forget_planes_state(self, Some(stdplane));
         unsafe {
             crate::notcurses_drop_planes(self);
         }
@@ -295,6 +435,12 @@ impl Notcurses {
         sigmask: Option<&mut NcSignalSet>,
         input: Option<&mut NcInput>,
     ) -> NcResult<char> {
+        if let Some((c, pushed)) = crate::notcurses::take_pushed_back_input(self) {
+            if let Some(input) = input {
+                *input = pushed;
+            }
+            return Ok(c);
+        }
         let ntime;
         if let Some(time) = time {
             ntime = &time as *const _;
@@ -326,15 +472,52 @@ impl Notcurses {
     ///
     /// *C style function: [notcurses_getc_nblock()][crate::notcurses_getc_nblock].*
     pub fn getc_nblock(&mut self, input: &mut NcInput) -> char {
+        if let Some((c, pushed)) = crate::notcurses::take_pushed_back_input(self) {
+            *input = pushed;
+            return c;
+        }
         crate::notcurses_getc_nblock(self, input)
     }
 
     ///
     /// *C style function: [notcurses_getc_nblocking()][crate::notcurses_getc_nblocking].*
     pub fn getc_nblocking(&mut self, input: &mut NcInput) -> char {
+        if let Some((c, pushed)) = crate::notcurses::take_pushed_back_input(self) {
+            *input = pushed;
+            return c;
+        }
         crate::notcurses_getc_nblocking(self, input)
     }
 
+    /// Returns the next input event if one is ready, without consuming it,
+    /// so that it's returned again by the next call to
+    /// [getc()][Notcurses#method.getc],
+    /// [getc_nblock()][Notcurses#method.getc_nblock] or
+    /// [getc_nblocking()][Notcurses#method.getc_nblocking].
+    ///
+    /// Returns None if no event is available, without blocking. Peeking again
+    /// before consuming the event returns the same event.
+    ///
+    /// The event is held back on the Rust side, so the C style functions
+    /// don't see it.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn peek_input(&mut self) -> NcResult<Option<NcInput>> {
+        if let Some((_, input)) = crate::notcurses::pushed_back_input(self) {
+            return Ok(Some(input));
+        }
+        let mut input = NcInput::new_empty();
+        let c = crate::notcurses_getc_nblock(self, &mut input);
+        if c as u32 as i32 == NCRESULT_ERR {
+            return Err(NcError::with_msg(NCRESULT_ERR, "Notcurses.peek_input()"));
+        }
+        if c == '\0' {
+            return Ok(None);
+        }
+        crate::notcurses::push_back_input(self, c, input);
+        Ok(Some(input))
+    }
+
     /// Gets a file descriptor suitable for input event poll()ing.
     ///
     /// When this descriptor becomes available, you can call
@@ -412,7 +595,12 @@ impl Notcurses {
     ///
     /// *C style function: [notcurses_mouse_enable()][crate::notcurses_mouse_enable].*
     pub fn mouse_enable(&mut self) -> NcResult<()> {
-        error![unsafe { crate::notcurses_mouse_enable(self) }]
+        error![
+            unsafe { crate::notcurses_mouse_enable(self) },
+            (),
+            "Notcurses.mouse_enable()",
+            NcErrorKind::Unsupported
+        ]
     }
 
     /// Returns the number of simultaneous colors claimed to be supported,
@@ -426,6 +614,78 @@ impl Notcurses {
         unsafe { crate::notcurses_palette_size(self) }
     }
 
+    /// Disables the bracketed paste mode of the terminal.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn paste_disable(&mut self) -> NcResult<()> {
+        crate::notcurses::write_terminal(b"\x1b[?2004l", "Notcurses.paste_disable()")
+    }
+
+    /// Enables the bracketed paste mode of the terminal, which surrounds
+    /// the pasted text with the [NCPASTE_START][crate::NCPASTE_START] and
+    /// [NCPASTE_END][crate::NCPASTE_END] markers.
+    ///
+    /// The pastes can then be read with
+    /// [NcPasteDecoder.read_input()][crate::NcPasteDecoder#method.read_input],
+    /// since [getc()][Notcurses#method.getc] doesn't receive the markers.
+    ///
+    /// It should be disabled before stopping notcurses.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn paste_enable(&mut self) -> NcResult<()> {
+        crate::notcurses::write_terminal(b"\x1b[?2004h", "Notcurses.paste_enable()")
+    }
+
+    /// Returns an indented tree of the [NcPlane]s in all the piles, starting
+    /// with the standard pile, with their names, dimensions and positions.
+    ///
+    /// Unlike [debug()][Notcurses#method.debug] it doesn't need an [NcFile].
+    ///
+    /// The C API doesn't allow to iterate over the piles, so only the piles
+    /// reachable from the planes known to the bindings are listed: the
+    /// standard pile, the ones created with [NcPlane::new()][NcPlane#method.new]
+    /// or [NcPlane::with_options()][NcPlane#method.with_options], and those
+    /// containing a plane with a name.
+    ///
+    /// See [NcPlane.pile_tree_string()][NcPlane#method.pile_tree_string]
+    /// for the format of each pile.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn plane_tree_string(&mut self) -> String {
+        crate::plane::pile_tops(self)
+            .into_iter()
+            .map(|top| crate::plane::pile_tree_string(unsafe { &mut *top }))
+            .collect()
+    }
+
+    /// Returns all the [NcPlane]s in the standard pile, from the bottom
+    /// to the top.
+    ///
+    /// See [planes_top_to_bottom()][Notcurses#method.planes_top_to_bottom].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn planes_bottom_to_top<'a>(&'a mut self) -> Vec<&'a mut NcPlane> {
+        let mut planes = self.planes_top_to_bottom();
+        planes.reverse();
+        planes
+    }
+
+    /// Returns all the [NcPlane]s in the standard pile, from the top
+    /// to the bottom, which is the order to follow for hit-testing.
+    ///
+    /// There's always at least one plane, the standard plane.
+    ///
+    /// The returned references are all distinct, but they are only valid
+    /// as long as none of the planes are destroyed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn planes_top_to_bottom<'a>(&'a mut self) -> Vec<&'a mut NcPlane> {
+        crate::plane::pile_planes(self.top())
+            .into_iter()
+            .map(|plane| unsafe { &mut *plane })
+            .collect()
+    }
+
     /// Refreshes the physical screen to match what was last rendered (i.e.,
     /// without reflecting any changes since the last call to
     /// [render][crate::Notcurses#method.render]).
@@ -444,6 +704,28 @@ impl Notcurses {
         ]
     }
 
+    /// Re-emits the pixel graphics (sprixels) that were last rendered,
+    /// without a full re-render.
+    ///
+    /// Pixel graphics can vanish or smear after the terminal is resized or
+    /// scrolled externally. Call this after reading an
+    /// [NCKEY_RESIZE][crate::NCKEY_RESIZE] event when there are images on
+    /// screen, instead of a plain [refresh][Notcurses#method.refresh], which
+    /// is only needed when cell-based content may have been corrupted.
+    ///
+    /// It's a no-op on terminals without pixel graphics support, as reported
+    /// by [cansixel()][Notcurses#method.cansixel], which is always false with
+    /// this version of notcurses.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn sprixel_refresh(&mut self) -> NcResult<()> {
+        if !self.cansixel() {
+            return Ok(());
+        }
+        self.refresh()?;
+        Ok(())
+    }
+
     /// Renders and rasterizes the standard pile in one shot. Blocking call.
     ///
     /// *C style function: [notcurses_render()][crate::notcurses_render].*
@@ -451,6 +733,70 @@ impl Notcurses {
         error![unsafe { crate::notcurses_render(self) }]
     }
 
+    /// Renders the pile of which the `plane` is a part, without writing it
+    /// out to the terminal, which is done by
+    /// [rasterize_pile][Notcurses#method.rasterize_pile].
+    ///
+    /// Rendering the pile again replaces the previous render, so a pile can
+    /// be rendered while another one is being rasterized.
+    ///
+    /// Returns an error if the `plane` belongs to another context.
+    ///
+    /// *C style function: [ncpile_render()][crate::ncpile_render].*
+    pub fn render_pile(&mut self, plane: &mut NcPlane) -> NcResult<()> {
+        self.check_own_plane(plane, "Notcurses.render_pile()")?;
+        plane.render()
+    }
+
+    /// Writes out to the terminal the last render of the pile of which the
+    /// `plane` is a part, made with [render_pile][Notcurses#method.render_pile].
+    ///
+    /// It's a blocking call. Rasterizing a pile that has never been rendered
+    /// doesn't write anything.
+    ///
+    /// Returns an error if the `plane` belongs to another context.
+    ///
+    /// *C style function: [ncpile_rasterize()][crate::ncpile_rasterize].*
+    pub fn rasterize_pile(&mut self, plane: &mut NcPlane) -> NcResult<()> {
+        self.check_own_plane(plane, "Notcurses.rasterize_pile()")?;
+        plane.rasterize()
+    }
+
+    /// Returns an error if the `plane` doesn't belong to this context.
+    fn check_own_plane(&self, plane: &NcPlane, msg: &str) -> NcResult<()> {
+        if unsafe { crate::ncplane_notcurses_const(plane) } != self as *const Notcurses {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                &format!["{}: the plane belongs to another context", msg],
+            ));
+        }
+        Ok(())
+    }
+
+    /// Renders and rasterizes the standard pile, like
+    /// [render][Notcurses#method.render], unless `abort` is set.
+    ///
+    /// The flag is checked before rendering and again before rasterizing,
+    /// since the C API doesn't allow to interrupt either phase, so the
+    /// rasterization, which writes out the frame, is skipped for a stale frame.
+    ///
+    /// Returns `false` if aborted, and `true` if the frame was written out.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_interruptible(&mut self, abort: &AtomicBool) -> NcResult<bool> {
+        if abort.load(Ordering::Acquire) {
+            return Ok(false);
+        }
+        let stdplane = self.stdplane()?;
+        stdplane.render()?;
+        if abort.load(Ordering::Acquire) {
+            return Ok(false);
+        }
+        stdplane.rasterize()?;
+        Ok(true)
+    }
+
     /// Performs the rendering and rasterization portion of
     /// [render][Notcurses#method.render] but do not write the resulting buffer
     /// out to the terminal.
@@ -479,6 +825,58 @@ impl Notcurses {
         error![unsafe { crate::notcurses_render_to_file(self, fp.as_nc_ptr()) }]
     }
 
+    /// Writes the last rendered frame, in its entirety, to 'fp',
+    /// according to the `options`.
+    ///
+    /// In append mode the frame is written at the end of the file, so it can
+    /// be interleaved with other writes to the same file. In any case the file
+    /// is flushed afterwards.
+    ///
+    /// If [render()][Notcurses#method.render] has not yet been called,
+    /// nothing will be written, except for the timestamp.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_to_file_opts(
+        &mut self,
+        fp: &mut NcFile,
+        options: NcRenderFileOptions,
+    ) -> NcResult<()> {
+        let io_error = |msg: &str| NcError::with_kind(NCRESULT_ERR, NcErrorKind::Io, msg);
+        let file = fp.as_libc_ptr();
+        unsafe {
+            if options.append {
+                if libc::fseek(file, 0, libc::SEEK_END) != 0 {
+                    return Err(io_error("seeking the end of the file"));
+                }
+            } else {
+                if libc::fflush(file) != 0 || libc::ftruncate(libc::fileno(file), 0) != 0 {
+                    return Err(io_error("truncating the file"));
+                }
+                libc::rewind(file);
+            }
+            if options.timestamp {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default();
+                let header = crate::notcurses::frame_header(now);
+                let written = libc::fwrite(
+                    header.as_ptr() as *const libc::c_void,
+                    1,
+                    header.len(),
+                    file,
+                );
+                if written != header.len() {
+                    return Err(io_error("writing the timestamp"));
+                }
+            }
+        }
+        self.render_to_file(fp)?;
+        if unsafe { libc::fflush(file) } != 0 {
+            return Err(io_error("flushing the file"));
+        }
+        Ok(())
+    }
+
     /// Acquires an atomic snapshot of the Notcurses object's stats.
     ///
     /// *C style function: [notcurses_stats()][crate::notcurses_stats].*
@@ -507,6 +905,22 @@ impl Notcurses {
         }
     }
 
+    /// Returns an owned copy of an atomic snapshot of the stats.
+    ///
+    /// It allocates a temporary [NcStats] object, so that it works even if
+    /// future versions of Notcurses enlarge the structure.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn stats_snapshot(&mut self) -> NcStats {
+        unsafe {
+            let stats = crate::notcurses_stats_alloc(self);
+            crate::notcurses_stats(self, stats);
+            let snapshot = *stats;
+            libc::free(stats as *mut libc::c_void);
+            snapshot
+        }
+    }
+
     /// [notcurses_stdplane()][crate::notcurses_stdplane], plus free bonus
     /// dimensions written to non-NULL y/x!
     ///
@@ -517,7 +931,20 @@ impl Notcurses {
         y: &mut NcDimension,
         x: &mut NcDimension,
     ) -> NcResult<&'a mut NcPlane> {
-        crate::notcurses_stddim_yx(self, y, x)
+        let (plane, geom) = self.stddim_geom()?;
+        *y = geom.rows;
+        *x = geom.cols;
+        Ok(plane)
+    }
+
+    /// [stdplane()][Notcurses#method.stdplane], plus its dimensions
+    /// as an [NcPlaneGeom].
+    ///
+    /// *C style function: [notcurses_stddim_yx()][crate::notcurses_stddim_yx].*
+    pub fn stddim_geom<'a>(&'a mut self) -> NcResult<(&'a mut NcPlane, NcPlaneGeom)> {
+        let plane = self.stdplane()?;
+        let geom = plane.geom();
+        Ok((plane, geom))
     }
 
     /// [stdplane_const()][Notcurses#method.stdplane_const], plus free
@@ -557,6 +984,8 @@ impl Notcurses {
     ///
     /// *C style function: [notcurses_stop()][crate::notcurses_stop].*
     pub fn stop(&mut self) -> NcResult<()> {
+        crate::notcurses::take_pushed_back_input(self);
+        crate::plane::forget_planes_state(self, None);
         error![unsafe { crate::notcurses_stop(self) }]
     }
 
@@ -586,11 +1015,32 @@ impl Notcurses {
         unsafe { crate::notcurses_supported_styles(self) as NcStyleMask }
     }
 
+    /// Returns the names of the supported curses-style attributes,
+    /// e.g. `["bold", "italic", "underline"]`.
+    ///
+    /// Returns an empty vector if no style is supported.
+    ///
+    /// See also [NcStyle::names][crate::NcStyle#method.names].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn supported_style_names(&self) -> Vec<&'static str> {
+        crate::NcStyle::supported(self).names()
+    }
+
     /// Returns our current idea of the terminal dimensions in rows and cols.
     ///
     /// *C style function: [notcurses_supported_styles()][crate::notcurses_supported_styles].*
     pub fn term_dim_yx(&self) -> (NcDimension, NcDimension) {
-        crate::notcurses_term_dim_yx(self)
+        self.term_geom().into()
+    }
+
+    /// Returns our current idea of the terminal dimensions,
+    /// as an [NcPlaneGeom].
+    ///
+    /// *C style function: [notcurses_term_dim_yx()][crate::notcurses_term_dim_yx].*
+    pub fn term_geom(&self) -> NcPlaneGeom {
+        let (rows, cols) = crate::notcurses_term_dim_yx(self);
+        NcPlaneGeom::new(rows, cols)
     }
 
     /// Returns the topmost [NcPlane], of which there is always at least one.
@@ -618,4 +1068,22 @@ impl Notcurses {
         }
         (major as u32, minor as u32, patch as u32, tweak as u32)
     }
+
+    /// Flashes the screen, by reversing the colors of the standard plane
+    /// during one render cycle of 100 milliseconds.
+    ///
+    /// Planes above the standard plane are not reversed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn visual_bell(&mut self) -> NcResult<()> {
+        let stdplane = self.stdplane()?;
+        let (rows, cols) = stdplane.dim_yx();
+        let reverse = |_, _, cell: &mut NcCell| cell.stylemask ^= NCSTYLE_REVERSE;
+
+        stdplane.map_region(0, 0, rows, cols, reverse)?;
+        self.render()?;
+        sleep![0, 100];
+        stdplane.map_region(0, 0, rows, cols, reverse)?;
+        self.render()
+    }
 }
@@ -60,6 +60,12 @@
 //W+ notcurses_stddim_yx
 //W+ notcurses_stddim_yx_const
 //W+ notcurses_term_dim_yx
+//
+// TODO: not yet available in the bundled C API:
+// - notcurses_check_pixel_support
+// - notcurses_cursor_yx
+// - notcurses_default_foreground
+// - notcurses_default_background
 
 #[cfg(test)]
 mod test;
@@ -82,6 +88,23 @@ pub type Notcurses = crate::bindings::ffi::notcurses;
 /// Options struct for [`Notcurses`]
 pub type NotcursesOptions = crate::bindings::ffi::notcurses_options;
 
+/// Options for [Notcurses.render_to_file_opts()][Notcurses#method.render_to_file_opts].
+///
+/// By default the file is truncated before writing the frame, and the frame
+/// isn't prefixed.
+///
+/// With `timestamp` the frame is preceded by a line with the time it was
+/// written, in seconds since the UNIX epoch with millisecond precision,
+/// e.g. `#ncframe 1612345678.901`, so that a recording can be split into
+/// frames and replayed with the original timing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NcRenderFileOptions {
+    /// Append the frame to the end of the file, instead of truncating it.
+    pub append: bool,
+    /// Prefix the frame with a timestamp line.
+    pub timestamp: bool,
+}
+
 /// Do not call setlocale()
 ///
 /// notcurses_init() will call setlocale() to inspect the current locale. If
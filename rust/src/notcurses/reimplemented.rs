@@ -77,7 +77,10 @@ pub fn notcurses_stddim_yx<'a>(
     unsafe {
         let sp = crate::notcurses_stdplane(nc);
         if sp != null_mut() {
-            crate::ncplane_dim_yx(sp, &mut (*y as i32), &mut (*x as i32));
+            let (mut rows, mut cols) = (0, 0);
+            crate::ncplane_dim_yx(sp, &mut rows, &mut cols);
+            *y = rows as NcDimension;
+            *x = cols as NcDimension;
             return Ok(&mut *sp);
         }
     }
@@ -97,7 +100,10 @@ pub fn notcurses_stddim_yx_const<'a>(
     unsafe {
         let sp = crate::notcurses_stdplane_const(nc);
         if sp != null() {
-            crate::ncplane_dim_yx(sp, &mut (*y as i32), &mut (*x as i32));
+            let (mut rows, mut cols) = (0, 0);
+            crate::ncplane_dim_yx(sp, &mut rows, &mut cols);
+            *y = rows as NcDimension;
+            *x = cols as NcDimension;
             return Ok(&*sp);
         }
     }
@@ -1,4 +1,374 @@
 //! Test `Notcurses` methods and associated functions.
 
-// use crate::Notcurses;
-// use serial_test::serial;
+use crate::notcurses::{best_blitter_for, push_back_input, ScopeGuard};
+use crate::{
+    NCBLIT_1x1, NCBLIT_2x2, NCBLIT_3x2, NcError, NcErrorKind, NcFile, NcInput, NcPlane,
+    NcRenderFileOptions, Notcurses, NotcursesOptions, NCBLIT_SIXEL, NCOPTION_SUPPRESS_BANNERS,
+    NCRESULT_ERR,
+};
+use core::sync::atomic::{AtomicBool, Ordering};
+use serial_test::serial;
+use std::ffi::CString;
+
+#[test]
+#[serial]
+fn bell() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    nc.bell()?;
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn best_blitter() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let blitter = nc.best_blitter();
+    assert_eq![
+        best_blitter_for(nc.cansixel(), nc.cansextant(), nc.canutf8()),
+        blitter
+    ];
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn best_blitter_priority() {
+    // pixel graphics win over everything else
+    assert_eq![NCBLIT_SIXEL, best_blitter_for(true, true, true)];
+    assert_eq![NCBLIT_SIXEL, best_blitter_for(true, false, false)];
+    // sextants need UTF-8
+    assert_eq![NCBLIT_3x2, best_blitter_for(false, true, true)];
+    assert_eq![NCBLIT_1x1, best_blitter_for(false, true, false)];
+    // quadrants
+    assert_eq![NCBLIT_2x2, best_blitter_for(false, false, true)];
+    // ASCII only
+    assert_eq![NCBLIT_1x1, best_blitter_for(false, false, false)];
+}
+
+#[test]
+#[serial]
+fn planes_top_to_bottom() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()? as *mut NcPlane;
+    let order = |nc: &mut Notcurses| -> Vec<*mut NcPlane> {
+        nc.planes_top_to_bottom()
+            .into_iter()
+            .map(|plane| plane as *mut NcPlane)
+            .collect()
+    };
+    // a single plane pile
+    assert_eq![vec![stdplane], order(nc)];
+
+    let a = NcPlane::new_bound(nc.stdplane()?, 0, 0, 2, 2)? as *mut NcPlane;
+    let b = NcPlane::new_bound(nc.stdplane()?, 1, 1, 2, 2)? as *mut NcPlane;
+    assert_eq![vec![b, a, stdplane], order(nc)];
+
+    unsafe { &mut *a }.move_top();
+    unsafe { &mut *b }.move_bottom();
+    assert_eq![vec![a, stdplane, b], order(nc)];
+
+    let mut reversed = order(nc);
+    reversed.reverse();
+    let bottom_to_top: Vec<*mut NcPlane> = nc
+        .planes_bottom_to_top()
+        .into_iter()
+        .map(|plane| plane as *mut NcPlane)
+        .collect();
+    assert_eq![reversed, bottom_to_top];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_interruptible() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let abort = AtomicBool::new(true);
+    assert_eq![false, nc.render_interruptible(&abort)?];
+
+    abort.store(false, Ordering::Release);
+    assert_eq![true, nc.render_interruptible(&abort)?];
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn frame_header() {
+    use core::time::Duration;
+    assert_eq![
+        "#ncframe 1612345678.009\n",
+        crate::notcurses::frame_header(Duration::from_millis(1_612_345_678_009))
+    ];
+}
+
+#[test]
+#[serial]
+fn render_to_file_opts() -> crate::NcResult<()> {
+    let path = std::env::temp_dir().join("nc-render-to-file-opts");
+    let cpath = CString::new(path.to_str().unwrap()).unwrap();
+    let file = unsafe { libc::fopen(cpath.as_ptr(), CString::new("w+").unwrap().as_ptr()) };
+    assert![!file.is_null()];
+    let mut file = NcFile::from_libc(file);
+
+    let nc = Notcurses::new()?;
+    let options = NcRenderFileOptions::new()
+        .set_append(true)
+        .set_timestamp(true);
+    nc.stdplane()?.putstr_yx(0, 0, "first")?;
+    nc.render()?;
+    nc.render_to_file_opts(&mut file, options)?;
+    nc.stdplane()?.putstr_yx(0, 0, "second")?;
+    nc.render()?;
+    nc.render_to_file_opts(&mut file, options)?;
+
+    let recording = file.read_string()?;
+    assert_eq![2, recording.matches("#ncframe ").count()];
+    assert![recording.starts_with("#ncframe ")];
+    let second = recording.rfind("#ncframe ").unwrap();
+    assert![recording[..second].contains("first")];
+    assert![recording[second..].contains("second")];
+
+    // truncating leaves only the last frame
+    nc.render_to_file_opts(&mut file, NcRenderFileOptions::new())?;
+    let frame = file.read_string()?;
+    assert![!frame.contains("#ncframe ")];
+    assert![frame.contains("second")];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn sprixel_refresh() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    // a refresh redraws what was last rasterized
+    nc.render()?;
+    let refreshes = nc.stats_snapshot().refreshes;
+
+    nc.sprixel_refresh()?;
+    if nc.cansixel() {
+        assert_eq![refreshes + 1, nc.stats_snapshot().refreshes];
+    } else {
+        // no-op without pixel graphics support
+        assert_eq![refreshes, nc.stats_snapshot().refreshes];
+    }
+
+    // unlike a plain refresh
+    nc.refresh()?;
+    assert![nc.stats_snapshot().refreshes > refreshes];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn stddim_geom() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (rows, cols) = nc.stdplane()?.dim_yx();
+
+    let (plane, geom) = nc.stddim_geom()?;
+    assert_eq![rows, geom.rows];
+    assert_eq![cols, geom.cols];
+    assert_eq![geom, plane.geom()];
+    assert_eq![geom, nc.term_geom()];
+    assert_eq![(rows, cols), nc.term_dim_yx()];
+
+    let (mut y, mut x) = (0, 0);
+    nc.stddim_yx(&mut y, &mut x)?;
+    assert_eq![(rows, cols), (y, x)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn visual_bell() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.putstr_yx(0, 0, "bell")?;
+    nc.visual_bell()?;
+    // the styles are restored
+    let mut cell = crate::NcCell::new();
+    stdplane.ncplane_at_yx_cell(0, 0, &mut cell)?;
+    assert_eq![0, cell.stylemask];
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn supported_style_names() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let names = nc.supported_style_names();
+    assert_eq![
+        crate::NcStyle::from_bits_truncate(nc.supported_styles())
+            .names()
+            .len(),
+        names.len()
+    ];
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn cursor_enable_out_of_bounds() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let (rows, cols) = nc.term_dim_yx();
+    let err = nc.cursor_enable(rows, cols).unwrap_err();
+    assert_eq![crate::NcErrorKind::BoundsError, err.kind()];
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn check_pixel_support() -> crate::NcResult<()> {
+    use crate::NcPixelImpl;
+
+    let nc = Notcurses::new()?;
+    nc.render()?;
+    let pixel = nc.check_pixel_support()?;
+    assert_eq![nc.cansixel(), pixel == NcPixelImpl::Sixel];
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_and_rasterize_pile() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let pile = NcPlane::new(nc, 0, 0, 2, 10)?;
+    pile.putstr("pile")?;
+
+    // a pile never rendered writes nothing
+    nc.rasterize_pile(pile)?;
+
+    let before = nc.stats_snapshot();
+    nc.render_pile(pile)?;
+    let rendered = nc.stats_snapshot();
+    assert_eq![before.renders + 1, rendered.renders];
+    assert_eq![before.writeouts, rendered.writeouts];
+
+    nc.rasterize_pile(pile)?;
+    let rasterized = nc.stats_snapshot();
+    assert_eq![rendered.writeouts + 1, rasterized.writeouts];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn scope_guard() {
+    use std::cell::Cell;
+    let calls = Cell::new(0);
+
+    drop(ScopeGuard::new(|| calls.set(calls.get() + 1)));
+    assert_eq![1, calls.get()];
+
+    ScopeGuard::new(|| calls.set(calls.get() + 1)).disarm();
+    assert_eq![1, calls.get()];
+
+    // the function is called while unwinding
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _guard = ScopeGuard::new(|| calls.set(calls.get() + 1));
+        panic!["in scope"];
+    }));
+    assert![res.is_err()];
+    assert_eq![2, calls.get()];
+}
+
+#[test]
+#[serial]
+fn with() -> crate::NcResult<()> {
+    let options = NotcursesOptions::with_flags(NCOPTION_SUPPRESS_BANNERS);
+
+    // the local modes of the terminal, which notcurses puts in cbreak mode
+    // while running, and restores when stopped
+    let lflag = || {
+        use std::os::unix::io::AsRawFd;
+        let tty = std::fs::File::open("/dev/tty").ok()?;
+        let mut termios = unsafe { core::mem::zeroed::<libc::termios>() };
+        match unsafe { libc::tcgetattr(tty.as_raw_fd(), &mut termios) } {
+            0 => Some(termios.c_lflag),
+            _ => None,
+        }
+    };
+    let before = lflag();
+    let stopped = || lflag() == before;
+
+    let (rows, cols) = Notcurses::with(options, |nc| {
+        nc.stdplane()?.putstr("hello")?;
+        nc.render()?;
+        if let Some(before) = before {
+            if before & libc::ICANON != 0 {
+                assert_eq![Some(before & !(libc::ICANON | libc::ECHO)), lflag()];
+            }
+        }
+        Ok(nc.term_dim_yx())
+    })?;
+    assert![rows > 0 && cols > 0];
+    assert![stopped()];
+
+    // the error of the closure is returned, after stopping the context
+    let res: crate::NcResult<()> = Notcurses::with(options, |_nc| {
+        Err(NcError::with_kind(
+            NCRESULT_ERR,
+            NcErrorKind::Other,
+            "failing closure",
+        ))
+    });
+    assert_eq![NcErrorKind::Other, res.unwrap_err().kind()];
+    assert![stopped()];
+
+    // the context is stopped while unwinding from a panic
+    let res = std::panic::catch_unwind(|| {
+        Notcurses::with(options, |_nc| -> crate::NcResult<()> {
+            panic!["in closure"]
+        })
+    });
+    assert![res.is_err()];
+    assert![stopped()];
+
+    // after being stopped, a new context can be initialized
+    let nc = Notcurses::new()?;
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn peek_input() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+
+    // simulates an event that was ready when peeking
+    let event = NcInput::with_all_args('x', None, None, true, false, false, 7);
+    push_back_input(nc, 'x', event);
+
+    // peeking doesn't consume it
+    assert_eq![Some(event), nc.peek_input()?];
+    assert_eq![Some(event), nc.peek_input()?];
+
+    // it's read once
+    let mut input = NcInput::new_empty();
+    assert_eq!['x', nc.getc_nblock(&mut input)];
+    assert_eq![event, input];
+    assert![input.alt];
+    // no event is available
+    assert_eq![None, nc.peek_input()?];
+
+    push_back_input(
+        nc,
+        'y',
+        NcInput::with_all_args('y', None, None, false, false, false, 8),
+    );
+    assert_eq![Ok('y'), nc.getc(None, None, None).map_err(|_| ())];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! `NcPalette` helper functions.
+
+use crate::{NcErrorKind, NcResult, NcRgb, NCRESULT_ERR};
+
+/// Helper function that parses the palette `text` for
+/// [NcPalette.load()][crate::NcPalette#method.load], one `RRGGBB` hex color
+/// per line, for consecutive entries starting at index 0.
+///
+/// Returns an error with the line number of the first invalid color, or of
+/// the first line past the 256 entries of a palette.
+pub(crate) fn parse_palette(text: &str) -> NcResult<Vec<NcRgb>> {
+    let mut colors = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        let invalid = |msg: &str| {
+            crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                &format!["NcPalette.load(): line {}: {}", idx + 1, msg],
+            )
+        };
+        if idx >= 256 {
+            return Err(invalid("more than 256 colors"));
+        }
+        let hex = line.trim();
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid(&format!["invalid RRGGBB color `{}`", hex]));
+        }
+        colors.push(NcRgb::from_str_radix(hex, 16).map_err(|_| invalid("invalid color"))?);
+    }
+    Ok(colors)
+}
+
+/// Helper function that formats the `colors` of a palette for
+/// [NcPalette.save()][crate::NcPalette#method.save], one `RRGGBB` hex color
+/// per line.
+pub(crate) fn format_palette(colors: &[NcRgb]) -> String {
+    colors
+        .iter()
+        .map(|rgb| format!["{:06X}\n", rgb & 0xffffff])
+        .collect()
+}
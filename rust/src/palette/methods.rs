@@ -1,6 +1,11 @@
 //! `NcPalette` methods and associated functions.
 
-use crate::{error, NcChannel, NcColor, NcPalette, NcPaletteIndex, NcResult, NcRgb, Notcurses};
+use core::ops::Range;
+
+use crate::{
+    error, NcChannel, NcColor, NcDimension, NcError, NcErrorKind, NcPalette, NcPaletteIndex,
+    NcResult, NcRgb, Notcurses, NCRESULT_ERR,
+};
 
 impl NcPalette {
     /// New NcPalette.
@@ -51,4 +56,90 @@ impl NcPalette {
     pub fn set(&mut self, index: NcPaletteIndex, rgb: NcRgb) {
         crate::channel_set(&mut self.chans[index as usize], rgb);
     }
+
+    /// Sets the [NcRgb] values of the consecutive entries starting at `start`
+    /// to the `colors`, and returns the number of entries set.
+    ///
+    /// The colors that would go past the end of the palette are ignored.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_range(&mut self, start: usize, colors: &[NcRgb]) -> NcDimension {
+        let entries = self.chans.iter_mut().skip(start);
+        let mut count = 0;
+        for (chan, &rgb) in entries.zip(colors) {
+            crate::channel_set(chan, rgb);
+            count += 1;
+        }
+        count
+    }
+
+    /// Rotates the entries within the `range` of indices `by` positions,
+    /// towards the end of the range if positive, or towards its start if
+    /// negative, wrapping around.
+    ///
+    /// The range is clamped to the size of the palette. Once the palette is
+    /// [used][NcPalette#method.use], the cells with palette indexed colors
+    /// change without having to be redrawn.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn cycle(&mut self, range: Range<usize>, by: i32) {
+        let end = range.end.min(self.chans.len());
+        if range.start >= end {
+            return;
+        }
+        let entries = &mut self.chans[range.start..end];
+        let shift = (by.rem_euclid(entries.len() as i32)) as usize;
+        entries.rotate_right(shift);
+    }
+
+    /// Saves the [NcRgb] values of all the entries of this NcPalette to the
+    /// file at `path`, one `RRGGBB` hex color per line.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn save(&self, path: &str) -> NcResult<()> {
+        let colors: Vec<NcRgb> = self.chans.iter().map(|&chan| chan as NcRgb).collect();
+        std::fs::write(path, crate::palette::format_palette(&colors)).map_err(|_| {
+            NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::Io,
+                "NcPalette.save(): can't write the file",
+            )
+        })
+    }
+
+    /// New NcPalette, with the colors of the file at `path` saved with
+    /// [save()][NcPalette#method.save].
+    ///
+    /// See [load_into()][NcPalette#method.load_into].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn load<'a>(nc: &mut Notcurses, path: &str) -> NcResult<&'a mut Self> {
+        let palette = Self::new(nc);
+        if let Err(error) = palette.load_into(path) {
+            palette.free();
+            return Err(error);
+        }
+        Ok(palette)
+    }
+
+    /// Sets the [NcRgb] values of the consecutive entries starting at index 0
+    /// to the colors of the file at `path`, one `RRGGBB` hex color per line,
+    /// and returns the number of entries set.
+    ///
+    /// The entries past the end of the file are left unchanged. Returns an
+    /// error with the line number of the first invalid color, in which case
+    /// no entry is changed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn load_into(&mut self, path: &str) -> NcResult<NcDimension> {
+        let text = std::fs::read_to_string(path).map_err(|_| {
+            NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::Io,
+                "NcPalette.load(): can't read the file",
+            )
+        })?;
+        let colors = crate::palette::parse_palette(&text)?;
+        Ok(self.set_range(0, &colors))
+    }
 }
@@ -23,8 +23,13 @@
 //W+ palette256_set
 //W+ palette256_set_rgb
 
+#[cfg(test)]
+mod test;
+
+mod helpers;
 mod methods;
 mod reimplemented;
+pub(crate) use helpers::*;
 pub use methods::*;
 pub use reimplemented::*;
 
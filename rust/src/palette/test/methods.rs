@@ -0,0 +1,101 @@
+//! Test `NcPalette` methods and associated functions.
+
+use crate::{
+    palette::{format_palette, parse_palette},
+    NcErrorKind, NcPalette, NcRgb, Notcurses,
+};
+
+use serial_test::serial;
+
+/// Returns the [NcRgb] values of the `palette` entries in `range`.
+fn rgbs(palette: &NcPalette, range: core::ops::Range<usize>) -> Vec<NcRgb> {
+    palette.chans[range].iter().map(|c| c & 0xffffff).collect()
+}
+
+#[test]
+#[serial]
+fn set_range_and_cycle() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let palette = NcPalette::new(nc);
+
+    assert_eq![4, palette.set_range(10, &[0x1, 0x2, 0x3, 0x4])];
+    assert_eq![vec![0x1, 0x2, 0x3, 0x4], rgbs(palette, 10..14)];
+
+    palette.cycle(10..14, 1);
+    assert_eq![vec![0x4, 0x1, 0x2, 0x3], rgbs(palette, 10..14)];
+    palette.cycle(10..14, -2);
+    assert_eq![vec![0x2, 0x3, 0x4, 0x1], rgbs(palette, 10..14)];
+    // a whole turn
+    palette.cycle(10..14, 8);
+    assert_eq![vec![0x2, 0x3, 0x4, 0x1], rgbs(palette, 10..14)];
+
+    // clamped to the end of the palette
+    assert_eq![2, palette.set_range(254, &[0xa, 0xb, 0xc])];
+    palette.cycle(254..300, 1);
+    assert_eq![vec![0xb, 0xa], rgbs(palette, 254..256)];
+    palette.cycle(300..400, 1);
+    palette.cycle(12..12, 1);
+
+    palette.free();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn parse_and_format() -> crate::NcResult<()> {
+    assert_eq![
+        vec![0x002b36, 0xFDF6E3],
+        parse_palette("002b36\n FDF6E3 \n")?
+    ];
+    assert![parse_palette("")?.is_empty()];
+    assert_eq!["002B36\nFDF6E3\n", format_palette(&[0x002b36, 0xfdf6e3])];
+
+    let err = parse_palette("002b36\n00zz36\n").unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+    assert![err.msg.contains("line 2")];
+    assert![parse_palette("002b36\n\n").is_err()];
+    assert![parse_palette("#002b36").is_err()];
+
+    let too_many = format_palette(&[0; 257]);
+    assert![parse_palette(&too_many)
+        .unwrap_err()
+        .msg
+        .contains("line 257")];
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn save_and_load() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let palette = NcPalette::new(nc);
+    palette.set_range(0, &[0x002b36, 0x073642, 0x586e75]);
+    palette.set(255, 0xfdf6e3);
+
+    let path = std::env::temp_dir().join("libnotcurses-sys-palette.txt");
+    let path = path.to_str().unwrap();
+    palette.save(path)?;
+    let loaded = NcPalette::load(nc, path)?;
+    assert_eq![rgbs(palette, 0..256), rgbs(loaded, 0..256)];
+
+    // a shorter file leaves the rest of the entries unchanged
+    std::fs::write(path, "FF0000\n00FF00\n").unwrap();
+    assert_eq![2, loaded.load_into(path)?];
+    assert_eq![vec![0xff0000, 0x00ff00, 0x586e75], rgbs(loaded, 0..3)];
+    assert_eq![vec![0xfdf6e3], rgbs(loaded, 255..256)];
+
+    // an invalid file changes nothing
+    std::fs::write(path, "000000\nnot a color\n").unwrap();
+    let err = loaded.load_into(path).unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+    assert_eq![vec![0xff0000], rgbs(loaded, 0..1)];
+    assert![NcPalette::load(nc, path).is_err()];
+
+    std::fs::remove_file(path).unwrap();
+    assert_eq![NcErrorKind::Io, loaded.load_into(path).unwrap_err().kind()];
+
+    palette.free();
+    loaded.free();
+    nc.stop()?;
+    Ok(())
+}
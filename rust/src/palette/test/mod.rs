@@ -0,0 +1,4 @@
+//! `NcPalette` tests.
+
+#[cfg(test)]
+mod methods;
@@ -54,6 +54,25 @@ use crate::NcColor;
 ///
 pub type NcPixel = u32;
 
+/// The pixel graphics protocol supported by the terminal.
+///
+/// See [Notcurses.check_pixel_support()][crate::Notcurses#method.check_pixel_support].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NcPixelImpl {
+    /// No pixel support.
+    None,
+    /// Sixel.
+    Sixel,
+    /// Linux framebuffer.
+    LinuxFb,
+    /// iTerm2.
+    ItermGraphics,
+    /// Kitty prior to C=1 and animation.
+    KittyStatic,
+    /// Kitty with animation.
+    KittyAnimated,
+}
+
 /// Constructs a libav-compatible ABGR pixel from [NcColor] RGB components.
 #[inline]
 pub const fn ncpixel(red: NcColor, green: NcColor, blue: NcColor) -> NcPixel {
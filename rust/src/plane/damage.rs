@@ -0,0 +1,195 @@
+//! `NcDamageTracker`
+
+use crate::{NcDimension, NcPlane, NcResult};
+
+/// A rectangular region of an [NcPlane], in cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NcRect {
+    /// The top row.
+    pub y: NcDimension,
+    /// The leftmost column.
+    pub x: NcDimension,
+    /// The number of rows.
+    pub len_y: NcDimension,
+    /// The number of columns.
+    pub len_x: NcDimension,
+}
+
+/// # `NcRect` methods
+impl NcRect {
+    /// New NcRect.
+    pub const fn new(
+        y: NcDimension,
+        x: NcDimension,
+        len_y: NcDimension,
+        len_x: NcDimension,
+    ) -> Self {
+        Self { y, x, len_y, len_x }
+    }
+
+    /// Returns true if the rectangle has no cells.
+    pub const fn is_empty(&self) -> bool {
+        self.len_y == 0 || self.len_x == 0
+    }
+
+    /// Returns true if `other` lies entirely within this rectangle.
+    pub fn contains(&self, other: &NcRect) -> bool {
+        other.y >= self.y
+            && other.x >= self.x
+            && other.y + other.len_y <= self.y + self.len_y
+            && other.x + other.len_x <= self.x + self.len_x
+    }
+}
+
+/// Wraps an [NcPlane] and records the regions modified through it,
+/// so that rendering can be skipped entirely while nothing changed.
+///
+/// Notcurses already avoids writing out the cells that didn't change, but
+/// a render still composes every plane, which is wasted in idle loops.
+///
+/// The writes done with the methods of the tracker are recorded
+/// automatically. Other modifications must be recorded with
+/// [mark_dirty()][NcDamageTracker#method.mark_dirty], or with
+/// [mark_all_dirty()][NcDamageTracker#method.mark_all_dirty], which
+/// [plane_mut()][NcDamageTracker#method.plane_mut] does on your behalf.
+/// A change of the dimensions of the plane, e.g. after a resize, marks the
+/// whole plane as dirty.
+pub struct NcDamageTracker<'a> {
+    plane: &'a mut NcPlane,
+    damage: Vec<NcRect>,
+    // dimensions at the last render
+    rows: NcDimension,
+    cols: NcDimension,
+}
+
+/// # `NcDamageTracker` constructors
+impl<'a> NcDamageTracker<'a> {
+    /// New NcDamageTracker wrapping `plane`.
+    ///
+    /// The whole plane starts as dirty, so that the first render happens.
+    pub fn new(plane: &'a mut NcPlane) -> Self {
+        let (rows, cols) = plane.dim_yx();
+        let mut tracker = Self {
+            plane,
+            damage: Vec::new(),
+            rows,
+            cols,
+        };
+        tracker.mark_all_dirty();
+        tracker
+    }
+}
+
+/// # `NcDamageTracker` methods
+impl<'a> NcDamageTracker<'a> {
+    /// Returns a reference to the wrapped [NcPlane].
+    pub fn plane(&self) -> &NcPlane {
+        &*self.plane
+    }
+
+    /// Returns a mutable reference to the wrapped [NcPlane],
+    /// marking the whole plane as dirty.
+    pub fn plane_mut(&mut self) -> &mut NcPlane {
+        self.mark_all_dirty();
+        &mut *self.plane
+    }
+
+    /// Returns the regions modified since the last render.
+    pub fn damage(&self) -> &[NcRect] {
+        &self.damage
+    }
+
+    /// Returns true if anything changed since the last render,
+    /// including the dimensions of the plane.
+    pub fn is_dirty(&self) -> bool {
+        !self.damage.is_empty() || self.plane.dim_yx() != (self.rows, self.cols)
+    }
+
+    /// Records the `rect`angle as modified.
+    ///
+    /// It's clipped to the plane, and ignored if it ends up empty.
+    pub fn mark_dirty(&mut self, rect: NcRect) {
+        let (rows, cols) = self.plane.dim_yx();
+        crate::plane::damage_add(&mut self.damage, rect, rows, cols);
+    }
+
+    /// Records the whole plane as modified.
+    pub fn mark_all_dirty(&mut self) {
+        let (rows, cols) = self.plane.dim_yx();
+        self.damage.clear();
+        crate::plane::damage_add(&mut self.damage, NcRect::new(0, 0, rows, cols), rows, cols);
+    }
+
+    /// Forgets the recorded damage, without rendering.
+    pub fn clear(&mut self) {
+        self.damage.clear();
+        let (rows, cols) = self.plane.dim_yx();
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    /// Renders and rasterizes the pile of the plane if anything changed
+    /// since the last render, and clears the damage.
+    ///
+    /// Returns true if it rendered.
+    pub fn render_damaged(&mut self) -> NcResult<bool> {
+        if !self.is_dirty() {
+            return Ok(false);
+        }
+        self.plane.render()?;
+        self.plane.rasterize()?;
+        self.clear();
+        Ok(true)
+    }
+
+    /// Writes a string at the cursor, recording the cells written.
+    ///
+    /// See [NcPlane.putstr()][NcPlane#method.putstr].
+    pub fn putstr(&mut self, string: &str) -> NcResult<NcDimension> {
+        let (y, x) = self.plane.cursor_yx();
+        self.putstr_yx(y, x, string)
+    }
+
+    /// Writes a string at `y`, `x`, recording the cells written.
+    ///
+    /// See [NcPlane.putstr_yx()][NcPlane#method.putstr_yx].
+    pub fn putstr_yx(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        string: &str,
+    ) -> NcResult<NcDimension> {
+        let res = self.plane.putstr_yx(y, x, string);
+        self.mark_written(y, x);
+        res
+    }
+
+    /// Writes a `ch`aracter at `y`, `x`, recording the cells written.
+    ///
+    /// See [NcPlane.putchar_yx()][NcPlane#method.putchar_yx].
+    pub fn putchar_yx(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        ch: char,
+    ) -> NcResult<NcDimension> {
+        let res = self.plane.putchar_yx(y, x, ch);
+        self.mark_written(y, x);
+        res
+    }
+
+    /// Erases the plane, marking it all as dirty.
+    ///
+    /// See [NcPlane.erase()][NcPlane#method.erase].
+    pub fn erase(&mut self) {
+        self.plane.erase();
+        self.mark_all_dirty();
+    }
+
+    // records the cells written from `y`, `x` up to the current cursor
+    fn mark_written(&mut self, y: NcDimension, x: NcDimension) {
+        let (rows, cols) = self.plane.dim_yx();
+        let rect = crate::plane::damage_written((y, x), self.plane.cursor_yx(), rows, cols);
+        crate::plane::damage_add(&mut self.damage, rect, rows, cols);
+    }
+}
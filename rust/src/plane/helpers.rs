@@ -1,4 +1,14 @@
-use crate::{NcDimension, NcOffset, NcPlane, NcPlaneOptions, Notcurses};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+use crate::{
+    cstring, NCBLIT_1x1, NCBLIT_2x1, NCBLIT_2x2, NCBLIT_3x2, NcBlitter, NcChannelPair, NcDimension,
+    NcIntResult, NcOffset, NcPaletteIndex, NcPlane, NcPlaneOptions, NcRect, NcResult, NcRgb,
+    NcStyleMask, Notcurses, NCBLIT_SIXEL, NCRESULT_ERR, NCSTYLE_BLINK, NCSTYLE_BOLD, NCSTYLE_DIM,
+    NCSTYLE_INVIS, NCSTYLE_ITALIC, NCSTYLE_REVERSE, NCSTYLE_STRUCK, NCSTYLE_UNDERLINE,
+};
 
 /// Helper function for a new NcPlane on C style tests.
 #[allow(dead_code)]
@@ -23,3 +33,1315 @@ pub(crate) unsafe fn ncplane_new_bound_test<'a>(
 ) -> &'a mut NcPlane {
     &mut *crate::ncplane_create(plane, &NcPlaneOptions::new(y, x, rows, cols))
 }
+
+/// Helper function that wraps `text` into lines of at most `width` columns.
+///
+/// Lines are broken at whitespace when possible, and words longer than
+/// `width` are split. Newlines in `text` are preserved. The widths are
+/// measured in columns with [strwidth], so wide characters are accounted for.
+pub(crate) fn wrap_text(text: &str, width: NcDimension) -> Vec<String> {
+    wrap_text_anchored(text, width)
+        .into_iter()
+        .map(|(line, _)| line)
+        .collect()
+}
+
+/// Helper function that wraps `text` like [wrap_text], returning along with
+/// each line the position in `text` where it starts.
+///
+/// The position is the index of the paragraph, and the number of non
+/// whitespace characters of the paragraph before the line, so that it
+/// doesn't depend on the `width`.
+pub(crate) fn wrap_text_anchored(text: &str, width: NcDimension) -> Vec<(String, (usize, usize))> {
+    let mut lines = Vec::new();
+    if width == 0 {
+        return lines;
+    }
+    // the width in columns, or the number of characters if not printable
+    let cols_of = |s: &str| strwidth(s).unwrap_or_else(|_| s.chars().count() as NcDimension);
+    for (paragraph_idx, paragraph) in text.split('\n').enumerate() {
+        let mut line = String::new();
+        let mut line_cols = 0;
+        let mut line_start = 0;
+        let mut position = 0;
+        for mut word in paragraph.split_whitespace() {
+            let mut word_start = position;
+            position += word.chars().count();
+            let mut word_cols = cols_of(word);
+            if !line.is_empty() && line_cols + 1 + word_cols <= width {
+                line.push(' ');
+                line.push_str(word);
+                line_cols += 1 + word_cols;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push((core::mem::take(&mut line), (paragraph_idx, line_start)));
+            }
+            while word_cols > width {
+                // at least one character, even if it's wider than the line
+                let split = match fitting_prefix(word, width) {
+                    Ok((prefix, _)) if !prefix.is_empty() => prefix.len(),
+                    _ => word.chars().next().map_or(0, char::len_utf8),
+                };
+                let (part, rest) = word.split_at(split);
+                lines.push((part.to_string(), (paragraph_idx, word_start)));
+                word_start += part.chars().count();
+                word = rest;
+                word_cols = cols_of(word);
+            }
+            line = word.to_string();
+            line_cols = word_cols;
+            line_start = word_start;
+        }
+        lines.push((line, (paragraph_idx, line_start)));
+    }
+    lines
+}
+
+/// Helper function that returns the index of the line wrapped by
+/// [wrap_text_anchored] that contains the `anchor` position.
+pub(crate) fn anchored_line(lines: &[(String, (usize, usize))], anchor: (usize, usize)) -> usize {
+    lines
+        .iter()
+        .rposition(|(_, start)| *start <= anchor)
+        .unwrap_or(0)
+}
+
+/// Helper function that returns the width of `string` in columns,
+/// computed with [ncstrwidth][crate::ncstrwidth].
+pub(crate) fn strwidth(string: &str) -> NcResult<NcDimension> {
+    let width = unsafe { crate::ncstrwidth(cstring![string]) };
+    if width < 0 {
+        return Err(crate::NcError::with_kind(
+            width,
+            crate::NcErrorKind::InvalidInput,
+            "non printable string",
+        ));
+    }
+    Ok(width as NcDimension)
+}
+
+/// Helper function that returns an error for the `method` drawing a box
+/// of `y_len` * `x_len`, if it's smaller than 2x2, since a box needs corners.
+pub(crate) fn box_min_size(method: &str, y_len: NcDimension, x_len: NcDimension) -> NcResult<()> {
+    if y_len < 2 || x_len < 2 {
+        return Err(crate::NcError::with_kind(
+            NCRESULT_ERR,
+            crate::NcErrorKind::InvalidInput,
+            &format![
+                "{}: a box can't be smaller than 2x2, got {}x{}",
+                method, y_len, x_len
+            ],
+        ));
+    }
+    Ok(())
+}
+
+/// Helper function that returns the longest prefix of `string` that fits in
+/// `cols` columns, and its width.
+///
+/// The prefix is measured as a whole, so that zero width characters
+/// (e.g. combining marks) stay together with the preceding character.
+pub(crate) fn fitting_prefix(string: &str, cols: NcDimension) -> NcResult<(&str, NcDimension)> {
+    let (mut end, mut width) = (0, 0);
+    for (i, c) in string.char_indices() {
+        let next = i + c.len_utf8();
+        let next_width = strwidth(&string[..next])?;
+        if next_width > cols {
+            break;
+        }
+        end = next;
+        width = next_width;
+    }
+    Ok((&string[..end], width))
+}
+
+/// Helper function that truncates `string` with an ellipsis (`…`) if it's
+/// wider than `cols` columns, and returns it along with its width.
+///
+/// See [putstr_ellipsis()][NcPlane#method.putstr_ellipsis].
+pub(crate) fn ellipsized(string: &str, cols: NcDimension) -> NcResult<(String, NcDimension)> {
+    if cols == 0 {
+        return Ok((String::new(), 0));
+    }
+    let width = strwidth(string)?;
+    if width <= cols {
+        return Ok((string.to_string(), width));
+    }
+    let (prefix, width) = fitting_prefix(string, cols - 1)?;
+    Ok((format!["{}…", prefix], width + 1))
+}
+
+/// The text stored by [set_reflow_text()][NcPlane#method.set_reflow_text],
+/// along with the scroll position.
+pub(crate) struct ReflowText {
+    pub(crate) text: String,
+    // index of the topmost visible line
+    pub(crate) top: usize,
+    // position of the topmost visible line in the text, see wrap_text_anchored
+    pub(crate) anchor: (usize, usize),
+    pub(crate) anchored: bool,
+}
+
+/// Helper resize callback that reflows the text stored by
+/// [set_reflow_text()][NcPlane#method.set_reflow_text].
+///
+/// The topmost visible line is kept if the reflow is anchored, or else
+/// its index, which is clamped so that the last line stays at the bottom.
+pub(crate) fn reflow_resizecb(plane: &mut NcPlane) -> NcIntResult {
+    // taken out of the state while drawing, since writing reads the state
+    let mut reflow = match take_plane_reflow(plane) {
+        Some(reflow) => reflow,
+        None => return NCRESULT_ERR,
+    };
+    let res = draw_reflow_text(plane, &mut reflow);
+    set_plane_reflow(plane, Some(reflow));
+    res
+}
+
+/// Wraps the `reflow` text to the width of the `plane`, and draws it.
+fn draw_reflow_text(plane: &mut NcPlane, reflow: &mut ReflowText) -> NcIntResult {
+    let (rows, cols) = plane.dim_yx();
+    if cols == 0 {
+        return 0;
+    }
+    let lines = wrap_text_anchored(&reflow.text, cols);
+    let max_top = lines.len().saturating_sub(rows as usize);
+    if reflow.anchored {
+        reflow.top = anchored_line(&lines, reflow.anchor).min(max_top);
+    } else {
+        reflow.top = reflow.top.min(max_top);
+        reflow.anchor = lines.get(reflow.top).map_or((0, 0), |(_, start)| *start);
+    }
+
+    plane.erase();
+    for (y, (line, _)) in lines
+        .iter()
+        .skip(reflow.top)
+        .take(rows as usize)
+        .enumerate()
+    {
+        if plane.putstr_yx(y as NcDimension, 0, line).is_err() {
+            return NCRESULT_ERR;
+        }
+    }
+    0
+}
+
+/// Helper function that chooses the [NcBlitter] showing the most pixels of an
+/// `img` of (height, width) pixels in a `target` region of (rows, cols),
+/// from the capabilities of the terminal.
+///
+/// Used by [NcPlane.choose_blitter_for()][NcPlane#method.choose_blitter_for].
+pub(crate) fn blitter_for_geom(
+    pixel: bool,
+    sextant: bool,
+    utf8: bool,
+    target: (NcDimension, NcDimension),
+    img: (NcDimension, NcDimension),
+) -> NcBlitter {
+    let (rows, cols) = (target.0 as u64, target.1 as u64);
+    let (img_h, img_w) = (img.0 as u64, img.1 as u64);
+
+    // the cell geometry is unknown, so pixel graphics are assumed to show
+    // every pixel of the image.
+    if pixel {
+        return NCBLIT_SIXEL;
+    }
+
+    // (blitter, pixels per cell vertically, pixels per cell horizontally),
+    // from the lower to the higher resolution.
+    let mut candidates = vec![(NCBLIT_1x1, 1, 1)];
+    if utf8 {
+        candidates.push((NCBLIT_2x1, 2, 1));
+        candidates.push((NCBLIT_2x2, 2, 2));
+        if sextant {
+            candidates.push((NCBLIT_3x2, 3, 2));
+        }
+    }
+
+    let mut best = (NCBLIT_1x1, 0);
+    for (blitter, px_y, px_x) in candidates {
+        let shown = img_h.min(rows * px_y) * img_w.min(cols * px_x);
+        // on a tie the lower resolution blitter wins, with better colors.
+        if shown > best.1 {
+            best = (blitter, shown);
+        }
+    }
+    best.0
+}
+
+/// Helper function that bilinearly interpolates the RGB colors of the four
+/// corner [NcChannelPair]s, at the fractions `ty` of the height and `tx` of
+/// the width.
+///
+/// The alpha and default color flags are taken from `ul`.
+pub(crate) fn channels_bilerp(
+    ul: NcChannelPair,
+    ur: NcChannelPair,
+    ll: NcChannelPair,
+    lr: NcChannelPair,
+    ty: f32,
+    tx: f32,
+) -> NcChannelPair {
+    fn lerp(a: NcRgb, b: NcRgb, t: f32) -> NcRgb {
+        let mut rgb = 0;
+        for shift in &[16, 8, 0] {
+            let (a, b) = (((a >> shift) & 0xff) as f32, ((b >> shift) & 0xff) as f32);
+            rgb |= ((a + (b - a) * t).round() as NcRgb) << shift;
+        }
+        rgb
+    }
+    fn bilerp(ul: NcRgb, ur: NcRgb, ll: NcRgb, lr: NcRgb, ty: f32, tx: f32) -> NcRgb {
+        lerp(lerp(ul, ur, tx), lerp(ll, lr, tx), ty)
+    }
+
+    let mut channels = ul;
+    if !ul.fg_default_p() {
+        channels.set_fg_rgb(bilerp(
+            ul.fg_rgb(),
+            ur.fg_rgb(),
+            ll.fg_rgb(),
+            lr.fg_rgb(),
+            ty,
+            tx,
+        ));
+    }
+    if !ul.bg_default_p() {
+        channels.set_bg_rgb(bilerp(
+            ul.bg_rgb(),
+            ur.bg_rgb(),
+            ll.bg_rgb(),
+            lr.bg_rgb(),
+            ty,
+            tx,
+        ));
+    }
+    channels
+}
+
+/// Helper function that returns the row of the column `x` at `frame`,
+/// for [column_animate()][NcPlane#method.column_animate].
+///
+/// Each column starts at its own row, scattered from its index,
+/// and advances one row per frame, wrapping around the `rows`.
+pub(crate) fn column_row(x: NcDimension, frame: u64, rows: NcDimension) -> NcDimension {
+    if rows == 0 {
+        return 0;
+    }
+    let phase = (x as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 32;
+    (phase.wrapping_add(frame) % rows as u64) as NcDimension
+}
+
+/// A mouse area of a plane: `(y, x, len_y, len_x, id)`.
+type MouseArea = (NcDimension, NcDimension, NcDimension, NcDimension, u32);
+
+/// The state of a plane kept on the Rust side, since the C API has no room
+/// for it: the opaque pointer is left to the user.
+///
+/// It's forgotten on every path that destroys the plane, so that a new plane
+/// allocated at the same address doesn't inherit it: [NcPlane.destroy()],
+/// the `destroy` methods of the widgets, [Notcurses.drop_planes()] and
+/// [Notcurses.stop()].
+///
+/// [NcPlane.destroy()]: NcPlane#method.destroy
+/// [Notcurses.drop_planes()]: Notcurses#method.drop_planes
+/// [Notcurses.stop()]: Notcurses#method.stop
+#[derive(Default)]
+struct PlaneState {
+    /// The address of the Notcurses context of the plane.
+    nc: usize,
+    /// The name set with NcPlane::set_name().
+    name: Option<String>,
+    /// Whether autogrow is enabled.
+    autogrow: bool,
+    /// The mouse areas, in the order they were added.
+    mouse_areas: Vec<MouseArea>,
+    /// The order in which it was created as the root of a new pile.
+    pile_order: Option<usize>,
+    /// The text set with NcPlane::set_reflow_text().
+    reflow: Option<ReflowText>,
+}
+
+impl PlaneState {
+    fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && !self.autogrow
+            && self.mouse_areas.is_empty()
+            && self.pile_order.is_none()
+            && self.reflow.is_none()
+    }
+}
+
+thread_local! {
+    // the Rust side state of the planes, by their address
+    static PLANE_STATE: RefCell<HashMap<usize, PlaneState>> = RefCell::new(HashMap::new());
+    // the number of piles created, for their order
+    static PILES_CREATED: Cell<usize> = Cell::new(0);
+}
+
+/// Reads the state of the `plane` with `f`, if it has any.
+fn read_plane_state<R>(plane: *const NcPlane, f: impl FnOnce(&PlaneState) -> R) -> Option<R> {
+    PLANE_STATE.with(|states| states.borrow().get(&(plane as usize)).map(f))
+}
+
+/// Updates the state of the `plane` with `f`, and drops it if it's empty.
+fn update_plane_state<R>(plane: *const NcPlane, f: impl FnOnce(&mut PlaneState) -> R) -> R {
+    PLANE_STATE.with(|states| {
+        let mut states = states.borrow_mut();
+        let state = states.entry(plane as usize).or_insert_with(|| PlaneState {
+            nc: unsafe { crate::ncplane_notcurses_const(plane) } as usize,
+            ..Default::default()
+        });
+        let result = f(state);
+        if state.is_empty() {
+            states.remove(&(plane as usize));
+        }
+        result
+    })
+}
+
+/// Helper function that forgets the Rust side state of the `plane`,
+/// before it's destroyed.
+pub(crate) fn forget_plane_state(plane: *const NcPlane) {
+    PLANE_STATE.with(|states| states.borrow_mut().remove(&(plane as usize)));
+}
+
+/// Helper function that forgets the Rust side state of the `plane` and of
+/// all the planes bound to it, directly or not, before they're destroyed.
+pub(crate) fn forget_family_state(plane: &mut NcPlane) {
+    for member in plane_family(plane) {
+        forget_plane_state(member);
+    }
+}
+
+/// Helper function that forgets the Rust side state of all the planes of
+/// the `nc` context, except the `keep` plane, before they're destroyed.
+pub(crate) fn forget_planes_state(nc: *const Notcurses, keep: Option<*const NcPlane>) {
+    let keep = keep.map(|plane| plane as usize);
+    PLANE_STATE.with(|states| {
+        states
+            .borrow_mut()
+            .retain(|&plane, state| state.nc != nc as usize || Some(plane) == keep)
+    });
+}
+
+/// Helper function that records the `plane` as the root of a new pile,
+/// so that [pile_tops] can reach it.
+pub(crate) fn set_pile_root(plane: *const NcPlane) {
+    let order = PILES_CREATED.with(|count| count.replace(count.get() + 1));
+    update_plane_state(plane, |state| state.pile_order = Some(order));
+}
+
+/// Helper function that sets the reflow text of the `plane`, or forgets it
+/// if None.
+pub(crate) fn set_plane_reflow(plane: *const NcPlane, reflow: Option<ReflowText>) {
+    update_plane_state(plane, |state| state.reflow = reflow);
+}
+
+/// Helper function that takes the reflow text out of the state of the
+/// `plane`, if it has any.
+pub(crate) fn take_plane_reflow(plane: *const NcPlane) -> Option<ReflowText> {
+    update_plane_state(plane, |state| state.reflow.take())
+}
+
+/// Helper function that updates the reflow text of the `plane` with `f`,
+/// if it has any, and returns its result.
+///
+/// `f` must not access the state of any plane.
+pub(crate) fn update_plane_reflow<R>(
+    plane: *const NcPlane,
+    f: impl FnOnce(&mut ReflowText) -> R,
+) -> Option<R> {
+    update_plane_state(plane, |state| state.reflow.as_mut().map(f))
+}
+
+/// Helper function that returns the name set for the `plane`, if any.
+pub(crate) fn plane_name(plane: *const NcPlane) -> Option<String> {
+    read_plane_state(plane, |state| state.name.clone()).flatten()
+}
+
+/// Helper function that sets the name of the `plane`, or forgets it if None.
+pub(crate) fn set_plane_name(plane: *const NcPlane, name: Option<&str>) {
+    update_plane_state(plane, |state| state.name = name.map(str::to_string));
+}
+
+/// Helper function that returns true if autogrow is enabled for the `plane`.
+pub(crate) fn plane_autogrow(plane: *const NcPlane) -> bool {
+    read_plane_state(plane, |state| state.autogrow).unwrap_or(false)
+}
+
+/// Helper function that enables or disables autogrow for the `plane`,
+/// and returns whether it was previously enabled.
+pub(crate) fn set_plane_autogrow(plane: *const NcPlane, enable: bool) -> bool {
+    update_plane_state(plane, |state| {
+        core::mem::replace(&mut state.autogrow, enable)
+    })
+}
+
+/// Helper function that adds a mouse `area` to the `plane`, on top of the
+/// previous ones.
+pub(crate) fn add_mouse_area(plane: *const NcPlane, area: MouseArea) {
+    update_plane_state(plane, |state| state.mouse_areas.push(area));
+}
+
+/// Helper function that removes the mouse areas with the `id` from the
+/// `plane`, or all of them if None, and returns how many were removed.
+pub(crate) fn remove_mouse_areas(plane: *const NcPlane, id: Option<u32>) -> usize {
+    update_plane_state(plane, |state| {
+        let len = state.mouse_areas.len();
+        match id {
+            Some(id) => state.mouse_areas.retain(|area| area.4 != id),
+            None => state.mouse_areas.clear(),
+        }
+        len - state.mouse_areas.len()
+    })
+}
+
+/// Helper function that returns the id of the topmost mouse area of the
+/// `plane` containing `y`, `x`, which is the last one added.
+pub(crate) fn mouse_area_at(plane: *const NcPlane, y: NcDimension, x: NcDimension) -> Option<u32> {
+    let contains = |&(ay, ax, len_y, len_x, _): &MouseArea| {
+        y >= ay && y - ay < len_y && x >= ax && x - ax < len_x
+    };
+    read_plane_state(plane, |state| {
+        state
+            .mouse_areas
+            .iter()
+            .rev()
+            .find(|area| contains(area))
+            .map(|area| area.4)
+    })
+    .flatten()
+}
+
+/// Helper function that returns the number of rows the cursor advances
+/// while writing `string` from the column `x` of a plane `cols` wide,
+/// wrapping at its right edge and at each newline.
+pub(crate) fn wrapped_rows(
+    string: &str,
+    x: NcDimension,
+    cols: NcDimension,
+) -> NcResult<NcDimension> {
+    let (mut rows, mut x) = (0, x);
+    let mut buf = [0; 4];
+    for c in string.chars() {
+        if c == '\n' {
+            rows += 1;
+            x = 0;
+            continue;
+        }
+        let width = strwidth(c.encode_utf8(&mut buf))?;
+        if x + width > cols {
+            rows += 1;
+            x = 0;
+        }
+        x += width;
+    }
+    Ok(rows)
+}
+
+/// Helper function that returns an indented tree of the planes in the pile
+/// of the `plane`, for [pile_tree_string()][NcPlane#method.pile_tree_string].
+///
+/// Each line has the name, the dimensions and the position of a plane,
+/// relative to its parent. Bound planes are nested under their parents,
+/// and siblings are listed from the top to the bottom of the z-axis.
+///
+/// Unnamed planes are shown as `<unnamed #N>`, where `N` is the position
+/// of the plane in the z-axis of the pile, starting with 0 at the top.
+pub(crate) fn pile_tree_string(plane: &mut NcPlane) -> String {
+    // the planes of the pile, from the top to the bottom
+    let mut planes: Vec<*mut NcPlane> = Vec::new();
+    let mut below = unsafe { crate::ncpile_top(plane) };
+    while !below.is_null() {
+        planes.push(below);
+        below = unsafe { crate::ncplane_below(below) };
+    }
+
+    let mut string = String::new();
+    for (index, &plane) in planes.iter().enumerate() {
+        let parent = plane_parent(plane);
+        if parent.is_null() || parent == plane {
+            write_plane_tree(&mut string, &planes, index, 0);
+        }
+    }
+    string
+}
+
+/// Helper function that returns the top plane of each pile of the `nc`
+/// context, starting with the standard pile.
+///
+/// The C API doesn't allow to iterate over the piles, so they're reached
+/// from the planes known on the Rust side: the standard plane, the roots of
+/// the piles created with [NcPlane::new()][NcPlane#method.new] and
+/// [NcPlane::with_options()][NcPlane#method.with_options], and the planes
+/// with a Rust side state, e.g. a name. The piles created otherwise, e.g.
+/// by reparenting a plane to itself, are left out if none of their planes
+/// is known. The piles created by the bindings follow the standard one,
+/// in the order they were created.
+pub(crate) fn pile_tops(nc: &mut Notcurses) -> Vec<*mut NcPlane> {
+    let mut known: Vec<(usize, usize)> = PLANE_STATE.with(|states| {
+        states
+            .borrow()
+            .iter()
+            .filter(|(_, state)| state.nc == nc as *mut Notcurses as usize)
+            .map(|(&plane, state)| (state.pile_order.unwrap_or(usize::MAX), plane))
+            .collect()
+    });
+    known.sort_unstable();
+
+    let stdplane = unsafe { crate::notcurses_stdplane(nc) };
+    let mut tops = vec![unsafe { crate::ncpile_top(stdplane) }];
+    for (_, plane) in known {
+        let top = unsafe { crate::ncpile_top(plane as *mut NcPlane) };
+        if !top.is_null() && !tops.contains(&top) {
+            tops.push(top);
+        }
+    }
+    tops
+}
+
+/// Writes the line of the plane at `index`, followed by its bound planes.
+fn write_plane_tree(string: &mut String, planes: &[*mut NcPlane], index: usize, depth: usize) {
+    let plane = unsafe { &*planes[index] };
+    let name = plane_name(plane).unwrap_or_else(|| format!["<unnamed #{}>", index]);
+    let (rows, cols) = plane.dim_yx();
+    let (y, x) = plane.yx();
+    string.push_str(&format![
+        "{:indent$}{} {}x{} @ {},{}\n",
+        "",
+        name,
+        rows,
+        cols,
+        y,
+        x,
+        indent = depth * 2
+    ]);
+    for (child, &plane) in planes.iter().enumerate() {
+        if child != index && plane_parent(plane) == planes[index] {
+            write_plane_tree(string, planes, child, depth + 1);
+        }
+    }
+}
+
+/// Helper function that returns the [NcEgc][crate::NcEgc], the [NcStyleMask]
+/// and the [NcChannelPair] of the cell at `y`, `x` of the `plane`.
+///
+/// The right half of a wide glyph has an empty EGC.
+pub(crate) fn plane_cell_at(
+    plane: &NcPlane,
+    y: NcDimension,
+    x: NcDimension,
+) -> NcResult<(String, NcStyleMask, NcChannelPair)> {
+    let (mut stylemask, mut channels) = (0, 0);
+    let egc =
+        unsafe { crate::ncplane_at_yx(plane, y as i32, x as i32, &mut stylemask, &mut channels) };
+    if egc.is_null() {
+        return Err(crate::NcError::with_kind(
+            NCRESULT_ERR,
+            crate::NcErrorKind::NullReturn,
+            "retrieving a cell",
+        ));
+    }
+    let string = crate::rstring![egc].to_string();
+    unsafe { libc::free(egc as *mut libc::c_void) };
+    Ok((string, stylemask, channels))
+}
+
+/// Helper function that returns the absolute coordinates of the `plane`.
+pub(crate) fn plane_abs_yx(plane: &NcPlane) -> (NcOffset, NcOffset) {
+    let (mut y, mut x) = (0, 0);
+    unsafe { crate::ncplane_translate_abs(plane, &mut y, &mut x) };
+    (-y, -x)
+}
+
+/// Helper function that returns the `plane` and all the planes bound to it,
+/// directly or not, from the top to the bottom of their pile.
+pub(crate) fn plane_family(plane: &mut NcPlane) -> Vec<*mut NcPlane> {
+    let root = plane as *mut NcPlane;
+    let in_family = |mut member: *mut NcPlane| loop {
+        if member == root {
+            return true;
+        }
+        let parent = plane_parent(member);
+        if parent.is_null() || parent == member {
+            return false;
+        }
+        member = parent;
+    };
+
+    pile_planes(plane)
+        .into_iter()
+        .filter(|&member| in_family(member))
+        .collect()
+}
+
+/// Helper function that reparents the `plane`, along with the planes bound
+/// to it, to `parent`, keeping the position of each one relative to its
+/// parent, and their relative z-order, right above `parent`.
+///
+/// [ncplane_reparent_family()][crate::ncplane_reparent_family] updates
+/// neither the absolute coordinates of the bound planes, nor their pile
+/// when crossing piles. So the planes are reparented one at a time, from
+/// the root of the family down, with [ncplane_reparent()][crate::ncplane_reparent],
+/// which leaves their bound planes behind until it's their turn.
+pub(crate) fn reparent_family_keeping_layout(
+    plane: &mut NcPlane,
+    parent: &mut NcPlane,
+) -> NcResult<()> {
+    let (root, parent): (*mut NcPlane, *mut NcPlane) = (plane, parent);
+    // from the top to the bottom of the z-axis
+    let family = plane_family(plane);
+    if family.contains(&parent) {
+        return Err(crate::NcError::with_kind(
+            NCRESULT_ERR,
+            crate::NcErrorKind::InvalidInput,
+            "can't reparent a plane into its own family",
+        ));
+    }
+    let depth = |mut member: *mut NcPlane| {
+        let mut depth = 0;
+        while member != root {
+            member = plane_parent(member);
+            depth += 1;
+        }
+        depth
+    };
+    let mut members: Vec<_> = family
+        .iter()
+        .map(|&member| {
+            let new_parent = if member == root {
+                parent
+            } else {
+                plane_parent(member)
+            };
+            let (y, x) = unsafe { &*member }.yx();
+            (depth(member), member, new_parent, y, x)
+        })
+        .collect();
+    members.sort_by_key(|&(depth, ..)| depth);
+
+    for (_, member, new_parent, y, x) in members {
+        if unsafe { crate::ncplane_reparent(member, new_parent) }.is_null() {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                crate::NcErrorKind::InvalidInput,
+                "reparenting a plane",
+            ));
+        }
+        unsafe { &mut *member }.move_yx(y, x)?;
+    }
+
+    // from the bottom of the family to its top
+    let mut below = parent;
+    for &member in family.iter().rev() {
+        // moving a plane to where it already is, is an error
+        if unsafe { crate::ncplane_below(member) } != below {
+            let res = unsafe { crate::ncplane_move_above(member, below) };
+            if res < crate::NCRESULT_OK {
+                return Err(crate::NcError::with_msg(res, "restacking a plane"));
+            }
+        }
+        below = member;
+    }
+    Ok(())
+}
+
+/// Helper function that returns all the planes in the pile of the `plane`,
+/// from the top to the bottom.
+pub(crate) fn pile_planes(plane: &mut NcPlane) -> Vec<*mut NcPlane> {
+    let mut planes = Vec::new();
+    let mut below = unsafe { crate::ncpile_top(plane) };
+    while !below.is_null() {
+        planes.push(below);
+        below = unsafe { crate::ncplane_below(below) };
+    }
+    planes
+}
+
+/// Returns the parent of the `plane`, which is itself for a root plane.
+fn plane_parent(plane: *mut NcPlane) -> *mut NcPlane {
+    unsafe { crate::ncplane_parent_const(plane) as *mut NcPlane }
+}
+
+/// A piece of a string containing ANSI escape sequences,
+/// for [putansi()][NcPlane#method.putansi].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AnsiToken<'s> {
+    /// Text to be written.
+    Text(&'s str),
+    /// The parameters of an SGR (Select Graphic Rendition) sequence.
+    Sgr(Vec<u32>),
+}
+
+/// Helper function that splits a `string` into alternating runs of
+/// whitespace and of other characters, returning each run along with
+/// whether it's a token (i.e. not whitespace).
+///
+/// Used by [putstr_tokens()][NcPlane#method.putstr_tokens].
+pub(crate) fn whitespace_tokens(string: &str) -> Vec<(&str, bool)> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_token = None;
+    for (i, c) in string.char_indices() {
+        let is_token = !c.is_whitespace();
+        match in_token {
+            Some(current) if current != is_token => {
+                tokens.push((&string[start..i], current));
+                start = i;
+            }
+            _ => (),
+        }
+        in_token = Some(is_token);
+    }
+    if let Some(current) = in_token {
+        tokens.push((&string[start..], current));
+    }
+    tokens
+}
+
+/// Helper function that splits a `string` into text and SGR sequences.
+///
+/// Other escape sequences are dropped, as well as a truncated escape
+/// sequence at the end of the string.
+pub(crate) fn ansi_tokens(string: &str) -> Vec<AnsiToken> {
+    const ESC: u8 = 0x1b;
+    let bytes = string.as_bytes();
+    let mut tokens = Vec::new();
+    let (mut i, mut text_start) = (0, 0);
+
+    while i < bytes.len() {
+        if bytes[i] != ESC {
+            i += 1;
+            continue;
+        }
+        if text_start < i {
+            tokens.push(AnsiToken::Text(&string[text_start..i]));
+        }
+        i = match bytes.get(i + 1) {
+            // CSI: parameters, then a final byte
+            Some(b'[') => match bytes[i + 2..]
+                .iter()
+                .position(|b| (0x40..=0x7e).contains(b))
+            {
+                Some(pos) => {
+                    let end = i + 2 + pos;
+                    if bytes[end] == b'm' {
+                        tokens.push(AnsiToken::Sgr(sgr_params(&string[i + 2..end])));
+                    }
+                    end + 1
+                }
+                None => bytes.len(),
+            },
+            // OSC: terminated by BEL or ST
+            Some(b']') => {
+                let rest = &bytes[i + 2..];
+                let terminator = rest
+                    .iter()
+                    .enumerate()
+                    .find(|&(j, &b)| b == 0x07 || (b == ESC && rest.get(j + 1) == Some(&b'\\')));
+                match terminator {
+                    Some((j, 0x07)) => i + 2 + j + 1,
+                    Some((j, _)) => i + 2 + j + 2,
+                    None => bytes.len(),
+                }
+            }
+            // a two bytes sequence
+            Some(b) if b.is_ascii() => i + 2,
+            _ => i + 1,
+        };
+        text_start = i;
+    }
+    if text_start < bytes.len() {
+        tokens.push(AnsiToken::Text(&string[text_start..]));
+    }
+    tokens
+}
+
+/// Parses the parameters of an SGR sequence, an empty one meaning 0.
+fn sgr_params(params: &str) -> Vec<u32> {
+    params.split(';').map(|p| p.parse().unwrap_or(0)).collect()
+}
+
+/// Helper function that applies the SGR `params` to the `plane`.
+///
+/// The reset codes go back to the `channels` and `stylemask` provided.
+pub(crate) fn apply_sgr(
+    plane: &mut NcPlane,
+    params: &[u32],
+    channels: NcChannelPair,
+    stylemask: NcStyleMask,
+) {
+    let mut params = params.iter().copied();
+    while let Some(param) = params.next() {
+        match param {
+            0 => {
+                plane.set_channels(channels);
+                plane.set_styles(stylemask);
+            }
+            1 => plane.on_styles(NCSTYLE_BOLD),
+            2 => plane.on_styles(NCSTYLE_DIM),
+            3 => plane.on_styles(NCSTYLE_ITALIC),
+            4 => plane.on_styles(NCSTYLE_UNDERLINE),
+            5 => plane.on_styles(NCSTYLE_BLINK),
+            7 => plane.on_styles(NCSTYLE_REVERSE),
+            8 => plane.on_styles(NCSTYLE_INVIS),
+            9 => plane.on_styles(NCSTYLE_STRUCK),
+            22 => plane.off_styles(NCSTYLE_BOLD | NCSTYLE_DIM),
+            23 => plane.off_styles(NCSTYLE_ITALIC),
+            24 => plane.off_styles(NCSTYLE_UNDERLINE),
+            25 => plane.off_styles(NCSTYLE_BLINK),
+            27 => plane.off_styles(NCSTYLE_REVERSE),
+            28 => plane.off_styles(NCSTYLE_INVIS),
+            29 => plane.off_styles(NCSTYLE_STRUCK),
+            30..=37 => plane.set_fg_palindex((param - 30) as NcPaletteIndex),
+            90..=97 => plane.set_fg_palindex((param - 90 + 8) as NcPaletteIndex),
+            40..=47 => plane.set_bg_palindex((param - 40) as NcPaletteIndex),
+            100..=107 => plane.set_bg_palindex((param - 100 + 8) as NcPaletteIndex),
+            38 => match sgr_color(&mut params) {
+                Some(SgrColor::Palette(index)) => plane.set_fg_palindex(index),
+                Some(SgrColor::Rgb(r, g, b)) => plane.set_fg_rgb8(r, g, b),
+                None => (),
+            },
+            48 => match sgr_color(&mut params) {
+                Some(SgrColor::Palette(index)) => plane.set_bg_palindex(index),
+                Some(SgrColor::Rgb(r, g, b)) => plane.set_bg_rgb8(r, g, b),
+                None => (),
+            },
+            39 => {
+                plane.set_fchannel(crate::channels_fchannel(channels));
+            }
+            49 => {
+                plane.set_bchannel(crate::channels_bchannel(channels));
+            }
+            _ => (),
+        }
+    }
+}
+
+/// An extended SGR color.
+enum SgrColor {
+    Palette(NcPaletteIndex),
+    Rgb(u8, u8, u8),
+}
+
+/// Parses the extended color following an SGR 38 or 48 parameter,
+/// either `5;index` for 256 colors, or `2;r;g;b` for truecolor.
+fn sgr_color(params: &mut impl Iterator<Item = u32>) -> Option<SgrColor> {
+    let mut component = || params.next().map(|c| c.min(255) as u8);
+    match component()? {
+        5 => Some(SgrColor::Palette(component()?)),
+        2 => Some(SgrColor::Rgb(component()?, component()?, component()?)),
+        _ => None,
+    }
+}
+
+/// A tag of the markup of [putmarkup()][NcPlane#method.putmarkup].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum MarkupTag {
+    Bold,
+    Italic,
+    Underline,
+    Fg(NcRgb),
+    Bg(NcRgb),
+}
+
+impl MarkupTag {
+    /// Returns the name of the tag, as used by its closing tag.
+    fn name(self) -> &'static str {
+        match self {
+            MarkupTag::Bold => "b",
+            MarkupTag::Italic => "i",
+            MarkupTag::Underline => "u",
+            MarkupTag::Fg(_) => "fg",
+            MarkupTag::Bg(_) => "bg",
+        }
+    }
+}
+
+/// A piece of a string containing markup,
+/// for [putmarkup()][NcPlane#method.putmarkup].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum MarkupToken<'s> {
+    /// Text to be written.
+    Text(&'s str),
+    /// An opening tag.
+    Open(MarkupTag),
+    /// A closing tag, matching the last tag opened.
+    Close,
+}
+
+/// Helper function that splits a `string` into text and markup tags.
+///
+/// Anything between `<` and `>` that isn't a known tag is kept as text.
+///
+/// Returns an error for a color tag with an invalid color, a closing tag
+/// not matching the last tag opened, or a tag left open at the end.
+pub(crate) fn markup_tokens(string: &str) -> NcResult<Vec<MarkupToken>> {
+    let parse_error = |msg| {
+        Err(crate::NcError::with_kind(
+            NCRESULT_ERR,
+            crate::NcErrorKind::InvalidInput,
+            msg,
+        ))
+    };
+    let mut tokens = Vec::new();
+    let mut open: Vec<MarkupTag> = Vec::new();
+    let (mut i, mut text_start) = (0, 0);
+
+    while let Some(lt) = string[i..].find('<').map(|pos| i + pos) {
+        let gt = match string[lt..].find('>') {
+            Some(pos) => lt + pos,
+            None => break,
+        };
+        let tag = &string[lt + 1..gt];
+        let token = match tag {
+            "b" => Some(MarkupToken::Open(MarkupTag::Bold)),
+            "i" => Some(MarkupToken::Open(MarkupTag::Italic)),
+            "u" => Some(MarkupToken::Open(MarkupTag::Underline)),
+            "/b" | "/i" | "/u" | "/fg" | "/bg" => Some(MarkupToken::Close),
+            _ if tag.starts_with("fg=") || tag.starts_with("bg=") => {
+                let hex = tag[3..].trim_start_matches('#');
+                let rgb = match NcRgb::from_str_radix(hex, 16) {
+                    Ok(rgb) if hex.len() == 6 => rgb,
+                    _ => return parse_error("markup color not in the rrggbb hex form"),
+                };
+                if tag.starts_with("fg=") {
+                    Some(MarkupToken::Open(MarkupTag::Fg(rgb)))
+                } else {
+                    Some(MarkupToken::Open(MarkupTag::Bg(rgb)))
+                }
+            }
+            _ => None,
+        };
+        match token {
+            Some(token) => {
+                match token {
+                    MarkupToken::Open(opened) => open.push(opened),
+                    _ => match open.pop() {
+                        Some(opened) if opened.name() == &tag[1..] => (),
+                        _ => return parse_error("markup closing tag not matching the open one"),
+                    },
+                }
+                if text_start < lt {
+                    tokens.push(MarkupToken::Text(&string[text_start..lt]));
+                }
+                tokens.push(token);
+                i = gt + 1;
+                text_start = i;
+            }
+            // not a tag, keep looking after the `<`
+            None => i = lt + 1,
+        }
+    }
+    if !open.is_empty() {
+        return parse_error("markup tag left open");
+    }
+    if text_start < string.len() {
+        tokens.push(MarkupToken::Text(&string[text_start..]));
+    }
+    Ok(tokens)
+}
+
+/// Helper function that returns the lengths of the region of a plane of
+/// `dims` rows and columns starting at `y`, `x`, following the convention
+/// of the [region methods][NcPlane#regions], where a length of `-1` means
+/// through the edge of the plane.
+pub(crate) fn region_lens(
+    dims: (NcDimension, NcDimension),
+    y: NcDimension,
+    x: NcDimension,
+    len_y: NcOffset,
+    len_x: NcOffset,
+) -> NcResult<(NcDimension, NcDimension)> {
+    fn resolve(start: NcDimension, len: NcOffset, dim: NcDimension) -> NcResult<NcDimension> {
+        if start >= dim {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                crate::NcErrorKind::BoundsError,
+                "region starting outside of the plane",
+            ));
+        }
+        match len {
+            -1 => Ok(dim - start),
+            len if len < 0 => Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                crate::NcErrorKind::InvalidInput,
+                "negative region length other than -1",
+            )),
+            len if start + len as NcDimension > dim => Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                crate::NcErrorKind::BoundsError,
+                "region extending beyond the plane",
+            )),
+            len => Ok(len as NcDimension),
+        }
+    }
+    Ok((resolve(y, len_y, dims.0)?, resolve(x, len_x, dims.1)?))
+}
+
+/// Helper function that returns the start and the length of the thumb of
+/// a scrollbar with a track `len` cells long, for a content `total` units long,
+/// of which `visible` are shown, starting at `offset`.
+///
+/// The thumb is at least 1 cell long, and fills the whole track if all
+/// the content is visible.
+pub(crate) fn scrollbar_thumb(
+    len: NcDimension,
+    total: usize,
+    visible: usize,
+    offset: usize,
+) -> (NcDimension, NcDimension) {
+    if len == 0 {
+        return (0, 0);
+    }
+    if visible >= total {
+        return (0, len);
+    }
+    let thumb = ((len as f64 * visible as f64 / total as f64).round() as NcDimension)
+        .max(1)
+        .min(len);
+    let max_offset = total - visible;
+    let start = ((len - thumb) as f64 * offset.min(max_offset) as f64 / max_offset as f64).round();
+    (start as NcDimension, thumb)
+}
+
+/// Helper function that clamps the `offset` of a view `visible` cells long
+/// over a content `total` cells long, so that it doesn't go past its edges.
+///
+/// If the content is shorter than the view, the offset is always 0.
+pub(crate) fn viewport_clamp(offset: i64, total: NcDimension, visible: NcDimension) -> NcDimension {
+    let max_offset = total.saturating_sub(visible) as i64;
+    offset.max(0).min(max_offset) as NcDimension
+}
+
+/// Helper function that returns the [NcRgb] colors of the upper and lower
+/// halves of a cell with the `egc` and `channels`, for
+/// [as_rgba()][NcPlane#method.as_rgba].
+///
+/// Returns `None` if the `egc` isn't empty, a space, `▀`, `▄` or `█`.
+pub(crate) fn cell_pixels(egc: &str, channels: NcChannelPair) -> Option<(NcRgb, NcRgb)> {
+    let (fg, bg) = (
+        crate::channels_fg_rgb(channels),
+        crate::channels_bg_rgb(channels),
+    );
+    match egc {
+        "" | " " => Some((bg, bg)),
+        "▀" => Some((fg, bg)),
+        "▄" => Some((bg, fg)),
+        "█" => Some((fg, fg)),
+        _ => None,
+    }
+}
+
+/// Helper function that reorders a right-to-left `string`, from the logical
+/// order in which it's stored, to the visual order in which it's displayed,
+/// from left to right.
+///
+/// Each line is a right-to-left paragraph: the order of its characters is
+/// reversed, and the brackets are mirrored, except in the runs of
+/// left-to-right characters (e.g. latin words or numbers), which keep their
+/// order, along with the neutral characters between them.
+///
+/// The combining marks stay after their base character.
+pub(crate) fn rtl_visual_order(string: &str) -> String {
+    string
+        .split('\n')
+        .map(rtl_visual_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reorders a single line for [rtl_visual_order].
+fn rtl_visual_line(line: &str) -> String {
+    // splits the line in clusters: a character plus its combining marks
+    let mut clusters: Vec<String> = Vec::new();
+    for c in line.chars() {
+        match clusters.last_mut() {
+            Some(cluster) if is_combining_mark(c) => cluster.push(c),
+            _ => clusters.push(c.to_string()),
+        }
+    }
+
+    // groups each run of left-to-right clusters, from its first to its last
+    // strong character, and leaves the rest as single clusters
+    let strong_ltr = |cluster: &str| {
+        let c = cluster.chars().next().unwrap_or(' ');
+        c.is_alphanumeric() && !is_rtl(c)
+    };
+    let mut runs: Vec<(String, bool)> = Vec::new();
+    let mut i = 0;
+    while i < clusters.len() {
+        if !strong_ltr(&clusters[i]) {
+            runs.push((clusters[i].clone(), false));
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        let mut j = i + 1;
+        while j < clusters.len() && !is_rtl(clusters[j].chars().next().unwrap_or(' ')) {
+            if strong_ltr(&clusters[j]) {
+                end = j + 1;
+            }
+            j += 1;
+        }
+        runs.push((clusters[i..end].concat(), true));
+        i = end;
+    }
+
+    runs.iter()
+        .rev()
+        .map(|(run, ltr)| {
+            if *ltr {
+                run.clone()
+            } else {
+                run.chars().map(mirrored).collect()
+            }
+        })
+        .collect()
+}
+
+/// Returns true if `c` is a character of a right-to-left script.
+fn is_rtl(c: char) -> bool {
+    matches![c as u32, 0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF]
+}
+
+/// Returns true if `c` is a combining mark of the latin, hebrew or arabic
+/// scripts, a zero width joiner, or a variation selector.
+fn is_combining_mark(c: char) -> bool {
+    matches![
+        c as u32,
+        0x0300..=0x036F
+            | 0x0591..=0x05BD
+            | 0x05BF
+            | 0x05C1..=0x05C2
+            | 0x05C4..=0x05C5
+            | 0x05C7
+            | 0x0610..=0x061A
+            | 0x064B..=0x065F
+            | 0x0670
+            | 0x06D6..=0x06DC
+            | 0x06DF..=0x06E4
+            | 0x06E7..=0x06E8
+            | 0x06EA..=0x06ED
+            | 0x200D
+            | 0xFE00..=0xFE0F
+    ]
+}
+
+/// Returns the mirrored glyph of a bracket `c`, or `c` itself.
+fn mirrored(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        _ => c,
+    }
+}
+
+/// Helper function that returns the length in eighths of a cell of the bars
+/// of a histogram of the `values`, `len` cells long at most.
+///
+/// The bars are scaled so that the highest value fills the `len` cells.
+/// The negative and NaN values have empty bars, so do all of them if the
+/// highest value is 0.
+pub(crate) fn histogram_bars(values: &[f64], len: NcDimension) -> Vec<NcDimension> {
+    let max = values
+        .iter()
+        .cloned()
+        .filter(|v| *v > 0.0)
+        .fold(0.0, f64::max);
+    let eighths = len as f64 * 8.0;
+    values
+        .iter()
+        .map(|&value| {
+            if max > 0.0 && value > 0.0 {
+                (value / max * eighths).round() as NcDimension
+            } else {
+                0
+            }
+        })
+        .collect()
+}
+
+/// Helper function that adds the `rect`angle to the `damage` of a plane of
+/// `rows` * `cols`, clipped to it.
+///
+/// Empty rectangles and the ones already covered are ignored, and the ones
+/// covered by the new rectangle are removed.
+pub(crate) fn damage_add(
+    damage: &mut Vec<NcRect>,
+    rect: NcRect,
+    rows: NcDimension,
+    cols: NcDimension,
+) {
+    let y = rect.y.min(rows);
+    let x = rect.x.min(cols);
+    let rect = NcRect::new(y, x, rect.len_y.min(rows - y), rect.len_x.min(cols - x));
+    if rect.is_empty() || damage.iter().any(|r| r.contains(&rect)) {
+        return;
+    }
+    damage.retain(|r| !rect.contains(r));
+    damage.push(rect);
+}
+
+/// Helper function that returns the rectangle written on a plane of
+/// `rows` * `cols`, from the `start` position to the `end` position
+/// of the cursor, both as `(y, x)`.
+///
+/// A write spanning several rows covers them entirely. If the cursor ended
+/// up above the start, because the plane scrolled, it covers the whole plane.
+pub(crate) fn damage_written(
+    start: (NcDimension, NcDimension),
+    end: (NcDimension, NcDimension),
+    rows: NcDimension,
+    cols: NcDimension,
+) -> NcRect {
+    let ((start_y, start_x), (end_y, end_x)) = (start, end);
+    if end_y == start_y && end_x >= start_x {
+        NcRect::new(start_y, start_x, 1, end_x - start_x)
+    } else if end_y > start_y {
+        NcRect::new(start_y, 0, end_y - start_y + 1, cols)
+    } else {
+        NcRect::new(0, 0, rows, cols)
+    }
+}
+
+/// Helper function that returns the width of an [NcTable][crate::NcTable]
+/// with columns of text `widths` wide, including the padding and separators.
+pub(crate) fn table_width(widths: &[NcDimension]) -> NcDimension {
+    if widths.is_empty() {
+        return 0;
+    }
+    1 + widths.iter().map(|w| w + 3).sum::<NcDimension>()
+}
+
+/// Helper function that narrows the widest of the column `widths` of an
+/// [NcTable][crate::NcTable], one cell at a time, until the table fits in
+/// `cols` columns.
+///
+/// Returns None if it doesn't fit even with columns one cell wide.
+pub(crate) fn table_fit_widths(
+    widths: &[NcDimension],
+    cols: NcDimension,
+) -> Option<Vec<NcDimension>> {
+    let mut widths = widths.to_vec();
+    while table_width(&widths) > cols {
+        let widest = widths.iter().max().copied().unwrap_or(0);
+        if widest <= 1 {
+            return None;
+        }
+        if let Some(w) = widths.iter_mut().find(|w| **w == widest) {
+            *w -= 1;
+        }
+    }
+    Some(widths)
+}
@@ -3,10 +3,14 @@
 use core::ptr::{null, null_mut};
 
 use crate::{
-    cstring, error, error_ref, error_ref_mut, rstring, NcAlign, NcAlphaBits, NcBoxMask, NcCell,
-    NcChannel, NcChannelPair, NcColor, NcDimension, NcEgc, NcFadeCb, NcOffset, NcPaletteIndex,
-    NcPlane, NcPlaneOptions, NcResizeCb, NcResult, NcRgb, NcStyleMask, NcTime, Notcurses,
-    NCRESULT_ERR,
+    cstring, error, error_ref, error_ref_mut, rstring, NcAlign, NcAlphaBits, NcBackdropFit,
+    NcBlitter, NcBoxMask, NcBrailleCanvas, NcCell, NcChannel, NcChannelPair, NcChannelPairMethods,
+    NcColor, NcDimension, NcDockPosition, NcEgc, NcErrorKind, NcFadeCb, NcInput, NcOffset,
+    NcOwnedCell, NcPaletteIndex, NcPlane, NcPlaneGeom, NcPlaneOptions, NcPlaneSnapshot, NcResizeCb,
+    NcResult, NcRgb, NcScrollbarSide, NcSpriteSheet, NcStyleMask, NcStyleScope, NcTable,
+    NcTextPlacement, NcTime, NcVisual, NcVisualOptions, Notcurses, NCCELL_ALPHA_HIGHCONTRAST,
+    NCCELL_ALPHA_TRANSPARENT, NCRESULT_ERR, NCRESULT_OK, NCSTYLE_BOLD, NCSTYLE_ITALIC,
+    NCSTYLE_UNDERLINE,
 };
 
 /// # NcPlaneOptions Constructors
@@ -68,6 +72,25 @@ impl NcPlaneOptions {
     }
 }
 
+/// # NcPlaneGeom Constructors
+impl NcPlaneGeom {
+    /// New NcPlaneGeom.
+    pub const fn new(rows: NcDimension, cols: NcDimension) -> Self {
+        Self { rows, cols }
+    }
+
+    /// Returns the rows and columns as a tuple.
+    pub const fn yx(&self) -> (NcDimension, NcDimension) {
+        (self.rows, self.cols)
+    }
+}
+
+impl From<NcPlaneGeom> for (NcDimension, NcDimension) {
+    fn from(geom: NcPlaneGeom) -> Self {
+        geom.yx()
+    }
+}
+
 /// # NcPlane constructors and destructors
 impl NcPlane {
     /// New NcPlane.
@@ -94,7 +117,11 @@ impl NcPlane {
         nc: &mut Notcurses,
         options: NcPlaneOptions,
     ) -> NcResult<&'a mut NcPlane> {
-        error_ref_mut![unsafe { crate::ncpile_create(nc, &options) }]
+        let plane = unsafe { crate::ncpile_create(nc, &options) };
+        if !plane.is_null() {
+            crate::plane::set_pile_root(plane);
+        }
+        error_ref_mut![plane]
     }
 
     /// New NcPlane, bound to another NcPlane.
@@ -143,6 +170,7 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_destroy()][crate::ncplane_destroy].*
     pub fn destroy(&mut self) -> NcResult<()> {
+        crate::plane::forget_plane_state(self);
         error![unsafe { crate::ncplane_destroy(self) }]
     }
 }
@@ -217,6 +245,9 @@ impl NcPlane {
     /// Sets the current foreground [NcChannel] for this NcPlane.
     /// Returns the updated [NcChannelPair].
     ///
+    /// The whole `channel` is copied, including its default color flag,
+    /// while the background channel is preserved untouched.
+    ///
     /// *C style function: [ncplane_set_fchannel()][crate::ncplane_set_fchannel].*
     pub fn set_fchannel(&mut self, channel: NcChannel) -> NcChannelPair {
         unsafe { crate::ncplane_set_fchannel(self, channel) }
@@ -225,11 +256,38 @@ impl NcPlane {
     /// Sets the current background [NcChannel] for this NcPlane.
     /// Returns the updated [NcChannelPair].
     ///
+    /// The whole `channel` is copied, including its default color flag,
+    /// while the foreground channel is preserved untouched.
+    ///
     /// *C style function: [ncplane_set_bchannel()][crate::ncplane_set_bchannel].*
     pub fn set_bchannel(&mut self, channel: NcChannel) -> NcChannelPair {
         unsafe { crate::ncplane_set_bchannel(self, channel) }
     }
 
+    /// Sets the `channels` of each of the cells at the `coords` (`y`, `x`),
+    /// keeping content and attributes unchanged.
+    ///
+    /// The coordinates outside of the plane are skipped.
+    ///
+    /// Returns the number of cells set.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn color_cells(
+        &mut self,
+        coords: &[(NcDimension, NcDimension)],
+        channels: NcChannelPair,
+    ) -> NcResult<usize> {
+        let (rows, cols) = self.dim_yx();
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let mut count = 0;
+        for &(y, x) in coords.iter().filter(|&&(y, x)| y < rows && x < cols) {
+            self.cursor_move_yx(y, x)?;
+            count += self.stain(y, x, channels, channels, channels, channels)? as usize;
+        }
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        Ok(count)
+    }
+
     /// Sets the given [NcChannelPair]s throughout the specified region,
     /// keeping content and attributes unchanged.
     ///
@@ -250,6 +308,38 @@ impl NcPlane {
             unsafe { crate::ncplane_stain(self, y_stop as i32, x_stop as i32, ul, ur, ll, lr) };
         error![res, res as NcDimension];
     }
+
+    /// Sets the given [NcChannelPair]s throughout the region starting at
+    /// `y`, `x`, spanning `len_y` rows by `len_x` columns, keeping content and
+    /// attributes unchanged.
+    ///
+    /// The lengths follow the convention of the [region methods][NcPlane#regions],
+    /// where `-1` means through the edge of the plane.
+    ///
+    /// Returns the number of cells set.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn stain_region(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        len_y: NcOffset,
+        len_x: NcOffset,
+        ul: NcChannelPair,
+        ur: NcChannelPair,
+        ll: NcChannelPair,
+        lr: NcChannelPair,
+    ) -> NcResult<NcDimension> {
+        let (len_y, len_x) = crate::plane::region_lens(self.dim_yx(), y, x, len_y, len_x)?;
+        if len_y == 0 || len_x == 0 {
+            return Ok(0);
+        }
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        self.cursor_move_yx(y, x)?;
+        let res = self.stain(y + len_y - 1, x + len_x - 1, ul, ur, ll, lr);
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        res
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -319,6 +409,28 @@ impl NcPlane {
         }
     }
 
+    /// Sets the foreground RGB components for this NcPlane, clipping each one
+    /// to the range of an [NcColor] (0..=255), instead of wrapping it.
+    ///
+    /// Returns true if any component was out of range.
+    ///
+    /// *C style function: [ncplane_set_fg_rgb8_clipped()][crate::ncplane_set_fg_rgb8_clipped].*
+    pub fn set_fg_rgb8_clipped(&mut self, red: i32, green: i32, blue: i32) -> bool {
+        unsafe { crate::ncplane_set_fg_rgb8_clipped(self, red, green, blue) };
+        [red, green, blue].iter().any(|c| !(0..=255).contains(c))
+    }
+
+    /// Sets the background RGB components for this NcPlane, clipping each one
+    /// to the range of an [NcColor] (0..=255), instead of wrapping it.
+    ///
+    /// Returns true if any component was out of range.
+    ///
+    /// *C style function: [ncplane_set_bg_rgb8_clipped()][crate::ncplane_set_bg_rgb8_clipped].*
+    pub fn set_bg_rgb8_clipped(&mut self, red: i32, green: i32, blue: i32) -> bool {
+        unsafe { crate::ncplane_set_bg_rgb8_clipped(self, red, green, blue) };
+        [red, green, blue].iter().any(|c| !(0..=255).contains(c))
+    }
+
     /// Gets the foreground [NcRgb] from this NcPlane, shifted to LSBs.
     ///
     /// *C style function: [ncplane_fg_rgb()][crate::ncplane_fg_rgb].*
@@ -355,6 +467,40 @@ impl NcPlane {
         }
     }
 
+    /// Sets the foreground [NcRgb] for this NcPlane from a standard CSS
+    /// color `name`, matched case-insensitively.
+    ///
+    /// It's an error to pass an unknown name.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_fg_named(&mut self, name: &str) -> NcResult<()> {
+        self.set_fg_rgb(crate::channel::named_rgb(name).ok_or_else(|| {
+            crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                &format!["NcPlane.set_fg_named(): unknown color `{}`", name],
+            )
+        })?);
+        Ok(())
+    }
+
+    /// Sets the background [NcRgb] for this NcPlane from a standard CSS
+    /// color `name`, matched case-insensitively.
+    ///
+    /// It's an error to pass an unknown name.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_bg_named(&mut self, name: &str) -> NcResult<()> {
+        self.set_bg_rgb(crate::channel::named_rgb(name).ok_or_else(|| {
+            crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                &format!["NcPlane.set_bg_named(): unknown color `{}`", name],
+            )
+        })?);
+        Ok(())
+    }
+
     /// Is this NcPlane's foreground using the "default foreground color"?
     ///
     /// *C style function: [ncplane_fg_default_p()][crate::ncplane_fg_default_p].*
@@ -413,6 +559,35 @@ impl NcPlane {
         error![res, res as NcDimension]
     }
 
+    /// Sets the given style throughout the region starting at `y`, `x`,
+    /// spanning `len_y` rows by `len_x` columns, keeping content and channels
+    /// unchanged.
+    ///
+    /// The lengths follow the convention of the [region methods][NcPlane#regions],
+    /// where `-1` means through the edge of the plane.
+    ///
+    /// Returns the number of cells set.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn restyle_region(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        len_y: NcOffset,
+        len_x: NcOffset,
+        stylemask: NcStyleMask,
+    ) -> NcResult<NcDimension> {
+        let (len_y, len_x) = crate::plane::region_lens(self.dim_yx(), y, x, len_y, len_x)?;
+        if len_y == 0 || len_x == 0 {
+            return Ok(0);
+        }
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        self.cursor_move_yx(y, x)?;
+        let res = self.format(y + len_y - 1, x + len_x - 1, stylemask);
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        res
+    }
+
     /// Returns the current styling for this NcPlane.
     ///
     /// *C style function: [ncplane_styles()][crate::ncplane_styles].*
@@ -447,6 +622,17 @@ impl NcPlane {
         }
     }
 
+    /// Returns an [NcStyleScope] guard that restores the current channels
+    /// and styles of this NcPlane when dropped.
+    ///
+    /// The guard dereferences to this NcPlane, which stays borrowed while
+    /// the guard lives.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn style_scope(&mut self) -> NcStyleScope {
+        NcStyleScope::new(self)
+    }
+
     /// Sets this NcPlane's foreground [NcPaletteIndex].
     ///
     /// Also sets the foreground palette index bit, sets it foreground-opaque,
@@ -573,6 +759,75 @@ impl NcPlane {
         error![unsafe { crate::ncplane_set_base(self, &(egc as i8), stylemask as u32, channels) }]
     }
 
+    /// Renders the `visual` with the `blitter` as a backdrop for this NcPlane,
+    /// clipped or tiled according to `fit`, and returns the backdrop plane.
+    ///
+    /// Since the base cell of a plane is a single cell, the image goes into a
+    /// new plane of the same size, bound to this one at its origin, and placed
+    /// right below it. It's revealed wherever this plane is transparent.
+    ///
+    /// The background alpha of this plane is set to
+    /// [NCCELL_ALPHA_TRANSPARENT][crate::NCCELL_ALPHA_TRANSPARENT], so that
+    /// the image shows behind the text written afterwards.
+    ///
+    /// The backdrop moves along with this plane, but isn't resized with it,
+    /// and must be [destroy][NcPlane#method.destroy]ed when no longer needed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_base_visual<'a>(
+        &mut self,
+        nc: &mut Notcurses,
+        visual: &mut NcVisual,
+        blitter: NcBlitter,
+        fit: NcBackdropFit,
+    ) -> NcResult<&'a mut NcPlane> {
+        let options = NcVisualOptions::without_plane(0, 0, 0, 0, 0, 0, blitter, 0);
+        let tile = visual.render(nc, &options)?;
+        let (tile_rows, tile_cols) = tile.dim_yx();
+
+        let (rows, cols) = self.dim_yx();
+        let backdrop = match NcPlane::new_bound(self, 0, 0, rows, cols) {
+            Ok(backdrop) => backdrop,
+            Err(error) => {
+                let _ = tile.destroy();
+                return Err(error);
+            }
+        };
+        let mut res = Ok(());
+        for (y, x, len_y, len_x) in
+            crate::visual::backdrop_regions(tile_rows, tile_cols, rows, cols, fit)
+        {
+            res = backdrop.mergedown(tile, 0, 0, len_y, len_x, y, x);
+            if res.is_err() {
+                break;
+            }
+        }
+        let _ = tile.destroy();
+        if let Err(error) = res.and_then(|_| backdrop.move_below(self)) {
+            let _ = backdrop.destroy();
+            return Err(error);
+        }
+
+        self.set_bg_alpha(NCCELL_ALPHA_TRANSPARENT)?;
+        Ok(backdrop)
+    }
+
+    /// Blits the frame at `index` of the sprite `sheet` onto this NcPlane,
+    /// with its upper-left corner at the cell `y`, `x`.
+    ///
+    /// See [NcSpriteSheet.blit_frame()][NcSpriteSheet#method.blit_frame].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn blit_sprite(
+        &mut self,
+        sheet: &mut NcSpriteSheet,
+        index: usize,
+        y: NcDimension,
+        x: NcDimension,
+    ) -> NcResult<()> {
+        sheet.blit_frame(self, index, y, x)
+    }
+
     /// Sets this NcPlane's base NcCell.
     ///
     /// This function must be called with a zero `cell`.
@@ -589,6 +844,153 @@ impl NcPlane {
         error![unsafe { crate::ncplane_set_base_cell(self, cell) }]
     }
 
+    /// Sets this NcPlane's base cell from an [NcOwnedCell].
+    ///
+    /// See [set_base_cell()][NcPlane#method.set_base_cell].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_base_owned_cell(&mut self, cell: &NcOwnedCell) -> NcResult<()> {
+        let mut base = cell.to_plane_cell(self);
+        let res = self.set_base_cell(&base);
+        unsafe { crate::cell_release(self, &mut base) };
+        res
+    }
+
+    /// Fills every cell of this NcPlane with a space, colored with a gradient
+    /// from the four corner [NcChannelPair]s, and returns the number of cells
+    /// filled.
+    ///
+    /// This is a one-time fill that overwrites any existing content, so it
+    /// should be called before drawing the foreground.
+    ///
+    /// See [gradient][NcPlane#method.gradient] for the preconditions
+    /// on the channels.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_base_gradient(
+        &mut self,
+        ul: NcChannelPair,
+        ur: NcChannelPair,
+        ll: NcChannelPair,
+        lr: NcChannelPair,
+    ) -> NcResult<NcDimension> {
+        let (rows, cols) = self.dim_yx();
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        self.home();
+        let res = unsafe {
+            crate::ncplane_gradient(
+                self,
+                cstring![" "],
+                0,
+                ul,
+                ur,
+                ll,
+                lr,
+                rows as i32 - 1,
+                cols as i32 - 1,
+            )
+        };
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        error![res, res as NcDimension]
+    }
+
+    /// Sets the channels of the base [NcCell] of this NcPlane to the color
+    /// at position `step` of the `colors` list, wrapping around its end.
+    ///
+    /// Calling it with a successive `step` on every frame animates the
+    /// background, cheaply, since only the base cell is updated. The base cell
+    /// is only visible where nothing was written (or was erased), so sparse
+    /// foreground content gets drawn over the animated background.
+    ///
+    /// The [NcEgc] and [NcStyleMask] of the base cell are preserved.
+    /// It's an error to pass an empty list of `colors`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn animate_base(&mut self, colors: &[NcChannelPair], step: usize) -> NcResult<()> {
+        if colors.is_empty() {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.animate_base(): no colors",
+            ));
+        }
+        let mut cell = NcCell::new();
+        self.base(&mut cell)?;
+        cell.channels = colors[step % colors.len()];
+        let res = self.set_base_cell(&cell);
+        unsafe { crate::cell_release(self, &mut cell) };
+        res
+    }
+
+    /// Creates a flat RGBA image from the selected region of the NcPlane,
+    /// and returns its bytes, along with its height and width in pixels.
+    ///
+    /// Starts at `beg_y`, `beg_x` (which must lie on the plane), continuing
+    /// for `len_y` x `len_x` cells. The lengths follow the convention of the
+    /// [region methods][NcPlane#regions], where `-1` means through the edge
+    /// of the plane.
+    ///
+    /// The region can contain spaces, `▀`, `▄` and `█`, and the resolution
+    /// depends on the `blitter`:
+    /// - [NCBLIT_2x1][crate::NCBLIT_2x1] (and [NCBLIT_DEFAULT][crate::NCBLIT_DEFAULT])
+    ///   results in 2 pixels per cell, one above the other.
+    /// - [NCBLIT_1x1][crate::NCBLIT_1x1] results in 1 pixel per cell, with
+    ///   the color of the upper half of the cell.
+    ///
+    /// Other blitters, including the pixel ones, are not supported, and
+    /// return an [Unsupported][NcErrorKind::Unsupported] error.
+    ///
+    /// *(No equivalent C style function)*
+    //
+    // Doesn't use ncplane_rgba(), which swaps the halves of `▀` and `▄`.
+    pub fn as_rgba(
+        &self,
+        blitter: NcBlitter,
+        beg_y: NcDimension,
+        beg_x: NcDimension,
+        len_y: NcOffset,
+        len_x: NcOffset,
+    ) -> NcResult<(Vec<u8>, NcDimension, NcDimension)> {
+        let full_cells = match blitter {
+            crate::NCBLIT_1x1 => true,
+            crate::NCBLIT_2x1 | crate::NCBLIT_DEFAULT => false,
+            _ => {
+                return Err(crate::NcError::with_kind(
+                    NCRESULT_ERR,
+                    NcErrorKind::Unsupported,
+                    "NcPlane.as_rgba(): only the 1x1 and 2x1 blitters are supported",
+                ))
+            }
+        };
+        let (len_y, len_x) = crate::plane::region_lens(self.dim_yx(), beg_y, beg_x, len_y, len_x)?;
+        let height = if full_cells { len_y } else { len_y * 2 };
+
+        let mut rgba = vec![0; (height * len_x * 4) as usize];
+        let mut put_pixel = |py: NcDimension, x: NcDimension, rgb: NcRgb| {
+            let i = ((py * len_x + x) * 4) as usize;
+            rgba[i..i + 4].copy_from_slice(&[(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, 0xff]);
+        };
+        for y in 0..len_y {
+            for x in 0..len_x {
+                let (egc, _, channels) = crate::plane::plane_cell_at(self, beg_y + y, beg_x + x)?;
+                let (top, bottom) = crate::plane::cell_pixels(&egc, channels).ok_or_else(|| {
+                    crate::NcError::with_kind(
+                        NCRESULT_ERR,
+                        NcErrorKind::InvalidInput,
+                        "NcPlane.as_rgba(): the region contains glyphs not in the blitter",
+                    )
+                })?;
+                if full_cells {
+                    put_pixel(y, x, top);
+                } else {
+                    put_pixel(y * 2, x, top);
+                    put_pixel(y * 2 + 1, x, bottom);
+                }
+            }
+        }
+        Ok((rgba, height, len_x))
+    }
+
     /// Creates a flat string from the NcEgc's of the selected region of the
     /// NcPlane.
     ///
@@ -636,6 +1038,236 @@ impl NcPlane {
         unsafe { crate::ncplane_erase(self) }
     }
 
+    /// Erases every NcCell in the region starting at `y`, `x`, spanning
+    /// `len_y` rows by `len_x` columns, resetting their attributes to normal,
+    /// their colors to the default color, and leaving them undrawn.
+    ///
+    /// The lengths follow the convention of the [region methods][NcPlane#regions],
+    /// where `-1` means through the edge of the plane.
+    ///
+    /// Returns the number of cells erased.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn erase_region(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        len_y: NcOffset,
+        len_x: NcOffset,
+    ) -> NcResult<NcDimension> {
+        let (len_y, len_x) = crate::plane::region_lens(self.dim_yx(), y, x, len_y, len_x)?;
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let empty = NcCell::new();
+        let mut res = Ok(len_y * len_x);
+        'rows: for cy in y..y + len_y {
+            for cx in x..x + len_x {
+                if let Err(error) = self.putc_yx(cy, cx, &empty) {
+                    res = Err(error);
+                    break 'rows;
+                }
+            }
+        }
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        res
+    }
+
+    /// Fills the whole NcPlane with copies of the `egc`, styled with
+    /// `channels` and `stylemask`.
+    ///
+    /// See [fill_region][NcPlane#method.fill_region] for the details.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fill(
+        &mut self,
+        egc: &str,
+        channels: NcChannelPair,
+        stylemask: NcStyleMask,
+    ) -> NcResult<NcDimension> {
+        self.fill_region(0, 0, -1, -1, egc, channels, stylemask)
+    }
+
+    /// Fills the region starting at `y`, `x`, spanning `len_y` rows by
+    /// `len_x` columns, with copies of the `egc`, styled with `channels`
+    /// and `stylemask`.
+    ///
+    /// The `egc` must be a single grapheme cluster, one or two columns wide.
+    /// A wide glyph fills two columns per copy, so when the width of the
+    /// region is odd, the last column of each row is filled with a space,
+    /// styled the same.
+    ///
+    /// The lengths follow the convention of the [region methods][NcPlane#regions],
+    /// where `-1` means through the edge of the plane.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fill_region(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        len_y: NcOffset,
+        len_x: NcOffset,
+        egc: &str,
+        channels: NcChannelPair,
+        stylemask: NcStyleMask,
+    ) -> NcResult<NcDimension> {
+        let (len_y, len_x) = crate::plane::region_lens(self.dim_yx(), y, x, len_y, len_x)?;
+        let width = crate::plane::strwidth(egc)?;
+        if width == 0 || width > 2 {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.fill_region(): the EGC must be 1 or 2 columns wide",
+            ));
+        }
+
+        let mut cell = NcCell::new();
+        cell.stylemask = stylemask;
+        cell.channels = channels;
+        let loaded = unsafe { crate::cell_load(self, &mut cell, cstring![egc]) };
+        if loaded < 0 || loaded as usize != egc.len() {
+            unsafe { crate::cell_release(self, &mut cell) };
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.fill_region(): not a single EGC",
+            ));
+        }
+        let mut pad = NcCell::with_char7b(' ');
+        pad.stylemask = stylemask;
+        pad.channels = channels;
+
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let mut res = Ok(len_y * len_x);
+        'rows: for cy in y..y + len_y {
+            let mut cx = x;
+            while cx < x + len_x {
+                let fill = if x + len_x - cx >= width { &cell } else { &pad };
+                match self.putc_yx(cy, cx, fill) {
+                    Ok(cols) => cx += cols.max(1),
+                    Err(error) => {
+                        res = Err(error);
+                        break 'rows;
+                    }
+                }
+            }
+        }
+        unsafe { crate::cell_release(self, &mut cell) };
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        res
+    }
+
+    /// Applies the closure `f` to each [NcCell] in the region starting at
+    /// `y`, `x`, spanning `len_y` rows by `len_x` columns.
+    ///
+    /// Each NcCell is read, handed to `f` along with its coordinates for
+    /// mutation, and then written back.
+    ///
+    /// The region is traversed row by row, left to right. Wide glyphs spanning
+    /// two columns are visited once, at the coordinates of their left column.
+    ///
+    /// The region must lie within the plane. The cursor is not moved.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn map_region<F>(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        len_y: NcDimension,
+        len_x: NcDimension,
+        mut f: F,
+    ) -> NcResult<()>
+    where
+        F: FnMut(NcDimension, NcDimension, &mut NcCell),
+    {
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        for cy in y..y + len_y {
+            let mut cx = x;
+            while cx < x + len_x {
+                let mut cell = NcCell::new();
+                let res =
+                    unsafe { crate::ncplane_at_yx_cell(self, cy as i32, cx as i32, &mut cell) };
+                if res < 0 {
+                    return Err(crate::NcError::with_msg(res, "NcPlane.map_region()"));
+                }
+                if crate::cell_wide_right_p(&cell) {
+                    // the left column of this glyph is outside the region
+                    cx += 1;
+                    continue;
+                }
+                let wide = crate::cell_double_wide_p(&cell);
+                f(cy, cx, &mut cell);
+                let res = self.putc_yx(cy, cx, &cell);
+                unsafe { crate::cell_release(self, &mut cell) };
+                res?;
+                cx += if wide { 2 } else { 1 };
+            }
+        }
+        self.cursor_move_yx(cursor_y, cursor_x)
+    }
+
+    /// Returns an iterator over the [NcCell]s of the row `y`, along with
+    /// their column.
+    ///
+    /// Every column is yielded, including the blank ones, except the right
+    /// column of wide glyphs, which are yielded once, at their left column.
+    /// A row outside of the plane yields nothing.
+    ///
+    /// The NcCells are invalidated if the plane is destroyed, and ought to be
+    /// released with [cell_release()][crate::cell_release] if their
+    /// [NcEgc] is longer than 4 bytes.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn row_cells(
+        &mut self,
+        y: NcDimension,
+    ) -> impl Iterator<Item = (NcDimension, NcCell)> + '_ {
+        let cols = if y < self.dim_y() { self.dim_x() } else { 0 };
+        let mut x = 0;
+        core::iter::from_fn(move || {
+            while x < cols {
+                let mut cell = NcCell::new();
+                let res = unsafe { crate::ncplane_at_yx_cell(self, y as i32, x as i32, &mut cell) };
+                if res < 0 {
+                    return None;
+                }
+                if crate::cell_wide_right_p(&cell) {
+                    // the left column of this glyph is outside the row
+                    x += 1;
+                    continue;
+                }
+                let cx = x;
+                x += if crate::cell_double_wide_p(&cell) {
+                    2
+                } else {
+                    1
+                };
+                return Some((cx, cell));
+            }
+            None
+        })
+    }
+
+    /// Returns an iterator over the rows of this NcPlane, yielding for each
+    /// one its index and its [NcCell]s, as returned by
+    /// [row_cells()][NcPlane#method.row_cells].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn cell_rows(
+        &mut self,
+    ) -> impl Iterator<Item = (NcDimension, Vec<(NcDimension, NcCell)>)> + '_ {
+        let rows = self.dim_y();
+        let mut y = 0;
+        core::iter::from_fn(move || {
+            if y >= rows {
+                return None;
+            }
+            let row = (y, self.row_cells(y).collect());
+            y += 1;
+            Some(row)
+        })
+    }
+
     /// Replaces the NcCell at the specified coordinates with the provided NcCell,
     /// advancing the cursor by its width (but not past the end of the plane).
     ///
@@ -659,29 +1291,76 @@ impl NcPlane {
     /// The new NcCell must already be associated with the Plane.
     /// On success, returns the number of columns the cursor was advanced.
     ///
+    /// It's an error to write a wide NcCell when there's only one column left
+    /// before the edge of the plane, unless scrolling is enabled, in which case
+    /// it wraps to the next line.
+    ///
     /// *C style function: [ncplane_putc()][crate::ncplane_putc].*
     pub fn putc(&mut self, cell: &NcCell) -> NcResult<NcDimension> {
         let res = crate::ncplane_putc(self, cell);
         error![res, res as NcDimension]
     }
 
-    /// Calls [putchar_yx][NcPlane#method.putchar_yx] at the current cursor location.
+    /// Replaces the cell at `y`, `x` with the provided [NcOwnedCell],
+    /// loading its EGC into the egcpool of this NcPlane.
     ///
     /// On success, returns the number of columns the cursor was advanced.
     ///
+    /// *(No equivalent C style function)*
+    pub fn put_owned_cell(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        cell: &NcOwnedCell,
+    ) -> NcResult<NcDimension> {
+        let mut plane_cell = cell.to_plane_cell(self);
+        let res = self.putc_yx(y, x, &plane_cell);
+        unsafe { crate::cell_release(self, &mut plane_cell) };
+        res
+    }
+
+    /// Returns a copy of the cell at `y`, `x`, as an [NcOwnedCell].
+    ///
+    /// The right half of a wide glyph has an empty EGC.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn owned_cell_at(&self, y: NcDimension, x: NcDimension) -> NcResult<NcOwnedCell> {
+        let (egc, stylemask, channels) = crate::plane::plane_cell_at(self, y, x)?;
+        Ok(NcOwnedCell {
+            egc,
+            stylemask,
+            channels,
+        })
+    }
+
+    /// Calls [putchar_yx][NcPlane#method.putchar_yx] at the current cursor location.
+    ///
+    /// On success, returns the number of columns the cursor was advanced,
+    /// which is the width of `ch` (e.g. 2 for a wide glyph).
+    ///
+    /// As with [putc][NcPlane#method.putc], a wide `ch` that doesn't fit before
+    /// the edge of the plane is an error, unless scrolling is enabled.
+    ///
     /// *C style function: [ncplane_putchar()][crate::ncplane_putchar].*
     pub fn putchar(&mut self, ch: char) -> NcResult<NcDimension> {
         let res = crate::ncplane_putchar(self, ch);
         error![res, res as NcDimension]
     }
 
-    // TODO: call put_egc
-    // /// Replaces the [NcEgc][crate::NcEgc] to the current location, but retain
-    // /// the styling. The current styling of the plane will not be changed.
-    // pub fn putchar_stained(&mut self, y: NcDimension, x: NcDimension, ch: char) ->
-    // NcResult<NcDimension> {
-    //     error![crate::ncplane_putchar_stained(self, ch)]
-    // }
+    /// Replaces the [NcEgc][crate::NcEgc] at the current location with `ch`,
+    /// but retains the channels and styling of the destination cell.
+    /// The current styling of the plane will not be changed.
+    ///
+    /// Over a blank cell this writes `ch` with the cell's default channels.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *C style function: [ncplane_putegc_stained()][crate::ncplane_putegc_stained].*
+    pub fn putchar_stained(&mut self, ch: char) -> NcResult<NcDimension> {
+        let res =
+            unsafe { crate::ncplane_putegc_stained(self, cstring![ch.to_string()], null_mut()) };
+        error![res, res as NcDimension]
+    }
 
     /// Replaces the [NcEgc][crate::NcEgc], but retain the styling.
     /// The current styling of the plane will not be changed.
@@ -699,6 +1378,29 @@ impl NcPlane {
         error![res, res as NcDimension]
     }
 
+    /// Writes the `ch`aracter at `y`, `x`, styled with `channels` and
+    /// `stylemask`, instead of the current channels and styles of the plane,
+    /// which are left untouched.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putchar_yx_styled(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        ch: char,
+        channels: NcChannelPair,
+        stylemask: NcStyleMask,
+    ) -> NcResult<NcDimension> {
+        let mut cell = NcCell::with_char(ch, self);
+        cell.channels = channels;
+        cell.stylemask = stylemask;
+        let res = self.putc_yx(y, x, &cell);
+        unsafe { crate::cell_release(self, &mut cell) };
+        res
+    }
+
     /// Writes a series of [NcEgc][crate::NcEgc]s to the current location,
     /// using the current style.
     ///
@@ -708,13 +1410,64 @@ impl NcPlane {
     /// On error, a non-positive number is returned, indicating the number of
     /// columns which were written before the error.
     ///
+    /// If [autogrow][NcPlane#method.set_autogrow] is enabled, the plane grows
+    /// to fit the string instead.
+    ///
     /// *C style function: [ncplane_putstr()][crate::ncplane_putstr].*
     #[inline]
     pub fn putstr(&mut self, string: &str) -> NcResult<NcDimension> {
+        if crate::plane::plane_autogrow(self) {
+            let (y, x) = self.cursor_yx();
+            return self.putstr_autogrow(y, x, string);
+        }
         let res = crate::ncplane_putstr(self, string);
         error![res, res as NcDimension]
     }
 
+    /// Writes as much of a string as fits at the current cursor position,
+    /// one grapheme cluster at a time.
+    ///
+    /// Stops cleanly before the first grapheme cluster that doesn't fit before
+    /// the edge of the plane, e.g. a wide glyph when only one column is left,
+    /// and before a newline, so that the rest of the string can be written
+    /// later, on another row or plane. If scrolling is enabled, the plane
+    /// scrolls instead and the whole string is written.
+    ///
+    /// Returns the number of columns the cursor was advanced, and the number
+    /// of bytes of `string` consumed, which always lands on a grapheme
+    /// cluster boundary.
+    ///
+    /// *C style function: [ncplane_putegc_yx()][crate::ncplane_putegc_yx].*
+    pub fn putstr_partial(&mut self, string: &str) -> NcResult<(NcDimension, usize)> {
+        let cstring = std::ffi::CString::new(string).map_err(|_| {
+            crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.putstr_partial(): the string contains a NUL byte",
+            )
+        })?;
+        let bytes = cstring.as_bytes();
+        let (mut cols, mut consumed) = (0, 0);
+        while consumed < bytes.len() && bytes[consumed] != b'\n' {
+            let mut egc_bytes = 0;
+            let res = unsafe {
+                crate::ncplane_putegc_yx(
+                    self,
+                    -1,
+                    -1,
+                    cstring.as_ptr().add(consumed),
+                    &mut egc_bytes,
+                )
+            };
+            if res < 0 || egc_bytes <= 0 {
+                break;
+            }
+            cols += res as NcDimension;
+            consumed += egc_bytes as usize;
+        }
+        Ok((cols, consumed))
+    }
+
     /// Same as [putstr][NcPlane#method.putstr], but it also tries to move the
     /// cursor to the beginning of the next row.
     ///
@@ -728,6 +1481,12 @@ impl NcPlane {
     pub fn putstrln(&mut self, string: &str) -> NcResult<NcDimension> {
         let cols = self.putstr(string)?;
         let (y, _x) = self.cursor_yx();
+        if crate::plane::plane_autogrow(self) {
+            let (rows, cols) = self.dim_yx();
+            if y + 1 >= rows {
+                self.resize_simple(y + 2, cols)?;
+            }
+        }
         self.cursor_move_yx(y + 1, 0)?;
         return Ok(cols);
     }
@@ -748,6 +1507,202 @@ impl NcPlane {
         error![res, res as NcDimension]
     }
 
+    /// Writes a series of [NcEgc][crate::NcEgc]s to the current location,
+    /// retaining the channels and styling of each destination cell, and
+    /// adding `stylemask` on top of the existing styles.
+    ///
+    /// This is useful for highlighting (e.g. in bold) part of some text
+    /// that was already written with its own colors.
+    ///
+    /// The written cells must fit in the current row.
+    /// The current styling of the plane will not be changed.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_stained_styled(
+        &mut self,
+        string: &str,
+        stylemask: NcStyleMask,
+    ) -> NcResult<NcDimension> {
+        let (y, x) = self.cursor_yx();
+        let cols = self.putstr_stained(string)?;
+        let (end_y, end_x) = self.cursor_yx();
+        if end_y != y {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::BoundsError,
+                "NcPlane.putstr_stained_styled(): the string doesn't fit in the row",
+            ));
+        }
+        for col in x..x + cols {
+            let (mut styles, mut channels) = (0, 0);
+            self.at_yx(y, col, &mut styles, &mut channels)?;
+            self.cursor_move_yx(y, col)?;
+            self.format(y, col, styles | stylemask)?;
+        }
+        self.cursor_move_yx(end_y, end_x)?;
+        Ok(cols)
+    }
+
+    /// Writes a series of [NcEgc][crate::NcEgc]s to the current location,
+    /// with a foreground that contrasts with the background of each cell.
+    ///
+    /// It uses [NCCELL_ALPHA_HIGHCONTRAST][crate::NCCELL_ALPHA_HIGHCONTRAST]
+    /// for the foreground, so that notcurses computes the contrasting color
+    /// per cell, which works over gradients. The plane's channels are restored
+    /// afterwards.
+    ///
+    /// See also [NcChannel::contrasting()][crate::NcChannelMethods#tymethod.contrasting]
+    /// for computing a single contrasting color.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_contrasting(&mut self, string: &str) -> NcResult<NcDimension> {
+        let channels = self.channels();
+        self.set_fg_alpha(NCCELL_ALPHA_HIGHCONTRAST)?;
+        let res = self.putstr(string);
+        self.set_channels(channels);
+        res
+    }
+
+    /// Writes a string containing ANSI escape sequences to the current
+    /// location, translating the SGR sequences into channels and styles.
+    ///
+    /// It supports the bold, dim, italic, underline, blink, reverse, invisible
+    /// and struck styles, the 16 colors, and the 256 colors and truecolor
+    /// forms, for both the foreground and the background. The 16 and 256
+    /// colors are set as palette indices.
+    ///
+    /// A reset (`0`, `39` or `49`) goes back to the channels and styles the
+    /// plane had when called, which are restored at the end. Any other escape
+    /// sequence is skipped, as well as a truncated one at the end.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putansi(&mut self, string: &str) -> NcResult<NcDimension> {
+        let (channels, stylemask) = (self.channels(), self.styles());
+        let mut res = Ok(0);
+        for token in crate::plane::ansi_tokens(string) {
+            match token {
+                crate::plane::AnsiToken::Text(text) => match self.putstr(text) {
+                    Ok(cols) => res = res.map(|total| total + cols),
+                    Err(error) => {
+                        res = Err(error);
+                        break;
+                    }
+                },
+                crate::plane::AnsiToken::Sgr(params) => {
+                    crate::plane::apply_sgr(self, &params, channels, stylemask)
+                }
+            }
+        }
+        self.set_channels(channels);
+        self.set_styles(stylemask);
+        res
+    }
+
+    /// Writes a string to the current location, coloring and styling each of
+    /// its whitespace-delimited tokens according to `classify`.
+    ///
+    /// `classify` is called for each token, and returns the [NcChannelPair]
+    /// and [NcStyleMask] to write it with. The whitespace between the tokens
+    /// is preserved exactly, and written with the channels and styles the
+    /// plane had when called, which are restored at the end.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_tokens(
+        &mut self,
+        string: &str,
+        classify: impl Fn(&str) -> (NcChannelPair, NcStyleMask),
+    ) -> NcResult<NcDimension> {
+        let (channels, stylemask) = (self.channels(), self.styles());
+        let mut res = Ok(0);
+        for (text, is_token) in crate::plane::whitespace_tokens(string) {
+            if is_token {
+                let (token_channels, token_stylemask) = classify(text);
+                self.set_channels(token_channels);
+                self.set_styles(token_stylemask);
+            } else {
+                self.set_channels(channels);
+                self.set_styles(stylemask);
+            }
+            match self.putstr(text) {
+                Ok(cols) => res = res.map(|total| total + cols),
+                Err(error) => {
+                    res = Err(error);
+                    break;
+                }
+            }
+        }
+        self.set_channels(channels);
+        self.set_styles(stylemask);
+        res
+    }
+
+    /// Writes a string containing markup tags to the current location,
+    /// translating the tags into channels and styles.
+    ///
+    /// The supported tags are `<b>`, `<i>` and `<u>` for the bold, italic and
+    /// underline styles, and `<fg=rrggbb>` and `<bg=rrggbb>` for the foreground
+    /// and background colors, in hexadecimal, optionally preceded by a `#`.
+    /// They are closed by `</b>`, `</i>`, `</u>`, `</fg>` and `</bg>`
+    /// respectively, which restore the channels and styles in effect when the
+    /// tag was opened, so tags can be nested.
+    ///
+    /// Anything between `<` and `>` that isn't a known tag, like `<a>` or a
+    /// lone `<`, is written as literal text.
+    ///
+    /// Returns an [InvalidInput][crate::NcErrorKind::InvalidInput] error,
+    /// without writing anything, for a color that isn't valid, a closing tag
+    /// not matching the last tag opened, or a tag left open at the end.
+    ///
+    /// The channels and styles of the plane are restored at the end.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putmarkup(&mut self, string: &str) -> NcResult<NcDimension> {
+        let tokens = crate::plane::markup_tokens(string)?;
+        let (channels, stylemask) = (self.channels(), self.styles());
+        let mut saved = Vec::new();
+        let mut res = Ok(0);
+        for token in tokens {
+            match token {
+                crate::plane::MarkupToken::Text(text) => match self.putstr(text) {
+                    Ok(cols) => res = res.map(|total| total + cols),
+                    Err(error) => {
+                        res = Err(error);
+                        break;
+                    }
+                },
+                crate::plane::MarkupToken::Open(tag) => {
+                    saved.push((self.channels(), self.styles()));
+                    match tag {
+                        crate::plane::MarkupTag::Bold => self.on_styles(NCSTYLE_BOLD),
+                        crate::plane::MarkupTag::Italic => self.on_styles(NCSTYLE_ITALIC),
+                        crate::plane::MarkupTag::Underline => self.on_styles(NCSTYLE_UNDERLINE),
+                        crate::plane::MarkupTag::Fg(rgb) => self.set_fg_rgb(rgb),
+                        crate::plane::MarkupTag::Bg(rgb) => self.set_bg_rgb(rgb),
+                    }
+                }
+                crate::plane::MarkupToken::Close => {
+                    if let Some((channels, stylemask)) = saved.pop() {
+                        self.set_channels(channels);
+                        self.set_styles(stylemask);
+                    }
+                }
+            }
+        }
+        self.set_channels(channels);
+        self.set_styles(stylemask);
+        res
+    }
+
     /// Write a string, which is a series of [NcEgc][crate::NcEgc]s, to the
     /// current location, using the current style.
     ///
@@ -759,6 +1714,9 @@ impl NcPlane {
     /// On error, a non-positive number is returned, indicating the number of
     /// columns which were written before the error.
     ///
+    /// If [autogrow][NcPlane#method.set_autogrow] is enabled, the plane grows
+    /// to fit the string instead.
+    ///
     /// *C style function: [ncplane_putstr_yx()][crate::ncplane_putstr_yx].*
     pub fn putstr_yx(
         &mut self,
@@ -766,9 +1724,164 @@ impl NcPlane {
         x: NcDimension,
         string: &str,
     ) -> NcResult<NcDimension> {
+        if crate::plane::plane_autogrow(self) {
+            return self.putstr_autogrow(y, x, string);
+        }
         let res = unsafe { crate::ncplane_putstr_yx(self, y as i32, x as i32, cstring![string]) };
         error![res, res as NcDimension]
     }
+
+    /// Grows this NcPlane to fit the `string` written at `y`, `x`, wrapping
+    /// at its right edge and at each newline, and writes it.
+    fn putstr_autogrow(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        string: &str,
+    ) -> NcResult<NcDimension> {
+        let (rows, cols) = self.dim_yx();
+        let needed = y + 1 + crate::plane::wrapped_rows(string, x, cols)?;
+        if needed > rows {
+            self.resize_simple(needed, cols)?;
+        }
+        // the plane is tall enough, so scrolling only wraps the lines.
+        // Not using set_scrolling(), which would disable autogrow
+        let scrolling = unsafe { crate::ncplane_set_scrolling(self, true) };
+        let res = unsafe { crate::ncplane_putstr_yx(self, y as i32, x as i32, cstring![string]) };
+        unsafe { crate::ncplane_set_scrolling(self, scrolling) };
+        error![res, res as NcDimension]
+    }
+
+    /// Writes a string in the row `y`, aligned according to `align`,
+    /// and returns where it was placed as an [NcTextPlacement].
+    ///
+    /// The width of the string is computed with [ncstrwidth][crate::ncstrwidth],
+    /// so wide characters are accounted for.
+    ///
+    /// A string wider than the plane is clipped at its end, so that it fills
+    /// the whole row, independently of the alignment.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_aligned_at(
+        &mut self,
+        y: NcDimension,
+        align: NcAlign,
+        string: &str,
+    ) -> NcResult<NcTextPlacement> {
+        let cols = self.dim_x();
+        let mut string = string;
+        let mut width = crate::plane::strwidth(string)?;
+        let clipped = width > cols;
+        if clipped {
+            width = 0;
+            let mut end = 0;
+            for (i, c) in string.char_indices() {
+                let cwidth = crate::plane::strwidth(&c.to_string())?;
+                if width + cwidth > cols {
+                    break;
+                }
+                width += cwidth;
+                end = i + c.len_utf8();
+            }
+            string = &string[..end];
+        }
+
+        let start = match align {
+            crate::NCALIGN_LEFT => 0,
+            crate::NCALIGN_CENTER => (cols - width) / 2,
+            crate::NCALIGN_RIGHT => cols - width,
+            _ => {
+                return Err(crate::NcError::with_kind(
+                    NCRESULT_ERR,
+                    NcErrorKind::InvalidInput,
+                    "NcPlane.putstr_aligned_at(): invalid alignment",
+                ))
+            }
+        };
+        self.putstr_yx(y, start, string)?;
+        Ok(NcTextPlacement {
+            start,
+            end: start + width,
+            cols: width,
+            clipped,
+        })
+    }
+
+    /// Writes a single line `string` centered in this NcPlane, both vertically
+    /// and horizontally, with `channels` and `stylemask`.
+    ///
+    /// The row is `(rows - 1) / 2`, and the string is aligned as with
+    /// [putstr_aligned_at()][NcPlane#method.putstr_aligned_at], so wide
+    /// characters are accounted for, and a string wider than the plane is
+    /// clipped to fill the whole row.
+    ///
+    /// The channels and styles of the plane are left unchanged.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn center_text(
+        &mut self,
+        string: &str,
+        channels: NcChannelPair,
+        stylemask: NcStyleMask,
+    ) -> NcResult<()> {
+        let mut scope = self.style_scope();
+        scope.set_channels(channels);
+        scope.set_styles(stylemask);
+        let y = scope.dim_y().saturating_sub(1) / 2;
+        scope.putstr_aligned_at(y, crate::NCALIGN_CENTER, string)?;
+        Ok(())
+    }
+
+    /// Writes a right-to-left string (e.g. hebrew or arabic) at the current
+    /// cursor position, reordered to be displayed correctly.
+    ///
+    /// This is a basic reordering, not the full unicode bidirectional
+    /// algorithm: each line is handled as a right-to-left paragraph, whose
+    /// characters are written in reverse order, with their brackets mirrored.
+    /// The embedded runs of left-to-right characters (e.g. latin words or
+    /// numbers) keep their order, but a mostly left-to-right string with
+    /// right-to-left fragments won't be displayed as expected. The arabic
+    /// letters are not shaped.
+    ///
+    /// Returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_rtl(&mut self, string: &str) -> NcResult<NcDimension> {
+        self.putstr(&crate::plane::rtl_visual_order(string))
+    }
+
+    /// Draws the `table` at the origin of this NcPlane, with the `channels`,
+    /// and returns the dimensions drawn, as `(rows, cols)`.
+    ///
+    /// See [NcTable.render()][NcTable#method.render].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn draw_table(
+        &mut self,
+        table: &NcTable,
+        channels: NcChannelPair,
+    ) -> NcResult<(NcDimension, NcDimension)> {
+        table.render(self, channels)
+    }
+
+    /// Writes a string at the current cursor position, truncated with an
+    /// ellipsis (`…`) if it's wider than `cols` columns.
+    ///
+    /// The ellipsis takes one column of the budget, and the truncation happens
+    /// at a character boundary, so the result can be one column narrower than
+    /// `cols` when a wide character doesn't fit. Nothing is written when
+    /// `cols` is 0, and only the ellipsis when it's 1 and the string doesn't fit.
+    ///
+    /// Returns the number of columns written.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_ellipsis(&mut self, cols: NcDimension, string: &str) -> NcResult<NcDimension> {
+        let (string, width) = crate::plane::ellipsized(string, cols)?;
+        if width > 0 {
+            self.putstr(&string)?;
+        }
+        Ok(width)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -790,6 +1903,88 @@ impl NcPlane {
         unsafe { &mut *crate::ncplane_dup(self, null_mut()) }
     }
 
+    /// Captures the cells, the base cell, the cursor position, the channels
+    /// and the styles of this NcPlane, to [restore][NcPlane#method.restore]
+    /// them later.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn snapshot(&mut self) -> NcResult<NcPlaneSnapshot> {
+        let (rows, cols) = self.dim_yx();
+        let mut cells = Vec::with_capacity((rows * cols) as usize);
+        for y in 0..rows {
+            for x in 0..cols {
+                cells.push(crate::plane::plane_cell_at(self, y, x)?);
+            }
+        }
+
+        let mut base = NcCell::new();
+        self.base(&mut base)?;
+        let egc = rstring![crate::cell_extended_gcluster(self, &base)].to_string();
+        unsafe { crate::cell_release(self, &mut base) };
+
+        Ok(NcPlaneSnapshot {
+            rows,
+            cols,
+            cells,
+            base: (egc, base.stylemask, base.channels),
+            cursor: self.cursor_yx(),
+            channels: self.channels(),
+            stylemask: self.styles(),
+        })
+    }
+
+    /// Restores the state captured in a `snapshot` of this NcPlane,
+    /// or of another plane of the same size.
+    ///
+    /// Returns an error, without modifying the plane, if its size is
+    /// different from the one of the snapshot.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn restore(&mut self, snapshot: &NcPlaneSnapshot) -> NcResult<()> {
+        if self.dim_yx() != snapshot.dim_yx() {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::BoundsError,
+                "NcPlane.restore(): the snapshot has a different size",
+            ));
+        }
+
+        let (egc, stylemask, channels) = snapshot.base();
+        let mut base = NcCell::with_str(self, egc);
+        base.stylemask = stylemask;
+        base.channels = channels;
+        let res = self.set_base_cell(&base);
+        unsafe { crate::cell_release(self, &mut base) };
+        res?;
+        self.erase();
+
+        let (rows, cols) = snapshot.dim_yx();
+        for y in 0..rows {
+            let mut x = 0;
+            while x < cols {
+                let (egc, stylemask, channels) = snapshot.cell_at(y, x).unwrap_or_default();
+                // erased cells are already empty
+                if egc.is_empty() && stylemask == 0 && channels == 0 {
+                    x += 1;
+                    continue;
+                }
+                let mut cell = NcCell::with_str(self, egc);
+                cell.stylemask = stylemask;
+                cell.channels = channels;
+                let res = self.putc_yx(y, x, &cell);
+                unsafe { crate::cell_release(self, &mut cell) };
+                // skips the right half of a wide glyph
+                x += res?.max(1);
+            }
+        }
+
+        let (cursor_y, cursor_x) = snapshot.cursor_yx();
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        self.set_channels(snapshot.channels);
+        self.set_styles(snapshot.stylemask);
+        Ok(())
+    }
+
     /// Returns the topmost NcPlane of the current pile.
     ///
     /// *C style function: [ncpile_top()][crate::ncpile_top].*
@@ -834,6 +2029,22 @@ impl NcPlane {
         error![unsafe { crate::ncplane_move_yx(self, y, x) }]
     }
 
+    /// Moves this NcPlane to the absolute `y`, `x` terminal coordinates,
+    /// independently of the plane to which it is bound.
+    ///
+    /// The coordinates are translated relative to the parent plane, taking
+    /// into account the whole chain of planes up to the root.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn move_yx_abs(&mut self, y: NcOffset, x: NcOffset) -> NcResult<()> {
+        let (mut rel_y, mut rel_x) = (y, x);
+        let parent = unsafe { crate::ncplane_parent_const(self) };
+        if !parent.is_null() && parent != self as *const NcPlane {
+            unsafe { crate::ncplane_translate_abs(parent, &mut rel_y, &mut rel_x) };
+        }
+        self.move_yx(rel_y, rel_x)
+    }
+
     /// Returns the NcPlane above this one, or None if already at the top.
     ///
     /// *C style function: [ncplane_above()][crate::ncplane_above].*
@@ -913,12 +2124,76 @@ impl NcPlane {
     /// See [`mergedown`][NcPlane#method.mergedown]
     /// for more information.
     ///
-    /// *C style function: [ncplane_mergedown_simple()][crate::ncplane_mergedown_simple].*
-    //
-    // TODO: maybe create a reversed method, and/or an associated function,
-    // for `mergedown` too.
-    pub fn mergedown_simple(&mut self, source: &NcPlane) -> NcResult<()> {
-        error![unsafe { crate::ncplane_mergedown_simple(source, self) }]
+    /// *C style function: [ncplane_mergedown_simple()][crate::ncplane_mergedown_simple].*
+    //
+    // TODO: maybe create a reversed method, and/or an associated function,
+    // for `mergedown` too.
+    pub fn mergedown_simple(&mut self, source: &NcPlane) -> NcResult<()> {
+        error![unsafe { crate::ncplane_mergedown_simple(source, self) }]
+    }
+
+    /// Composites the `layers` onto the `dst` NcPlane, from the bottom to the
+    /// top, flattening them into a single plane.
+    ///
+    /// Each layer is merged down according to its absolute position, so only
+    /// the region overlapping `dst` is composited, and the alpha of each cell
+    /// is respected, e.g. the transparent cells of a layer let the lower ones
+    /// show through. The current contents of `dst` are the bottom-most layer.
+    ///
+    /// Returns an error if `dst` is one of the `layers`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn composite(dst: &mut NcPlane, layers: &[&NcPlane]) -> NcResult<()> {
+        if layers.iter().any(|&layer| core::ptr::eq(layer, dst)) {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.composite(): the destination can't be a layer",
+            ));
+        }
+        let (dst_y, dst_x) = dst.dim_yx();
+        for layer in layers {
+            let (layer_y, layer_x) = layer.dim_yx();
+            // the region is only validated, the whole layer is merged down
+            // according to its position
+            dst.mergedown(layer, 0, 0, layer_y.min(dst_y), layer_x.min(dst_x), 0, 0)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the name set with [set_name()][NcPlane#method.set_name], if any.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn name(&self) -> Option<String> {
+        crate::plane::plane_name(self)
+    }
+
+    /// Sets the name of this NcPlane, used to identify it in the output of
+    /// [pile_tree_string()][NcPlane#method.pile_tree_string].
+    ///
+    /// The name is kept on the Rust side, since the C API only accepts a name
+    /// at creation, in [NcPlaneOptions], and doesn't allow to retrieve it.
+    /// It's forgotten when the plane is destroyed, including by its widget
+    /// or by [Notcurses.stop()][Notcurses#method.stop].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_name(&mut self, name: &str) {
+        crate::plane::set_plane_name(self, Some(name));
+    }
+
+    /// Returns an indented tree of the planes in the pile of this NcPlane,
+    /// with their names, dimensions and positions, for debugging.
+    ///
+    /// Bound planes are nested under their parents, and siblings are listed
+    /// from the top to the bottom. A plane without a
+    /// [name][NcPlane#method.set_name] is shown as `<unnamed #N>`, `N` being
+    /// its position in the z-axis of the pile, starting with 0 at the top.
+    ///
+    /// See also [Notcurses.plane_tree_string()][Notcurses#method.plane_tree_string].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn pile_tree_string(&mut self) -> String {
+        crate::plane::pile_tree_string(self)
     }
 
     /// Gets the parent to which this NcPlane is bound, if any.
@@ -958,7 +2233,10 @@ impl NcPlane {
     /// Like [`reparent`][NcPlane#method.reparent], except any bound
     /// planes comes along with this NcPlane to its new destination.
     ///
-    /// Their z-order is maintained.
+    /// Their z-order is maintained. But the absolute coordinates of the
+    /// bound planes aren't updated, so they don't keep their positions
+    /// relative to this NcPlane, and when moved to another pile they keep
+    /// referring to the old one. See [move_to_pile()][NcPlane#method.move_to_pile].
     ///
     /// *C style function: [ncplane_reparent_family()][crate::ncplane_reparent_family].*
     //
@@ -967,6 +2245,85 @@ impl NcPlane {
         error_ref_mut![unsafe { crate::ncplane_reparent_family(self, newparent) }]
     }
 
+    /// Moves this NcPlane, along with the planes bound to it, to the pile of
+    /// `target_root`, binding it to that plane.
+    ///
+    /// The moved planes stop being rendered with their old pile, and are
+    /// rendered with the new one instead. E.g. a scene can be drawn in an
+    /// off-screen pile, created with [new()][NcPlane#method.new], and then
+    /// moved to the standard pile by passing the standard plane.
+    ///
+    /// Unlike [reparent_family()][NcPlane#method.reparent_family], the planes
+    /// are moved one at a time, so that all of them belong to the new pile,
+    /// and each one keeps its position relative to its parent. Their z-order
+    /// is maintained, right above `target_root`.
+    ///
+    /// It's an error to move the standard plane, or to move a plane into its
+    /// own family.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn move_to_pile(&mut self, target_root: &mut NcPlane) -> NcResult<()> {
+        let stdplane = unsafe { crate::notcurses_stdplane_const(self.notcurses_const()?) };
+        if core::ptr::eq(stdplane, self) {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.move_to_pile(): the standard plane can't be moved",
+            ));
+        }
+        crate::plane::reparent_family_keeping_layout(self, target_root)
+    }
+
+    /// Reparents this NcPlane, along with the planes bound to it, to `parent`,
+    /// and places them in the z-axis according to `position`, keeping their
+    /// relative z-order.
+    ///
+    /// This NcPlane keeps its absolute position, clamped so that it lies within
+    /// `parent`, if it fits, so it doesn't jump when docked, and the planes
+    /// bound to it keep their positions relative to their parents. Since no
+    /// rendering happens in between the steps, the intermediate states are
+    /// never shown.
+    ///
+    /// `parent` may belong to another pile, in which case the planes are moved
+    /// to that pile. The standard plane can't be docked.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn dock_into(&mut self, parent: &mut NcPlane, position: NcDockPosition) -> NcResult<()> {
+        let (abs_y, abs_x) = crate::plane::plane_abs_yx(self);
+        crate::plane::reparent_family_keeping_layout(self, parent)?;
+
+        let (parent_y, parent_x) = crate::plane::plane_abs_yx(parent);
+        let clamp = |abs: NcOffset, origin: NcOffset, len: NcDimension, parent_len: NcDimension| {
+            let max = parent_len as NcOffset - len as NcOffset;
+            (abs - origin).min(max).max(0)
+        };
+        let ((rows, cols), (parent_rows, parent_cols)) = (self.dim_yx(), parent.dim_yx());
+        self.move_yx(
+            clamp(abs_y, parent_y, rows, parent_rows),
+            clamp(abs_x, parent_x, cols, parent_cols),
+        )?;
+
+        // from the bottom of the family to its top
+        let family = crate::plane::plane_family(self);
+        let mut target: *mut NcPlane = parent;
+        for &plane in family.iter().rev() {
+            match position {
+                NcDockPosition::Top => unsafe { crate::ncplane_move_top(plane) },
+                NcDockPosition::Bottom => {
+                    // moving a plane to where it already is, is an error
+                    if unsafe { crate::ncplane_below(plane) } != target {
+                        let res = unsafe { crate::ncplane_move_above(plane, target) };
+                        if res < NCRESULT_OK {
+                            return Err(crate::NcError::with_msg(res, "NcPlane.dock_into()"));
+                        }
+                    }
+                    target = plane;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Makes the physical screen match the last rendered frame from the pile of
     /// which this NcPlane is a part.
     ///
@@ -994,6 +2351,38 @@ impl NcPlane {
         error_ref_mut![unsafe { crate::ncplane_notcurses(self) }]
     }
 
+    /// Chooses the [NcBlitter] that shows the most pixels of an image of
+    /// `img_px_w` * `img_px_h` pixels, when fitted into a region of
+    /// `target_rows` * `target_cols` cells, among the ones supported by the
+    /// terminal of this NcPlane.
+    ///
+    /// Pixel graphics ([NCBLIT_SIXEL][crate::NCBLIT_SIXEL]) are preferred when
+    /// available. Otherwise, more cell subdivisions are only chosen when they
+    /// show more of the image, e.g. an image of extreme aspect ratio that
+    /// fits in a single row would only gain from vertical subdivisions, while
+    /// an image smaller than the region is best drawn with
+    /// [NCBLIT_1x1][crate::NCBLIT_1x1].
+    ///
+    /// Blitters that sacrifice colors, like braille, are never chosen.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn choose_blitter_for(
+        &self,
+        target_rows: NcDimension,
+        target_cols: NcDimension,
+        img_px_w: NcDimension,
+        img_px_h: NcDimension,
+    ) -> NcResult<NcBlitter> {
+        let nc = self.notcurses_const()?;
+        Ok(crate::plane::blitter_for_geom(
+            nc.cansixel(),
+            nc.cansextant(),
+            nc.canutf8(),
+            (target_rows, target_cols),
+            (img_px_h, img_px_w),
+        ))
+    }
+
     /// Gets an immutable reference to the [Notcurses] context of this NcPlane.
     ///
     /// *C style function: [ncplane_notcurses_const()][crate::ncplane_notcurses_const].*
@@ -1049,7 +2438,12 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_cursor_move_yx()][crate::ncplane_cursor_move_yx].*
     pub fn cursor_move_yx(&mut self, y: NcDimension, x: NcDimension) -> NcResult<()> {
-        error![unsafe { crate::ncplane_cursor_move_yx(self, y as i32, x as i32) }]
+        error![
+            unsafe { crate::ncplane_cursor_move_yx(self, y as i32, x as i32) },
+            (),
+            "NcPlane.cursor_move_yx()",
+            NcErrorKind::BoundsError
+        ]
     }
 
     /// Moves the cursor to the specified row within this NcPlane.
@@ -1119,9 +2513,16 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_dim_yx()][crate::ncplane_dim_yx].*
     pub fn dim_yx(&self) -> (NcDimension, NcDimension) {
+        self.geom().into()
+    }
+
+    /// Returns the dimensions of this NcPlane, as an [NcPlaneGeom].
+    ///
+    /// *C style function: [ncplane_dim_yx()][crate::ncplane_dim_yx].*
+    pub fn geom(&self) -> NcPlaneGeom {
         let (mut y, mut x) = (0, 0);
         unsafe { crate::ncplane_dim_yx(self, &mut y, &mut x) };
-        (y as NcDimension, x as NcDimension)
+        NcPlaneGeom::new(y as NcDimension, x as NcDimension)
     }
 
     /// Return the rows of this NcPlane.
@@ -1206,6 +2607,16 @@ impl NcPlane {
         }]
     }
 
+    /// Resizes this NcPlane to the visual region's size.
+    ///
+    /// Suitable for use as an [NcResizeCb], see
+    /// [set_resize_maximize][NcPlane#method.set_resize_maximize].
+    ///
+    /// *C style function: [ncplane_resize_maximize()][crate::ncplane_resize_maximize].*
+    pub fn resize_maximize(&mut self) -> NcResult<()> {
+        error![unsafe { crate::ncplane_resize_maximize(self) }]
+    }
+
     /// Realigns this NcPlane against its parent, using the alignment specified
     /// at creation time.
     ///
@@ -1247,6 +2658,32 @@ impl NcPlane {
         unsafe { crate::ncplane_set_resizecb(self, crate::ncresizecb_to_c(resizecb)) }
     }
 
+    /// Installs [resize_maximize][NcPlane#method.resize_maximize] as the
+    /// resize callback, so that this NcPlane always fills the visual region
+    /// when the terminal is resized.
+    ///
+    /// To combine this behavior with custom logic, call
+    /// `resize_maximize()` from your own callback
+    /// in [set_resizecb][NcPlane#method.set_resizecb] instead.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_resize_maximize(&mut self) {
+        unsafe { crate::ncplane_set_resizecb(self, Some(crate::ncplane_resize_maximize)) }
+    }
+
+    /// Installs [resize_realign][NcPlane#method.resize_realign] as the
+    /// resize callback, so that this NcPlane keeps its alignment against its
+    /// parent when the terminal is resized.
+    ///
+    /// To combine this behavior with custom logic, call
+    /// `resize_realign()` from your own callback
+    /// in [set_resizecb][NcPlane#method.set_resizecb] instead.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_resize_realign(&mut self) {
+        unsafe { crate::ncplane_set_resizecb(self, Some(crate::ncplane_resize_realign)) }
+    }
+
     /// Rotate the plane π/2 radians clockwise.
     ///
     /// This cannot be performed on arbitrary planes, because glyphs cannot be
@@ -1324,6 +2761,30 @@ impl NcPlane {
         unsafe { crate::ncplane_y(self) as NcOffset }
     }
 
+    /// Enables or disables autogrow, and returns true if it was previously
+    /// enabled, or false if disabled.
+    ///
+    /// With autogrow enabled, the plane grows as many rows as needed for
+    /// the strings written with [putstr()][NcPlane#method.putstr],
+    /// [putstr_yx()][NcPlane#method.putstr_yx] and
+    /// [putstrln()][NcPlane#method.putstrln], which wrap at its right edge
+    /// and at each newline, instead of failing past the bottom.
+    ///
+    /// Autogrow and [scrolling][NcPlane#method.set_scrolling] are mutually
+    /// exclusive, enabling either one disables the other.
+    ///
+    /// A bound plane can grow past the bottom of its parent, where it's still
+    /// drawn, but a resize callback of the parent may shrink it back.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_autogrow(&mut self, enable: bool) -> bool {
+        let previous = crate::plane::set_plane_autogrow(self, enable);
+        if enable {
+            self.set_scrolling(false);
+        }
+        previous
+    }
+
     /// Sets the scrolling behaviour of the plane, and
     /// returns true if scrolling was previously enabled, of false, if disabled.
     ///
@@ -1335,10 +2796,48 @@ impl NcPlane {
     /// fail at the eleventh character. In either case, the cursor will be left
     /// at location 0x10; it must be moved before further printing can take place. I
     ///
+    /// Enabling scrolling disables [autogrow][NcPlane#method.set_autogrow].
+    ///
     /// *C style function: [ncplane_set_scrolling()][crate::ncplane_set_scrolling].*
     pub fn set_scrolling(&mut self, scroll: bool) -> bool {
+        if scroll {
+            crate::plane::set_plane_autogrow(self, false);
+        }
         unsafe { crate::ncplane_set_scrolling(self, scroll) }
     }
+
+    /// Creates a new scrolling NcPlane bound to this one, spanning its full
+    /// width from row `top` to row `bottom` (both inclusive), and returns it.
+    ///
+    /// Writes that overflow the returned plane scroll only that band, while
+    /// the rows of this plane above and below it (e.g. a header and a footer)
+    /// stay fixed.
+    ///
+    /// It's an error if `top >= bottom`. A `bottom` past the end of this plane
+    /// is clamped to its last row.
+    ///
+    /// The scroll region is a plane of its own, so it must be used for writing
+    /// the scrolling content, and it can be destroyed when no longer needed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_scroll_region<'a>(
+        &mut self,
+        top: NcDimension,
+        bottom: NcDimension,
+    ) -> NcResult<&'a mut NcPlane> {
+        let (rows, cols) = self.dim_yx();
+        let bottom = bottom.min(rows.saturating_sub(1));
+        if top >= bottom {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.set_scroll_region(): top >= bottom",
+            ));
+        }
+        let region = NcPlane::new_bound(self, top as NcOffset, 0, bottom - top + 1, cols)?;
+        region.set_scrolling(true);
+        Ok(region)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1430,7 +2929,11 @@ impl NcPlane {
         )]
     }
 
+    /// NcPlane.[box_sized()][NcPlane#method.box_sized] with the double
+    /// box-drawing characters.
     ///
+    /// Returns an error if the box is smaller than 2x2, since it needs
+    /// its corners.
     ///
     /// *C style function: [ncplane_double_box_sized()][crate::ncplane_double_box_sized].*
     #[inline]
@@ -1442,7 +2945,47 @@ impl NcPlane {
         x_len: NcDimension,
         boxmask: NcBoxMask,
     ) -> NcResult<()> {
-        error![crate::ncplane_double_box(
+        crate::plane::box_min_size("NcPlane.double_box_sized()", y_len, x_len)?;
+        error![crate::ncplane_double_box_sized(
+            self, stylemask, channels, y_len, x_len, boxmask
+        )]
+    }
+
+    /// NcPlane.[box()][NcPlane#method.box] with the rounded box-drawing characters.
+    ///
+    /// *C style function: [ncplane_rounded_box()][crate::ncplane_rounded_box].*
+    #[inline]
+    pub fn rounded_box(
+        &mut self,
+        stylemask: NcStyleMask,
+        channels: NcChannelPair,
+        y_stop: NcDimension,
+        x_stop: NcDimension,
+        boxmask: NcBoxMask,
+    ) -> NcResult<()> {
+        error![crate::ncplane_rounded_box(
+            self, stylemask, channels, y_stop, x_stop, boxmask
+        )]
+    }
+
+    /// NcPlane.[box_sized()][NcPlane#method.box_sized] with the rounded
+    /// box-drawing characters.
+    ///
+    /// Returns an error if the box is smaller than 2x2, since it needs
+    /// its corners.
+    ///
+    /// *C style function: [ncplane_rounded_box_sized()][crate::ncplane_rounded_box_sized].*
+    #[inline]
+    pub fn rounded_box_sized(
+        &mut self,
+        stylemask: NcStyleMask,
+        channels: NcChannelPair,
+        y_len: NcDimension,
+        x_len: NcDimension,
+        boxmask: NcBoxMask,
+    ) -> NcResult<()> {
+        crate::plane::box_min_size("NcPlane.rounded_box_sized()", y_len, x_len)?;
+        error![crate::ncplane_rounded_box_sized(
             self, stylemask, channels, y_len, x_len, boxmask
         )]
     }
@@ -1481,6 +3024,78 @@ impl NcPlane {
         )]
     }
 
+    /// Draws a rounded perimeter around this NcPlane, with its colors
+    /// interpolated between the four corner [NcChannelPair]s.
+    ///
+    /// Each side set in `boxmask` is left undrawn, and each corner is only
+    /// drawn if one of its adjacent sides is.
+    ///
+    /// The plane must be at least 3x3, so that the border has an interior.
+    /// The cursor, the current channels and the styles are preserved.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn perimeter_gradient(
+        &mut self,
+        ul: NcChannelPair,
+        ur: NcChannelPair,
+        ll: NcChannelPair,
+        lr: NcChannelPair,
+        stylemask: NcStyleMask,
+        boxmask: NcBoxMask,
+    ) -> NcResult<()> {
+        let (rows, cols) = self.dim_yx();
+        if rows < 3 || cols < 3 {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::BoundsError,
+                "NcPlane.perimeter_gradient(): plane too small",
+            ));
+        }
+        let top = boxmask & crate::NCBOXMASK_TOP == 0;
+        let right = boxmask & crate::NCBOXMASK_RIGHT == 0;
+        let bottom = boxmask & crate::NCBOXMASK_BOTTOM == 0;
+        let left = boxmask & crate::NCBOXMASK_LEFT == 0;
+
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let (channels, styles) = (self.channels(), self.styles());
+        self.set_styles(stylemask);
+
+        let (last_y, last_x) = (rows - 1, cols - 1);
+        let mut res = Ok(());
+        'rows: for y in 0..rows {
+            for x in 0..cols {
+                let egc = match (y, x) {
+                    (0, 0) if top || left => "╭",
+                    (0, x) if x == last_x && (top || right) => "╮",
+                    (y, 0) if y == last_y && (bottom || left) => "╰",
+                    (y, x) if y == last_y && x == last_x && (bottom || right) => "╯",
+                    (0, x) if x > 0 && x < last_x && top => "─",
+                    (y, x) if y == last_y && x > 0 && x < last_x && bottom => "─",
+                    (y, 0) if y > 0 && y < last_y && left => "│",
+                    (y, x) if x == last_x && y > 0 && y < last_y && right => "│",
+                    _ => continue,
+                };
+                self.set_channels(crate::plane::channels_bilerp(
+                    ul,
+                    ur,
+                    ll,
+                    lr,
+                    y as f32 / last_y as f32,
+                    x as f32 / last_x as f32,
+                ));
+                res = self.putstr_yx(y, x, egc).map(|_| ());
+                if res.is_err() {
+                    break 'rows;
+                }
+            }
+        }
+
+        self.set_channels(channels);
+        self.set_styles(styles);
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        res
+    }
+
     ///
     ///
     /// *C style function: [ncplane_perimeter_rounded()][crate::ncplane_perimeter_rounded].*
@@ -1495,6 +3110,370 @@ impl NcPlane {
             self, stylemask, channels, boxmask
         )]
     }
+
+    /// Draws a scrollbar along the `side` of this NcPlane, for a content
+    /// `total` units long, of which `visible` units are shown, starting
+    /// at `offset`.
+    ///
+    /// The track is drawn with `░` and the thumb with `█`, both with
+    /// `channels`. The thumb is proportional to `visible / total`, at least
+    /// one cell long, and it fills the whole track if `visible >= total`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn draw_scrollbar(
+        &mut self,
+        side: NcScrollbarSide,
+        total: usize,
+        visible: usize,
+        offset: usize,
+        channels: NcChannelPair,
+    ) -> NcResult<()> {
+        let (rows, cols) = self.dim_yx();
+        let vertical = matches![side, NcScrollbarSide::Left | NcScrollbarSide::Right];
+        let len = if vertical { rows } else { cols };
+        let (start, thumb) = crate::plane::scrollbar_thumb(len, total, visible, offset);
+
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        for i in 0..len {
+            let (y, x) = match side {
+                NcScrollbarSide::Left => (i, 0),
+                NcScrollbarSide::Right => (i, cols - 1),
+                NcScrollbarSide::Top => (0, i),
+                NcScrollbarSide::Bottom => (rows - 1, i),
+            };
+            let ch = if i >= start && i < start + thumb {
+                '█'
+            } else {
+                '░'
+            };
+            self.putchar_yx_styled(y, x, ch, channels, 0)?;
+        }
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        Ok(())
+    }
+
+    /// Erases this NcPlane and draws a histogram of the `values` on it,
+    /// with `channels`.
+    ///
+    /// If `vertical` is true each value is a column, drawn from the bottom up,
+    /// otherwise it's a row, drawn from the left. The bars are scaled so that
+    /// the highest value fills the plane, with a resolution of an eighth of
+    /// a cell, and the values that don't fit in the plane are left out.
+    ///
+    /// The negative values have empty bars, and so have all of them when
+    /// the highest value is 0.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn draw_histogram(
+        &mut self,
+        values: &[f64],
+        channels: NcChannelPair,
+        vertical: bool,
+    ) -> NcResult<()> {
+        const VERTICAL: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        const HORIZONTAL: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+        self.erase();
+        let (rows, cols) = self.dim_yx();
+        let (len, count) = if vertical { (rows, cols) } else { (cols, rows) };
+        let values = &values[..values.len().min(count as usize)];
+
+        for (i, eighths) in crate::plane::histogram_bars(values, len)
+            .into_iter()
+            .enumerate()
+        {
+            let i = i as NcDimension;
+            for cell in 0..len {
+                let filled = eighths.saturating_sub(cell * 8).min(8);
+                if filled == 0 {
+                    break;
+                }
+                let (y, x, ch) = if vertical {
+                    (rows - 1 - cell, i, VERTICAL[filled as usize - 1])
+                } else {
+                    (i, cell, HORIZONTAL[filled as usize - 1])
+                };
+                self.putchar_yx_styled(y, x, ch, channels, 0)?;
+            }
+        }
+        self.cursor_move_yx(0, 0)?;
+        Ok(())
+    }
+
+    /// Erases this NcPlane and draws a text box on it: a rounded border with
+    /// `border_channels`, the `title` centered in the top edge, and the `body`
+    /// wrapped inside, with one cell of padding.
+    ///
+    /// A title wider than the top edge is truncated with an ellipsis (`…`),
+    /// and the lines of the body that don't fit inside are left out.
+    /// The body is written with the current channels and styles of the plane.
+    ///
+    /// Returns an error if the plane is smaller than 2x2 cells.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn text_box(
+        &mut self,
+        title: &str,
+        body: &str,
+        border_channels: NcChannelPair,
+    ) -> NcResult<()> {
+        let (rows, cols) = self.dim_yx();
+        if rows < 2 || cols < 2 {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.text_box(): the plane is too small",
+            ));
+        }
+        self.erase();
+        self.perimeter_rounded(0, border_channels, 0)?;
+
+        // the title leaves a corner and a space at each side
+        if !title.is_empty() && cols > 4 {
+            let (title, width) = crate::plane::ellipsized(title, cols - 4)?;
+            let x = 1 + (cols - 2 - (width + 2)) / 2;
+            let mut scope = self.style_scope();
+            scope.set_channels(border_channels);
+            scope.set_styles(crate::NCSTYLE_NONE);
+            scope.putstr_yx(0, x, &format![" {} ", title])?;
+        }
+
+        let (inner_rows, inner_cols) = (rows.saturating_sub(4), cols.saturating_sub(4));
+        for (y, line) in crate::plane::wrap_text(body, inner_cols)
+            .iter()
+            .take(inner_rows as usize)
+            .enumerate()
+        {
+            let (line, _) = crate::plane::fitting_prefix(line, inner_cols)?;
+            self.putstr_yx(2 + y as NcDimension, 2, line)?;
+        }
+        self.cursor_move_yx(0, 0)?;
+        Ok(())
+    }
+}
+
+// -----------------------------------------------------------------------------
+/// ## NcPlane methods: mouse areas
+impl NcPlane {
+    /// Adds a mouse area to this NcPlane, identified by `id`, starting at
+    /// `y`, `x` and spanning `len_y` rows by `len_x` columns, on top of
+    /// the previous ones.
+    ///
+    /// The areas are kept on the Rust side, and forgotten when the plane is
+    /// destroyed, including by its widget or by
+    /// [Notcurses.stop()][Notcurses#method.stop]. They aren't clipped to the plane,
+    /// and they don't follow it if it's resized.
+    ///
+    /// Returns an error if either length is 0.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn add_mouse_area(
+        &mut self,
+        y: NcDimension,
+        x: NcDimension,
+        len_y: NcDimension,
+        len_x: NcDimension,
+        id: u32,
+    ) -> NcResult<()> {
+        if len_y == 0 || len_x == 0 {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcPlane.add_mouse_area(): empty area",
+            ));
+        }
+        crate::plane::add_mouse_area(self, (y, x, len_y, len_x, id));
+        Ok(())
+    }
+
+    /// Removes the mouse areas identified by `id` from this NcPlane,
+    /// and returns how many were removed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn remove_mouse_area(&mut self, id: u32) -> usize {
+        crate::plane::remove_mouse_areas(self, Some(id))
+    }
+
+    /// Removes all the mouse areas from this NcPlane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn clear_mouse_areas(&mut self) {
+        crate::plane::remove_mouse_areas(self, None);
+    }
+
+    /// Returns the id of the topmost mouse area containing the point at
+    /// `y`, `x`, relative to this NcPlane, or None if there's none.
+    ///
+    /// When several areas overlap, the last added is on top.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn hit_test(&self, y: NcDimension, x: NcDimension) -> Option<u32> {
+        crate::plane::mouse_area_at(self, y, x)
+    }
+
+    /// Returns the id of the topmost mouse area containing the point of
+    /// the mouse `input`, whose coordinates are relative to the screen,
+    /// or None if there's none, or the point is outside this NcPlane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn hit_test_input(&self, input: &NcInput) -> Option<u32> {
+        let (abs_y, abs_x) = crate::plane::plane_abs_yx(self);
+        let (y, x) = (input.y - abs_y, input.x - abs_x);
+        let (rows, cols) = self.dim_yx();
+        if y < 0 || x < 0 || y as NcDimension >= rows || x as NcDimension >= cols {
+            return None;
+        }
+        self.hit_test(y as NcDimension, x as NcDimension)
+    }
+}
+
+// -----------------------------------------------------------------------------
+/// ## NcPlane methods: braille canvas
+impl NcPlane {
+    /// Returns a new [NcBrailleCanvas] of `width` x `height` pixels
+    /// drawn on this NcPlane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn braille_canvas<'a>(
+        &'a mut self,
+        width: NcDimension,
+        height: NcDimension,
+    ) -> NcBrailleCanvas<'a> {
+        NcBrailleCanvas::new(self, width, height)
+    }
+}
+
+// -----------------------------------------------------------------------------
+/// ## NcPlane methods: text reflow
+impl NcPlane {
+    /// Writes `text` wrapped to the width of this NcPlane, and installs a
+    /// resize callback that clears the plane and wraps it again to the new
+    /// width every time the parent plane is resized.
+    ///
+    /// Lines are broken at whitespace, and those that don't fit in the plane
+    /// are left out, and can be scrolled into view with
+    /// [scroll_reflow][NcPlane#method.scroll_reflow].
+    /// A resize to zero columns leaves the plane untouched.
+    ///
+    /// The text is kept on the Rust side, replacing any previous reflow text,
+    /// and it's freed when the plane is [destroyed][NcPlane#method.destroy].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_reflow_text(&mut self, text: String) -> NcResult<()> {
+        self.unset_reflow_text();
+        let reflow = crate::plane::ReflowText {
+            text,
+            top: 0,
+            anchor: (0, 0),
+            anchored: false,
+        };
+        crate::plane::set_plane_reflow(self, Some(reflow));
+        self.set_resizecb(Some(crate::plane::reflow_resizecb));
+        self.reflow()
+    }
+
+    /// Wraps again the text set with
+    /// [set_reflow_text][NcPlane#method.set_reflow_text]
+    /// to the current width of this NcPlane.
+    ///
+    /// Resize callbacks are only invoked when the parent plane is resized,
+    /// so call this after resizing the plane itself.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn reflow(&mut self) -> NcResult<()> {
+        if !self.reflows_text() {
+            return Err(crate::NcError::with_msg(NCRESULT_ERR, "NcPlane.reflow()"));
+        }
+        error![crate::plane::reflow_resizecb(self)]
+    }
+
+    /// Removes the resize callback installed by
+    /// [set_reflow_text][NcPlane#method.set_reflow_text], freeing the text.
+    ///
+    /// The contents of the plane are left untouched. It's a no-op if no
+    /// reflow text was set.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn unset_reflow_text(&mut self) {
+        if !self.reflows_text() {
+            return;
+        }
+        crate::plane::set_plane_reflow(self, None);
+        self.set_resizecb(None);
+    }
+
+    /// Sets whether the reflow keeps the topmost visible line of the text
+    /// at the top of the plane, so that the scroll position is preserved
+    /// when the width changes.
+    ///
+    /// The topmost visible line is then the one containing the first word
+    /// that was at the top before the reflow, even if it's now much longer
+    /// or shorter. Otherwise the index of the topmost line is kept, which
+    /// makes the text jump around.
+    ///
+    /// In both cases the topmost line is moved up if needed, so that the last
+    /// line of the text doesn't end above the bottom of the plane, but an
+    /// anchored reflow goes back to the anchor once there's room again.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_reflow_anchored(&mut self, anchored: bool) -> NcResult<()> {
+        if !self.reflows_text() {
+            return Err(crate::NcError::with_msg(
+                NCRESULT_ERR,
+                "NcPlane.set_reflow_anchored()",
+            ));
+        }
+        crate::plane::update_plane_reflow(self, |reflow| reflow.anchored = anchored);
+        Ok(())
+    }
+
+    /// Returns the index of the topmost visible line of the text set with
+    /// [set_reflow_text][NcPlane#method.set_reflow_text], once wrapped.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn reflow_top(&self) -> NcResult<usize> {
+        if !self.reflows_text() {
+            return Err(crate::NcError::with_msg(
+                NCRESULT_ERR,
+                "NcPlane.reflow_top()",
+            ));
+        }
+        Ok(crate::plane::update_plane_reflow(self, |reflow| reflow.top).unwrap_or(0))
+    }
+
+    /// Scrolls the text set with
+    /// [set_reflow_text][NcPlane#method.set_reflow_text] by `lines`,
+    /// down if positive and up if negative, and draws it again.
+    ///
+    /// It stops at the first line, and when the last line reaches the
+    /// bottom of the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn scroll_reflow(&mut self, lines: isize) -> NcResult<()> {
+        if !self.reflows_text() {
+            return Err(crate::NcError::with_msg(
+                NCRESULT_ERR,
+                "NcPlane.scroll_reflow()",
+            ));
+        }
+        let (rows, cols) = self.dim_yx();
+        crate::plane::update_plane_reflow(self, |reflow| {
+            let wrapped = crate::plane::wrap_text_anchored(&reflow.text, cols);
+            let max_top = wrapped.len().saturating_sub(rows as usize) as isize;
+            reflow.top = (reflow.top as isize + lines).max(0).min(max_top) as usize;
+            reflow.anchor = wrapped.get(reflow.top).map_or((0, 0), |(_, start)| *start);
+        });
+        self.reflow()
+    }
+
+    /// Returns true if this NcPlane has text set with
+    /// [set_reflow_text][NcPlane#method.set_reflow_text].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn reflows_text(&self) -> bool {
+        self.resizecb().map(|cb| cb as usize)
+            == Some(crate::plane::reflow_resizecb as NcResizeCb as usize)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1558,6 +3537,57 @@ impl NcPlane {
         error![unsafe { crate::ncplane_pulse(self, time, fader, null_mut()) }]
     }
 
+    /// Cross-fades between the `from` and `to` planes by `t`, in the range
+    /// `0.0..=1.0`, writing the result onto the `onto` plane.
+    ///
+    /// The channels of each cell are interpolated with
+    /// [NcChannelPair::lerp()][crate::NcChannelPairMethods#tymethod.lerp],
+    /// while the glyph and the styles are taken from `from` while `t` is below
+    /// 0.5, and from `to` afterwards. Calling it with an increasing `t` on
+    /// successive frames transitions from one plane to the other.
+    ///
+    /// Only the area where the three planes overlap, from their origins,
+    /// is written. A wide glyph that doesn't fit in it is replaced by a space.
+    /// The cursor of `onto` is not moved.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn crossfade(from: &NcPlane, to: &NcPlane, onto: &mut NcPlane, t: f32) -> NcResult<()> {
+        let (from_rows, from_cols) = from.dim_yx();
+        let (to_rows, to_cols) = to.dim_yx();
+        let (onto_rows, onto_cols) = onto.dim_yx();
+        let rows = from_rows.min(to_rows).min(onto_rows);
+        let cols = from_cols.min(to_cols).min(onto_cols);
+
+        let (cursor_y, cursor_x) = onto.cursor_yx();
+        for y in 0..rows {
+            let mut x = 0;
+            while x < cols {
+                let (from_egc, from_style, from_channels) =
+                    crate::plane::plane_cell_at(from, y, x)?;
+                let (to_egc, to_style, to_channels) = crate::plane::plane_cell_at(to, y, x)?;
+                let (mut egc, stylemask) = if t < 0.5 {
+                    (from_egc, from_style)
+                } else {
+                    (to_egc, to_style)
+                };
+                if egc.is_empty() || (x + 1 == cols && crate::plane::strwidth(&egc)? > 1) {
+                    // the right half of a wide glyph on the other side,
+                    // or a wide glyph not fitting
+                    egc = " ".to_string();
+                }
+
+                let mut cell = NcCell::with_str(onto, &egc);
+                cell.stylemask = stylemask;
+                cell.channels = NcChannelPair::lerp(from_channels, to_channels, t)
+                    & !crate::NCCELL_WIDEASIAN_MASK;
+                let res = onto.putc_yx(y, x, &cell);
+                unsafe { crate::cell_release(onto, &mut cell) };
+                x += res?.max(1);
+            }
+        }
+        onto.cursor_move_yx(cursor_y, cursor_x)
+    }
+
     /// Draws a gradient with its upper-left corner at the current cursor
     /// position, stopping at `y_stop` * `xstop`.
     ///
@@ -1635,6 +3665,104 @@ impl NcPlane {
         error![res, res as NcDimension]
     }
 
+    /// Paints every cell of the plane with the glyph, channels and styles
+    /// returned by `f` for its `y`, `x` coordinates.
+    ///
+    /// Returns the number of cells painted.
+    ///
+    /// The function is called once per cell, in row-major order. A wide glyph
+    /// occupies its natural width, and `f` is not called for the cells it
+    /// covers. It is an error to return a wide glyph that doesn't fit in
+    /// the rest of the row.
+    ///
+    /// The cursor, channels and styles of the plane are restored afterwards.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn paint_with<F>(&mut self, f: F) -> NcResult<NcDimension>
+    where
+        F: Fn(NcDimension, NcDimension) -> (NcChannelPair, char, NcStyleMask),
+    {
+        let (rows, cols) = self.dim_yx();
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let (channels, styles) = (self.channels(), self.styles());
+
+        let mut painted = 0;
+        let mut res = Ok(());
+        'rows: for y in 0..rows {
+            let mut x = 0;
+            while x < cols {
+                let (cell_channels, ch, stylemask) = f(y, x);
+                self.set_channels(cell_channels);
+                self.set_styles(stylemask);
+                match self.putstr_yx(y, x, &ch.to_string()) {
+                    Ok(width) => {
+                        painted += 1;
+                        x += width.max(1);
+                    }
+                    Err(e) => {
+                        res = Err(e);
+                        break 'rows;
+                    }
+                }
+            }
+        }
+
+        self.set_channels(channels);
+        self.set_styles(styles);
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        res.map(|_| painted)
+    }
+
+    /// Animates each column independently, writing the glyph returned by `f`
+    /// for the column and the current `frame` number, at the row tracked
+    /// for that column.
+    ///
+    /// Each column starts at its own row, and advances one row per frame,
+    /// wrapping around at the bottom, which is the bookkeeping needed by
+    /// falling characters effects. When `f` returns None the column is
+    /// left untouched.
+    ///
+    /// `f` is called once per column, from left to right. A wide glyph
+    /// occupies its natural width, and `f` is not called for the column it
+    /// covers. It is an error to return a wide glyph in the last column.
+    ///
+    /// The cursor and channels of the plane are restored afterwards.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn column_animate<F>(&mut self, mut f: F, frame: u64) -> NcResult<()>
+    where
+        F: FnMut(NcDimension, u64) -> Option<(char, NcChannelPair)>,
+    {
+        let (rows, cols) = self.dim_yx();
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let channels = self.channels();
+
+        let mut res = Ok(());
+        let mut x = 0;
+        while x < cols {
+            let (ch, glyph_channels) = match f(x, frame) {
+                Some(glyph) => glyph,
+                None => {
+                    x += 1;
+                    continue;
+                }
+            };
+            self.set_channels(glyph_channels);
+            let y = crate::plane::column_row(x, frame, rows);
+            match self.putstr_yx(y, x, &ch.to_string()) {
+                Ok(width) => x += width.max(1),
+                Err(e) => {
+                    res = Err(e);
+                    break;
+                }
+            }
+        }
+
+        self.set_channels(channels);
+        self.cursor_move_yx(cursor_y, cursor_x)?;
+        res
+    }
+
     /// Draws a high-resolution gradient using upper blocks and synced backgrounds.
     ///
     /// Returns the number of cells filled on success,
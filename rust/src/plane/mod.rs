@@ -2,10 +2,10 @@
 
 // functions already exported by bindgen : 108 (5 + 103)
 // -----------------------------------------------------
-// (X) wont:  6
+// (X) wont:  2
 // (D) depr:  4
-// (#) test: 13
-// (W) wrap: 77 of 98
+// (#) test: 15
+// (W) wrap: 83 of 101
 // -------------------------------------------
 //W  ncpile_bottom
 //W# ncpile_create
@@ -59,8 +59,8 @@
 //W  ncplane_pulse
 //   ncplane_putchar_stained
 //   ncplane_putc_yx
-// X ncplane_putegc_stained            // unneeded
-// X ncplane_putegc_yx                 // unneeded
+//W  ncplane_putegc_stained
+//W  ncplane_putegc_yx
 //   ncplane_putnstr_aligned
 //   ncplane_putnstr_yx
 //   ncplane_putstr_aligned
@@ -73,10 +73,10 @@
 //W  ncplane_reparent
 //W  ncplane_reparent_family
 //W# ncplane_resize
-//W  ncplane_resizecb
-//   ncplane_resize_maximize
+//W# ncplane_resizecb
+//W  ncplane_resize_maximize
 //W  ncplane_resize_realign
-//   ncplane_rgba
+//W  ncplane_rgba
 //W  ncplane_rotate_ccw
 //W  ncplane_rotate_cw
 //W  ncplane_set_base
@@ -87,7 +87,7 @@
 //W  ncplane_set_bg_palindex
 //W  ncplane_set_bg_rgb
 //W  ncplane_set_bg_rgb8
-// X ncplane_set_bg_rgb8_clipped       // unneeded
+//W  ncplane_set_bg_rgb8_clipped
 //W# ncplane_set_channels
 //W# ncplane_set_fchannel
 //W  ncplane_set_fg_alpha
@@ -95,8 +95,8 @@
 //W  ncplane_set_fg_palindex
 //W  ncplane_set_fg_rgb
 //W  ncplane_set_fg_rgb8
-// X ncplane_set_fg_rgb8_clipped       // unneeded
-//W  ncplane_set_resizecb
+//W  ncplane_set_fg_rgb8_clipped
+//W# ncplane_set_resizecb
 //W  ncplane_set_scrolling
 //W  ncplane_set_styles
 //   ncplane_set_userptr
@@ -120,7 +120,7 @@
 // ------------------------------------------
 // (X) wont:  9
 // (+) done: 32 / 0
-// (W) wrap: 24
+// (W) wrap: 26
 // (#) test:  5
 // ------------------------------------------
 //W+ ncplane_align
@@ -159,8 +159,8 @@
 // X ncplane_putwstr_aligned           // unneeded
 // X ncplane_putwstr_yx                // unneeded
 //W# ncplane_resize_simple
-// + ncplane_rounded_box
-// + ncplane_rounded_box_sized
+//W+ ncplane_rounded_box
+//W+ ncplane_rounded_box_sized
 // + ncplane_vline
 // + ncplane_vprintf
 //
@@ -169,13 +169,29 @@
 #[cfg(test)]
 mod test;
 
+mod damage;
 mod helpers;
 mod methods;
+#[cfg(feature = "png")]
+mod png;
 mod reimplemented;
+mod scope;
+mod scroll;
+mod snapshot;
+mod table;
+mod viewport;
 
+pub use damage::{NcDamageTracker, NcRect};
 #[allow(unused_imports)]
 pub(crate) use helpers::*;
 pub use reimplemented::*;
+pub use scope::NcStyleScope;
+pub use scroll::NcScrollPlane;
+pub use snapshot::NcPlaneSnapshot;
+pub use table::NcTable;
+pub use viewport::NcViewport;
+
+use crate::NcDimension;
 
 // NcPlane
 /// Fundamental drawing surface.
@@ -218,6 +234,22 @@ pub use reimplemented::*;
 /// the reparented plane are moved along with it. Their relative z-order is maintained.
 ///
 ///
+/// # Regions
+///
+/// The methods operating on a rectangular region
+/// ([restyle_region][NcPlane#method.restyle_region],
+/// [stain_region][NcPlane#method.stain_region],
+/// [erase_region][NcPlane#method.erase_region] &
+/// [fill_region][NcPlane#method.fill_region]) share the same convention:
+///
+/// - the region starts at `y`, `x`, which must lie within the plane.
+/// - `len_y` & `len_x` are [NcOffset][crate::NcOffset]s, where `-1` means
+///   through the edge of the plane, and `0` an empty region.
+/// - any other negative length, or a region extending beyond the plane,
+///   is an error.
+/// - the cursor is not moved.
+///
+///
 /// # Methods & Associated Functions
 ///
 /// - [Constructors & Destructors](#ncplane-constructors-and-destructors)
@@ -239,6 +271,61 @@ pub type NcPlane = crate::bindings::ffi::ncplane;
 /// Options struct for [`NcPlane`]
 pub type NcPlaneOptions = crate::bindings::ffi::ncplane_options;
 
+/// The geometry of an [`NcPlane`], or of the terminal, in cells.
+///
+/// Returned by [`NcPlane.geom()`][NcPlane#method.geom],
+/// [`Notcurses.stddim_geom()`][crate::Notcurses#method.stddim_geom] &
+/// [`Notcurses.term_geom()`][crate::Notcurses#method.term_geom].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NcPlaneGeom {
+    /// Number of rows.
+    pub rows: NcDimension,
+    /// Number of columns.
+    pub cols: NcDimension,
+}
+
+/// Where a string was placed in an [`NcPlane`] row.
+///
+/// Returned by
+/// [`NcPlane.putstr_aligned_at()`][NcPlane#method.putstr_aligned_at].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NcTextPlacement {
+    /// The first column occupied.
+    pub start: NcDimension,
+    /// The column after the last one occupied.
+    pub end: NcDimension,
+    /// The number of columns occupied.
+    pub cols: NcDimension,
+    /// Whether the string had to be clipped to fit in the row.
+    pub clipped: bool,
+}
+
+/// Where an [`NcPlane`] is placed in the z-axis when docked into a new parent.
+///
+/// See [`NcPlane.dock_into()`][NcPlane#method.dock_into].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcDockPosition {
+    /// Above every other plane of the pile.
+    Top,
+    /// Right above the new parent, below the other planes bound to it.
+    Bottom,
+}
+
+/// The edge of an [`NcPlane`] along which a scrollbar is drawn.
+///
+/// See [`NcPlane.draw_scrollbar()`][NcPlane#method.draw_scrollbar].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcScrollbarSide {
+    /// A vertical scrollbar in the leftmost column.
+    Left,
+    /// A vertical scrollbar in the rightmost column.
+    Right,
+    /// A horizontal scrollbar in the top row.
+    Top,
+    /// A horizontal scrollbar in the bottom row.
+    Bottom,
+}
+
 /// Horizontal alignment relative to the parent plane. Set alignment in 'x'.
 pub const NCPLANE_OPTION_HORALIGNED: u64 = crate::bindings::ffi::NCPLANE_OPTION_HORALIGNED as u64;
 
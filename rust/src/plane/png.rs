@@ -0,0 +1,33 @@
+//! Saving an `NcPlane` as a PNG image.
+//!
+//! Requires the `png` feature.
+
+use std::{fs::File, io::BufWriter};
+
+use crate::{NcBlitter, NcError, NcErrorKind, NcPlane, NcResult, NCRESULT_ERR};
+
+/// ## NcPlane methods: PNG screenshots
+impl NcPlane {
+    /// Saves the contents of this NcPlane as a PNG image at `path`.
+    ///
+    /// The image is created with [as_rgba()][NcPlane#method.as_rgba], so the
+    /// plane must only contain the glyphs of the `blitter`, which determines
+    /// the resolution: [NCBLIT_2x1][crate::NCBLIT_2x1] results in 2 pixels
+    /// per cell, and [NCBLIT_1x1][crate::NCBLIT_1x1] in 1 pixel per cell.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn save_png(&self, path: &str, blitter: NcBlitter) -> NcResult<()> {
+        let (rgba, height, width) = self.as_rgba(blitter, 0, 0, -1, -1)?;
+        let io_error = |msg: &str| NcError::with_kind(NCRESULT_ERR, NcErrorKind::Io, msg);
+
+        let file = File::create(path)
+            .map_err(|_| io_error("NcPlane.save_png(): can't create the file"))?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+        encoder.set_color(png::ColorType::RGBA);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&rgba))
+            .map_err(|_| io_error("NcPlane.save_png(): can't write the image"))
+    }
+}
@@ -558,6 +558,9 @@ pub fn ncplane_double_box_sized(
     x_len: NcDimension,
     boxmask: NcBoxMask,
 ) -> NcIntResult {
+    if y_len < 2 || x_len < 2 {
+        return NCRESULT_ERR;
+    }
     let (mut y, mut x) = (0, 0);
     unsafe {
         crate::ncplane_cursor_yx(plane, &mut y, &mut x);
@@ -642,6 +645,9 @@ pub fn ncplane_rounded_box_sized(
     x_len: NcDimension,
     boxmask: NcBoxMask,
 ) -> NcIntResult {
+    if y_len < 2 || x_len < 2 {
+        return NCRESULT_ERR;
+    }
     let (mut y, mut x) = (0, 0);
     unsafe {
         crate::ncplane_cursor_yx(plane, &mut y, &mut x);
@@ -0,0 +1,50 @@
+//! `NcStyleScope`
+
+use core::ops::{Deref, DerefMut};
+
+use crate::{NcChannelPair, NcPlane, NcStyleMask};
+
+/// A guard that restores the channels and styles an [NcPlane] had when it
+/// was created, once it's dropped.
+///
+/// It dereferences to the plane, so that its channels and styles can be
+/// freely changed while it's alive.
+///
+/// See [style_scope()][NcPlane#method.style_scope].
+pub struct NcStyleScope<'a> {
+    plane: &'a mut NcPlane,
+    channels: NcChannelPair,
+    stylemask: NcStyleMask,
+}
+
+impl<'a> NcStyleScope<'a> {
+    /// New NcStyleScope, saving the current channels and styles of `plane`.
+    pub fn new(plane: &'a mut NcPlane) -> Self {
+        Self {
+            channels: plane.channels(),
+            stylemask: plane.styles(),
+            plane,
+        }
+    }
+}
+
+impl Deref for NcStyleScope<'_> {
+    type Target = NcPlane;
+
+    fn deref(&self) -> &NcPlane {
+        self.plane
+    }
+}
+
+impl DerefMut for NcStyleScope<'_> {
+    fn deref_mut(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+}
+
+impl Drop for NcStyleScope<'_> {
+    fn drop(&mut self) {
+        self.plane.set_channels(self.channels);
+        self.plane.set_styles(self.stylemask);
+    }
+}
@@ -0,0 +1,121 @@
+//! `NcScrollPlane`
+
+use std::collections::VecDeque;
+
+use crate::{NcDimension, NcOwnedCell, NcPlane, NcResult};
+
+/// An [NcPlane] that retains the lines scrolled off its top, so that
+/// they can be scrolled back into view, like a terminal's scrollback.
+///
+/// The cells of the lines are kept on the Rust side, as [NcOwnedCell]s with
+/// their own styles and channels, and the plane is redrawn from them.
+/// Lines wider than the plane are wrapped. At most `max_history` lines are
+/// retained besides the visible ones, the oldest ones being dropped first.
+pub struct NcScrollPlane<'a> {
+    plane: &'a mut NcPlane,
+    lines: VecDeque<Vec<NcOwnedCell>>,
+    max_history: usize,
+    // number of lines scrolled back from the bottom
+    offset: usize,
+}
+
+/// # `NcScrollPlane` constructors
+impl<'a> NcScrollPlane<'a> {
+    /// New NcScrollPlane drawing on `plane`, retaining up to `max_history`
+    /// lines scrolled off its top.
+    pub fn new(plane: &'a mut NcPlane, max_history: usize) -> Self {
+        Self {
+            plane,
+            lines: VecDeque::new(),
+            max_history,
+            offset: 0,
+        }
+    }
+}
+
+/// # `NcScrollPlane` methods
+impl<'a> NcScrollPlane<'a> {
+    /// Returns a mutable reference to the backing [NcPlane].
+    pub fn plane(&mut self) -> &mut NcPlane {
+        &mut *self.plane
+    }
+
+    /// Returns the number of lines scrolled off the top of the plane.
+    pub fn history_len(&self) -> usize {
+        self.lines.len().saturating_sub(self.rows())
+    }
+
+    /// Returns the number of lines currently scrolled back from the bottom.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Appends a line at the bottom, with the current styles and channels
+    /// of the plane.
+    ///
+    /// If the view is scrolled back, it stays on the same lines.
+    pub fn push_line(&mut self, string: &str) -> NcResult<()> {
+        let wrapped = crate::plane::wrap_text(string, self.plane.dim_x());
+        let pushed = wrapped.len();
+        for text in wrapped {
+            let line = self.line_cells(&text)?;
+            self.lines.push_back(line);
+        }
+        let max_lines = self.max_history + self.rows();
+        while self.lines.len() > max_lines {
+            self.lines.pop_front();
+        }
+        if self.offset > 0 {
+            self.offset = (self.offset + pushed).min(self.history_len());
+        }
+        self.redraw()
+    }
+
+    /// Scrolls back `n` lines, up to the oldest retained line.
+    pub fn scroll_back(&mut self, n: usize) -> NcResult<()> {
+        self.offset = (self.offset + n).min(self.history_len());
+        self.redraw()
+    }
+
+    /// Scrolls forward `n` lines, up to the newest line.
+    pub fn scroll_forward(&mut self, n: usize) -> NcResult<()> {
+        self.offset = self.offset.saturating_sub(n);
+        self.redraw()
+    }
+
+    /// Scrolls forward to the newest line.
+    pub fn scroll_to_bottom(&mut self) -> NcResult<()> {
+        self.offset = 0;
+        self.redraw()
+    }
+
+    /// Redraws the visible lines on the plane.
+    pub fn redraw(&mut self) -> NcResult<()> {
+        self.plane.erase();
+
+        let end = self.lines.len() - self.offset;
+        let start = end.saturating_sub(self.rows());
+        for (y, line) in self.lines.range(start..end).enumerate() {
+            // the right halves of the wide glyphs are left to them
+            for (x, cell) in line.iter().enumerate().filter(|(_, c)| !c.egc.is_empty()) {
+                self.plane
+                    .put_owned_cell(y as NcDimension, x as NcDimension, cell)?;
+            }
+        }
+        self.plane.cursor_move_yx(0, 0)
+    }
+
+    /// Returns the number of visible rows.
+    fn rows(&self) -> usize {
+        self.plane.dim_y() as usize
+    }
+
+    // returns the cells of the `text` written with the current styles and
+    // channels of the plane, on its last row, which is redrawn afterwards
+    fn line_cells(&mut self, text: &str) -> NcResult<Vec<NcOwnedCell>> {
+        let y = self.plane.dim_y().saturating_sub(1);
+        self.plane.erase_region(y, 0, 1, -1)?;
+        let cols = self.plane.putstr_yx(y, 0, text)?;
+        (0..cols).map(|x| self.plane.owned_cell_at(y, x)).collect()
+    }
+}
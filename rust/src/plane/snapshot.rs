@@ -0,0 +1,57 @@
+//! `NcPlaneSnapshot`
+
+use crate::{NcChannelPair, NcDimension, NcPlane, NcStyleMask};
+
+/// A copy of the complete state of an [NcPlane], that can be restored later.
+///
+/// It owns the EGCs of the cells, so it's independent of the plane.
+///
+/// See [snapshot()][NcPlane#method.snapshot] and
+/// [restore()][NcPlane#method.restore].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NcPlaneSnapshot {
+    pub(crate) rows: NcDimension,
+    pub(crate) cols: NcDimension,
+    // the cells, row by row, the right half of a wide glyph has an empty EGC
+    pub(crate) cells: Vec<(String, NcStyleMask, NcChannelPair)>,
+    pub(crate) base: (String, NcStyleMask, NcChannelPair),
+    pub(crate) cursor: (NcDimension, NcDimension),
+    pub(crate) channels: NcChannelPair,
+    pub(crate) stylemask: NcStyleMask,
+}
+
+/// # `NcPlaneSnapshot` Methods
+impl NcPlaneSnapshot {
+    /// Returns the dimensions of the captured plane.
+    pub fn dim_yx(&self) -> (NcDimension, NcDimension) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the EGC, the [NcStyleMask] and the [NcChannelPair] of the
+    /// captured cell at `y`, `x`, or None if it's out of bounds.
+    ///
+    /// The right half of a wide glyph has an empty EGC.
+    pub fn cell_at(
+        &self,
+        y: NcDimension,
+        x: NcDimension,
+    ) -> Option<(&str, NcStyleMask, NcChannelPair)> {
+        if y >= self.rows || x >= self.cols {
+            return None;
+        }
+        let (egc, stylemask, channels) = &self.cells[(y * self.cols + x) as usize];
+        Some((egc, *stylemask, *channels))
+    }
+
+    /// Returns the EGC, the [NcStyleMask] and the [NcChannelPair] of the
+    /// captured base cell.
+    pub fn base(&self) -> (&str, NcStyleMask, NcChannelPair) {
+        let (egc, stylemask, channels) = &self.base;
+        (egc, *stylemask, *channels)
+    }
+
+    /// Returns the captured cursor position.
+    pub fn cursor_yx(&self) -> (NcDimension, NcDimension) {
+        self.cursor
+    }
+}
@@ -0,0 +1,181 @@
+//! `NcTable`
+
+use crate::{
+    NcAlign, NcChannelPair, NcDimension, NcError, NcErrorKind, NcPlane, NcResult, NCALIGN_CENTER,
+    NCALIGN_LEFT, NCALIGN_RIGHT, NCRESULT_ERR,
+};
+
+/// A table of text with a header row, drawn with box-drawing separators.
+///
+/// Each column is as wide as its widest cell, measured in columns, so that
+/// wide characters are accounted for. The cells are padded by one space on
+/// each side, and aligned in their column.
+///
+/// Missing cells at the end of a row are left empty, and the cells beyond
+/// the number of headers are ignored.
+///
+/// # Example
+/// ```text
+/// ┌─────┬───────┐
+/// │ Key │ Value │
+/// ├─────┼───────┤
+/// │ a   │     1 │
+/// └─────┴───────┘
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NcTable {
+    headers: Vec<String>,
+    aligns: Vec<NcAlign>,
+    rows: Vec<Vec<String>>,
+}
+
+/// # `NcTable` constructors
+impl NcTable {
+    /// New NcTable with the column `headers`, all aligned to the left.
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            aligns: vec![NCALIGN_LEFT; headers.len()],
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the [NcAlign]ment of the `column`.
+    ///
+    /// It's a no-op if the column doesn't exist.
+    pub fn align(mut self, column: usize, align: NcAlign) -> Self {
+        if let Some(a) = self.aligns.get_mut(column) {
+            *a = align;
+        }
+        self
+    }
+
+    /// Adds a row with the `cells`.
+    pub fn row(mut self, cells: &[&str]) -> Self {
+        self.push_row(cells);
+        self
+    }
+}
+
+/// # `NcTable` methods
+impl NcTable {
+    /// Adds a row with the `cells`.
+    pub fn push_row(&mut self, cells: &[&str]) {
+        self.rows
+            .push(cells.iter().map(|c| c.to_string()).collect());
+    }
+
+    /// Returns the number of rows, without the header.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if there are no rows besides the header.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the dimensions the whole table needs, as `(rows, cols)`.
+    ///
+    /// A table without columns has no dimensions.
+    pub fn dims(&self) -> NcResult<(NcDimension, NcDimension)> {
+        let widths = self.widths()?;
+        Ok((self.height(), crate::plane::table_width(&widths)))
+    }
+
+    /// Draws the table at the origin of the `plane`, with the `channels`,
+    /// and returns the dimensions drawn, as `(rows, cols)`.
+    ///
+    /// If the table is wider than the plane, the widest columns are narrowed
+    /// and their cells truncated with an ellipsis (`…`).
+    ///
+    /// Returns an error if the plane is too short for all the rows, or too
+    /// narrow even for columns one cell wide.
+    pub fn render(
+        &self,
+        plane: &mut NcPlane,
+        channels: NcChannelPair,
+    ) -> NcResult<(NcDimension, NcDimension)> {
+        if self.headers.is_empty() {
+            return Ok((0, 0));
+        }
+        let (plane_rows, plane_cols) = plane.dim_yx();
+        let bounds_error = |msg: &str| {
+            NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::BoundsError,
+                &format!["NcTable.render(): {}", msg],
+            )
+        };
+        let widths = crate::plane::table_fit_widths(&self.widths()?, plane_cols)
+            .ok_or_else(|| bounds_error("the plane is too narrow"))?;
+        let (rows, cols) = (self.height(), crate::plane::table_width(&widths));
+        if rows > plane_rows {
+            return Err(bounds_error("the plane is too short"));
+        }
+
+        let previous = plane.channels();
+        plane.set_channels(channels);
+        let res = self.draw(plane, &widths);
+        plane.set_channels(previous);
+        res.map(|_| (rows, cols))
+    }
+
+    // the widths of the text of the columns
+    fn widths(&self) -> NcResult<Vec<NcDimension>> {
+        let mut widths = Vec::with_capacity(self.headers.len());
+        for (column, header) in self.headers.iter().enumerate() {
+            let mut width = crate::plane::strwidth(header)?;
+            for row in &self.rows {
+                if let Some(cell) = row.get(column) {
+                    width = width.max(crate::plane::strwidth(cell)?);
+                }
+            }
+            widths.push(width);
+        }
+        Ok(widths)
+    }
+
+    // the top and bottom borders, the header and its separator, and the rows
+    fn height(&self) -> NcDimension {
+        if self.headers.is_empty() {
+            return 0;
+        }
+        self.rows.len() as NcDimension + 4
+    }
+
+    fn draw(&self, plane: &mut NcPlane, widths: &[NcDimension]) -> NcResult<()> {
+        let separator = |left: &str, middle: &str, right: &str| {
+            let lines: Vec<String> = widths.iter().map(|&w| "─".repeat(w as usize + 2)).collect();
+            format!["{}{}{}", left, lines.join(middle), right]
+        };
+        plane.putstr_yx(0, 0, &separator("┌", "┬", "┐"))?;
+        plane.putstr_yx(1, 0, &self.row_line(&self.headers, widths)?)?;
+        plane.putstr_yx(2, 0, &separator("├", "┼", "┤"))?;
+        for (y, row) in self.rows.iter().enumerate() {
+            plane.putstr_yx(y as NcDimension + 3, 0, &self.row_line(row, widths)?)?;
+        }
+        plane.putstr_yx(self.height() - 1, 0, &separator("└", "┴", "┘"))?;
+        Ok(())
+    }
+
+    // a row of cells, truncated, aligned and padded to the widths
+    fn row_line(&self, cells: &[String], widths: &[NcDimension]) -> NcResult<String> {
+        let mut line = String::from("│");
+        for (column, &width) in widths.iter().enumerate() {
+            let cell = cells.get(column).map_or("", |c| c.as_str());
+            let (text, text_width) = crate::plane::ellipsized(cell, width)?;
+            let padding = width - text_width;
+            let left = match self.aligns[column] {
+                NCALIGN_RIGHT => padding,
+                NCALIGN_CENTER => padding / 2,
+                _ => 0,
+            };
+            line.push_str(&" ".repeat(left as usize + 1));
+            line.push_str(&text);
+            line.push_str(&" ".repeat((padding - left) as usize + 1));
+            line.push('│');
+        }
+        Ok(line)
+    }
+}
@@ -0,0 +1,110 @@
+//! Test `NcDamageTracker` methods.
+
+use crate::{
+    plane::{damage_add, damage_written},
+    NcDamageTracker, NcPlane, NcRect, Notcurses,
+};
+
+use serial_test::serial;
+
+#[test]
+fn add_rects() {
+    let mut damage = Vec::new();
+    damage_add(&mut damage, NcRect::new(1, 2, 1, 3), 10, 20);
+    assert_eq![vec![NcRect::new(1, 2, 1, 3)], damage];
+
+    // covered and empty rectangles are ignored
+    damage_add(&mut damage, NcRect::new(1, 3, 1, 1), 10, 20);
+    damage_add(&mut damage, NcRect::new(4, 4, 0, 5), 10, 20);
+    assert_eq![1, damage.len()];
+
+    // clipped to the plane
+    damage_add(&mut damage, NcRect::new(8, 15, 5, 10), 10, 20);
+    assert_eq![NcRect::new(8, 15, 2, 5), damage[1]];
+    damage_add(&mut damage, NcRect::new(30, 30, 1, 1), 10, 20);
+    assert_eq![2, damage.len()];
+
+    // a covering rectangle replaces the covered ones
+    damage_add(&mut damage, NcRect::new(0, 0, 10, 20), 10, 20);
+    assert_eq![vec![NcRect::new(0, 0, 10, 20)], damage];
+}
+
+#[test]
+fn written_rects() {
+    assert_eq![
+        NcRect::new(2, 3, 1, 4),
+        damage_written((2, 3), (2, 7), 10, 20)
+    ];
+    assert_eq![
+        NcRect::new(2, 0, 2, 20),
+        damage_written((2, 3), (3, 1), 10, 20)
+    ];
+    // the plane scrolled
+    assert_eq![
+        NcRect::new(0, 0, 10, 20),
+        damage_written((9, 3), (9, 1), 10, 20)
+    ];
+}
+
+#[test]
+#[serial]
+fn tracks_writes() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 5, 20)?;
+    let mut tracker = NcDamageTracker::new(plane);
+
+    // starts all dirty
+    assert![tracker.is_dirty()];
+    assert_eq![&[NcRect::new(0, 0, 5, 20)], tracker.damage()];
+    tracker.clear();
+    assert![!tracker.is_dirty()];
+
+    tracker.putstr_yx(1, 2, "hello")?;
+    tracker.putchar_yx(3, 0, 'x')?;
+    assert_eq![
+        &[NcRect::new(1, 2, 1, 5), NcRect::new(3, 0, 1, 1)],
+        tracker.damage()
+    ];
+
+    tracker.mark_dirty(NcRect::new(0, 0, 2, 10));
+    assert_eq![
+        &[NcRect::new(3, 0, 1, 1), NcRect::new(0, 0, 2, 10)],
+        tracker.damage()
+    ];
+
+    tracker.erase();
+    assert_eq![&[NcRect::new(0, 0, 5, 20)], tracker.damage()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_clears() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 5, 20)?;
+    // to resize it behind the back of the tracker
+    let external = plane as *mut NcPlane;
+    let mut tracker = NcDamageTracker::new(plane);
+
+    assert![tracker.render_damaged()?];
+    assert![tracker.damage().is_empty()];
+    assert![!tracker.render_damaged()?];
+
+    // the pile of the plane is rasterized
+    tracker.putstr_yx(0, 0, "changed")?;
+    assert![tracker.render_damaged()?];
+    assert![!tracker.is_dirty()];
+    let (mut stylemask, mut channels) = (0, 0);
+    assert_eq![Some('c'), nc.at_yx(0, 0, &mut stylemask, &mut channels)];
+
+    // a resize invalidates the whole plane
+    unsafe { (*external).resize_simple(6, 20)? };
+    assert![tracker.is_dirty()];
+    assert![tracker.render_damaged()?];
+    assert![!tracker.is_dirty()];
+
+    nc.stop()?;
+    Ok(())
+}
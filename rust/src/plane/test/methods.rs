@@ -1,4 +1,1905 @@
 //! Test `NcPlane` methods and associated functions.
 
-// use crate::NcPlane;
-// use serial_test::serial;
+use crate::{
+    plane::{
+        ansi_tokens, histogram_bars, markup_tokens, region_lens, rtl_visual_order, scrollbar_thumb,
+        whitespace_tokens, AnsiToken, MarkupTag, MarkupToken,
+    },
+    NcCell, NcChannel, NcChannelMethods, NcChannelPair, NcChannelPairMethods, NcDockPosition,
+    NcErrorKind, NcPlane, NcResizeCbUnsafe, NcScrollbarSide, NcStyleMask, Notcurses,
+    NCCELL_BG_PALETTE, NCSTYLE_BOLD, NCSTYLE_ITALIC, NCSTYLE_NONE, NCSTYLE_UNDERLINE,
+};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn set_resize_presets() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 10, 10)?;
+    assert![plane.resizecb().is_none()];
+
+    plane.set_resize_maximize();
+    let cb = unsafe { crate::ncplane_resizecb(plane) };
+    assert_eq![
+        Some(crate::ncplane_resize_maximize as NcResizeCbUnsafe as usize),
+        cb.map(|cb| cb as usize)
+    ];
+
+    plane.set_resize_realign();
+    let cb = unsafe { crate::ncplane_resizecb(plane) };
+    assert_eq![
+        Some(crate::ncplane_resize_realign as NcResizeCbUnsafe as usize),
+        cb.map(|cb| cb as usize)
+    ];
+
+    plane.set_resizecb(None);
+    assert![plane.resizecb().is_none()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putchar_advances_cursor() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 5)?;
+
+    assert_eq![1, plane.putchar('a')?];
+    assert_eq![(0, 1), plane.cursor_yx()];
+
+    // a wide glyph advances the cursor by its column width
+    assert_eq![2, plane.putchar('字')?];
+    assert_eq![(0, 3), plane.cursor_yx()];
+
+    let cell = NcCell::with_char('b', plane);
+    assert_eq![1, plane.putc(&cell)?];
+    assert_eq![(0, 4), plane.cursor_yx()];
+
+    // only one column left: a wide glyph doesn't fit
+    assert![plane.putchar('字').is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putchar_stained() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 10)?;
+    let mut red: NcChannelPair = 0;
+    red.set_fg_rgb(0xff0000);
+    plane.set_channels(red);
+    plane.set_styles(NCSTYLE_ITALIC);
+    plane.putstr_yx(0, 0, "abc")?;
+
+    let mut active: NcChannelPair = 0;
+    active.set_fg_rgb(0x00ff00);
+    plane.set_channels(active);
+    plane.set_styles(NCSTYLE_NONE);
+    plane.cursor_move_yx(0, 1)?;
+    assert_eq![1, plane.putchar_stained('x')?];
+    assert_eq![(0, 2), plane.cursor_yx()];
+
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq!['x', plane.at_yx(0, 1, &mut stylemask, &mut channels)?];
+    assert_eq![(NCSTYLE_ITALIC, red), (stylemask, channels)];
+
+    // over a blank cell, the default channels are kept
+    plane.cursor_move_yx(0, 5)?;
+    plane.putchar_stained('y')?;
+    assert_eq!['y', plane.at_yx(0, 5, &mut stylemask, &mut channels)?];
+    assert_eq![(0, 0), (stylemask, channels)];
+
+    assert_eq![active, plane.channels()];
+    assert_eq![0, plane.styles()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_stained_styled() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 10)?;
+    let mut red: NcChannelPair = 0;
+    red.set_fg_rgb(0xff0000);
+    plane.set_channels(red);
+    plane.set_styles(NCSTYLE_ITALIC);
+    plane.putstr_yx(0, 0, "hello")?;
+    plane.set_channels(0);
+    plane.set_styles(NCSTYLE_NONE);
+
+    plane.cursor_move_yx(0, 1)?;
+    assert_eq![3, plane.putstr_stained_styled("ELL", NCSTYLE_BOLD)?];
+    assert_eq![(0, 4), plane.cursor_yx()];
+    assert_eq!["hELLo", plane.contents(0, 0, 1, 0, false, true)];
+
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    plane.at_yx(0, 2, &mut stylemask, &mut channels)?;
+    assert_eq![(NCSTYLE_ITALIC | NCSTYLE_BOLD, red), (stylemask, channels)];
+    plane.at_yx(0, 4, &mut stylemask, &mut channels)?;
+    assert_eq![(NCSTYLE_ITALIC, red), (stylemask, channels)];
+
+    // the active styles are unchanged
+    assert_eq![0, plane.styles()];
+
+    plane.cursor_move_yx(0, 8)?;
+    assert![plane.putstr_stained_styled("xyz", NCSTYLE_BOLD).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putchar_yx_styled() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 10)?;
+    let mut active: NcChannelPair = 0;
+    active.set_fg_rgb(0x808080);
+    plane.set_channels(active);
+    plane.set_styles(NCSTYLE_ITALIC);
+
+    let mut red: NcChannelPair = 0;
+    red.set_fg_rgb(0xff0000);
+    red.set_bg_rgb(0x000040);
+    assert_eq![1, plane.putchar_yx_styled(1, 3, 'x', red, NCSTYLE_BOLD)?];
+    assert_eq![2, plane.putchar_yx_styled(0, 0, '字', red, 0)?];
+
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq!['x', plane.at_yx(1, 3, &mut stylemask, &mut channels)?];
+    assert_eq![(NCSTYLE_BOLD, red), (stylemask, channels)];
+
+    // the active channels and styles are unchanged
+    assert_eq![active, plane.channels()];
+    assert_eq![NCSTYLE_ITALIC, plane.styles()];
+    plane.putchar_yx(1, 5, 'y')?;
+    plane.at_yx(1, 5, &mut stylemask, &mut channels)?;
+    assert_eq![(NCSTYLE_ITALIC, active), (stylemask, channels)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn map_region() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 10)?;
+    plane.putstr("a字b")?;
+    plane.cursor_move_yx(1, 1)?;
+
+    let mut visited = vec![];
+    plane.map_region(0, 0, 1, 4, |y, x, cell| {
+        visited.push((y, x));
+        cell.set_fg_rgb(0x112233);
+    })?;
+
+    // the wide glyph is visited only once
+    assert_eq![vec![(0, 0), (0, 1), (0, 3)], visited];
+    assert_eq![(1, 1), plane.cursor_yx()];
+
+    let (mut stylemask, mut channels) = (0, 0);
+    plane.at_yx(0, 3, &mut stylemask, &mut channels)?;
+    assert_eq![0x112233, channels.fg_rgb()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn row_cells() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 3, 6)?;
+    plane.putstr_yx(0, 0, "a字b字")?;
+    plane.putstr_yx(2, 1, "c")?;
+
+    let row: Vec<_> = plane.row_cells(0).collect();
+    // the wide glyphs are yielded once
+    assert_eq![
+        vec![0, 1, 3, 4],
+        row.iter().map(|(x, _)| *x).collect::<Vec<_>>()
+    ];
+    assert![crate::cell_double_wide_p(&row[1].1)];
+    assert![!crate::cell_double_wide_p(&row[2].1)];
+
+    // a blank row yields blank cells
+    assert_eq![6, plane.row_cells(1).count()];
+    assert![plane.row_cells(1).all(|(_, cell)| cell.gcluster == 0)];
+    assert_eq![0, plane.row_cells(3).count()];
+
+    let rows: Vec<_> = plane
+        .cell_rows()
+        .map(|(y, cells)| (y, cells.len()))
+        .collect();
+    assert_eq![vec![(0, 4), (1, 6), (2, 6)], rows];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_fchannel_bchannel() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 2)?;
+
+    // starts with both channels using the default color
+    let channels = plane.channels();
+    assert![channels.fg_default_p()];
+    assert![channels.bg_default_p()];
+
+    let mut fchannel: NcChannel = 0;
+    fchannel.set_rgb(0x112233);
+    let channels = plane.set_fchannel(fchannel);
+    assert_eq![fchannel, channels.fchannel()];
+    assert_eq![0x112233, channels.fg_rgb()];
+    assert![!channels.fg_default_p()];
+    // the background keeps its default color
+    assert![channels.bg_default_p()];
+
+    let mut bchannel: NcChannel = 0;
+    bchannel.set_rgb(0x445566);
+    let channels = plane.set_bchannel(bchannel);
+    assert_eq![bchannel, channels.bchannel()];
+    assert![!channels.bg_default_p()];
+    // the foreground is preserved
+    assert_eq![fchannel, channels.fchannel()];
+
+    // setting a default channel doesn't touch the other one
+    let mut default: NcChannel = 0;
+    default.set_default();
+    let channels = plane.set_fchannel(default);
+    assert![channels.fg_default_p()];
+    assert_eq![bchannel, channels.bchannel()];
+    assert_eq![channels, plane.channels()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn wrap_text() {
+    use crate::plane::wrap_text;
+    assert_eq![
+        vec!["the quick", "brown fox"],
+        wrap_text("the quick brown fox", 10)
+    ];
+    assert_eq![vec!["abcd", "ef"], wrap_text("abcdef", 4)];
+    assert_eq![vec!["ab", "", "cd"], wrap_text("ab\n\ncd", 4)];
+    assert![wrap_text("the quick brown fox", 0).is_empty()];
+}
+
+#[test]
+fn wrap_text_anchored() {
+    use crate::plane::{anchored_line, wrap_text_anchored};
+    let lines = |text, width| -> Vec<(String, (usize, usize))> { wrap_text_anchored(text, width) };
+    assert_eq![
+        vec![
+            ("the quick".to_string(), (0, 0)),
+            ("brown fox".to_string(), (0, 8))
+        ],
+        lines("the quick brown fox", 10)
+    ];
+    assert_eq![
+        vec![("abcd".to_string(), (0, 0)), ("ef".to_string(), (0, 4))],
+        lines("abcdef", 4)
+    ];
+    assert_eq![
+        vec![
+            ("ab".to_string(), (0, 0)),
+            ("".to_string(), (1, 0)),
+            ("cd".to_string(), (2, 0))
+        ],
+        lines("ab\n\ncd", 4)
+    ];
+
+    // the, quick, brown, fox
+    let narrow = lines("the quick brown fox", 5);
+    assert_eq![2, anchored_line(&narrow, (0, 8))];
+    assert_eq![2, anchored_line(&narrow, (0, 10))];
+    assert_eq![3, anchored_line(&narrow, (1, 0))];
+    assert_eq![0, anchored_line(&[], (0, 8))];
+}
+
+#[test]
+#[serial]
+fn reflow_anchored() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 3, 12)?;
+    assert![plane.reflow_top().is_err()];
+    assert![plane.scroll_reflow(1).is_err()];
+
+    let text = "one two three four five six seven eight nine ten eleven twelve";
+    plane.set_reflow_text(text.to_string())?;
+    plane.set_reflow_anchored(true)?;
+    let top = |plane: &NcPlane| plane.contents(0, 0, 1, 0, false, true);
+
+    plane.scroll_reflow(2)?;
+    assert_eq![2, plane.reflow_top()?];
+    assert_eq!["five six", top(plane)];
+
+    // the topmost word stays at the top
+    plane.resize_simple(3, 6)?;
+    plane.reflow()?;
+    assert_eq![4, plane.reflow_top()?];
+    assert_eq!["five", top(plane)];
+
+    // everything fits, and it goes back to the anchor afterwards
+    plane.resize_simple(3, 30)?;
+    plane.reflow()?;
+    assert_eq![0, plane.reflow_top()?];
+    plane.resize_simple(3, 12)?;
+    plane.reflow()?;
+    assert_eq!["five six", top(plane)];
+
+    // clamped at both ends
+    plane.scroll_reflow(100)?;
+    assert_eq![4, plane.reflow_top()?];
+    assert_eq!["twelve", plane.contents(2, 0, 1, 0, false, true)];
+    plane.scroll_reflow(-100)?;
+    assert_eq![0, plane.reflow_top()?];
+
+    // without anchoring, the index of the topmost line is kept
+    plane.set_reflow_anchored(false)?;
+    plane.scroll_reflow(2)?;
+    plane.resize_simple(3, 6)?;
+    plane.reflow()?;
+    assert_eq![2, plane.reflow_top()?];
+    assert_eq!["three", top(plane)];
+
+    plane.unset_reflow_text();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_reflow_text() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 4, 10)?;
+    assert![!plane.reflows_text()];
+
+    plane.set_reflow_text("the quick brown fox jumps".to_string())?;
+    assert![plane.reflows_text()];
+    let row = |plane: &NcPlane, y| plane.contents(y, 0, 1, 0, false, true);
+    assert_eq!["the quick", row(plane, 0).trim_end()];
+    assert_eq!["brown fox", row(plane, 1).trim_end()];
+    assert_eq!["jumps", row(plane, 2).trim_end()];
+
+    // simulate a resize
+    plane.resize_simple(4, 5)?;
+    let cb = plane.resizecb().expect("no resize callback");
+    assert_eq![0, cb(plane)];
+    assert_eq!["the", row(plane, 0).trim_end()];
+    assert_eq!["quick", row(plane, 1).trim_end()];
+    assert_eq!["brown", row(plane, 2).trim_end()];
+    assert_eq!["fox", row(plane, 3).trim_end()];
+
+    plane.resize_simple(4, 12)?;
+    plane.reflow()?;
+    assert_eq!["the quick", row(plane, 0).trim_end()];
+    assert_eq!["brown fox", row(plane, 1).trim_end()];
+    assert_eq!["jumps", row(plane, 2).trim_end()];
+
+    plane.unset_reflow_text();
+    assert![!plane.reflows_text()];
+    assert![plane.resizecb().is_none()];
+
+    // wide characters are wrapped by their width, and the user pointer is
+    // left untouched
+    plane.resize_simple(4, 5)?;
+    plane.set_reflow_text("全角 全角全角".to_string())?;
+    assert_eq!["全角", row(plane, 0).trim_end()];
+    assert_eq!["全角", row(plane, 1).trim_end()];
+    assert_eq!["全角", row(plane, 2).trim_end()];
+    assert![unsafe { crate::ncplane_userptr(plane) }.is_null()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn wrap_text_wide() -> crate::NcResult<()> {
+    use crate::plane::wrap_text;
+    // the widths depend on the locale, which notcurses sets
+    let nc = Notcurses::new()?;
+
+    assert_eq![
+        vec!["全角 ab", "全角全角"],
+        wrap_text("全角 ab 全角全角", 8)
+    ];
+    assert_eq![vec!["全角", "全角", "全"], wrap_text("全角全角全", 4)];
+    assert_eq![vec!["全", "角"], wrap_text("全角", 3)];
+    // a character wider than the line still takes a line of its own
+    assert_eq![vec!["全", "角"], wrap_text("全角", 1)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn blitter_for_geom() {
+    use crate::plane::blitter_for_geom;
+    use crate::{NCBLIT_1x1, NCBLIT_2x1, NCBLIT_2x2, NCBLIT_3x2, NCBLIT_SIXEL};
+
+    // pixel graphics are preferred
+    assert_eq![
+        NCBLIT_SIXEL,
+        blitter_for_geom(true, true, true, (10, 10), (100, 100))
+    ];
+    // ASCII only terminals
+    assert_eq![
+        NCBLIT_1x1,
+        blitter_for_geom(false, true, false, (10, 10), (100, 100))
+    ];
+
+    // big images get the most subdivisions
+    assert_eq![
+        NCBLIT_3x2,
+        blitter_for_geom(false, true, true, (10, 10), (100, 100))
+    ];
+    assert_eq![
+        NCBLIT_2x2,
+        blitter_for_geom(false, false, true, (10, 10), (100, 100))
+    ];
+    // small images don't need them
+    assert_eq![
+        NCBLIT_1x1,
+        blitter_for_geom(false, true, true, (10, 10), (10, 10))
+    ];
+
+    // extreme aspect ratios only gain from subdivisions along one axis
+    assert_eq![
+        NCBLIT_3x2,
+        blitter_for_geom(false, true, true, (1, 100), (3, 100))
+    ];
+    assert_eq![
+        NCBLIT_2x1,
+        blitter_for_geom(false, false, true, (1, 100), (3, 100))
+    ];
+    assert_eq![
+        NCBLIT_2x2,
+        blitter_for_geom(false, true, true, (1, 100), (1, 200))
+    ];
+}
+
+#[test]
+#[serial]
+fn choose_blitter_for() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 10, 10)?;
+    assert_eq![
+        crate::plane::blitter_for_geom(
+            nc.cansixel(),
+            nc.cansextant(),
+            nc.canutf8(),
+            (10, 20),
+            (60, 40)
+        ),
+        plane.choose_blitter_for(10, 20, 40, 60)?
+    ];
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn animate_base() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 4, 4)?;
+
+    let mut colors: [NcChannelPair; 3] = [0; 3];
+    colors[0].set_bg_rgb(0x110000);
+    colors[1].set_bg_rgb(0x001100);
+    colors[2].set_bg_rgb(0x000011);
+
+    let mut base = NcCell::new();
+    for step in 0..6 {
+        plane.animate_base(&colors, step)?;
+        plane.base(&mut base)?;
+        assert_eq![colors[step % 3], base.channels];
+    }
+    assert![plane.animate_base(&[], 0).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_base_gradient() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 4, 6)?;
+    plane.cursor_move_yx(1, 2)?;
+
+    let mut ul: NcChannelPair = 0;
+    ul.set_bg_rgb(0xff0000);
+    let mut lr: NcChannelPair = 0;
+    lr.set_bg_rgb(0x0000ff);
+    assert_eq![24, plane.set_base_gradient(ul, ul, lr, lr)?];
+    // the cursor is preserved
+    assert_eq![(1, 2), plane.cursor_yx()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn perimeter_gradient() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 5, 8)?;
+
+    let corner = |rgb| {
+        let mut channels: NcChannelPair = 0;
+        channels.set_fg_rgb(rgb);
+        channels.set_bg_rgb(0);
+        channels
+    };
+    let (ul, ur, ll, lr) = (
+        corner(0xff0000),
+        corner(0x00ff00),
+        corner(0x0000ff),
+        corner(0xffffff),
+    );
+    plane.perimeter_gradient(ul, ur, ll, lr, 0, 0)?;
+
+    let mut cell = NcCell::new();
+    for &(y, x, channels) in &[(0, 0, ul), (0, 7, ur), (4, 0, ll), (4, 7, lr)] {
+        plane.ncplane_at_yx_cell(y, x, &mut cell)?;
+        assert_eq![channels.fg_rgb(), cell.channels.fg_rgb()];
+        assert_eq![channels.bg_rgb(), cell.channels.bg_rgb()];
+    }
+    // interpolated along the top side
+    plane.ncplane_at_yx_cell(0, 3, &mut cell)?;
+    assert![cell.channels.fg_rgb() != ul.fg_rgb()];
+    assert![cell.channels.fg_rgb() != ur.fg_rgb()];
+
+    // too small for a proper border
+    let small = NcPlane::new(nc, 0, 0, 2, 2)?;
+    assert![small.perimeter_gradient(ul, ur, ll, lr, 0, 0).is_err()];
+    let small = NcPlane::new(nc, 0, 0, 1, 1)?;
+    assert![small.perimeter_gradient(ul, ur, ll, lr, 0, 0).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_scroll_region() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 5, 6)?;
+    plane.putstr_yx(0, 0, "header")?;
+    plane.putstr_yx(4, 0, "footer")?;
+
+    let region = plane.set_scroll_region(1, 3)?;
+    assert_eq![(3, 6), region.dim_yx()];
+    assert_eq![(1, 0), region.yx()];
+    region.putstr("one\ntwo\nthree\nfour\nfive")?;
+
+    // only the band scrolled
+    let row = |plane: &NcPlane, y| plane.contents(y, 0, 1, 0, false, true);
+    assert_eq!["three", row(region, 0).trim_end()];
+    assert_eq!["four", row(region, 1).trim_end()];
+    assert_eq!["five", row(region, 2).trim_end()];
+    assert_eq!["header", row(plane, 0).trim_end()];
+    assert_eq!["footer", row(plane, 4).trim_end()];
+
+    // a region larger than the plane is clamped
+    let region = plane.set_scroll_region(2, 100)?;
+    assert_eq![(3, 6), region.dim_yx()];
+
+    assert![plane.set_scroll_region(3, 3).is_err()];
+    assert![plane.set_scroll_region(4, 2).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_aligned_at() -> crate::NcResult<()> {
+    use crate::{NcTextPlacement, NCALIGN_CENTER, NCALIGN_LEFT, NCALIGN_RIGHT};
+
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 3, 10)?;
+
+    let placement = |start, cols, clipped| NcTextPlacement {
+        start,
+        end: start + cols,
+        cols,
+        clipped,
+    };
+    assert_eq![
+        placement(0, 4, false),
+        plane.putstr_aligned_at(0, NCALIGN_LEFT, "abcd")?
+    ];
+    assert_eq![
+        placement(3, 4, false),
+        plane.putstr_aligned_at(1, NCALIGN_CENTER, "abcd")?
+    ];
+    assert_eq![
+        placement(6, 4, false),
+        plane.putstr_aligned_at(2, NCALIGN_RIGHT, "abcd")?
+    ];
+    assert_eq!["abcd", plane.contents(2, 6, 1, 4, false, false)];
+
+    // wide characters
+    assert_eq![
+        placement(6, 4, false),
+        plane.putstr_aligned_at(0, NCALIGN_RIGHT, "全角")?
+    ];
+
+    // clipped
+    assert_eq![
+        placement(0, 10, true),
+        plane.putstr_aligned_at(1, NCALIGN_RIGHT, "0123456789abc")?
+    ];
+    assert_eq!["0123456789", plane.contents(1, 0, 1, 10, false, false)];
+    assert_eq![
+        placement(0, 10, true),
+        plane.putstr_aligned_at(2, NCALIGN_CENTER, "全角全角全角")?
+    ];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn move_yx_abs() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let root = NcPlane::new(nc, 2, 3, 20, 20)?;
+    let parent = NcPlane::new_bound(root, 1, 1, 15, 15)?;
+    let child = NcPlane::new_bound(parent, 0, 0, 2, 2)?;
+
+    child.move_yx_abs(10, 12)?;
+    // relative to the parent, at an absolute 3, 4
+    assert_eq![(7, 8), child.yx()];
+    let (mut y, mut x) = (10, 12);
+    assert![unsafe { crate::ncplane_translate_abs(child, &mut y, &mut x) }];
+    assert_eq![(0, 0), (y, x)];
+
+    // a root plane has no parent offset
+    root.move_yx_abs(5, 6)?;
+    assert_eq![(5, 6), root.yx()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_ellipsis() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 6, 10)?;
+    let row = |plane: &NcPlane, y| plane.contents(y, 0, 1, 0, false, true);
+
+    // exact fit
+    plane.cursor_move_yx(0, 0)?;
+    assert_eq![5, plane.putstr_ellipsis(5, "abcde")?];
+    assert_eq!["abcde", row(plane, 0).trim_end()];
+
+    // over budget
+    plane.cursor_move_yx(1, 0)?;
+    assert_eq![5, plane.putstr_ellipsis(5, "abcdefgh")?];
+    assert_eq!["abcd…", row(plane, 1).trim_end()];
+
+    // a wide character that doesn't fit before the ellipsis
+    plane.cursor_move_yx(2, 0)?;
+    assert_eq![3, plane.putstr_ellipsis(4, "全角全角")?];
+    assert_eq!["全…", row(plane, 2).trim_end()];
+    plane.cursor_move_yx(3, 0)?;
+    assert_eq![5, plane.putstr_ellipsis(5, "全角全角")?];
+    assert_eq!["全角…", row(plane, 3).trim_end()];
+
+    // edge cases
+    plane.cursor_move_yx(4, 0)?;
+    assert_eq![0, plane.putstr_ellipsis(0, "abc")?];
+    assert_eq![(4, 0), plane.cursor_yx()];
+    assert_eq![1, plane.putstr_ellipsis(1, "abc")?];
+    assert_eq!["…", row(plane, 4).trim_end()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_styles_ncstyle() -> crate::NcResult<()> {
+    use crate::{NcStyle, NCSTYLE_BOLD, NCSTYLE_ITALIC};
+
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 2)?;
+
+    plane.set_styles((NcStyle::BOLD | NcStyle::ITALIC).bits());
+    assert_eq![NCSTYLE_BOLD | NCSTYLE_ITALIC, plane.styles()];
+    plane.off_styles(NcStyle::BOLD.bits());
+    assert_eq![NCSTYLE_ITALIC, plane.styles()];
+    plane.on_styles(NCSTYLE_BOLD);
+    assert_eq![NCSTYLE_BOLD | NCSTYLE_ITALIC, plane.styles()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn paint_with() -> crate::NcResult<()> {
+    use crate::NCSTYLE_BOLD;
+
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 3, 4)?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0x112233);
+    channels.set_bg_rgb(0x445566);
+
+    assert_eq![12, plane.paint_with(|_, _| (channels, '#', NCSTYLE_BOLD))?];
+    for y in 0..3 {
+        assert_eq!["####", plane.contents(y, 0, 1, 4, false, false)];
+        for x in 0..4 {
+            let (mut stylemask, mut cell_channels) = (0, 0);
+            plane.at_yx(y, x, &mut stylemask, &mut cell_channels)?;
+            assert_eq![NCSTYLE_BOLD, stylemask];
+            assert_eq![channels, cell_channels];
+        }
+    }
+    assert_eq![(0, 0), plane.cursor_yx()];
+
+    // wide glyphs occupy two cells
+    assert_eq![6, plane.paint_with(|_, _| (channels, '全', NCSTYLE_BOLD))?];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn error_kinds() -> crate::NcResult<()> {
+    use crate::NcErrorKind;
+
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 2)?;
+
+    let err = plane.cursor_move_yx(2, 0).unwrap_err();
+    assert_eq![NcErrorKind::BoundsError, err.kind()];
+    let err = plane.perimeter_gradient(0, 0, 0, 0, 0, 0).unwrap_err();
+    assert_eq![NcErrorKind::BoundsError, err.kind()];
+    let err = plane.putstr_aligned_at(0, 99, "a").unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+    let err = plane.animate_base(&[], 0).unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn column_animate() -> crate::NcResult<()> {
+    use crate::plane::column_row;
+
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 5, 6)?;
+
+    for frame in 0..3 {
+        let mut called = vec![];
+        plane.column_animate(
+            |x, f| {
+                called.push((x, f));
+                Some(('x', 0))
+            },
+            frame,
+        )?;
+        assert_eq![(0..6).map(|x| (x, frame)).collect::<Vec<_>>(), called];
+        for x in 0..6 {
+            let y = column_row(x, frame, 5);
+            assert_eq!["x", plane.contents(y, x, 1, 1, false, false)];
+        }
+        // each column advances one row per frame
+        assert_eq![
+            (column_row(3, frame, 5) + 1) % 5,
+            column_row(3, frame + 1, 5)
+        ];
+    }
+
+    // skipped columns & wide glyphs
+    plane.erase();
+    let mut called = vec![];
+    plane.column_animate(
+        |x, _| {
+            called.push(x);
+            match x {
+                0 => Some(('全', 0)),
+                3 => None,
+                _ => Some(('y', 0)),
+            }
+        },
+        7,
+    )?;
+    assert_eq![vec![0, 2, 3, 4, 5], called];
+    assert_eq![
+        "",
+        plane
+            .contents(column_row(3, 7, 5), 3, 1, 1, false, false)
+            .trim()
+    ];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_name() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 10, 10)?;
+    assert_eq![None, plane.name()];
+
+    plane.set_name("main");
+    assert_eq![Some("main".to_string()), plane.name()];
+    plane.set_name("renamed");
+    assert_eq![Some("renamed".to_string()), plane.name()];
+
+    // forgotten when destroyed
+    let child = NcPlane::new_bound(plane, 0, 0, 2, 2)?;
+    child.set_name("child");
+    let ptr = child as *const NcPlane;
+    child.destroy()?;
+    assert_eq![None, crate::plane::plane_name(ptr)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn pile_tree_string() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let root = NcPlane::new(nc, 0, 0, 10, 20)?;
+    root.set_name("root");
+    let a = NcPlane::new_bound(root, 1, 1, 5, 5)?;
+    a.set_name("a");
+    let a1 = NcPlane::new_bound(a, 1, 2, 2, 2)?;
+    a1.set_name("a1");
+    // unnamed, on top of the pile
+    let _b = NcPlane::new_bound(root, 6, 6, 2, 3)?;
+
+    assert_eq![
+        "root 10x20 @ 0,0\n  <unnamed #0> 2x3 @ 6,6\n  a 5x5 @ 1,1\n    a1 2x2 @ 1,2\n",
+        a1.pile_tree_string()
+    ];
+
+    // every pile is listed, starting with the standard one
+    let tree = nc.plane_tree_string();
+    assert_eq![5, tree.lines().count()];
+    assert![tree.starts_with("<unnamed #0>")];
+    assert![tree.ends_with(&a1.pile_tree_string())];
+
+    // the piles follow in the order they were created
+    let other = NcPlane::new(nc, 0, 0, 1, 1)?;
+    other.set_name("other");
+    let tree = nc.plane_tree_string();
+    assert_eq![6, tree.lines().count()];
+    assert![tree.ends_with("    a1 2x2 @ 1,2\nother 1x1 @ 0,0\n")];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn ansi_tokens_parsing() {
+    assert_eq![vec![AnsiToken::Text("plain")], ansi_tokens("plain")];
+    assert_eq![
+        vec![
+            AnsiToken::Sgr(vec![1, 31]),
+            AnsiToken::Text("red"),
+            AnsiToken::Sgr(vec![0]),
+        ],
+        ansi_tokens("\x1b[1;31mred\x1b[m")
+    ];
+    // non SGR sequences are skipped
+    assert_eq![
+        vec![
+            AnsiToken::Text("a"),
+            AnsiToken::Text("b"),
+            AnsiToken::Text("c")
+        ],
+        ansi_tokens("a\x1b[2Kb\x1b]8;;file:///tmp\x1b\\c")
+    ];
+    // a truncated sequence at the end is dropped
+    assert_eq![vec![AnsiToken::Text("end")], ansi_tokens("end\x1b[38;5")];
+    assert_eq![vec![AnsiToken::Text("end")], ansi_tokens("end\x1b")];
+}
+
+#[test]
+#[serial]
+fn putansi() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 40)?;
+    let (channels, styles) = (plane.channels(), plane.styles());
+
+    let cols = plane
+        .putansi("\x1b[1;31mred\x1b[0m plain \x1b[3;4;38;2;10;20;30mrgb\x1b[48;5;200mbg\x1b[")?;
+    assert_eq![15, cols];
+    // restored at the end
+    assert_eq![channels, plane.channels()];
+    assert_eq![styles, plane.styles()];
+
+    let (mut stylemask, mut cell_channels): (NcStyleMask, NcChannelPair) = (0, 0);
+
+    // bold, 16 colors as a palette index
+    assert_eq!['r', plane.at_yx(0, 0, &mut stylemask, &mut cell_channels)?];
+    assert_eq![NCSTYLE_BOLD, stylemask];
+    assert![cell_channels.fchannel() & NCCELL_BG_PALETTE != 0];
+    assert_eq![1, cell_channels.fg_rgb()];
+
+    // reset
+    plane.at_yx(0, 4, &mut stylemask, &mut cell_channels)?;
+    assert_eq![styles, stylemask];
+    assert_eq![channels, cell_channels];
+
+    // truecolor
+    plane.at_yx(0, 10, &mut stylemask, &mut cell_channels)?;
+    assert_eq![NCSTYLE_ITALIC | NCSTYLE_UNDERLINE, stylemask];
+    assert_eq![0x0a141e, cell_channels.fg_rgb()];
+
+    // 256 colors
+    plane.at_yx(0, 13, &mut stylemask, &mut cell_channels)?;
+    assert![cell_channels.bchannel() & NCCELL_BG_PALETTE != 0];
+    assert_eq![200, cell_channels.bg_rgb()];
+    assert_eq![0x0a141e, cell_channels.fg_rgb()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn markup_tokens_parsing() {
+    use MarkupTag::*;
+    use MarkupToken::*;
+
+    assert_eq![vec![Text("plain")], markup_tokens("plain").unwrap()];
+    assert_eq![
+        vec![
+            Open(Bold),
+            Text("a "),
+            Open(Fg(0xff0000)),
+            Text("b"),
+            Close,
+            Close,
+            Text(" c"),
+        ],
+        markup_tokens("<b>a <fg=ff0000>b</fg></b> c").unwrap()
+    ];
+    assert_eq![
+        vec![Open(Bg(0x00ff00)), Text("x"), Close],
+        markup_tokens("<bg=#00ff00>x</bg>").unwrap()
+    ];
+    // unknown tags and lone brackets are text
+    assert_eq![
+        vec![Text("1 < 2 <a>"), Open(Italic), Text(">"), Close],
+        markup_tokens("1 < 2 <a><i>></i>").unwrap()
+    ];
+
+    // malformed markup
+    let kind = |res: crate::NcResult<_>| res.unwrap_err().kind();
+    assert_eq![
+        NcErrorKind::InvalidInput,
+        kind(markup_tokens("<fg=red>x</fg>"))
+    ];
+    assert_eq![
+        NcErrorKind::InvalidInput,
+        kind(markup_tokens("<fg=fff>x</fg>"))
+    ];
+    assert_eq![
+        NcErrorKind::InvalidInput,
+        kind(markup_tokens("<b><i>x</b></i>"))
+    ];
+    assert_eq![NcErrorKind::InvalidInput, kind(markup_tokens("x</u>"))];
+    assert_eq![NcErrorKind::InvalidInput, kind(markup_tokens("<u>x"))];
+}
+
+#[test]
+#[serial]
+fn putmarkup() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 20)?;
+    plane.set_fg_rgb(0x808080);
+
+    assert_eq![5, plane.putmarkup("<b>a<fg=ff0000>b<i>c</i>d</fg>e</b>")?];
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq!['a', plane.at_yx(0, 0, &mut stylemask, &mut channels)?];
+    assert_eq![(NCSTYLE_BOLD, 0x808080), (stylemask, channels.fg_rgb())];
+    plane.at_yx(0, 1, &mut stylemask, &mut channels)?;
+    assert_eq![(NCSTYLE_BOLD, 0xff0000), (stylemask, channels.fg_rgb())];
+    plane.at_yx(0, 2, &mut stylemask, &mut channels)?;
+    assert_eq![
+        (NCSTYLE_BOLD | NCSTYLE_ITALIC, 0xff0000),
+        (stylemask, channels.fg_rgb())
+    ];
+    // each closing tag restores the state before its opening tag
+    plane.at_yx(0, 3, &mut stylemask, &mut channels)?;
+    assert_eq![(NCSTYLE_BOLD, 0xff0000), (stylemask, channels.fg_rgb())];
+    plane.at_yx(0, 4, &mut stylemask, &mut channels)?;
+    assert_eq![(NCSTYLE_BOLD, 0x808080), (stylemask, channels.fg_rgb())];
+
+    // the plane is left as it was
+    assert_eq![0, plane.styles()];
+    assert_eq![0x808080, plane.channels().fg_rgb()];
+
+    // nothing is written on a parse error
+    assert![plane.putmarkup("x<b>y").is_err()];
+    assert_eq![(0, 5), plane.cursor_yx()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn region_lens_convention() {
+    let dims = (10, 20);
+    // -1 goes through each edge
+    assert_eq![(10, 20), region_lens(dims, 0, 0, -1, -1).unwrap()];
+    assert_eq![(3, 5), region_lens(dims, 7, 15, -1, -1).unwrap()];
+    assert_eq![(1, 1), region_lens(dims, 9, 19, -1, -1).unwrap()];
+    assert_eq![(4, 20), region_lens(dims, 2, 0, 4, -1).unwrap()];
+    assert_eq![(10, 6), region_lens(dims, 0, 3, -1, 6).unwrap()];
+
+    // explicit lengths reaching each edge, and an empty region
+    assert_eq![(3, 5), region_lens(dims, 7, 15, 3, 5).unwrap()];
+    assert_eq![(0, 0), region_lens(dims, 7, 15, 0, 0).unwrap()];
+
+    // beyond the edges
+    let kind = |res: crate::NcResult<_>| res.unwrap_err().kind();
+    assert_eq![
+        NcErrorKind::BoundsError,
+        kind(region_lens(dims, 7, 15, 4, 5))
+    ];
+    assert_eq![
+        NcErrorKind::BoundsError,
+        kind(region_lens(dims, 7, 15, 3, 6))
+    ];
+    assert_eq![
+        NcErrorKind::BoundsError,
+        kind(region_lens(dims, 10, 0, -1, -1))
+    ];
+    assert_eq![
+        NcErrorKind::BoundsError,
+        kind(region_lens(dims, 0, 20, -1, -1))
+    ];
+
+    // only -1 is a valid negative length
+    assert_eq![
+        NcErrorKind::InvalidInput,
+        kind(region_lens(dims, 0, 0, -2, 1))
+    ];
+    assert_eq![
+        NcErrorKind::InvalidInput,
+        kind(region_lens(dims, 0, 0, 1, -5))
+    ];
+}
+
+#[test]
+#[serial]
+fn restyle_stain_erase_region() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 4, 10)?;
+    for y in 0..4 {
+        plane.putstr_yx(y, 0, "0123456789")?;
+    }
+    plane.cursor_move_yx(1, 1)?;
+
+    // the right half to bold, through the edges
+    assert_eq![20, plane.restyle_region(0, 5, -1, -1, NCSTYLE_BOLD)?];
+    assert_eq![(1, 1), plane.cursor_yx()];
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq!['4', plane.at_yx(3, 4, &mut stylemask, &mut channels)?];
+    assert_eq![0, stylemask];
+    assert_eq!['5', plane.at_yx(3, 5, &mut stylemask, &mut channels)?];
+    assert_eq![NCSTYLE_BOLD, stylemask];
+    assert_eq!['9', plane.at_yx(0, 9, &mut stylemask, &mut channels)?];
+    assert_eq![NCSTYLE_BOLD, stylemask];
+
+    // the bottom row
+    let mut red: NcChannelPair = 0;
+    red.set_bg_rgb(0xff0000);
+    assert_eq![10, plane.stain_region(3, 0, -1, -1, red, red, red, red)?];
+    plane.at_yx(3, 0, &mut stylemask, &mut channels)?;
+    assert_eq![0xff0000, channels.bg_rgb()];
+    plane.at_yx(2, 0, &mut stylemask, &mut channels)?;
+    assert![channels.bg_default_p()];
+
+    // the left column
+    assert_eq![4, plane.erase_region(0, 0, -1, 1)?];
+    assert_eq![(1, 1), plane.cursor_yx()];
+    assert_eq!['1', plane.at_yx(0, 1, &mut stylemask, &mut channels)?];
+
+    assert_eq![0, plane.restyle_region(0, 0, 0, -1, NCSTYLE_BOLD)?];
+    assert![plane.restyle_region(0, 5, 1, 6, NCSTYLE_BOLD).is_err()];
+    assert![plane.erase_region(4, 0, -1, -1).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn fill_region() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 4, 10)?;
+    let mut blue: NcChannelPair = 0;
+    blue.set_bg_rgb(0x0000ff);
+
+    assert_eq![40, plane.fill("░", blue, NCSTYLE_BOLD)?];
+    assert_eq![(0, 0), plane.cursor_yx()];
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq!['░', plane.at_yx(3, 9, &mut stylemask, &mut channels)?];
+    assert_eq![NCSTYLE_BOLD, stylemask];
+    assert_eq![0x0000ff, channels.bg_rgb()];
+
+    // a wide glyph in an odd width leaves a space in the last column
+    assert_eq![10, plane.fill_region(1, 2, 2, 5, "字", 0, 0)?];
+    assert_eq!['字', plane.at_yx(1, 2, &mut stylemask, &mut channels)?];
+    assert_eq!['字', plane.at_yx(2, 4, &mut stylemask, &mut channels)?];
+    assert_eq![' ', plane.at_yx(2, 6, &mut stylemask, &mut channels)?];
+    assert_eq!['░', plane.at_yx(2, 7, &mut stylemask, &mut channels)?];
+
+    assert_eq![0, plane.fill_region(0, 0, 0, -1, " ", 0, 0)?];
+    assert![plane.fill_region(0, 0, 1, 11, " ", 0, 0).is_err()];
+    assert![plane.fill("ab", 0, 0).is_err()];
+    assert![plane.fill("", 0, 0).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn dock_into() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let root = NcPlane::new(nc, 0, 0, 20, 60)?;
+    let sidebar = NcPlane::new_bound(root, 0, 40, 20, 20)?;
+    let _widget = NcPlane::new_bound(sidebar, 0, 0, 2, 20)?;
+    let panel = NcPlane::new_bound(root, 5, 55, 4, 8)?;
+    let child = NcPlane::new_bound(panel, 1, 1, 1, 4)?;
+
+    // the family goes right above the sidebar, below its widget
+    panel.dock_into(sidebar, NcDockPosition::Bottom)?;
+    assert_eq![sidebar as *const NcPlane, panel.parent_const()? as *const _];
+    assert_eq![panel as *mut NcPlane, sidebar.above()? as *mut _];
+    assert_eq![child as *mut NcPlane, panel.above()? as *mut _];
+    // the absolute position is kept, clamped within the sidebar,
+    // and the bound planes follow
+    assert_eq![(5, 12), panel.yx()];
+    assert_eq![(1, 1), child.yx()];
+    assert_eq![(6, 53), crate::plane::plane_abs_yx(child)];
+
+    panel.dock_into(root, NcDockPosition::Top)?;
+    assert_eq![child as *mut NcPlane, root.top() as *mut _];
+    assert_eq![panel as *mut NcPlane, child.below()? as *mut _];
+    assert_eq![(5, 52), panel.yx()];
+    assert_eq![(1, 1), child.yx()];
+
+    // from another pile
+    let floating = NcPlane::new(nc, 30, 0, 2, 2)?;
+    floating.dock_into(sidebar, NcDockPosition::Top)?;
+    assert_eq![floating as *mut NcPlane, root.top() as *mut _];
+    assert_eq![(18, 0), floating.yx()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn crossfade() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let from = NcPlane::new(nc, 0, 0, 2, 4)?;
+    let to = NcPlane::new(nc, 0, 0, 3, 3)?;
+    let onto = NcPlane::new(nc, 0, 0, 4, 5)?;
+
+    let mut black: NcChannelPair = 0;
+    black.set_fg_rgb(0x000000);
+    black.set_bg_rgb(0x000000);
+    let mut bright: NcChannelPair = 0;
+    bright.set_fg_rgb(0xffffff);
+    bright.set_bg_rgb(0x0000ff);
+    from.fill("a", black, NCSTYLE_BOLD)?;
+    to.fill("b", bright, 0)?;
+    onto.putstr_yx(0, 3, "x")?;
+
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    NcPlane::crossfade(from, to, onto, 0.25)?;
+    assert_eq!['a', onto.at_yx(1, 2, &mut stylemask, &mut channels)?];
+    assert_eq![NCSTYLE_BOLD, stylemask];
+    assert_eq![(0x404040, 0x000040), (channels.fg_rgb(), channels.bg_rgb())];
+
+    // the midpoint takes the glyph from the destination
+    NcPlane::crossfade(from, to, onto, 0.5)?;
+    assert_eq!['b', onto.at_yx(0, 0, &mut stylemask, &mut channels)?];
+    assert_eq![0, stylemask];
+    assert_eq![(0x808080, 0x000080), (channels.fg_rgb(), channels.bg_rgb())];
+
+    // only the overlap is written
+    assert_eq!['x', onto.at_yx(0, 3, &mut stylemask, &mut channels)?];
+    // and the cursor stays after the "x"
+    assert_eq![(0, 4), onto.cursor_yx()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn wrapped_rows() -> crate::NcResult<()> {
+    assert_eq![0, crate::plane::wrapped_rows("", 0, 10)?];
+    assert_eq![0, crate::plane::wrapped_rows("0123456789", 0, 10)?];
+    assert_eq![1, crate::plane::wrapped_rows("0123456789a", 0, 10)?];
+    assert_eq![1, crate::plane::wrapped_rows("6789a", 5, 10)?];
+    assert_eq![2, crate::plane::wrapped_rows("one\ntwo\n", 0, 10)?];
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_autogrow() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 10)?;
+    assert_eq![false, plane.set_autogrow(true)];
+    assert_eq![true, plane.set_autogrow(true)];
+
+    plane.putstr_yx(0, 0, "one\ntwo\nthree\nfour")?;
+    assert_eq![(4, 10), plane.dim_yx()];
+    assert_eq![(3, 4), plane.cursor_yx()];
+
+    plane.putstrln("")?;
+    assert_eq![(5, 10), plane.dim_yx()];
+    plane.putstr(&"x".repeat(25))?;
+    assert_eq![(7, 10), plane.dim_yx()];
+
+    let (mut stylemask, mut channels) = (0, 0);
+    assert_eq!['o', plane.at_yx(0, 0, &mut stylemask, &mut channels)?];
+    assert_eq!['x', plane.at_yx(6, 4, &mut stylemask, &mut channels)?];
+
+    // scrolling and autogrow are mutually exclusive
+    assert_eq![false, plane.set_scrolling(true)];
+    assert_eq![false, plane.set_autogrow(true)];
+    assert_eq![false, plane.set_scrolling(false)];
+
+    plane.destroy()?;
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn color_cells() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 3, 10)?;
+    for y in 0..3 {
+        plane.putstr_yx(y, 0, "0123456789")?;
+    }
+    plane.cursor_move_yx(2, 2)?;
+
+    let mut yellow: NcChannelPair = 0;
+    yellow.set_bg_rgb(0xffff00);
+    let coords = [(0, 1), (1, 5), (2, 9), (3, 0), (0, 10)];
+    assert_eq![3, plane.color_cells(&coords, yellow)?];
+    assert_eq![(2, 2), plane.cursor_yx()];
+
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    for &(y, x) in &coords[..3] {
+        let egc = plane.at_yx(y, x, &mut stylemask, &mut channels)?;
+        assert_eq![core::char::from_digit(x, 10), Some(egc)];
+        assert_eq![0xffff00, channels.bg_rgb()];
+    }
+    plane.at_yx(0, 0, &mut stylemask, &mut channels)?;
+    assert![channels.bg_default_p()];
+    plane.at_yx(1, 6, &mut stylemask, &mut channels)?;
+    assert![channels.bg_default_p()];
+
+    assert_eq![0, plane.color_cells(&[], yellow)?];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn scrollbar_thumb_geometry() {
+    // at both ends
+    assert_eq![(0, 1), scrollbar_thumb(10, 100, 10, 0)];
+    assert_eq![(9, 1), scrollbar_thumb(10, 100, 10, 90)];
+    // the offset is clamped to the end
+    assert_eq![(9, 1), scrollbar_thumb(10, 100, 10, 500)];
+    // half visible, in the middle
+    assert_eq![(5, 5), scrollbar_thumb(10, 100, 50, 50)];
+    // the thumb is at least a cell
+    assert_eq![(0, 1), scrollbar_thumb(10, 1000, 1, 0)];
+    // all visible
+    assert_eq![(0, 10), scrollbar_thumb(10, 5, 10, 0)];
+    assert_eq![(0, 10), scrollbar_thumb(10, 0, 0, 0)];
+    assert_eq![(0, 0), scrollbar_thumb(0, 100, 10, 0)];
+}
+
+#[test]
+#[serial]
+fn draw_scrollbar() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 5, 10)?;
+    plane.cursor_move_yx(1, 1)?;
+    let mut grey: NcChannelPair = 0;
+    grey.set_fg_rgb(0x808080);
+
+    plane.draw_scrollbar(NcScrollbarSide::Right, 10, 5, 5, grey)?;
+    assert_eq![(1, 1), plane.cursor_yx()];
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    let track: Vec<char> = (0..5)
+        .map(|y| plane.at_yx(y, 9, &mut stylemask, &mut channels))
+        .collect::<crate::NcResult<_>>()?;
+    assert_eq![vec!['░', '░', '█', '█', '█'], track];
+    assert_eq![0x808080, channels.fg_rgb()];
+
+    plane.draw_scrollbar(NcScrollbarSide::Top, 10, 20, 0, grey)?;
+    for x in 0..10 {
+        assert_eq!['█', plane.at_yx(0, x, &mut stylemask, &mut channels)?];
+    }
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn center_text() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let mut red: NcChannelPair = 0;
+    red.set_fg_rgb(0xff0000);
+
+    // odd dimensions
+    let odd = NcPlane::new(nc, 0, 0, 5, 11)?;
+    odd.center_text("abc", red, NCSTYLE_BOLD)?;
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq!['a', odd.at_yx(2, 4, &mut stylemask, &mut channels)?];
+    assert_eq![(NCSTYLE_BOLD, 0xff0000), (stylemask, channels.fg_rgb())];
+    assert_eq!['c', odd.at_yx(2, 6, &mut stylemask, &mut channels)?];
+    // the plane keeps its own style
+    assert_eq![0, odd.styles()];
+
+    // even dimensions
+    let even = NcPlane::new(nc, 0, 0, 4, 10)?;
+    even.center_text("abc", red, 0)?;
+    assert_eq!['a', even.at_yx(1, 3, &mut stylemask, &mut channels)?];
+    assert_eq!['c', even.at_yx(1, 5, &mut stylemask, &mut channels)?];
+
+    // wide characters
+    even.center_text("全角", red, 0)?;
+    assert_eq!['全', even.at_yx(1, 3, &mut stylemask, &mut channels)?];
+    assert_eq!['角', even.at_yx(1, 5, &mut stylemask, &mut channels)?];
+
+    // clipped
+    even.center_text("0123456789abc", red, 0)?;
+    assert_eq!["0123456789", even.contents(1, 0, 1, 10, false, false)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn text_box() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 6, 20)?;
+    // the empty cells are left out of the contents
+    let row = |plane: &NcPlane, y| plane.contents(y, 0, 1, 0, false, true);
+    let (mut stylemask, mut channels) = (0, 0);
+
+    plane.text_box("Title", "hello world, this is wrapped", 0)?;
+    assert_eq!["╭───── Title ──────╮", row(plane, 0)];
+    assert_eq!["│hello world,│", row(plane, 2)];
+    assert_eq!["│this is wrapped│", row(plane, 3)];
+    assert_eq!['h', plane.at_yx(2, 2, &mut stylemask, &mut channels)?];
+    assert_eq!['t', plane.at_yx(3, 2, &mut stylemask, &mut channels)?];
+    assert_eq!["╰──────────────────╯", row(plane, 5)];
+
+    // the title is truncated, and the body clipped
+    plane.text_box(
+        "A very long title indeed",
+        "one two three four five six seven",
+        0,
+    )?;
+    assert_eq!["╭ A very long tit… ╮", row(plane, 0)];
+    assert_eq!["│one two three│", row(plane, 2)];
+    assert_eq!["│four five six│", row(plane, 3)];
+    assert_eq!["││", row(plane, 4)];
+
+    let small = NcPlane::new(nc, 0, 0, 1, 20)?;
+    assert![small.text_box("Title", "body", 0).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn rtl_visual_order_runs() {
+    assert_eq!["םולש", rtl_visual_order("שלום")];
+    // the brackets are mirrored
+    assert_eq!["(םולש)!", rtl_visual_order("!(שלום)")];
+    // the left-to-right runs keep their order
+    assert_eq![
+        "notcurses לש 2.1.2 הסרג",
+        rtl_visual_order("גרסה 2.1.2 של notcurses")
+    ];
+    // the combining marks stay after their base character
+    assert_eq![
+        "\u{5DD}\u{5D5}\u{5B9}\u{5DC}\u{5E9}\u{5C1}\u{5B8}",
+        rtl_visual_order("\u{5E9}\u{5C1}\u{5B8}\u{5DC}\u{5D5}\u{5B9}\u{5DD}")
+    ];
+    // each line is reordered on its own
+    assert_eq!["אב\nדג", rtl_visual_order("בא\nגד")];
+    assert_eq!["", rtl_visual_order("")];
+}
+
+#[test]
+#[serial]
+fn putstr_rtl() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 20)?;
+    let (mut stylemask, mut channels) = (0, 0);
+
+    assert_eq![7, plane.putstr_rtl("שלום 42")?];
+    let cells: Result<String, _> = (0..7)
+        .map(|x| plane.at_yx(0, x, &mut stylemask, &mut channels))
+        .collect();
+    assert_eq!["42 םולש", cells?];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn histogram_bars_scale() {
+    assert_eq![vec![16, 8, 0, 4], histogram_bars(&[1.0, 0.5, 0.0, 0.25], 2)];
+    assert_eq![vec![3, 8], histogram_bars(&[3.0, 8.0], 1)];
+    // all equal
+    assert_eq![vec![16, 16], histogram_bars(&[3.0, 3.0], 2)];
+    assert_eq![vec![0, 0], histogram_bars(&[0.0, 0.0], 2)];
+    // negative and NaN values
+    assert_eq![vec![0, 0, 8], histogram_bars(&[-1.0, f64::NAN, 2.0], 1)];
+    assert![histogram_bars(&[], 2).is_empty()];
+}
+
+#[test]
+#[serial]
+fn draw_histogram() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 4)?;
+    let (mut stylemask, mut channels) = (0, 0);
+    let mut cell = |plane: &mut NcPlane, y, x| plane.at_yx(y, x, &mut stylemask, &mut channels);
+
+    // 16, 8, 5 and 0 eighths
+    plane.draw_histogram(&[1.0, 0.5, 0.3, 0.0], 0, true)?;
+    assert_eq![('█', '█'), (cell(plane, 0, 0)?, cell(plane, 1, 0)?)];
+    assert_eq!['█', cell(plane, 1, 1)?];
+    assert_eq!['▅', cell(plane, 1, 2)?];
+    // the empty cells are left out of the contents
+    assert_eq!["█", plane.contents(0, 0, 1, 0, false, true)];
+
+    // horizontal, the values beyond the plane's height are left out
+    plane.draw_histogram(&[0.53, 1.0, 2.0], 0, false)?;
+    assert_eq!['█', cell(plane, 0, 1)?];
+    assert_eq!['▏', cell(plane, 0, 2)?];
+    assert_eq!['█', cell(plane, 1, 3)?];
+
+    // an empty input clears the plane
+    plane.draw_histogram(&[], 0, true)?;
+    assert_eq!["", plane.contents(0, 0, 0, 0, true, true)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn mouse_areas() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 2, 4, 10, 20)?;
+
+    plane.add_mouse_area(0, 0, 3, 10, 1)?;
+    plane.add_mouse_area(5, 0, 2, 10, 2)?;
+    // overlapping the first one
+    plane.add_mouse_area(1, 5, 3, 10, 3)?;
+
+    // disjoint
+    assert_eq![Some(1), plane.hit_test(0, 0)];
+    assert_eq![Some(2), plane.hit_test(6, 9)];
+    assert_eq![None, plane.hit_test(6, 10)];
+    assert_eq![None, plane.hit_test(4, 0)];
+    // overlapping, the last added is on top
+    assert_eq![Some(3), plane.hit_test(2, 5)];
+    assert_eq![Some(1), plane.hit_test(2, 4)];
+
+    // the input coordinates are relative to the screen
+    let input = |y, x| crate::NcInput {
+        y,
+        x,
+        ..crate::NcInput::new_empty()
+    };
+    assert_eq![Some(1), plane.hit_test_input(&input(2, 4))];
+    assert_eq![None, plane.hit_test_input(&input(1, 4))];
+
+    assert_eq![1, plane.remove_mouse_area(3)];
+    assert_eq![Some(1), plane.hit_test(2, 5)];
+    plane.clear_mouse_areas();
+    assert_eq![None, plane.hit_test(0, 0)];
+
+    assert![plane.add_mouse_area(0, 0, 0, 10, 4).is_err()];
+
+    // destroyed by its widget, a plane at the same address gets no areas
+    let bar_plane = NcPlane::new_bound(plane, 0, 0, 1, 10)?;
+    bar_plane.add_mouse_area(0, 0, 1, 10, 5)?;
+    let address = bar_plane as *const NcPlane;
+    crate::NcProgBar::new(bar_plane).destroy();
+    assert_eq![None, crate::plane::mouse_area_at(address, 0, 0)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_rgb8_clipped() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 1)?;
+
+    assert![!plane.set_fg_rgb8_clipped(255, 0, 128)];
+    assert_eq![0xff0080, plane.fg_rgb()];
+    // 256 is clipped to 255, and negatives to 0
+    assert![plane.set_fg_rgb8_clipped(256, -1, 128)];
+    assert_eq![0xff0080, plane.fg_rgb()];
+    assert![plane.set_bg_rgb8_clipped(1000, 300, i32::MIN)];
+    assert_eq![0xffff00, plane.bg_rgb()];
+    assert![!plane.set_bg_rgb8_clipped(0, 0, 0)];
+    assert_eq![0, plane.bg_rgb()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn move_to_pile() -> crate::NcResult<()> {
+    use crate::plane::pile_planes;
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    let offscreen = NcPlane::new(nc, 0, 0, 10, 10)?;
+    let scene = NcPlane::new_bound(offscreen, 1, 1, 5, 5)?;
+    let child = NcPlane::new_bound(scene, 1, 1, 2, 2)?;
+    let (scene_ptr, child_ptr) = (scene as *mut NcPlane, child as *mut NcPlane);
+
+    assert![!pile_planes(stdplane).contains(&scene_ptr)];
+    scene.move_to_pile(stdplane)?;
+    // the family comes along
+    let planes = pile_planes(stdplane);
+    assert![planes.contains(&scene_ptr) && planes.contains(&child_ptr)];
+    assert_eq![vec![offscreen as *mut NcPlane], pile_planes(offscreen)];
+    assert_eq![(1, 1), scene.yx()];
+    // the bound planes belong to the new pile, at the same relative position
+    assert_eq![stdplane as *mut NcPlane, child.bottom() as *mut _];
+    assert_eq![(1, 1), child.yx()];
+    assert_eq![(2, 2), crate::plane::plane_abs_yx(child)];
+
+    // and back
+    scene.move_to_pile(offscreen)?;
+    assert![!pile_planes(stdplane).contains(&scene_ptr)];
+    assert![pile_planes(offscreen).contains(&child_ptr)];
+
+    assert_eq![
+        NcErrorKind::InvalidInput,
+        stdplane.move_to_pile(offscreen).unwrap_err().kind()
+    ];
+    // a plane can't be moved into its own family
+    assert![scene.move_to_pile(child).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn cell_pixels() {
+    use crate::plane::cell_pixels;
+
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0xff0000);
+    channels.set_bg_rgb(0x0000ff);
+    assert_eq![Some((0x0000ff, 0x0000ff)), cell_pixels(" ", channels)];
+    assert_eq![Some((0x0000ff, 0x0000ff)), cell_pixels("", channels)];
+    // the upper half block is drawn with the foreground color
+    assert_eq![Some((0xff0000, 0x0000ff)), cell_pixels("▀", channels)];
+    assert_eq![Some((0x0000ff, 0xff0000)), cell_pixels("▄", channels)];
+    assert_eq![Some((0xff0000, 0xff0000)), cell_pixels("█", channels)];
+    assert_eq![None, cell_pixels("x", channels)];
+}
+
+#[test]
+#[serial]
+fn as_rgba() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 3)?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0xff0000);
+    channels.set_bg_rgb(0x0000ff);
+    plane.set_channels(channels);
+    plane.putstr_yx(0, 0, "▀▄█")?;
+    plane.putstr_yx(1, 0, "   ")?;
+
+    let (rgba, height, width) = plane.as_rgba(crate::NCBLIT_2x1, 0, 0, -1, -1)?;
+    assert_eq![(4, 3), (height, width)];
+    assert_eq![4 * 3 * 4, rgba.len()];
+    let pixel = |y: usize, x: usize| &rgba[(y * 3 + x) * 4..(y * 3 + x) * 4 + 4];
+    let (red, blue): ([u8; 4], [u8; 4]) = ([0xff, 0, 0, 0xff], [0, 0, 0xff, 0xff]);
+    assert_eq![(&red, &blue), (pixel(0, 0), pixel(1, 0))];
+    assert_eq![(&blue, &red), (pixel(0, 1), pixel(1, 1))];
+    assert_eq![(&red, &red), (pixel(0, 2), pixel(1, 2))];
+    assert_eq![(&blue, &blue), (pixel(2, 0), pixel(3, 0))];
+
+    // a cell blitter with a lower resolution
+    let (rgba, height, width) = plane.as_rgba(crate::NCBLIT_1x1, 1, 0, 1, 2)?;
+    assert_eq![(1, 2), (height, width)];
+    assert_eq![vec![0, 0, 0xff, 0xff, 0, 0, 0xff, 0xff], rgba];
+    // keeping the upper half of the half blocks
+    let (rgba, _, _) = plane.as_rgba(crate::NCBLIT_1x1, 0, 0, 1, -1)?;
+    assert_eq![[red, blue, red].concat(), rgba];
+
+    assert_eq![
+        NcErrorKind::Unsupported,
+        plane
+            .as_rgba(crate::NCBLIT_SIXEL, 0, 0, -1, -1)
+            .unwrap_err()
+            .kind()
+    ];
+    plane.putstr_yx(1, 1, "x")?;
+    assert![plane.as_rgba(crate::NCBLIT_2x1, 0, 0, -1, -1).is_err()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn composite() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let dst = NcPlane::new(nc, 0, 0, 1, 6)?;
+    dst.putstr_yx(0, 0, "aaaaaa")?;
+
+    // the empty cells of the layers let the lower ones show through
+    let middle = NcPlane::new(nc, 0, 0, 1, 6)?;
+    middle.putstr_yx(0, 1, "bbbb")?;
+    let top = NcPlane::new(nc, 0, 2, 1, 2)?;
+    top.putstr_yx(0, 0, "c")?;
+    // only the overlap of a larger layer is composited,
+    // and its empty first column lets the "b" below show through
+    let offset = NcPlane::new(nc, 0, 4, 2, 10)?;
+    offset.putstr_yx(0, 1, "dddddddd")?;
+    offset.putstr_yx(1, 0, "eeeeeeeeee")?;
+
+    NcPlane::composite(dst, &[&*middle, &*top, &*offset])?;
+    assert_eq!["abcbbd", dst.contents(0, 0, 1, 0, false, true)];
+    // the layers are unchanged
+    assert_eq!["bbbb", middle.contents(0, 0, 1, 0, false, true)];
+
+    // with transparent backgrounds the color of the lower layer shows through
+    let mut blue: NcChannelPair = 0;
+    blue.set_bg_rgb(0x0000ff);
+    dst.set_channels(blue);
+    dst.putstr_yx(0, 0, "      ")?;
+    let glass = NcPlane::new(nc, 0, 0, 1, 6)?;
+    glass.set_bg_alpha(crate::NCCELL_ALPHA_TRANSPARENT)?;
+    glass.putstr_yx(0, 0, "xy")?;
+    NcPlane::composite(dst, &[&*glass])?;
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq!['x', dst.at_yx(0, 0, &mut stylemask, &mut channels)?];
+    assert_eq![0x0000ff, channels.bg_rgb()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn whitespace_tokens_split() {
+    assert_eq![
+        vec![
+            ("let", true),
+            (" ", false),
+            ("x", true),
+            ("  \t", false),
+            ("=", true),
+            (" ", false),
+            ("\"字\";", true),
+        ],
+        whitespace_tokens("let x  \t= \"字\";")
+    ];
+    assert_eq![vec![("  ", false)], whitespace_tokens("  ")];
+    assert_eq![
+        vec![(" ", false), ("a", true), (" ", false)],
+        whitespace_tokens(" a ")
+    ];
+    assert![whitespace_tokens("").is_empty()];
+}
+
+#[test]
+#[serial]
+fn putstr_tokens() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 20)?;
+    let (mut keyword, mut number): (NcChannelPair, NcChannelPair) = (0, 0);
+    keyword.set_fg_rgb(0xff8000);
+    number.set_fg_rgb(0x00ff00);
+    let mut active: NcChannelPair = 0;
+    active.set_bg_rgb(0x202020);
+    plane.set_channels(active);
+    plane.set_styles(NCSTYLE_ITALIC);
+
+    let classify = |token: &str| match token {
+        "let" => (keyword, NCSTYLE_BOLD),
+        t if t.parse::<i32>().is_ok() => (number, 0),
+        _ => (0, 0),
+    };
+    assert_eq![11, plane.putstr_tokens("let  x = 42", classify)?];
+    assert_eq!["let  x = 42", plane.contents(0, 0, 1, 0, false, true)];
+
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    let mut cell_at = |x| -> crate::NcResult<(NcStyleMask, NcChannelPair)> {
+        plane.at_yx(0, x, &mut stylemask, &mut channels)?;
+        Ok((stylemask, channels))
+    };
+    assert_eq![(NCSTYLE_BOLD, keyword), cell_at(0)?];
+    assert_eq![(NCSTYLE_BOLD, keyword), cell_at(2)?];
+    // the whitespace is written with the active attributes
+    assert_eq![(NCSTYLE_ITALIC, active), cell_at(3)?];
+    assert_eq![(NCSTYLE_ITALIC, active), cell_at(4)?];
+    assert_eq![(0, 0), cell_at(5)?];
+    assert_eq![(0, number), cell_at(10)?];
+
+    // the active attributes are restored
+    assert_eq![active, plane.channels()];
+    assert_eq![NCSTYLE_ITALIC, plane.styles()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn putstr_partial() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 5)?;
+
+    // stops before a wide glyph that doesn't fit in the last column
+    let string = "ab漢字";
+    let (cols, consumed) = plane.putstr_partial(string)?;
+    assert_eq![(4, 5), (cols, consumed)];
+    assert![string.is_char_boundary(consumed)];
+    assert_eq!["字", &string[consumed..]];
+    assert_eq![(0, 4), plane.cursor_yx()];
+
+    // a combining character is consumed together with its base
+    plane.cursor_move_yx(0, 2)?;
+    let string = "e\u{301}xyz";
+    assert_eq![(3, 5), plane.putstr_partial(string)?];
+    assert_eq!["z", &string[5..]];
+
+    // nothing fits
+    plane.cursor_move_yx(0, 4)?;
+    assert_eq![(0, 0), plane.putstr_partial("漢")?];
+
+    // stops before a newline
+    plane.cursor_move_yx(0, 0)?;
+    assert_eq![(2, 2), plane.putstr_partial("ab\ncd")?];
+    assert_eq![(0, 0), plane.putstr_partial("")?];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn boxes_sized() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 8, 10)?;
+    let row = |plane: &NcPlane, y| plane.contents(y, 0, 1, 0, false, true);
+
+    plane.cursor_move_yx(0, 1)?;
+    plane.rounded_box_sized(0, 0, 3, 5, 0)?;
+    assert_eq!["╭───╮", row(plane, 0)];
+    assert_eq!["││", row(plane, 1)];
+    assert_eq!["╰───╯", row(plane, 2)];
+    let (mut stylemask, mut channels) = (0, 0);
+    assert_eq!['╭', plane.at_yx(0, 1, &mut stylemask, &mut channels)?];
+    assert_eq!['╯', plane.at_yx(2, 5, &mut stylemask, &mut channels)?];
+
+    plane.cursor_move_yx(3, 0)?;
+    plane.double_box_sized(0, 0, 4, 4, 0)?;
+    assert_eq!["╔══╗", row(plane, 3)];
+    assert_eq!["║║", row(plane, 4)];
+    assert_eq!["║║", row(plane, 5)];
+    assert_eq!["╚══╝", row(plane, 6)];
+    assert_eq!['║', plane.at_yx(5, 3, &mut stylemask, &mut channels)?];
+
+    // a box needs its corners
+    plane.cursor_move_yx(0, 0)?;
+    let err = plane.rounded_box_sized(0, 0, 1, 5, 0).unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+    let err = plane.double_box_sized(0, 0, 3, 0, 0).unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn set_named() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 2)?;
+
+    plane.set_fg_named("Tomato")?;
+    plane.set_bg_named("navy")?;
+    assert_eq![0xff6347, plane.fg_rgb()];
+    assert_eq![0x000080, plane.bg_rgb()];
+
+    // unknown names leave the colors unchanged
+    let err = plane.set_fg_named("blurple").unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+    assert![plane.set_bg_named("").is_err()];
+    assert_eq![0xff6347, plane.fg_rgb()];
+    assert_eq![0x000080, plane.bg_rgb()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn plane_state_forgotten() -> crate::NcResult<()> {
+    use crate::plane::{mouse_area_at, plane_autogrow, plane_name};
+
+    let nc = Notcurses::new()?;
+    let stdplane = nc.stdplane()?;
+    stdplane.set_name("std");
+    let std_address = stdplane as *const NcPlane;
+
+    let plane = NcPlane::new(nc, 0, 0, 4, 4)?;
+    plane.set_name("old");
+    plane.set_autogrow(true);
+    plane.add_mouse_area(0, 0, 2, 2, 7)?;
+    let address = plane as *const NcPlane;
+    assert_eq![Some(7), mouse_area_at(address, 1, 1)];
+
+    // a new plane at the same address mustn't inherit anything
+    nc.drop_planes();
+    assert_eq![None, plane_name(address)];
+    assert![!plane_autogrow(address)];
+    assert_eq![None, mouse_area_at(address, 1, 1)];
+    // except the standard plane, which isn't dropped
+    assert_eq![Some("std".to_string()), plane_name(std_address)];
+
+    let bound = NcPlane::new_bound(stdplane, 0, 0, 2, 2)?;
+    bound.set_name("bound");
+    let bound_address = bound as *const NcPlane;
+    bound.destroy()?;
+    assert_eq![None, plane_name(bound_address)];
+
+    nc.stop()?;
+    assert_eq![None, plane_name(std_address)];
+    Ok(())
+}
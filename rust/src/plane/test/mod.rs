@@ -1,7 +1,28 @@
 //! `NcPlane` tests.
 
+#[cfg(test)]
+mod damage;
+
 #[cfg(test)]
 mod methods;
 
+#[cfg(all(test, feature = "png"))]
+mod png;
+
 #[cfg(test)]
 mod reimplemented;
+
+#[cfg(test)]
+mod scope;
+
+#[cfg(test)]
+mod scroll;
+
+#[cfg(test)]
+mod snapshot;
+
+#[cfg(test)]
+mod table;
+
+#[cfg(test)]
+mod viewport;
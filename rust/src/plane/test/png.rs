@@ -0,0 +1,38 @@
+//! Test saving an `NcPlane` as a PNG image.
+
+use crate::{NCBLIT_1x1, NCBLIT_2x1, NcChannelPair, NcChannelPairMethods, NcPlane, Notcurses};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn save_png() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 4)?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0x00ff00);
+    plane.set_channels(channels);
+    plane.putstr_yx(0, 0, "█▀▄ ")?;
+
+    let path = std::env::temp_dir().join("libnotcurses-sys-save_png.png");
+    let path = path.to_str().unwrap();
+    for &(blitter, height) in &[(NCBLIT_2x1, 4), (NCBLIT_1x1, 2)] {
+        if blitter == NCBLIT_1x1 {
+            plane.putstr_yx(0, 1, "  ")?;
+        }
+        plane.save_png(path, blitter)?;
+
+        let decoder = png::Decoder::new(std::fs::File::open(path).unwrap());
+        let (info, mut reader) = decoder.read_info().unwrap();
+        assert_eq![(4, height), (info.width, info.height)];
+        assert_eq![png::ColorType::RGBA, info.color_type];
+        let mut buf = vec![0; info.buffer_size()];
+        reader.next_frame(&mut buf).unwrap();
+        // the first pixel is the green full block
+        assert_eq![[0_u8, 0xff, 0, 0xff], &buf[0..4]];
+    }
+    std::fs::remove_file(path).unwrap();
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Test `NcStyleScope`.
+
+use crate::{
+    NcChannelPair, NcChannelPairMethods, NcPlane, NcStyleMask, Notcurses, NCSTYLE_BOLD,
+    NCSTYLE_NONE,
+};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn restores_on_drop() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 10)?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0x808080);
+    plane.set_channels(channels);
+
+    {
+        let mut scope = plane.style_scope();
+        scope.set_fg_rgb(0xff0000);
+        scope.set_bg_rgb(0x000040);
+        scope.on_styles(NCSTYLE_BOLD);
+        scope.putstr_yx(0, 0, "alert")?;
+        assert_eq![0xff0000, scope.channels().fg_rgb()];
+        assert_eq![NCSTYLE_BOLD, scope.styles()];
+
+        // nested scopes restore in turn
+        {
+            let mut inner = scope.style_scope();
+            inner.set_styles(NCSTYLE_NONE);
+        }
+        assert_eq![NCSTYLE_BOLD, scope.styles()];
+    }
+    assert_eq![channels, plane.channels()];
+    assert_eq![0, plane.styles()];
+
+    // what was written keeps its styling
+    let (mut stylemask, mut cell_channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    plane.at_yx(0, 0, &mut stylemask, &mut cell_channels)?;
+    assert_eq![
+        (NCSTYLE_BOLD, 0xff0000),
+        (stylemask, cell_channels.fg_rgb())
+    ];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! Test `NcScrollPlane` methods.
+
+use crate::{
+    NcChannelPair, NcChannelPairMethods, NcPlane, NcScrollPlane, NcStyleMask, Notcurses,
+    NCSTYLE_BOLD, NCSTYLE_NONE,
+};
+
+use serial_test::serial;
+
+/// Returns the visible rows of the plane, without trailing whitespace.
+fn rows(plane: &NcPlane) -> Vec<String> {
+    (0..plane.dim_y())
+        .map(|y| {
+            plane
+                .contents(y, 0, 1, 0, false, true)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+#[serial]
+fn scroll_back_forward() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 3, 10)?;
+    let mut scroll = NcScrollPlane::new(plane, 100);
+
+    for n in 0..6 {
+        scroll.push_line(&format!["line {}", n])?;
+    }
+    assert_eq![3, scroll.history_len()];
+    assert_eq![vec!["line 3", "line 4", "line 5"], rows(scroll.plane())];
+
+    scroll.scroll_back(2)?;
+    assert_eq![vec!["line 1", "line 2", "line 3"], rows(scroll.plane())];
+
+    // clamped to the oldest line
+    scroll.scroll_back(10)?;
+    assert_eq![3, scroll.offset()];
+    assert_eq![vec!["line 0", "line 1", "line 2"], rows(scroll.plane())];
+
+    scroll.scroll_forward(1)?;
+    assert_eq![vec!["line 1", "line 2", "line 3"], rows(scroll.plane())];
+
+    // a new line doesn't move a scrolled back view
+    scroll.push_line("line 6")?;
+    assert_eq![vec!["line 1", "line 2", "line 3"], rows(scroll.plane())];
+
+    scroll.scroll_to_bottom()?;
+    assert_eq![0, scroll.offset()];
+    assert_eq![vec!["line 4", "line 5", "line 6"], rows(scroll.plane())];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn max_history() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 10)?;
+    let mut scroll = NcScrollPlane::new(plane, 1);
+
+    for n in 0..5 {
+        scroll.push_line(&format!["line {}", n])?;
+    }
+    assert_eq![1, scroll.history_len()];
+    scroll.scroll_back(10)?;
+    assert_eq![vec!["line 2", "line 3"], rows(scroll.plane())];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn wrapped_lines() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 6)?;
+    let mut scroll = NcScrollPlane::new(plane, 10);
+
+    scroll.push_line("one two three")?;
+    assert_eq![1, scroll.history_len()];
+    assert_eq![vec!["two", "three"], rows(scroll.plane())];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn cells_retained() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 2, 10)?;
+    let mut scroll = NcScrollPlane::new(plane, 10);
+
+    let mut red: NcChannelPair = 0;
+    red.set_fg_rgb(0xff0000);
+    scroll.plane().set_styles(NCSTYLE_BOLD);
+    scroll.plane().set_channels(red);
+    scroll.push_line("red 全角")?;
+    scroll.plane().set_styles(NCSTYLE_NONE);
+    scroll.plane().set_channels(0);
+    for n in 0..3 {
+        scroll.push_line(&format!["line {}", n])?;
+    }
+
+    // scrolled back, the line keeps the styles, channels and wide glyphs
+    // it was written with
+    scroll.scroll_back(2)?;
+    assert_eq![vec!["red 全角", "line 0"], rows(scroll.plane())];
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    assert_eq![
+        'r',
+        scroll.plane().at_yx(0, 0, &mut stylemask, &mut channels)?
+    ];
+    assert_eq![(NCSTYLE_BOLD, 0xff0000), (stylemask, channels.fg_rgb())];
+    assert_eq![
+        '角',
+        scroll.plane().at_yx(0, 6, &mut stylemask, &mut channels)?
+    ];
+    assert_eq![(NCSTYLE_BOLD, 0xff0000), (stylemask, channels.fg_rgb())];
+    assert_eq![
+        'l',
+        scroll.plane().at_yx(1, 0, &mut stylemask, &mut channels)?
+    ];
+    assert_eq![(0, 0), (stylemask, channels)];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Test `NcPlaneSnapshot`.
+
+use crate::{NcChannelPair, NcChannelPairMethods, NcPlane, Notcurses, NCSTYLE_BOLD, NCSTYLE_NONE};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn snapshot_restore() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 3, 10)?;
+    let mut channels: NcChannelPair = 0;
+    channels.set_fg_rgb(0x40ff40);
+    plane.set_channels(channels);
+    plane.set_styles(NCSTYLE_BOLD);
+    plane.putstr_yx(1, 2, "before")?;
+
+    let snapshot = plane.snapshot()?;
+    assert_eq![(3, 10), snapshot.dim_yx()];
+    assert_eq![(1, 8), snapshot.cursor_yx()];
+    assert_eq![Some(("b", NCSTYLE_BOLD, channels)), snapshot.cell_at(1, 2)];
+    assert_eq![None, snapshot.cell_at(3, 0)];
+
+    plane.set_styles(NCSTYLE_NONE);
+    plane.set_channels(0);
+    plane.putstr_yx(0, 0, "after the edit")?;
+    plane.putstr_yx(2, 0, "more")?;
+
+    plane.restore(&snapshot)?;
+    assert_eq![snapshot, plane.snapshot()?];
+
+    // a plane of a different size is left untouched
+    let other = NcPlane::new(nc, 0, 0, 2, 10)?;
+    other.putstr_yx(0, 0, "other")?;
+    let other_snapshot = other.snapshot()?;
+    assert![other.restore(&snapshot).is_err()];
+    assert_eq![other_snapshot, other.snapshot()?];
+
+    nc.stop()?;
+    Ok(())
+}
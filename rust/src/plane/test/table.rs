@@ -0,0 +1,96 @@
+//! Test `NcTable` methods.
+
+use crate::{
+    plane::{table_fit_widths, table_width},
+    NcErrorKind, NcPlane, NcTable, Notcurses, NCALIGN_CENTER, NCALIGN_RIGHT,
+};
+
+use serial_test::serial;
+
+#[test]
+fn widths() {
+    assert_eq![0, table_width(&[])];
+    assert_eq![23, table_width(&[7, 9])];
+
+    assert_eq![Some(vec![7, 9]), table_fit_widths(&[7, 9], 23)];
+    // the widest column is narrowed first
+    assert_eq![Some(vec![7, 8]), table_fit_widths(&[7, 9], 22)];
+    assert_eq![Some(vec![4, 4]), table_fit_widths(&[7, 9], 15)];
+    assert_eq![Some(vec![1, 1]), table_fit_widths(&[7, 9], 9)];
+    assert_eq![None, table_fit_widths(&[7, 9], 8)];
+}
+
+#[test]
+#[serial]
+fn render() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 10, 30)?;
+    let row = |plane: &NcPlane, y| plane.contents(y, 0, 1, 0, false, true);
+
+    let table = NcTable::new(&["Key", "Value"])
+        .align(1, NCALIGN_RIGHT)
+        .row(&["name", "notcurses"])
+        .row(&["version", "2.1.2"])
+        .row(&["漢字"]);
+    assert_eq![3, table.len()];
+    assert_eq![(7, 23), table.dims()?];
+    assert_eq![(7, 23), plane.draw_table(&table, 0)?];
+
+    assert_eq!["┌─────────┬───────────┐", row(plane, 0)];
+    assert_eq!["│ Key     │     Value │", row(plane, 1)];
+    assert_eq!["├─────────┼───────────┤", row(plane, 2)];
+    assert_eq!["│ name    │ notcurses │", row(plane, 3)];
+    assert_eq!["│ version │     2.1.2 │", row(plane, 4)];
+    assert_eq!["└─────────┴───────────┘", row(plane, 6)];
+
+    // the separators are aligned despite the wide characters
+    let (mut stylemask, mut channels) = (0, 0);
+    for &x in &[0, 10, 22] {
+        assert_eq!['│', plane.at_yx(5, x, &mut stylemask, &mut channels)?];
+    }
+
+    let centered = NcTable::new(&["Centered"])
+        .align(0, NCALIGN_CENTER)
+        .row(&["ab"]);
+    plane.erase();
+    plane.draw_table(&centered, 0)?;
+    assert_eq!["│    ab    │", row(plane, 3)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn render_truncated_and_empty() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 6, 15)?;
+    let row = |plane: &NcPlane, y| plane.contents(y, 0, 1, 0, false, true);
+
+    let table = NcTable::new(&["Key", "Value"])
+        .row(&["name", "notcurses"])
+        .row(&["version", "2.1.2"]);
+    assert_eq![(6, 15), table.render(plane, 0)?];
+    assert_eq!["│ Key  │ Val… │", row(plane, 1)];
+    assert_eq!["│ name │ not… │", row(plane, 3)];
+    assert_eq!["│ ver… │ 2.1… │", row(plane, 4)];
+
+    // too narrow, or too short
+    let narrow = NcPlane::new(nc, 0, 0, 6, 8)?;
+    let err = table.render(narrow, 0).unwrap_err();
+    assert_eq![NcErrorKind::BoundsError, err.kind()];
+    let short = NcPlane::new(nc, 0, 0, 5, 30)?;
+    assert_eq![
+        NcErrorKind::BoundsError,
+        table.render(short, 0).unwrap_err().kind()
+    ];
+
+    // empty tables
+    assert![NcTable::new(&["Key"]).is_empty()];
+    assert_eq![(4, 7), NcTable::new(&["Key"]).render(plane, 0)?];
+    assert_eq![(0, 0), NcTable::new(&[]).render(plane, 0)?];
+    assert_eq![(0, 0), NcTable::new(&[]).dims()?];
+
+    nc.stop()?;
+    Ok(())
+}
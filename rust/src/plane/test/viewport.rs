@@ -0,0 +1,89 @@
+//! Test `NcViewport` methods.
+
+use crate::{plane::viewport_clamp, NcPlane, NcViewport, Notcurses};
+
+use serial_test::serial;
+
+#[test]
+fn clamp() {
+    assert_eq![0, viewport_clamp(-5, 100, 20)];
+    assert_eq![30, viewport_clamp(30, 100, 20)];
+    assert_eq![80, viewport_clamp(95, 100, 20)];
+    // content smaller than the view
+    assert_eq![0, viewport_clamp(3, 10, 20)];
+    assert_eq![0, viewport_clamp(0, 0, 0)];
+}
+
+#[test]
+#[serial]
+fn scrolling() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let content = NcPlane::new(nc, 0, 0, 10, 20)?;
+    for y in 0..10 {
+        content.putstr_yx(y, 0, &format!["{}abcdefghijklmnopqrs", y])?;
+    }
+    content.putstr_yx(9, 17, "end")?;
+    let view = NcPlane::new(nc, 0, 0, 3, 5)?;
+    let mut viewport = NcViewport::new(view, content)?;
+
+    assert_eq![(0, 0), viewport.offset_yx()];
+    assert_eq![(7, 15), viewport.max_offset_yx()];
+    assert_eq!["0abcd", viewport.view().contents(0, 0, 1, 0, false, true)];
+
+    viewport.scroll_to(2, 3)?;
+    assert_eq![(2, 3), viewport.offset_yx()];
+    assert_eq!["cdefg", viewport.view().contents(0, 0, 1, 0, false, true)];
+
+    // clamped at the edges of the content
+    viewport.scroll_by(100, 100)?;
+    assert_eq![(7, 15), viewport.offset_yx()];
+    assert_eq!["opend", viewport.view().contents(2, 0, 1, 0, false, true)];
+    viewport.scroll_by(-100, -1)?;
+    assert_eq![(0, 14), viewport.offset_yx()];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn content_smaller_than_view() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let content = NcPlane::new(nc, 0, 0, 2, 4)?;
+    content.putstr_yx(1, 0, "abcd")?;
+    let view = NcPlane::new(nc, 0, 0, 5, 10)?;
+    let mut viewport = NcViewport::new(view, content)?;
+
+    assert_eq![(0, 0), viewport.max_offset_yx()];
+    viewport.scroll_by(2, 2)?;
+    assert_eq![(0, 0), viewport.offset_yx()];
+    assert_eq!["abcd", viewport.view().contents(1, 0, 1, 0, false, true)];
+
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn content_shrunk() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let content = NcPlane::new(nc, 0, 0, 10, 20)?;
+    let view = NcPlane::new(nc, 0, 0, 3, 5)?;
+    let mut viewport = NcViewport::new(view, content)?;
+    viewport.scroll_to(7, 15)?;
+
+    // the offset is clamped again to the smaller content
+    viewport.content().resize_simple(4, 8)?;
+    viewport.content().putstr_yx(3, 0, "wxyz1234")?;
+    viewport.redraw()?;
+    assert_eq![(1, 3), viewport.offset_yx()];
+    assert_eq!["z1234", viewport.view().contents(2, 0, 1, 0, false, true)];
+
+    // and to zero when it's smaller than the view
+    viewport.content().resize_simple(2, 2)?;
+    viewport.redraw()?;
+    assert_eq![(0, 0), viewport.offset_yx()];
+
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! `NcViewport`
+
+use crate::{NcDimension, NcOffset, NcPlane, NcResult};
+
+/// A visible [NcPlane] showing a region of a larger content NcPlane,
+/// which can be scrolled around, e.g. to pan over a big image or table.
+///
+/// The planes in a pile are not clipped by their parents, so on each redraw
+/// the content plane is moved so that the visible region lies under the view
+/// plane, and it's merged down onto it. The content plane should therefore
+/// live in a pile that is not rendered, like the ones created with
+/// [NcPlane::new()][NcPlane#method.new].
+///
+/// The offsets are clamped so that the view doesn't go past the edges of the
+/// content. If the content is smaller than the view, it can't be scrolled.
+pub struct NcViewport<'a> {
+    view: &'a mut NcPlane,
+    content: &'a mut NcPlane,
+    // offset of the view over the content
+    y: NcDimension,
+    x: NcDimension,
+}
+
+/// # `NcViewport` constructors
+impl<'a> NcViewport<'a> {
+    /// New NcViewport showing the top left corner of `content` on `view`.
+    pub fn new(view: &'a mut NcPlane, content: &'a mut NcPlane) -> NcResult<Self> {
+        let mut viewport = Self {
+            view,
+            content,
+            y: 0,
+            x: 0,
+        };
+        viewport.redraw()?;
+        Ok(viewport)
+    }
+}
+
+/// # `NcViewport` methods
+impl<'a> NcViewport<'a> {
+    /// Returns a mutable reference to the view [NcPlane].
+    pub fn view(&mut self) -> &mut NcPlane {
+        &mut *self.view
+    }
+
+    /// Returns a mutable reference to the content [NcPlane].
+    ///
+    /// Call [redraw()][NcViewport#method.redraw] after modifying it.
+    pub fn content(&mut self) -> &mut NcPlane {
+        &mut *self.content
+    }
+
+    /// Returns the offset of the view over the content, as `(y, x)`.
+    pub fn offset_yx(&self) -> (NcDimension, NcDimension) {
+        (self.y, self.x)
+    }
+
+    /// Returns the maximum offset the view can be scrolled to, as `(y, x)`.
+    pub fn max_offset_yx(&self) -> (NcDimension, NcDimension) {
+        let (content_y, content_x) = self.content.dim_yx();
+        let (view_y, view_x) = self.view.dim_yx();
+        (
+            content_y.saturating_sub(view_y),
+            content_x.saturating_sub(view_x),
+        )
+    }
+
+    /// Scrolls the view so that its top left corner is over the content cell
+    /// at `y`, `x`, clamping it to the edges of the content.
+    pub fn scroll_to(&mut self, y: NcDimension, x: NcDimension) -> NcResult<()> {
+        self.move_to(y as i64, x as i64)
+    }
+
+    /// Scrolls the view by `dy` rows and `dx` columns, clamping it to the
+    /// edges of the content.
+    pub fn scroll_by(&mut self, dy: NcOffset, dx: NcOffset) -> NcResult<()> {
+        self.move_to(self.y as i64 + dy as i64, self.x as i64 + dx as i64)
+    }
+
+    /// Redraws the visible region of the content on the view plane.
+    ///
+    /// If the content or the view were resized, the offset is clamped again
+    /// to the edges of the content.
+    pub fn redraw(&mut self) -> NcResult<()> {
+        self.view.erase();
+        let (content_y, content_x) = self.content.dim_yx();
+        let (view_y, view_x) = self.view.dim_yx();
+        self.y = crate::plane::viewport_clamp(self.y as i64, content_y, view_y);
+        self.x = crate::plane::viewport_clamp(self.x as i64, content_x, view_x);
+        let len_y = content_y.saturating_sub(self.y).min(view_y);
+        let len_x = content_x.saturating_sub(self.x).min(view_x);
+        if len_y == 0 || len_x == 0 {
+            return Ok(());
+        }
+
+        // merging down is done according to the absolute positions
+        let (view_abs_y, view_abs_x) = crate::plane::plane_abs_yx(self.view);
+        self.content.move_yx_abs(
+            view_abs_y - self.y as NcOffset,
+            view_abs_x - self.x as NcOffset,
+        )?;
+        self.view
+            .mergedown(self.content, self.y, self.x, len_y, len_x, 0, 0)
+    }
+
+    // clamps the new offset, and redraws if it changed
+    fn move_to(&mut self, y: i64, x: i64) -> NcResult<()> {
+        let (content_y, content_x) = self.content.dim_yx();
+        let (view_y, view_x) = self.view.dim_yx();
+        let y = crate::plane::viewport_clamp(y, content_y, view_y);
+        let x = crate::plane::viewport_clamp(x, content_x, view_x);
+        if (y, x) == (self.y, self.x) {
+            return Ok(());
+        }
+        self.y = y;
+        self.x = x;
+        self.redraw()
+    }
+}
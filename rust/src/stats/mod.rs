@@ -0,0 +1,69 @@
+//! `NcStats`
+
+use core::time::Duration;
+
+use crate::Notcurses;
+
+mod rate;
+pub use rate::NcStatsRate;
+
+#[cfg(test)]
+mod test;
+
+/// notcurses runtime statistics
+pub type NcStats = crate::bindings::ffi::ncstats;
+
+/// # `NcStats` Methods.
+impl NcStats {
+    /// Allocates an NcStats object.
+    pub fn new<'a>(nc: &'a Notcurses) -> &'a mut Self {
+        unsafe { &mut *crate::notcurses_stats_alloc(nc) }
+    }
+
+    /// Acquires an atomic snapshot of the Notcurses object's stats.
+    pub fn stats(&mut self, nc: &Notcurses) {
+        unsafe { crate::notcurses_stats(nc, self) }
+    }
+
+    /// Resets all cumulative stats (immediate ones are not reset).
+    pub fn reset(&mut self, nc: &mut Notcurses) {
+        unsafe { crate::notcurses_stats_reset(nc, self) }
+    }
+
+    /// Returns the frames per second, from the rendered frames counter
+    /// accumulated `over` the provided wall time.
+    ///
+    /// Returns 0.0 for a zero duration.
+    pub fn fps(&self, over: Duration) -> f64 {
+        per_second(self.renders, over)
+    }
+
+    /// Returns the bytes emitted per second, from the rendered bytes counter
+    /// accumulated `over` the provided wall time.
+    ///
+    /// Returns 0.0 for a zero duration.
+    pub fn throughput_bytes_per_sec(&self, over: Duration) -> f64 {
+        per_second(self.render_bytes, over)
+    }
+}
+
+/// Returns how much a cumulative counter increased from `before` to `now`,
+/// counting from zero if it went backwards, e.g. after a stats reset.
+pub(crate) fn counted_since(now: u64, before: u64) -> u64 {
+    if now < before {
+        now
+    } else {
+        now - before
+    }
+}
+
+/// Returns the rate per second of `count` over the `over` duration,
+/// or 0.0 for a zero duration.
+pub(crate) fn per_second(count: u64, over: Duration) -> f64 {
+    let secs = over.as_secs_f64();
+    if secs > 0.0 {
+        count as f64 / secs
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,74 @@
+//! `NcStatsRate`
+
+use std::time::{Duration, Instant};
+
+use crate::{NcStats, Notcurses};
+
+/// Computes the rates of the [NcStats] cumulative counters between updates.
+///
+/// It keeps a baseline snapshot and its timestamp. Each call to
+/// [update()][NcStatsRate#method.update] takes a new snapshot, computes the
+/// rates over the elapsed interval, and makes it the new baseline.
+#[derive(Debug, Clone, Copy)]
+pub struct NcStatsRate {
+    baseline: NcStats,
+    timestamp: Instant,
+    fps: f64,
+    throughput: f64,
+}
+
+/// # `NcStatsRate` constructors
+impl NcStatsRate {
+    /// New NcStatsRate, taking the baseline snapshot now.
+    pub fn new(nc: &mut Notcurses) -> Self {
+        Self::with_baseline(nc.stats_snapshot(), Instant::now())
+    }
+
+    /// New NcStatsRate from a baseline snapshot taken at `timestamp`.
+    pub fn with_baseline(baseline: NcStats, timestamp: Instant) -> Self {
+        Self {
+            baseline,
+            timestamp,
+            fps: 0.0,
+            throughput: 0.0,
+        }
+    }
+}
+
+/// # `NcStatsRate` methods
+impl NcStatsRate {
+    /// Takes a new snapshot and computes the rates since the last update.
+    pub fn update(&mut self, nc: &mut Notcurses) {
+        self.update_with(nc.stats_snapshot(), Instant::now());
+    }
+
+    /// Computes the rates from a `snapshot` taken at `timestamp`, since the
+    /// baseline, and makes it the new baseline.
+    ///
+    /// If the counters went backwards (e.g. after a stats reset) the rates
+    /// are computed from zero, i.e. from the counts since the reset.
+    pub fn update_with(&mut self, snapshot: NcStats, timestamp: Instant) {
+        let over = timestamp.saturating_duration_since(self.timestamp);
+        let renders = crate::stats::counted_since(snapshot.renders, self.baseline.renders);
+        let bytes = crate::stats::counted_since(snapshot.render_bytes, self.baseline.render_bytes);
+        self.fps = crate::stats::per_second(renders, over);
+        self.throughput = crate::stats::per_second(bytes, over);
+        self.baseline = snapshot;
+        self.timestamp = timestamp;
+    }
+
+    /// Returns the frames per second during the last interval.
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// Returns the bytes emitted per second during the last interval.
+    pub fn throughput_bytes_per_sec(&self) -> f64 {
+        self.throughput
+    }
+
+    /// Returns the time elapsed since the last update.
+    pub fn elapsed(&self) -> Duration {
+        self.timestamp.elapsed()
+    }
+}
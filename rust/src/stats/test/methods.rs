@@ -0,0 +1,58 @@
+//! Test `NcStats` & `NcStatsRate` methods.
+
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::{NcStats, NcStatsRate};
+
+/// Returns a synthetic snapshot with the provided counters.
+fn snapshot(renders: u64, render_bytes: u64) -> NcStats {
+    let mut stats: NcStats = unsafe { core::mem::zeroed() };
+    stats.renders = renders;
+    stats.render_bytes = render_bytes;
+    stats
+}
+
+#[test]
+fn fps_throughput() {
+    let stats = snapshot(120, 4096);
+    assert_eq![60.0, stats.fps(Duration::from_secs(2))];
+    assert_eq![240.0, stats.fps(Duration::from_millis(500))];
+    assert_eq![
+        2048.0,
+        stats.throughput_bytes_per_sec(Duration::from_secs(2))
+    ];
+}
+
+#[test]
+fn zero_duration() {
+    let stats = snapshot(120, 4096);
+    assert_eq![0.0, stats.fps(Duration::from_secs(0))];
+    assert_eq![0.0, stats.throughput_bytes_per_sec(Duration::from_secs(0))];
+}
+
+#[test]
+fn rate_update() {
+    let start = Instant::now();
+    let mut rate = NcStatsRate::with_baseline(snapshot(10, 1000), start);
+    assert_eq![0.0, rate.fps()];
+
+    rate.update_with(snapshot(40, 4000), start + Duration::from_secs(1));
+    assert_eq![30.0, rate.fps()];
+    assert_eq![3000.0, rate.throughput_bytes_per_sec()];
+
+    // only the last interval counts
+    rate.update_with(snapshot(50, 4500), start + Duration::from_secs(3));
+    assert_eq![5.0, rate.fps()];
+    assert_eq![250.0, rate.throughput_bytes_per_sec()];
+
+    // zero-duration interval
+    rate.update_with(snapshot(60, 5000), start + Duration::from_secs(3));
+    assert_eq![0.0, rate.fps()];
+    assert_eq![0.0, rate.throughput_bytes_per_sec()];
+
+    // counters reset, counted from zero
+    rate.update_with(snapshot(5, 100), start + Duration::from_secs(4));
+    assert_eq![5.0, rate.fps()];
+    assert_eq![100.0, rate.throughput_bytes_per_sec()];
+}
@@ -0,0 +1,4 @@
+//! `NcStats` tests.
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,118 @@
+//! `NcTimeline` & `NcEasing`
+
+use core::time::Duration;
+
+#[cfg(test)]
+mod test;
+
+/// An easing function, that maps the linear progress of an animation
+/// between two keyframes to the progress of the animated value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcEasing {
+    /// Constant speed.
+    Linear,
+    /// Starts slowly and accelerates (quadratic).
+    EaseIn,
+    /// Starts quickly and decelerates (quadratic).
+    EaseOut,
+    /// Starts slowly, accelerates, and decelerates at the end (cubic).
+    EaseInOut,
+}
+
+impl Default for NcEasing {
+    fn default() -> Self {
+        NcEasing::Linear
+    }
+}
+
+/// # `NcEasing` Methods
+impl NcEasing {
+    /// Returns the eased progress for the linear progress `t`,
+    /// which is clamped to the `0.0..=1.0` range.
+    pub fn ease(&self, t: f64) -> f64 {
+        let t = t.max(0.0).min(1.0);
+        match self {
+            NcEasing::Linear => t,
+            NcEasing::EaseIn => t * t,
+            NcEasing::EaseOut => t * (2.0 - t),
+            NcEasing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// The keyframes of an animated value, which is interpolated between them.
+///
+/// Each keyframe has a time, measured from the start of the animation,
+/// a value, and the [NcEasing] used to reach it from the previous keyframe.
+///
+/// Before the first keyframe the value is the one of the first keyframe,
+/// and after the last one it's the one of the last keyframe.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NcTimeline {
+    // sorted by time, without duplicated times
+    keyframes: Vec<(Duration, f64, NcEasing)>,
+}
+
+/// # `NcTimeline` Constructors
+impl NcTimeline {
+    /// New NcTimeline, without keyframes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a keyframe with `value` at time `at`, reached from the previous
+    /// keyframe with `easing`.
+    ///
+    /// A keyframe at the same time as an existing one replaces it.
+    pub fn keyframe(mut self, at: Duration, value: f64, easing: NcEasing) -> Self {
+        match self
+            .keyframes
+            .binary_search_by_key(&at, |&(time, _, _)| time)
+        {
+            Ok(i) => self.keyframes[i] = (at, value, easing),
+            Err(i) => self.keyframes.insert(i, (at, value, easing)),
+        }
+        self
+    }
+}
+
+/// # `NcTimeline` Methods
+impl NcTimeline {
+    /// Returns the time of the last keyframe, or zero if there are none.
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .last()
+            .map_or(Duration::from_secs(0), |&(time, _, _)| time)
+    }
+
+    /// Returns true if the `elapsed` time is past the last keyframe.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration()
+    }
+
+    /// Returns the value at the `elapsed` time, interpolated between the
+    /// surrounding keyframes, or 0.0 if there are no keyframes.
+    pub fn value_at(&self, elapsed: Duration) -> f64 {
+        let next = match self
+            .keyframes
+            .iter()
+            .position(|&(time, _, _)| time > elapsed)
+        {
+            Some(next) => next,
+            None => return self.keyframes.last().map_or(0.0, |&(_, value, _)| value),
+        };
+        if next == 0 {
+            return self.keyframes[0].1;
+        }
+        let (from_time, from, _) = self.keyframes[next - 1];
+        let (to_time, to, easing) = self.keyframes[next];
+        let t = (elapsed - from_time).as_secs_f64() / (to_time - from_time).as_secs_f64();
+        from + (to - from) * easing.ease(t)
+    }
+}
@@ -0,0 +1,75 @@
+//! Test `NcTimeline` & `NcEasing` methods.
+
+use core::time::Duration;
+
+use crate::{NcEasing, NcTimeline};
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+fn assert_near(expected: f64, value: f64) {
+    assert![(expected - value).abs() < 1e-9, "{} != {}", expected, value];
+}
+
+#[test]
+fn easing() {
+    for easing in &[
+        NcEasing::Linear,
+        NcEasing::EaseIn,
+        NcEasing::EaseOut,
+        NcEasing::EaseInOut,
+    ] {
+        assert_near(0.0, easing.ease(0.0));
+        assert_near(1.0, easing.ease(1.0));
+        // the progress is clamped
+        assert_near(0.0, easing.ease(-1.0));
+        assert_near(1.0, easing.ease(2.0));
+    }
+    assert_near(0.25, NcEasing::Linear.ease(0.25));
+    assert_near(0.0625, NcEasing::EaseIn.ease(0.25));
+    assert_near(0.4375, NcEasing::EaseOut.ease(0.25));
+    assert_near(0.0625, NcEasing::EaseInOut.ease(0.25));
+    assert_near(0.5, NcEasing::EaseInOut.ease(0.5));
+    assert_near(0.9375, NcEasing::EaseInOut.ease(0.75));
+}
+
+#[test]
+fn value_at() {
+    let timeline = NcTimeline::new()
+        .keyframe(ms(100), 10.0, NcEasing::Linear)
+        .keyframe(ms(500), 50.0, NcEasing::Linear)
+        .keyframe(ms(900), 10.0, NcEasing::EaseInOut);
+    assert_eq![ms(900), timeline.duration()];
+
+    // before the first keyframe, and after the last one
+    assert_near(10.0, timeline.value_at(ms(0)));
+    assert_near(10.0, timeline.value_at(ms(2000)));
+    assert![timeline.is_finished(ms(900))];
+    assert![!timeline.is_finished(ms(899))];
+
+    // linear
+    assert_near(10.0, timeline.value_at(ms(100)));
+    assert_near(20.0, timeline.value_at(ms(200)));
+    assert_near(50.0, timeline.value_at(ms(500)));
+    // eased in and out
+    assert_near(47.5, timeline.value_at(ms(600)));
+    assert_near(30.0, timeline.value_at(ms(700)));
+    assert_near(12.5, timeline.value_at(ms(800)));
+}
+
+#[test]
+fn keyframes_order() {
+    // keyframes can be added in any order, and are replaced at the same time
+    let timeline = NcTimeline::new()
+        .keyframe(ms(1000), 100.0, NcEasing::EaseIn)
+        .keyframe(ms(0), 0.0, NcEasing::Linear)
+        .keyframe(ms(1000), 200.0, NcEasing::Linear);
+    assert_near(100.0, timeline.value_at(ms(500)));
+    assert_near(200.0, timeline.value_at(ms(1000)));
+
+    let empty = NcTimeline::new();
+    assert_near(0.0, empty.value_at(ms(10)));
+    assert_eq![ms(0), empty.duration()];
+    assert![empty.is_finished(ms(0))];
+}
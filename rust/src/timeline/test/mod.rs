@@ -0,0 +1,4 @@
+//! `NcTimeline` tests.
+
+#[cfg(test)]
+mod methods;
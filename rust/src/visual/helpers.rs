@@ -0,0 +1,253 @@
+use core::time::Duration;
+
+use crate::{
+    NCBLIT_1x1, NCBLIT_2x1, NCBLIT_2x2, NCBLIT_3x2, NCBLIT_4x1, NCBLIT_8x1, NcBackdropFit,
+    NcBlitter, NcDimension, NcError, NcErrorKind, NcFrameInfo, NcMediaKind, NcResult, NcRgb,
+    NcTime, NCBLIT_BRAILLE, NCBLIT_SIXEL, NCRESULT_ERR,
+};
+
+/// Helper function that lists the [NcBlitter]s supported with the
+/// capabilities of the terminal, from the lower to the higher resolution.
+///
+/// Used by [NcVisual.render_all_blitters()][crate::NcVisual#method.render_all_blitters].
+pub(crate) fn supported_blitters(pixel: bool, sextant: bool, utf8: bool) -> Vec<NcBlitter> {
+    let mut blitters = vec![NCBLIT_1x1];
+    if utf8 {
+        blitters.extend(&[NCBLIT_2x1, NCBLIT_2x2]);
+        if sextant {
+            blitters.push(NCBLIT_3x2);
+        }
+        blitters.extend(&[NCBLIT_4x1, NCBLIT_8x1, NCBLIT_BRAILLE]);
+    }
+    if pixel {
+        blitters.push(NCBLIT_SIXEL);
+    }
+    blitters
+}
+
+/// Helper function that returns the `(y, x, rows, cols)` of the largest area
+/// with the aspect ratio of `rows`×`cols`, centered in `avail_rows`×`avail_cols`.
+///
+/// Used by [NcVisual.render_letterboxed()][crate::NcVisual#method.render_letterboxed].
+pub(crate) fn letterbox_fit(
+    rows: NcDimension,
+    cols: NcDimension,
+    avail_rows: NcDimension,
+    avail_cols: NcDimension,
+) -> (NcDimension, NcDimension, NcDimension, NcDimension) {
+    if rows == 0 || cols == 0 {
+        return (0, 0, avail_rows, avail_cols);
+    }
+    let (rows, cols) = (rows as u64, cols as u64);
+    let (fit_rows, fit_cols) = if rows * avail_cols as u64 >= cols * avail_rows as u64 {
+        // relatively taller: bars on the sides
+        let fit_cols = (cols * avail_rows as u64 + rows / 2) / rows;
+        (avail_rows, (fit_cols as NcDimension).min(avail_cols))
+    } else {
+        // relatively wider: bars above and below
+        let fit_rows = (rows * avail_cols as u64 + cols / 2) / cols;
+        ((fit_rows as NcDimension).min(avail_rows), avail_cols)
+    };
+    (
+        (avail_rows - fit_rows) / 2,
+        (avail_cols - fit_cols) / 2,
+        fit_rows,
+        fit_cols,
+    )
+}
+
+/// Helper function that returns the `(y, x, len_y, len_x)` regions covered
+/// by copies of a `tile_rows`×`tile_cols` image in a `rows`×`cols` plane,
+/// clipped at its edges.
+///
+/// Used by [NcPlane.set_base_visual()][crate::NcPlane#method.set_base_visual].
+pub(crate) fn backdrop_regions(
+    tile_rows: NcDimension,
+    tile_cols: NcDimension,
+    rows: NcDimension,
+    cols: NcDimension,
+    fit: NcBackdropFit,
+) -> Vec<(NcDimension, NcDimension, NcDimension, NcDimension)> {
+    let mut regions = Vec::new();
+    if tile_rows == 0 || tile_cols == 0 {
+        return regions;
+    }
+    let (step_y, step_x) = match fit {
+        NcBackdropFit::Clip => (rows.max(tile_rows), cols.max(tile_cols)),
+        NcBackdropFit::Tile => (tile_rows, tile_cols),
+    };
+    for y in (0..rows).step_by(step_y as usize) {
+        for x in (0..cols).step_by(step_x as usize) {
+            regions.push((y, x, tile_rows.min(rows - y), tile_cols.min(cols - x)));
+        }
+    }
+    regions
+}
+
+/// Helper function that returns the [NcFrameInfo] of the frame number `index`,
+/// due to be displayed at `deadline`, given the deadlines of the `first` and
+/// the `previous` frames, in a stream running with `timescale`.
+///
+/// Used by [NcVisual.stream()][crate::NcVisual#method.stream].
+pub(crate) fn frame_info(
+    index: u64,
+    deadline: &NcTime,
+    first: &NcTime,
+    previous: Option<&NcTime>,
+    timescale: f32,
+) -> NcFrameInfo {
+    let nanos = |time: &NcTime| time.tv_sec as i128 * 1_000_000_000 + time.tv_nsec as i128;
+    let media_time = |from: &NcTime| {
+        let elapsed = (nanos(deadline) - nanos(from)).max(0) as f64;
+        Duration::from_nanos((elapsed / timescale as f64).round() as u64)
+    };
+    NcFrameInfo {
+        index,
+        pts: media_time(first),
+        duration: previous.map(media_time),
+    }
+}
+
+/// Helper function that resolves an image of palette indices into RGBA pixels,
+/// opaque and without padding.
+///
+/// The index of the pixel at `y`, `x` is the byte at `y * rowstride + x * pstride`
+/// of the `data`, and it must be lower than `palsize`.
+///
+/// Used by [NcVisual::from_palidx()][crate::NcVisual#method.from_palidx].
+pub(crate) fn palidx_to_rgba(
+    data: &[u8],
+    rows: NcDimension,
+    rowstride: NcDimension,
+    cols: NcDimension,
+    palsize: NcDimension,
+    pstride: NcDimension,
+    palette: &[NcRgb],
+) -> NcResult<Vec<u8>> {
+    let invalid = |msg: &str| NcError::with_kind(NCRESULT_ERR, NcErrorKind::InvalidInput, msg);
+    if rows == 0 || cols == 0 || pstride == 0 {
+        return Err(invalid("empty geometry"));
+    }
+    if rowstride < (cols - 1) * pstride + 1 {
+        return Err(invalid("rowstride shorter than a row"));
+    }
+    let (rows, rowstride, cols, pstride) = (
+        rows as usize,
+        rowstride as usize,
+        cols as usize,
+        pstride as usize,
+    );
+    if data.len() < (rows - 1) * rowstride + (cols - 1) * pstride + 1 {
+        return Err(invalid("data shorter than the geometry"));
+    }
+    if palsize as usize > palette.len() {
+        return Err(invalid("palsize larger than the palette"));
+    }
+
+    let mut rgba = Vec::with_capacity(rows * cols * 4);
+    for y in 0..rows {
+        for x in 0..cols {
+            let index = data[y * rowstride + x * pstride] as usize;
+            if index >= palsize as usize {
+                return Err(invalid("palette index out of range"));
+            }
+            let rgb = palette[index];
+            rgba.extend_from_slice(&[(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, 0xff]);
+        }
+    }
+    Ok(rgba)
+}
+
+/// Helper function that returns the size in pixels of the largest image with
+/// the aspect ratio of `y`×`x` pixels that fits in `max_rows`×`max_cols` cells,
+/// with a blitter of `toy`×`tox` pixels per cell.
+///
+/// Used by [NcVisual.resize_within()][crate::NcVisual#method.resize_within].
+pub(crate) fn fit_within(
+    y: NcDimension,
+    x: NcDimension,
+    max_rows: NcDimension,
+    max_cols: NcDimension,
+    toy: NcDimension,
+    tox: NcDimension,
+) -> NcResult<(NcDimension, NcDimension)> {
+    let invalid = |msg: &str| NcError::with_kind(NCRESULT_ERR, NcErrorKind::InvalidInput, msg);
+    if max_rows == 0 || max_cols == 0 {
+        return Err(invalid("empty bounding box"));
+    }
+    if y == 0 || x == 0 || toy == 0 || tox == 0 {
+        return Err(invalid("empty geometry"));
+    }
+    let (y, x) = (y as u64, x as u64);
+    let (max_y, max_x) = (max_rows as u64 * toy as u64, max_cols as u64 * tox as u64);
+    let (fit_y, fit_x) = if y * max_x >= x * max_y {
+        // relatively taller: limited by the height
+        (max_y, ((x * max_y + y / 2) / y).min(max_x))
+    } else {
+        // relatively wider: limited by the width
+        (((y * max_x + x / 2) / x).min(max_y), max_x)
+    };
+    Ok((fit_y.max(1) as NcDimension, fit_x.max(1) as NcDimension))
+}
+
+/// Helper function that returns the [NcMediaKind] of the media file at `path`,
+/// depending on whether it has `more_frames` after the first one.
+///
+/// The files with several frames are animations if their extension is the one
+/// of an image format, and videos otherwise.
+///
+/// Used by [Notcurses.display_media()][crate::Notcurses#method.display_media].
+pub(crate) fn media_kind(path: &str, more_frames: bool) -> NcMediaKind {
+    if !more_frames {
+        return NcMediaKind::Image;
+    }
+    let extension = std::path::Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("gif") | Some("apng") | Some("png") | Some("webp") => NcMediaKind::Animation,
+        _ => NcMediaKind::Video,
+    }
+}
+
+/// Helper function that returns the number of whole frames of
+/// `frame_h`×`frame_w` pixels that fit in a sprite sheet of
+/// `sheet_h`×`sheet_w` pixels, as `(rows, cols)`.
+///
+/// The pixels left over at the right and bottom edges are ignored.
+///
+/// Used by [NcSpriteSheet][crate::NcSpriteSheet].
+pub(crate) fn sprite_grid(
+    frame_h: NcDimension,
+    frame_w: NcDimension,
+    sheet_h: NcDimension,
+    sheet_w: NcDimension,
+) -> (NcDimension, NcDimension) {
+    if frame_h == 0 || frame_w == 0 {
+        return (0, 0);
+    }
+    (sheet_h / frame_h, sheet_w / frame_w)
+}
+
+/// Helper function that returns the origin in pixels of the frame at `index`,
+/// counting row by row, of a sprite sheet of `sheet_h`×`sheet_w` pixels with
+/// frames of `frame_h`×`frame_w` pixels, as `(y, x)`.
+///
+/// Returns None if the index is out of range.
+///
+/// Used by [NcSpriteSheet][crate::NcSpriteSheet].
+pub(crate) fn sprite_frame_origin(
+    index: usize,
+    frame_h: NcDimension,
+    frame_w: NcDimension,
+    sheet_h: NcDimension,
+    sheet_w: NcDimension,
+) -> Option<(NcDimension, NcDimension)> {
+    let (rows, cols) = sprite_grid(frame_h, frame_w, sheet_h, sheet_w);
+    if index >= (rows * cols) as usize {
+        return None;
+    }
+    let (row, col) = (index / cols as usize, index % cols as usize);
+    Some((row as NcDimension * frame_h, col as NcDimension * frame_w))
+}
@@ -0,0 +1,203 @@
+//! `NcMediaHandle`
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    NcBlitter, NcDecodeResult, NcError, NcErrorKind, NcPlane, NcResult, NcVisual, NcVisualOptions,
+    Notcurses, NCRESULT_ERR, NCSCALE_SCALE,
+};
+
+/// The kind of a media file, detected by
+/// [Notcurses.display_media()][crate::Notcurses#method.display_media].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcMediaKind {
+    /// A single frame, rendered once.
+    Image,
+    /// An animated image (e.g. a GIF), which loops.
+    Animation,
+    /// A video, which plays once.
+    Video,
+}
+
+/// A media file displayed in a plane, returned by
+/// [Notcurses.display_media()][crate::Notcurses#method.display_media].
+///
+/// The time-based media start playing right away, and advance with each call
+/// to [update()][NcMediaHandle#method.update], which must be done in a loop.
+///
+/// The visual is destroyed when the handle is dropped.
+#[derive(Debug)]
+pub struct NcMediaHandle<'a> {
+    path: String,
+    kind: NcMediaKind,
+    visual: &'a mut NcVisual,
+    plane: &'a mut NcPlane,
+    blitter: NcBlitter,
+    playing: bool,
+    // whether the visual must be reopened to play it from the start
+    rewind: bool,
+    frame_interval: Duration,
+    next_frame: Instant,
+}
+
+/// # `NcMediaHandle` constructors
+impl<'a> NcMediaHandle<'a> {
+    /// Opens the media file at `path`, detects its kind, and renders its first
+    /// frame into the `plane`, scaled to fit, with the `blitter`.
+    pub(crate) fn open(
+        nc: &mut Notcurses,
+        path: &str,
+        plane: &'a mut NcPlane,
+        blitter: NcBlitter,
+    ) -> NcResult<Self> {
+        let visual = open_first_frame(path)?;
+        // the second frame tells whether it's an image
+        let kind = match visual.decode() {
+            Ok(NcDecodeResult::FrameDecoded) => crate::visual::media_kind(path, true),
+            _ => crate::visual::media_kind(path, false),
+        };
+        let visual = if kind == NcMediaKind::Image {
+            visual
+        } else {
+            visual.destroy();
+            open_first_frame(path)?
+        };
+
+        let mut handle = Self {
+            path: path.to_string(),
+            kind,
+            visual,
+            plane,
+            blitter,
+            playing: kind != NcMediaKind::Image,
+            rewind: false,
+            frame_interval: Duration::from_millis(40),
+            next_frame: Instant::now(),
+        };
+        handle.render(nc)?;
+        handle.next_frame += handle.frame_interval;
+        Ok(handle)
+    }
+}
+
+/// # `NcMediaHandle` methods
+impl<'a> NcMediaHandle<'a> {
+    /// Returns the detected kind of media.
+    pub fn kind(&self) -> NcMediaKind {
+        self.kind
+    }
+
+    /// Returns true while a time-based media is playing.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Sets the interval between the frames of a time-based media,
+    /// 40 milliseconds by default.
+    ///
+    /// The frame rate of the media can't be queried in this version
+    /// of notcurses.
+    pub fn set_frame_interval(&mut self, interval: Duration) {
+        self.frame_interval = interval;
+    }
+
+    /// Starts or resumes playing a time-based media, from the start if it
+    /// was stopped, or a video had ended.
+    ///
+    /// It's a no-op for an image.
+    pub fn play(&mut self) -> NcResult<()> {
+        if self.kind == NcMediaKind::Image {
+            return Ok(());
+        }
+        if self.rewind {
+            let visual = open_first_frame(&self.path)?;
+            self.visual.destroy();
+            self.visual = visual;
+            self.rewind = false;
+        }
+        self.playing = true;
+        self.next_frame = Instant::now();
+        Ok(())
+    }
+
+    /// Pauses a time-based media on the current frame.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Stops a time-based media, so that it plays from the start next time.
+    ///
+    /// The current frame stays displayed.
+    pub fn stop(&mut self) {
+        self.playing = false;
+        self.rewind = self.kind != NcMediaKind::Image;
+    }
+
+    /// Advances a playing media to the next frame, and renders it into the
+    /// plane, if the frame interval has elapsed since the last one.
+    ///
+    /// A video stops at its end, while an animation starts over.
+    ///
+    /// Returns true if a new frame was rendered.
+    pub fn update(&mut self, nc: &mut Notcurses) -> NcResult<bool> {
+        let now = Instant::now();
+        if !self.playing || now < self.next_frame {
+            return Ok(false);
+        }
+        let decoded = match self.kind {
+            NcMediaKind::Animation => self.visual.decode_loop()?,
+            _ => self.visual.decode()?,
+        };
+        if decoded == NcDecodeResult::EndOfStream {
+            self.stop();
+            return Ok(false);
+        }
+        self.render(nc)?;
+        // doesn't try to catch up when late
+        self.next_frame = (self.next_frame + self.frame_interval).max(now);
+        Ok(true)
+    }
+
+    /// Renders the current frame again into the plane, e.g. after resizing it.
+    pub fn render(&mut self, nc: &mut Notcurses) -> NcResult<()> {
+        let options = NcVisualOptions::with_plane(
+            self.plane,
+            NCSCALE_SCALE,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            self.blitter,
+            0,
+        );
+        self.visual.render(nc, &options)?;
+        Ok(())
+    }
+}
+
+impl<'a> Drop for NcMediaHandle<'a> {
+    fn drop(&mut self) {
+        self.visual.destroy();
+    }
+}
+
+/// Opens the media file at `path` and decodes its first frame.
+fn open_first_frame<'a>(path: &str) -> NcResult<&'a mut NcVisual> {
+    let unsupported = || {
+        NcError::with_kind(
+            NCRESULT_ERR,
+            NcErrorKind::Unsupported,
+            &format!["unsupported or unreadable media file: {}", path],
+        )
+    };
+    let visual = NcVisual::from_file(path).map_err(|_| unsupported())?;
+    match visual.decode() {
+        Ok(NcDecodeResult::FrameDecoded) => Ok(visual),
+        _ => {
+            visual.destroy();
+            Err(unsupported())
+        }
+    }
+}
@@ -0,0 +1,493 @@
+//! `NcVisual*` methods and associated functions.
+
+use core::ffi::c_void;
+use core::ptr::null_mut;
+
+use crate::{
+    cstring, error, error_ref_mut, NcBlitter, NcChannelPair, NcDecodeResult, NcDimension, NcError,
+    NcErrorKind, NcFrameInfo, NcIntResult, NcOffset, NcPlane, NcRect, NcResult, NcRgb, NcScale,
+    NcTime, NcVisual, NcVisualOptions, Notcurses, NCRESULT_ERR, NCRESULT_OK, NCSCALE_NONE,
+    NCSCALE_STRETCH, NCVISUAL_OPTION_NODEGRADE,
+};
+
+/// # `NcVisualOptions` Constructors
+impl NcVisualOptions {
+    /// New NcVisualOptions that will render the visual into an existing `plane`.
+    ///
+    /// `y` and `x` specify where the visual will be rendered on the `plane`,
+    /// and the source is stretched/scaled relative to it.
+    ///
+    /// `beg_y`, `beg_x`, `len_y` & `len_x` specify the section of the visual
+    /// to render, in visual pixels. For the entire visual, pass 0 for all.
+    pub fn with_plane(
+        plane: &mut NcPlane,
+        scale: NcScale,
+        y: NcDimension,
+        x: NcDimension,
+        beg_y: NcDimension,
+        beg_x: NcDimension,
+        len_y: NcDimension,
+        len_x: NcDimension,
+        blitter: NcBlitter,
+        flags: u32,
+    ) -> Self {
+        Self {
+            // provided plane
+            n: plane,
+            // the scaling is ignored if no ncplane is provided
+            scaling: scale,
+            // where the visual will be rendered on the plane
+            y: y as i32,
+            x: x as i32,
+            // origin of rendered section
+            begy: beg_y as i32,
+            begx: beg_x as i32,
+            // size of rendered section
+            leny: len_y as i32,
+            lenx: len_x as i32,
+            // glyph set to use
+            blitter,
+            // bitmask over NCVISUAL_OPTION_*
+            flags: flags as u64,
+        }
+    }
+
+    /// New NcVisualOptions that will render the visual into a new plane,
+    /// having the exact size necessary to render the source with perfect
+    /// fidelity.
+    ///
+    /// `y` and `x` specify where the new plane will be placed, relative to the
+    /// standard plane's origin.
+    ///
+    /// `beg_y`, `beg_x`, `len_y` & `len_x` specify the section of the visual
+    /// to render, in visual pixels. For the entire visual, pass 0 for all.
+    pub fn without_plane(
+        y: NcDimension,
+        x: NcDimension,
+        beg_y: NcDimension,
+        beg_x: NcDimension,
+        len_y: NcDimension,
+        len_x: NcDimension,
+        blitter: NcBlitter,
+        flags: u32,
+    ) -> Self {
+        Self {
+            n: null_mut(),
+            scaling: NCSCALE_NONE,
+            y: y as i32,
+            x: x as i32,
+            begy: beg_y as i32,
+            begx: beg_x as i32,
+            leny: len_y as i32,
+            lenx: len_x as i32,
+            blitter,
+            flags: flags as u64,
+        }
+    }
+}
+
+/// # `NcVisual` Constructors & destructors
+impl NcVisual {
+    /// Opens a visual at `file`, extracts a codec and parameters,
+    /// and decodes the first image to memory.
+    ///
+    /// *C style function: [ncvisual_from_file()][crate::ncvisual_from_file].*
+    pub fn from_file<'a>(file: &str) -> NcResult<&'a mut NcVisual> {
+        let visual = unsafe { crate::ncvisual_from_file(cstring![file]) };
+        error_ref_mut![visual, &format!("NcVisual::from_file({})", file)]
+    }
+
+    /// Creates a visual from an image of palette indices.
+    ///
+    /// The index of the pixel at `y`, `x` is the byte at
+    /// `y * rowstride + x * pstride` of the `data`, and the color is
+    /// the entry of the `palette` at that index, which is opaque.
+    ///
+    /// Returns an error if the `data` is shorter than the geometry, if `palsize`
+    /// is larger than the `palette`, or if any index is not lower than `palsize`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_palidx<'a>(
+        data: &[u8],
+        rows: NcDimension,
+        rowstride: NcDimension,
+        cols: NcDimension,
+        palsize: NcDimension,
+        pstride: NcDimension,
+        palette: &[NcRgb],
+    ) -> NcResult<&'a mut NcVisual> {
+        let rgba =
+            crate::visual::palidx_to_rgba(data, rows, rowstride, cols, palsize, pstride, palette)?;
+        let visual = unsafe {
+            crate::ncvisual_from_rgba(
+                rgba.as_ptr() as *const c_void,
+                rows as i32,
+                cols as i32 * 4,
+                cols as i32,
+            )
+        };
+        error_ref_mut![visual, "NcVisual::from_palidx()"]
+    }
+
+    /// Destroys this NcVisual.
+    ///
+    /// Rendered elements will not be disrupted, but the visual can be neither
+    /// decoded nor rendered any further.
+    ///
+    /// *C style function: [ncvisual_destroy()][crate::ncvisual_destroy].*
+    pub fn destroy(&mut self) {
+        unsafe { crate::ncvisual_destroy(self) }
+    }
+}
+
+/// # `NcVisual` Methods
+impl NcVisual {
+    /// Extracts the next frame from this NcVisual.
+    ///
+    /// Returns [EndOfStream][NcDecodeResult::EndOfStream] once there are no
+    /// more frames. For a single-frame image this happens on the second call.
+    ///
+    /// *C style function: [ncvisual_decode()][crate::ncvisual_decode].*
+    pub fn decode(&mut self) -> NcResult<NcDecodeResult> {
+        NcDecodeResult::from_decode(unsafe { crate::ncvisual_decode(self) })
+    }
+
+    /// Extracts the next frame from this NcVisual, ala
+    /// [decode][NcVisual#method.decode], but if the end has been reached,
+    /// rewinds to the first frame.
+    ///
+    /// Returns [LoopedToStart][NcDecodeResult::LoopedToStart] when rewound.
+    /// A subsequent [render][NcVisual#method.render] will render the first
+    /// frame, as if the NcVisual had been closed and reopened.
+    ///
+    /// *C style function: [ncvisual_decode_loop()][crate::ncvisual_decode_loop].*
+    pub fn decode_loop(&mut self) -> NcResult<NcDecodeResult> {
+        NcDecodeResult::from_decode_loop(unsafe { crate::ncvisual_decode_loop(self) })
+    }
+
+    /// Returns the size in pixels of this NcVisual, and the number of pixels
+    /// rendered in each cell along each axis, for the blitter and scaling of
+    /// the `options`, as `(y, x, toy, tox)`.
+    ///
+    /// *C style function: [ncvisual_geom()][crate::ncvisual_geom].*
+    pub fn geom(
+        &self,
+        nc: &Notcurses,
+        options: &NcVisualOptions,
+    ) -> NcResult<(NcDimension, NcDimension, NcDimension, NcDimension)> {
+        let (mut y, mut x, mut toy, mut tox) = (0, 0, 0, 0);
+        let res =
+            unsafe { crate::ncvisual_geom(nc, self, options, &mut y, &mut x, &mut toy, &mut tox) };
+        error![
+            res,
+            (
+                y as NcDimension,
+                x as NcDimension,
+                toy as NcDimension,
+                tox as NcDimension
+            ),
+            "NcVisual.geom()"
+        ]
+    }
+
+    /// Resizes this NcVisual to `rows`×`cols` pixels, without preserving
+    /// the aspect ratio.
+    ///
+    /// It fails without a multimedia engine, unless the size doesn't change.
+    ///
+    /// *C style function: [ncvisual_resize()][crate::ncvisual_resize].*
+    pub fn resize(&mut self, rows: NcDimension, cols: NcDimension) -> NcResult<()> {
+        error![
+            unsafe { crate::ncvisual_resize(self, rows as i32, cols as i32) },
+            (),
+            "NcVisual.resize()"
+        ]
+    }
+
+    /// Resizes this NcVisual to the largest size that fits in `max_rows`×
+    /// `max_cols` cells when rendered with the `blitter`, preserving its
+    /// aspect ratio, and returns the resulting size in cells.
+    ///
+    /// The aspect ratio is preserved in the pixels of the blitter, as reported
+    /// by [geom()][NcVisual#method.geom], since the cells aren't square.
+    /// The visual is scaled up or down as needed.
+    ///
+    /// Returns an error if the bounding box is empty.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn resize_within(
+        &mut self,
+        nc: &Notcurses,
+        max_rows: NcDimension,
+        max_cols: NcDimension,
+        blitter: NcBlitter,
+    ) -> NcResult<(NcDimension, NcDimension)> {
+        let options = NcVisualOptions::without_plane(0, 0, 0, 0, 0, 0, blitter, 0);
+        let (y, x, toy, tox) = self.geom(nc, &options)?;
+        let (fit_y, fit_x) = crate::visual::fit_within(y, x, max_rows, max_cols, toy, tox)?;
+        self.resize(fit_y, fit_x)?;
+        Ok(((fit_y + toy - 1) / toy, (fit_x + tox - 1) / tox))
+    }
+
+    /// Renders the decoded frame according to the provided `options`.
+    ///
+    /// If no plane is provided in the `options`, a new one will be created,
+    /// having the exact size necessary to display the visual.
+    ///
+    /// Returns the plane to which the visual was drawn.
+    ///
+    /// *C style function: [ncvisual_render()][crate::ncvisual_render].*
+    pub fn render<'a>(
+        &mut self,
+        nc: &mut Notcurses,
+        options: &NcVisualOptions,
+    ) -> NcResult<&'a mut NcPlane> {
+        let plane = unsafe { crate::ncvisual_render(nc, self, options) };
+        error_ref_mut![plane, "NcVisual.render()"]
+    }
+
+    /// Blits the `src_crop` region of the decoded frame, in pixels, onto the
+    /// `plane`, with its upper-left corner at the cell `dst_yx`, without
+    /// scaling it.
+    ///
+    /// Returns an error if the crop is empty, or exceeds the bounds of the
+    /// visual.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn blit_region(
+        &mut self,
+        plane: &mut NcPlane,
+        src_crop: NcRect,
+        dst_yx: (NcDimension, NcDimension),
+        blitter: NcBlitter,
+    ) -> NcResult<()> {
+        let nc = plane.notcurses()?;
+        let geom_options = NcVisualOptions::without_plane(0, 0, 0, 0, 0, 0, blitter, 0);
+        let (rows, cols, _, _) = self.geom(nc, &geom_options)?;
+        if src_crop.is_empty() {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                "NcVisual.blit_region(): empty crop",
+            ));
+        }
+        if !NcRect::new(0, 0, rows, cols).contains(&src_crop) {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::BoundsError,
+                &format![
+                    "NcVisual.blit_region(): crop {:?} exceeds the {}x{} visual",
+                    src_crop, cols, rows
+                ],
+            ));
+        }
+        let options = NcVisualOptions::with_plane(
+            plane,
+            NCSCALE_NONE,
+            dst_yx.0,
+            dst_yx.1,
+            src_crop.y,
+            src_crop.x,
+            src_crop.len_y,
+            src_crop.len_x,
+            blitter,
+            0,
+        );
+        self.render(nc, &options)?;
+        Ok(())
+    }
+
+    /// Renders the decoded frame into the `plane` maintaining its aspect ratio,
+    /// centered, and fills the remaining area with `bar_channels`.
+    ///
+    /// The frame is scaled to the largest size that fits the plane, using the
+    /// [best blitter][Notcurses#method.best_blitter], into a temporary plane
+    /// which is merged down onto `plane` and destroyed, so it can be called
+    /// for each frame of a video. When the aspect ratios already match, no
+    /// bars are drawn and the frame covers the whole `plane`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_letterboxed(
+        &mut self,
+        nc: &mut Notcurses,
+        plane: &mut NcPlane,
+        bar_channels: NcChannelPair,
+    ) -> NcResult<()> {
+        let blitter = nc.best_blitter();
+        let options =
+            NcVisualOptions::with_plane(plane, NCSCALE_NONE, 0, 0, 0, 0, 0, 0, blitter, 0);
+        let (y, x, toy, tox) = self.geom(nc, &options)?;
+        let (toy, tox) = (toy.max(1), tox.max(1));
+        let (rows, cols) = ((y + toy - 1) / toy, (x + tox - 1) / tox);
+
+        let (avail_rows, avail_cols) = plane.dim_yx();
+        let (fit_y, fit_x, fit_rows, fit_cols) =
+            crate::visual::letterbox_fit(rows, cols, avail_rows, avail_cols);
+        if (fit_rows, fit_cols) != (avail_rows, avail_cols) {
+            plane.paint_with(|_, _| (bar_channels, ' ', 0))?;
+        } else {
+            plane.erase();
+        }
+
+        let image = NcPlane::new_bound(
+            plane,
+            fit_y as NcOffset,
+            fit_x as NcOffset,
+            fit_rows,
+            fit_cols,
+        )?;
+        let options =
+            NcVisualOptions::with_plane(image, NCSCALE_STRETCH, 0, 0, 0, 0, 0, 0, blitter, 0);
+        let res = self
+            .render(nc, &options)
+            .and_then(|_| plane.mergedown(image, 0, 0, fit_rows, fit_cols, fit_y, fit_x));
+        image.destroy()?;
+        res
+    }
+
+    /// Renders the decoded frame once with each [NcBlitter] supported by the
+    /// terminal, each into a new plane, e.g. in order to compare them.
+    ///
+    /// Returns the blitters along with the planes they were rendered into,
+    /// all of them placed at the origin of the standard plane, for the caller
+    /// to arrange them.
+    ///
+    /// Blitters not supported by the terminal are skipped, as well as those
+    /// that fail to render, instead of degrading to another blitter.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn render_all_blitters<'a>(
+        &mut self,
+        nc: &mut Notcurses,
+    ) -> NcResult<Vec<(NcBlitter, &'a mut NcPlane)>> {
+        let blitters =
+            crate::visual::supported_blitters(nc.cansixel(), nc.cansextant(), nc.canutf8());
+        let mut rendered = Vec::with_capacity(blitters.len());
+        for blitter in blitters {
+            let options = NcVisualOptions::without_plane(
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                blitter,
+                NCVISUAL_OPTION_NODEGRADE,
+            );
+            if let Ok(plane) = self.render(nc, &options) {
+                rendered.push((blitter, plane));
+            }
+        }
+        Ok(rendered)
+    }
+
+    /// Streams the entirety of the media, according to its own timing,
+    /// displaying each frame like
+    /// [ncvisual_simple_streamer()][crate::ncvisual_simple_streamer] does.
+    ///
+    /// Before each frame is displayed, the closure `f` is called with this
+    /// visual, the `options`, and the [NcFrameInfo] of the frame, e.g. in order
+    /// to synchronize the audio. The stream ceases when it returns false.
+    ///
+    /// `timescale` scales the duration of the frames: for a visual naturally
+    /// running at 30FPS, 0.1 results in 300FPS, and 10 in 3FPS. It's an error
+    /// to supply a timescale less than or equal to 0.
+    ///
+    /// Blocks until the stream ends. Returns true if the end of the media was
+    /// reached, and false if the closure ceased the stream.
+    ///
+    /// *C style function: [ncvisual_stream()][crate::ncvisual_stream].*
+    pub fn stream<F>(
+        &mut self,
+        nc: &mut Notcurses,
+        timescale: f32,
+        options: &NcVisualOptions,
+        f: F,
+    ) -> NcResult<bool>
+    where
+        F: FnMut(&mut NcVisual, &mut NcVisualOptions, NcFrameInfo) -> bool,
+    {
+        let mut state = StreamState {
+            f,
+            timescale,
+            first: None,
+            previous: None,
+            index: 0,
+        };
+        let res = unsafe {
+            crate::ncvisual_stream(
+                nc,
+                self,
+                timescale,
+                Some(stream_cb::<F>),
+                options,
+                &mut state as *mut StreamState<F> as *mut c_void,
+            )
+        };
+        error![res, res == NCRESULT_OK, "NcVisual.stream()"]
+    }
+}
+
+/// The state of [NcVisual.stream()][NcVisual#method.stream], passed as the
+/// curry to [stream_cb].
+struct StreamState<F> {
+    f: F,
+    timescale: f32,
+    first: Option<NcTime>,
+    previous: Option<NcTime>,
+    index: u64,
+}
+
+/// The streamcb of [NcVisual.stream()][NcVisual#method.stream].
+///
+/// Calls the closure with the [NcFrameInfo] of the frame, and then displays
+/// the frame, unless the closure ceases the stream.
+unsafe extern "C" fn stream_cb<F>(
+    visual: *mut NcVisual,
+    options: *mut NcVisualOptions,
+    deadline: *const NcTime,
+    curry: *mut c_void,
+) -> NcIntResult
+where
+    F: FnMut(&mut NcVisual, &mut NcVisualOptions, NcFrameInfo) -> bool,
+{
+    let state = &mut *(curry as *mut StreamState<F>);
+    let first = *state.first.get_or_insert(*deadline);
+    let info = crate::visual::frame_info(
+        state.index,
+        &*deadline,
+        &first,
+        state.previous.as_ref(),
+        state.timescale,
+    );
+    state.previous = Some(*deadline);
+    state.index += 1;
+
+    if !(state.f)(&mut *visual, &mut *options, info) {
+        return 1;
+    }
+    crate::ncvisual_simple_streamer(visual, options, deadline, null_mut())
+}
+
+/// # `NcDecodeResult` Methods
+impl NcDecodeResult {
+    /// Converts the [NcIntResult] returned by
+    /// [ncvisual_decode()][crate::ncvisual_decode].
+    pub(crate) fn from_decode(res: NcIntResult) -> NcResult<Self> {
+        match res {
+            NCRESULT_OK => Ok(NcDecodeResult::FrameDecoded),
+            1 => Ok(NcDecodeResult::EndOfStream),
+            _ => Err(NcError::with_msg(res, "NcVisual.decode()")),
+        }
+    }
+
+    /// Converts the [NcIntResult] returned by
+    /// [ncvisual_decode_loop()][crate::ncvisual_decode_loop].
+    pub(crate) fn from_decode_loop(res: NcIntResult) -> NcResult<Self> {
+        match res {
+            NCRESULT_OK => Ok(NcDecodeResult::FrameDecoded),
+            1 => Ok(NcDecodeResult::LoopedToStart),
+            _ => Err(NcError::with_msg(res, "NcVisual.decode_loop()")),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! `NcVisual`
+
+// functions already exported by bindgen : 18
+// -----------------------------------------
+// (W) wrap: 7
+// (#) test: 0
+// -----------------------------------------
+//   ncvisual_at_yx
+//W  ncvisual_decode
+//W  ncvisual_decode_loop
+//W  ncvisual_destroy
+//   ncvisual_from_bgra
+//W  ncvisual_from_file
+//   ncvisual_from_plane
+//   ncvisual_from_rgba
+//W  ncvisual_geom
+//   ncvisual_media_defblitter
+//   ncvisual_polyfill_yx
+//W  ncvisual_render
+//W  ncvisual_resize
+//   ncvisual_rotate
+//   ncvisual_set_yx
+//   ncvisual_simple_streamer
+//W  ncvisual_stream
+//   ncvisual_subtitle
+
+mod helpers;
+mod media;
+mod methods;
+mod sprite;
+
+#[cfg(test)]
+mod test;
+
+pub(crate) use helpers::*;
+pub use media::{NcMediaHandle, NcMediaKind};
+pub use sprite::NcSpriteSheet;
+
+/// How to scale an [`NcVisual`] during rendering
+///
+/// - NCSCALE_NONE will apply no scaling.
+/// - NCSCALE_SCALE scales a visual to the plane's size,
+///   maintaining aspect ratio.
+/// - NCSCALE_STRETCH stretches and scales the image in an
+///   attempt to fill the entirety of the plane.
+///
+pub type NcScale = crate::bindings::ffi::ncscale_e;
+
+/// Maintain original size.
+pub const NCSCALE_NONE: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_NONE;
+
+/// Maintain aspect ratio.
+pub const NCSCALE_SCALE: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_SCALE;
+
+/// Throw away aspect ratio.
+pub const NCSCALE_STRETCH: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_STRETCH;
+
+/// Maintain original size, admitting high-resolution blitters
+/// that don't preserve aspect ratio.
+pub const NCSCALE_NONE_HIRES: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_NONE_HIRES;
+
+/// Maintain aspect ratio, admitting high-resolution blitters
+/// that don't preserve aspect ratio.
+pub const NCSCALE_SCALE_HIRES: NcScale = crate::bindings::ffi::ncscale_e_NCSCALE_SCALE_HIRES;
+
+/// A visual bit of multimedia opened with LibAV|OIIO
+pub type NcVisual = crate::bindings::ffi::ncvisual;
+
+/// Options struct for [`NcVisual`]
+pub type NcVisualOptions = crate::bindings::ffi::ncvisual_options;
+
+/// Uses [`NCCELL_ALPHA_BLEND`][crate::NCCELL_ALPHA_BLEND] with visual.
+pub const NCVISUAL_OPTION_BLEND: u32 = crate::bindings::ffi::NCVISUAL_OPTION_BLEND;
+
+/// Fails rather than degrade.
+pub const NCVISUAL_OPTION_NODEGRADE: u32 = crate::bindings::ffi::NCVISUAL_OPTION_NODEGRADE;
+
+/// The result of decoding the next frame of an [`NcVisual`].
+///
+/// See [decode()][NcVisual#method.decode] &
+/// [decode_loop()][NcVisual#method.decode_loop].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcDecodeResult {
+    /// A new frame has been decoded.
+    FrameDecoded,
+    /// The end was reached and the visual was rewound to its first frame,
+    /// which has been decoded.
+    LoopedToStart,
+    /// The end was reached, there are no more frames to decode.
+    EndOfStream,
+}
+
+/// How a visual smaller or larger than a plane fills it, when used as its
+/// backdrop.
+///
+/// See [set_base_visual()][crate::NcPlane#method.set_base_visual].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NcBackdropFit {
+    /// Drawn once from the origin, clipped at the edges of the plane.
+    Clip,
+    /// Repeated from the origin until the plane is covered, clipped at the
+    /// edges of the plane.
+    Tile,
+}
+
+/// The timing of a frame streamed by [NcVisual.stream()][crate::NcVisual#method.stream].
+///
+/// It allows synchronizing an external source, like the audio of a video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NcFrameInfo {
+    /// The number of the frame, starting at 0.
+    pub index: u64,
+    /// The presentation timestamp, relative to the first frame,
+    /// in the time of the media, unaffected by the timescale.
+    pub pts: core::time::Duration,
+    /// The time elapsed since the presentation timestamp of the previous frame,
+    /// or None for the first one.
+    ///
+    /// The duration of a frame is only known once the next one is decoded,
+    /// so this is the duration of the previous frame, which is also the one
+    /// of this frame, unless the media has a variable frame rate.
+    pub duration: Option<core::time::Duration>,
+}
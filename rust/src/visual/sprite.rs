@@ -0,0 +1,126 @@
+//! `NcSpriteSheet`
+
+use crate::{
+    NcBlitter, NcDimension, NcError, NcErrorKind, NcPlane, NcRect, NcResult, NcVisual,
+    NcVisualOptions, Notcurses, NCBLIT_DEFAULT, NCRESULT_ERR,
+};
+
+/// An [NcVisual] holding a grid of equally sized frames, like the ones
+/// of an animated character, which can be blitted one at a time.
+///
+/// The frames are indexed row by row, starting at 0 on the top left.
+/// When the dimensions of the sheet are not a multiple of the frame size,
+/// the partial frames at the right and bottom edges are ignored.
+#[derive(Debug)]
+pub struct NcSpriteSheet<'a> {
+    visual: &'a mut NcVisual,
+    blitter: NcBlitter,
+    // size of a frame, in pixels
+    frame_h: NcDimension,
+    frame_w: NcDimension,
+    // size of the sheet, in pixels
+    sheet_h: NcDimension,
+    sheet_w: NcDimension,
+}
+
+/// # `NcSpriteSheet` constructors
+impl<'a> NcSpriteSheet<'a> {
+    /// New NcSpriteSheet from a `visual` with frames of
+    /// `frame_w`×`frame_h` pixels.
+    ///
+    /// The context `nc` is needed to query the size of the visual.
+    ///
+    /// Returns an error if not a single whole frame fits in the visual.
+    pub fn from_visual(
+        nc: &Notcurses,
+        visual: &'a mut NcVisual,
+        frame_w: NcDimension,
+        frame_h: NcDimension,
+    ) -> NcResult<Self> {
+        let options = NcVisualOptions::without_plane(0, 0, 0, 0, 0, 0, NCBLIT_DEFAULT, 0);
+        let (sheet_h, sheet_w, _, _) = visual.geom(nc, &options)?;
+        let (rows, cols) = crate::visual::sprite_grid(frame_h, frame_w, sheet_h, sheet_w);
+        if rows == 0 || cols == 0 {
+            return Err(NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::InvalidInput,
+                &format![
+                    "NcSpriteSheet::from_visual(): no {}x{} frame fits in a {}x{} sheet",
+                    frame_w, frame_h, sheet_w, sheet_h
+                ],
+            ));
+        }
+        Ok(Self {
+            visual,
+            blitter: NCBLIT_DEFAULT,
+            frame_h,
+            frame_w,
+            sheet_h,
+            sheet_w,
+        })
+    }
+}
+
+/// # `NcSpriteSheet` methods
+impl<'a> NcSpriteSheet<'a> {
+    /// Returns the number of frames.
+    pub fn frames(&self) -> usize {
+        let (rows, cols) =
+            crate::visual::sprite_grid(self.frame_h, self.frame_w, self.sheet_h, self.sheet_w);
+        (rows * cols) as usize
+    }
+
+    /// Returns the size of a frame in pixels, as `(height, width)`.
+    pub fn frame_size(&self) -> (NcDimension, NcDimension) {
+        (self.frame_h, self.frame_w)
+    }
+
+    /// Sets the [NcBlitter] used to blit the frames.
+    ///
+    /// It's [NCBLIT_DEFAULT] by default.
+    pub fn set_blitter(&mut self, blitter: NcBlitter) {
+        self.blitter = blitter;
+    }
+
+    /// Returns a mutable reference to the sheet [NcVisual].
+    pub fn visual(&mut self) -> &mut NcVisual {
+        &mut *self.visual
+    }
+
+    /// Blits the frame at `index` onto the `plane`, with its upper-left
+    /// corner at the cell `y`, `x`, without scaling it.
+    ///
+    /// Returns an error if the index is out of range.
+    pub fn blit_frame(
+        &mut self,
+        plane: &mut NcPlane,
+        index: usize,
+        y: NcDimension,
+        x: NcDimension,
+    ) -> NcResult<()> {
+        let (beg_y, beg_x) = crate::visual::sprite_frame_origin(
+            index,
+            self.frame_h,
+            self.frame_w,
+            self.sheet_h,
+            self.sheet_w,
+        )
+        .ok_or_else(|| {
+            NcError::with_kind(
+                NCRESULT_ERR,
+                NcErrorKind::BoundsError,
+                &format![
+                    "NcSpriteSheet.blit_frame(): index {} out of {} frames",
+                    index,
+                    self.frames()
+                ],
+            )
+        })?;
+        self.visual.blit_region(
+            plane,
+            NcRect::new(beg_y, beg_x, self.frame_h, self.frame_w),
+            (y, x),
+            self.blitter,
+        )
+    }
+}
@@ -0,0 +1,513 @@
+//! Test `NcVisual` methods and associated functions.
+
+use crate::visual::{
+    backdrop_regions, fit_within, frame_info, letterbox_fit, media_kind, palidx_to_rgba,
+    sprite_frame_origin, sprite_grid, supported_blitters,
+};
+use crate::{
+    NCBLIT_1x1, NCBLIT_2x1, NCBLIT_2x2, NCBLIT_3x2, NCBLIT_4x1, NCBLIT_8x1, NcBackdropFit,
+    NcChannelPair, NcChannelPairMethods, NcDecodeResult, NcErrorKind, NcPlane, NcRect,
+    NcSpriteSheet, NcStyleMask, NcTime, NcVisual, NcVisualOptions, Notcurses, NCBLIT_BRAILLE,
+    NCBLIT_SIXEL,
+};
+
+use core::time::Duration;
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn decode_result() {
+    assert_eq![
+        NcDecodeResult::FrameDecoded,
+        NcDecodeResult::from_decode(0).unwrap()
+    ];
+    assert_eq![
+        NcDecodeResult::EndOfStream,
+        NcDecodeResult::from_decode(1).unwrap()
+    ];
+    assert![NcDecodeResult::from_decode(-1).is_err()];
+}
+
+#[test]
+#[serial]
+fn decode_loop_result() {
+    assert_eq![
+        NcDecodeResult::FrameDecoded,
+        NcDecodeResult::from_decode_loop(0).unwrap()
+    ];
+    assert_eq![
+        NcDecodeResult::LoopedToStart,
+        NcDecodeResult::from_decode_loop(1).unwrap()
+    ];
+    assert![NcDecodeResult::from_decode_loop(-1).is_err()];
+}
+
+#[test]
+fn supported_blitters_by_capability() {
+    // ASCII only
+    assert_eq![vec![NCBLIT_1x1], supported_blitters(false, false, false)];
+    // sextants need UTF-8
+    assert_eq![vec![NCBLIT_1x1], supported_blitters(false, true, false)];
+
+    let utf8 = supported_blitters(false, false, true);
+    assert_eq![
+        vec![
+            NCBLIT_1x1,
+            NCBLIT_2x1,
+            NCBLIT_2x2,
+            NCBLIT_4x1,
+            NCBLIT_8x1,
+            NCBLIT_BRAILLE
+        ],
+        utf8
+    ];
+    assert![!utf8.contains(&NCBLIT_3x2)];
+    assert![!utf8.contains(&NCBLIT_SIXEL)];
+
+    let all = supported_blitters(true, true, true);
+    assert![all.contains(&NCBLIT_3x2)];
+    assert_eq![Some(&NCBLIT_SIXEL), all.last()];
+}
+
+#[test]
+fn letterbox_fit_areas() {
+    // 16:9 into 4:3, bars above and below
+    assert_eq![(3, 0, 23, 40), letterbox_fit(9, 16, 30, 40)];
+    // same aspect ratio, no bars
+    assert_eq![(0, 0, 30, 40), letterbox_fit(15, 20, 30, 40)];
+    assert_eq![(0, 0, 30, 40), letterbox_fit(30, 40, 30, 40)];
+    // taller, bars on the sides
+    assert_eq![(0, 17, 10, 5), letterbox_fit(20, 10, 10, 40)];
+    // an empty source takes the whole area
+    assert_eq![(0, 0, 10, 40), letterbox_fit(0, 10, 10, 40)];
+}
+
+#[test]
+#[serial]
+fn render_letterboxed() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 20, 20)?;
+
+    // a very wide image
+    let (rows, cols) = (8, 64);
+    let rgba = vec![0xff_00_80_ffu32; rows * cols];
+    let visual = unsafe {
+        &mut *crate::ncvisual_from_rgba(
+            rgba.as_ptr() as *const _,
+            rows as i32,
+            cols as i32 * 4,
+            cols as i32,
+        )
+    };
+
+    let mut bar: NcChannelPair = 0;
+    bar.set_bg_rgb(0x203040);
+    // called again, e.g. for each frame of a video, it doesn't leave any
+    // plane behind in the pile
+    for _ in 0..2 {
+        visual.render_letterboxed(nc, plane, bar)?;
+        assert![plane.below().is_err()];
+        assert![plane.above().is_err()];
+    }
+
+    // the bars carry the bar color
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    plane.at_yx(0, 0, &mut stylemask, &mut channels)?;
+    assert_eq![0x203040, channels.bg_rgb()];
+    plane.at_yx(19, 19, &mut stylemask, &mut channels)?;
+    assert_eq![0x203040, channels.bg_rgb()];
+
+    // the image fills the width, centered between the bars
+    let mut image_rows = vec![];
+    for y in 0..20 {
+        plane.at_yx(y, 10, &mut stylemask, &mut channels)?;
+        if channels.bg_rgb() != 0x203040 {
+            image_rows.push(y);
+            plane.at_yx(y, 0, &mut stylemask, &mut channels)?;
+            assert_ne![0x203040, channels.bg_rgb()];
+        }
+    }
+    let (first, last) = (image_rows[0], image_rows[image_rows.len() - 1]);
+    assert_eq![(last - first + 1) as usize, image_rows.len()];
+    assert![image_rows.len() < 20];
+    assert![(first as i32 - (19 - last) as i32).abs() <= 1];
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn backdrop_regions_fit() {
+    // smaller than the plane
+    assert_eq![
+        vec![(0, 0, 2, 3)],
+        backdrop_regions(2, 3, 4, 5, NcBackdropFit::Clip)
+    ];
+    assert_eq![
+        vec![(0, 0, 2, 3), (0, 3, 2, 2), (2, 0, 2, 3), (2, 3, 2, 2)],
+        backdrop_regions(2, 3, 4, 5, NcBackdropFit::Tile)
+    ];
+
+    // larger than the plane, clipped either way
+    assert_eq![
+        vec![(0, 0, 4, 5)],
+        backdrop_regions(10, 10, 4, 5, NcBackdropFit::Clip)
+    ];
+    assert_eq![
+        vec![(0, 0, 4, 5)],
+        backdrop_regions(10, 10, 4, 5, NcBackdropFit::Tile)
+    ];
+
+    // an empty image
+    assert![backdrop_regions(0, 3, 4, 5, NcBackdropFit::Tile).is_empty()];
+}
+
+#[test]
+#[serial]
+fn set_base_visual() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new_bound(nc.stdplane()?, 0, 0, 4, 8)?;
+
+    // a 2x2 red image, tiled
+    let rgba = vec![0xff_00_00_ffu32; 4];
+    let visual = unsafe { &mut *crate::ncvisual_from_rgba(rgba.as_ptr() as *const _, 2, 8, 2) };
+    let backdrop = plane.set_base_visual(nc, visual, NCBLIT_1x1, NcBackdropFit::Tile)?;
+    assert_eq![(4, 8), backdrop.dim_yx()];
+    assert_eq![(0, 0), backdrop.yx()];
+
+    plane.putstr_yx(2, 2, "x")?;
+    nc.render()?;
+
+    // both the empty cells and the text reveal the image
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    nc.at_yx(3, 3, &mut stylemask, &mut channels);
+    assert_eq![0xff0000, channels.bg_rgb()];
+    assert_eq![Some('x'), nc.at_yx(2, 2, &mut stylemask, &mut channels)];
+    assert_eq![0xff0000, channels.bg_rgb()];
+
+    backdrop.destroy()?;
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn frame_info_timing() {
+    let time = |tv_sec, tv_nsec| NcTime { tv_sec, tv_nsec };
+    let first = time(100, 900_000_000);
+
+    let info = frame_info(0, &first, &first, None, 1.0);
+    assert_eq![
+        (0, Duration::from_secs(0), None),
+        (info.index, info.pts, info.duration)
+    ];
+
+    // a variable frame rate: 40ms, then 60ms
+    let second = time(100, 940_000_000);
+    let third = time(101, 0);
+    let info = frame_info(1, &second, &first, Some(&first), 1.0);
+    assert_eq![Duration::from_millis(40), info.pts];
+    assert_eq![Some(Duration::from_millis(40)), info.duration];
+    let info = frame_info(2, &third, &first, Some(&second), 1.0);
+    assert_eq![Duration::from_millis(100), info.pts];
+    assert_eq![Some(Duration::from_millis(60)), info.duration];
+
+    // the timescale doesn't affect the media time
+    let info = frame_info(2, &third, &first, Some(&second), 2.0);
+    assert_eq![Duration::from_millis(50), info.pts];
+    assert_eq![Some(Duration::from_millis(30)), info.duration];
+}
+
+#[test]
+#[serial]
+fn stream() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    if nc.canopen_images() {
+        let file = concat![env!("CARGO_MANIFEST_DIR"), "/examples/image-16x16.png"];
+        let visual = NcVisual::from_file(file)?;
+        let plane = NcPlane::new(nc, 0, 0, 8, 16)?;
+        let options = NcVisualOptions::with_plane(
+            plane,
+            crate::NCSCALE_NONE,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            NCBLIT_1x1,
+            0,
+        );
+
+        let mut infos = vec![];
+        let ended = visual.stream(nc, 1.0, &options, |_, _, info| {
+            infos.push(info);
+            true
+        })?;
+        assert![ended];
+        assert![!infos.is_empty()];
+        assert_eq![None, infos[0].duration];
+        for (index, pair) in infos.windows(2).enumerate() {
+            assert_eq![index as u64 + 1, pair[1].index];
+            assert![pair[1].pts >= pair[0].pts];
+        }
+
+        // ceased by the closure
+        visual.decode_loop()?;
+        assert![!visual.stream(nc, 1.0, &options, |_, _, _| false)?];
+        visual.destroy();
+    }
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn palidx_to_rgba_resolution() -> crate::NcResult<()> {
+    let palette = [0xff0000, 0x00ff00, 0x0000ff];
+
+    // 2x2 indices, with a padding byte at the end of each row
+    let data = [0, 1, 9, 2, 0, 9];
+    assert_eq![
+        vec![
+            0xff, 0, 0, 0xff, 0, 0xff, 0, 0xff, //
+            0, 0, 0xff, 0xff, 0xff, 0, 0, 0xff,
+        ],
+        palidx_to_rgba(&data, 2, 3, 2, 3, 1, &palette)?
+    ];
+    // every other byte
+    assert_eq![
+        vec![0, 0, 0xff, 0xff],
+        palidx_to_rgba(&[2, 0], 1, 2, 1, 3, 2, &palette)?
+    ];
+
+    let kind = |res: crate::NcResult<Vec<u8>>| res.err().map(|e| e.kind());
+    let invalid = Some(NcErrorKind::InvalidInput);
+    // an index exceeding the palette size
+    assert_eq![
+        invalid,
+        kind(palidx_to_rgba(&[0, 2], 1, 2, 2, 2, 1, &palette))
+    ];
+    // a palette size exceeding the palette
+    assert_eq![
+        invalid,
+        kind(palidx_to_rgba(&[0, 1], 1, 2, 2, 4, 1, &palette))
+    ];
+    // data shorter than the geometry
+    assert_eq![
+        invalid,
+        kind(palidx_to_rgba(&data, 3, 3, 2, 3, 1, &palette))
+    ];
+    assert_eq![
+        invalid,
+        kind(palidx_to_rgba(&data, 2, 1, 2, 3, 1, &palette))
+    ];
+    assert_eq![invalid, kind(palidx_to_rgba(&[], 0, 0, 0, 3, 1, &palette))];
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn from_palidx() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let palette = [0x000000, 0x804020];
+    let visual = NcVisual::from_palidx(&[0, 1, 1, 0], 2, 2, 2, 2, 1, &palette)?;
+
+    // the pixels are RGBA bytes
+    let mut pixel = 0;
+    unsafe { crate::ncvisual_at_yx(visual, 0, 1, &mut pixel) };
+    assert_eq![[0x80, 0x40, 0x20, 0xff], pixel.to_ne_bytes()];
+    unsafe { crate::ncvisual_at_yx(visual, 1, 1, &mut pixel) };
+    assert_eq![[0, 0, 0, 0xff], pixel.to_ne_bytes()];
+
+    assert![NcVisual::from_palidx(&[0, 2], 1, 2, 2, 2, 1, &palette).is_err()];
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn fit_within_aspect() -> crate::NcResult<()> {
+    // 2x1 pixels per cell: a box of 20x40 pixels
+    assert_eq![(20, 40), fit_within(100, 200, 10, 40, 2, 1)?];
+    assert_eq![(20, 20), fit_within(100, 100, 10, 40, 2, 1)?];
+    assert_eq![(5, 40), fit_within(50, 400, 10, 40, 2, 1)?];
+    // scaled up
+    assert_eq![(20, 10), fit_within(4, 2, 10, 40, 2, 1)?];
+    // braille, 4x2 pixels per cell: a box of 40x20 pixels
+    assert_eq![(30, 20), fit_within(300, 200, 10, 10, 4, 2)?];
+    // never smaller than a pixel
+    assert_eq![(1, 40), fit_within(1, 1000, 10, 40, 2, 1)?];
+
+    let err = fit_within(100, 100, 0, 40, 2, 1).unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+    assert![fit_within(100, 100, 10, 0, 2, 1).is_err()];
+    assert![fit_within(0, 100, 10, 40, 2, 1).is_err()];
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn resize_within() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let visual = NcVisual::from_palidx(&[0; 8], 2, 4, 4, 1, 1, &[0x808080])?;
+
+    // the same size in pixels doesn't need a multimedia engine
+    assert_eq![(1, 4), visual.resize_within(nc, 1, 4, NCBLIT_2x1)?];
+    assert![visual.resize_within(nc, 0, 4, NCBLIT_2x1).is_err()];
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn media_kind_detection() {
+    use crate::NcMediaKind::*;
+
+    assert_eq![Image, media_kind("photo.jpg", false)];
+    assert_eq![Image, media_kind("still.gif", false)];
+    assert_eq![Animation, media_kind("spinner.gif", true)];
+    assert_eq![Animation, media_kind("dir/anim.WEBP", true)];
+    assert_eq![Video, media_kind("movie.mkv", true)];
+    assert_eq![Video, media_kind("no_extension", true)];
+}
+
+#[test]
+#[serial]
+fn display_media() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 8, 16)?;
+
+    let err = nc
+        .display_media(concat![env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"], plane)
+        .unwrap_err();
+    assert_eq![NcErrorKind::Unsupported, err.kind()];
+
+    if nc.canopen_images() {
+        let file = concat![env!("CARGO_MANIFEST_DIR"), "/examples/image-16x16.png"];
+        let mut media = nc.display_media(file, plane)?;
+        assert_eq![crate::NcMediaKind::Image, media.kind()];
+        assert![!media.is_playing()];
+        // an image doesn't play
+        media.play()?;
+        assert![!media.is_playing()];
+        assert![!media.update(nc)?];
+    }
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+fn sprite_frames() {
+    assert_eq![(2, 3), sprite_grid(2, 2, 4, 6)];
+    // the partial frames at the edges are ignored
+    assert_eq![(2, 3), sprite_grid(2, 2, 5, 7)];
+    assert_eq![(0, 0), sprite_grid(0, 2, 4, 6)];
+    assert_eq![(0, 3), sprite_grid(5, 2, 4, 6)];
+
+    assert_eq![Some((0, 0)), sprite_frame_origin(0, 2, 2, 5, 7)];
+    assert_eq![Some((0, 4)), sprite_frame_origin(2, 2, 2, 5, 7)];
+    assert_eq![Some((2, 2)), sprite_frame_origin(4, 2, 2, 5, 7)];
+    assert_eq![None, sprite_frame_origin(6, 2, 2, 5, 7)];
+    assert_eq![None, sprite_frame_origin(0, 5, 2, 4, 6)];
+}
+
+#[test]
+#[serial]
+fn sprite_sheet() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 4, 4)?;
+
+    // 6 frames of 2x2 pixels, with an extra column of white pixels
+    let (rows, cols) = (4, 7);
+    let color = |frame: u32| 0x10 * (frame + 1);
+    let rgba: Vec<u32> = (0..rows * cols)
+        .map(|i| {
+            let (y, x) = (i / cols, i % cols);
+            if x >= 6 {
+                0xff_ff_ff_ff
+            } else {
+                // as little-endian RGBA bytes, with the color in the red channel
+                0xff_00_00_00 | color(y / 2 * 3 + x / 2)
+            }
+        })
+        .collect();
+    let visual = unsafe {
+        &mut *crate::ncvisual_from_rgba(
+            rgba.as_ptr() as *const _,
+            rows as i32,
+            cols as i32 * 4,
+            cols as i32,
+        )
+    };
+
+    let mut sheet = NcSpriteSheet::from_visual(nc, visual, 2, 2)?;
+    assert_eq![6, sheet.frames()];
+    sheet.set_blitter(NCBLIT_1x1);
+
+    plane.blit_sprite(&mut sheet, 4, 1, 1)?;
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    for &(y, x) in &[(1, 1), (1, 2), (2, 1), (2, 2)] {
+        plane.at_yx(y, x, &mut stylemask, &mut channels)?;
+        assert_eq![color(4) << 16, channels.bg_rgb()];
+    }
+    plane.at_yx(3, 3, &mut stylemask, &mut channels)?;
+    assert_ne![color(4) << 16, channels.bg_rgb()];
+
+    sheet.blit_frame(plane, 2, 0, 0)?;
+    plane.at_yx(1, 1, &mut stylemask, &mut channels)?;
+    assert_eq![color(2) << 16, channels.bg_rgb()];
+
+    let err = sheet.blit_frame(plane, 6, 0, 0).unwrap_err();
+    assert_eq![NcErrorKind::BoundsError, err.kind()];
+    let err = NcSpriteSheet::from_visual(nc, sheet.visual(), 2, 5).unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+
+    sheet.visual().destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn blit_region() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 8, 10)?;
+
+    // 4x4 pixels, each one with a different red value
+    let red = |y: u32, x: u32| 0x10 * (y * 4 + x + 1);
+    let rgba: Vec<u32> = (0..16).map(|i| 0xff_00_00_00 | red(i / 4, i % 4)).collect();
+    let visual = unsafe { &mut *crate::ncvisual_from_rgba(rgba.as_ptr() as *const _, 4, 16, 4) };
+
+    visual.blit_region(plane, NcRect::new(1, 2, 2, 2), (3, 5), NCBLIT_1x1)?;
+
+    let (mut stylemask, mut channels): (NcStyleMask, NcChannelPair) = (0, 0);
+    let mut bg_at = |y, x| -> crate::NcResult<u32> {
+        plane.at_yx(y, x, &mut stylemask, &mut channels)?;
+        Ok(channels.bg_rgb())
+    };
+    // only the cropped pixels, at the destination offset
+    assert_eq![red(1, 2) << 16, bg_at(3, 5)?];
+    assert_eq![red(1, 3) << 16, bg_at(3, 6)?];
+    assert_eq![red(2, 2) << 16, bg_at(4, 5)?];
+    assert_eq![red(2, 3) << 16, bg_at(4, 6)?];
+    for &(y, x) in &[(3, 4), (2, 5), (5, 5), (3, 7), (0, 0)] {
+        assert_eq![0, bg_at(y, x)?];
+    }
+
+    // the crop must lie within the visual
+    let err = visual
+        .blit_region(plane, NcRect::new(3, 3, 2, 2), (0, 0), NCBLIT_1x1)
+        .unwrap_err();
+    assert_eq![NcErrorKind::BoundsError, err.kind()];
+    let err = visual
+        .blit_region(plane, NcRect::new(0, 0, 0, 2), (0, 0), NCBLIT_1x1)
+        .unwrap_err();
+    assert_eq![NcErrorKind::InvalidInput, err.kind()];
+
+    visual.destroy();
+    nc.stop()?;
+    Ok(())
+}
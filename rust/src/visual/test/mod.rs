@@ -0,0 +1,4 @@
+//! `NcVisual` tests
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,198 @@
+//! `NcActionMenu`
+
+use std::ffi::CString;
+
+use crate::{
+    NcChannelPair, NcInput, NcKeyCombo, NcMenu, NcMenuItem, NcMenuOptions, NcMenuSection, NcPlane,
+    NcResult, NCKEY_ENTER, NCKEY_RELEASE,
+};
+
+/// A section of an [NcActionMenu], whose items carry an action of type `A`.
+///
+/// It's built by chaining [item()][NcActionSection#method.item] and
+/// [separator()][NcActionSection#method.separator] calls.
+#[derive(Debug, Clone)]
+pub struct NcActionSection<A> {
+    name: String,
+    shortcut: NcInput,
+    // (description, shortcut, action), or None for a separator
+    items: Vec<Option<(String, NcInput, A)>>,
+}
+
+/// # `NcActionSection` constructors & builder methods
+impl<A> NcActionSection<A> {
+    /// New empty NcActionSection.
+    ///
+    /// The `shortcut` will be underlined if present in the `name`.
+    pub fn new(name: &str, shortcut: NcInput) -> Self {
+        Self {
+            name: name.to_string(),
+            shortcut,
+            items: Vec::new(),
+        }
+    }
+
+    /// Adds an item with its description, shortcut and `action`.
+    pub fn item(mut self, desc: &str, shortcut: NcInput, action: A) -> Self {
+        self.items.push(Some((desc.to_string(), shortcut, action)));
+        self
+    }
+
+    /// Adds a horizontal separator.
+    pub fn separator(mut self) -> Self {
+        self.items.push(None);
+        self
+    }
+}
+
+/// An [NcMenu] whose items are mapped to actions of type `A`.
+///
+/// The actions are stored in a Rust side table indexed by section and item,
+/// so [selected()][NcActionMenu#method.selected] returns a reference to the
+/// action of the chosen item, instead of its description. The action type
+/// doesn't need to implement [Clone].
+///
+/// Since the C API identifies the chosen item by its description, the
+/// descriptions of the items should be distinct.
+pub struct NcActionMenu<'a, A> {
+    menu: &'a mut NcMenu,
+    sections: Vec<NcActionSection<A>>,
+}
+
+/// # `NcActionMenu` constructors & destructors
+impl<'a, A> NcActionMenu<'a, A> {
+    /// New NcActionMenu on the `plane`, with the provided `sections`.
+    ///
+    /// There must be at least 1 section, and each one must contain at least
+    /// 1 item.
+    pub fn new(plane: &mut NcPlane, sections: Vec<NcActionSection<A>>) -> NcResult<Self> {
+        Self::with_all_args(plane, sections, 0, 0, 0)
+    }
+
+    /// New NcActionMenu on the `plane`, with all the arguments.
+    ///
+    /// See [NcMenuOptions::with_all_args] for the meaning of the arguments.
+    pub fn with_all_args(
+        plane: &mut NcPlane,
+        sections: Vec<NcActionSection<A>>,
+        style_header: NcChannelPair,
+        style_sections: NcChannelPair,
+        flags: u64,
+    ) -> NcResult<Self> {
+        // the C strings must outlive the creation of the menu, which copies them
+        let mut cstrings = Vec::new();
+        let mut cstr = |s: &str| {
+            let cstring = CString::new(s).unwrap_or_default();
+            let ptr = cstring.as_ptr() as *mut _;
+            cstrings.push(cstring);
+            ptr
+        };
+
+        let mut items: Vec<Vec<NcMenuItem>> = sections
+            .iter()
+            .map(|section| {
+                section
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        Some((desc, shortcut, _)) => NcMenuItem {
+                            desc: cstr(desc),
+                            shortcut: *shortcut,
+                        },
+                        None => NcMenuItem::new_empty(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut menu_sections: Vec<NcMenuSection> = sections
+            .iter()
+            .zip(items.iter_mut())
+            .map(|(section, items)| NcMenuSection {
+                name: cstr(&section.name),
+                items: items.as_mut_ptr(),
+                itemcount: items.len() as i32,
+                shortcut: section.shortcut,
+            })
+            .collect();
+
+        let options =
+            NcMenuOptions::with_all_args(&mut menu_sections, style_header, style_sections, flags);
+        let menu = NcMenu::new(plane, options)?;
+        Ok(Self { menu, sections })
+    }
+
+    /// Destroys the underlying [NcMenu].
+    ///
+    /// *C style function: [ncmenu_destroy()][crate::ncmenu_destroy].*
+    pub fn destroy(self) -> NcResult<()> {
+        self.menu.destroy()
+    }
+}
+
+/// # `NcActionMenu` methods
+impl<'a, A> NcActionMenu<'a, A> {
+    /// Returns a mutable reference to the underlying [NcMenu].
+    pub fn menu(&mut self) -> &mut NcMenu {
+        &mut *self.menu
+    }
+
+    /// Returns the action of the item with the `desc` description.
+    pub fn action(&self, desc: &str) -> Option<&A> {
+        self.items().find(|(d, _, _)| *d == desc).map(|(_, _, a)| a)
+    }
+
+    /// Returns the action at the `section` and `item` indices,
+    /// or None if it doesn't exist or it's a separator.
+    pub fn action_at(&self, section: usize, item: usize) -> Option<&A> {
+        self.sections
+            .get(section)?
+            .items
+            .get(item)?
+            .as_ref()
+            .map(|(_, _, action)| action)
+    }
+
+    /// Processes the `input`, and returns the action of the chosen item,
+    /// if any.
+    ///
+    /// An item is chosen by:
+    /// - pressing Enter, while a section is unrolled.
+    /// - a mouse click over an item of the unrolled section.
+    /// - the shortcut of the item.
+    ///
+    /// Choosing an item rolls up the menu. Any other input is offered to the
+    /// menu with [offer_input()][NcMenu#method.offer_input].
+    pub fn selected(&mut self, input: &NcInput) -> Option<&A> {
+        let key = core::char::from_u32(input.id);
+        let desc = if key == Some(NCKEY_RELEASE) {
+            let mut shortcut = NcInput::new_empty();
+            self.menu.mouse_selected(input, &mut shortcut)
+        } else if key == Some(NCKEY_ENTER) || key == Some('\n') || key == Some('\r') {
+            self.menu.selected(None).ok()
+        } else {
+            self.items()
+                .find(|(_, shortcut, _)| {
+                    NcKeyCombo::from_input(shortcut).map_or(false, |c| c.matches(input))
+                })
+                .map(|(desc, _, _)| desc.to_string())
+        };
+
+        match desc {
+            Some(desc) => {
+                let _ = self.menu.rollup();
+                self.action(&desc)
+            }
+            None => {
+                self.menu.offer_input(*input);
+                None
+            }
+        }
+    }
+
+    /// Returns an iterator over the items that aren't separators.
+    fn items(&self) -> impl Iterator<Item = &(String, NcInput, A)> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.items.iter().flatten())
+    }
+}
@@ -1,8 +1,8 @@
 use core::ptr::null_mut;
 
 use crate::{
-    cstring, error, error_ref_mut, error_str, ncmenu_create, NcInput, NcMenu, NcMenuOptions,
-    NcPlane, NcResult,
+    cstring, error, error_ref_mut, error_str, ncmenu_create, rstring, NcInput, NcMenu,
+    NcMenuOptions, NcPlane, NcResult,
 };
 
 /// # `NcMenu` constructors & destructors
@@ -22,6 +22,7 @@ impl NcMenu {
     ///
     /// *C style function: [ncmenu_destroy()][crate::ncmenu_destroy].*
     pub fn destroy(&mut self) -> NcResult<()> {
+        crate::plane::forget_plane_state(unsafe { crate::ncmenu_plane(self) });
         error![unsafe { crate::ncmenu_destroy(self) }]
     }
 }
@@ -38,30 +39,23 @@ impl NcMenu {
     }
 
     /// Returns the [NcMenuItem][crate::NcMenuItem] description
-    /// corresponding to the mouse `click`.
+    /// corresponding to the mouse `click`, or `None` if the click
+    /// didn't land on any item.
     ///
     /// The NcMenuItem must be on an actively unrolled section, and the click
     /// must be in the area of a valid item.
     ///
-    /// If `ninput` is provided, and the selected item has a shortcut,
-    /// it will be filled in with that shortcut.
+    /// If the selected item has a shortcut, `shortcut` will be filled in
+    /// with it.
     ///
     /// *C style function: [ncmenu_mouse_selected()][crate::ncmenu_mouse_selected].*
-    pub fn mouse_selected(
-        &self,
-        click: NcInput,
-        shortcut: Option<&mut NcInput>,
-    ) -> NcResult<String> {
-        let ninput;
-        if let Some(i) = shortcut {
-            ninput = i as *mut _;
+    pub fn mouse_selected(&self, click: &NcInput, shortcut: &mut NcInput) -> Option<String> {
+        let desc = unsafe { crate::ncmenu_mouse_selected(self, click, shortcut) };
+        if desc.is_null() {
+            None
         } else {
-            ninput = null_mut();
+            Some(rstring![desc].to_string())
         }
-        error_str![
-            unsafe { crate::ncmenu_mouse_selected(self, &click, ninput) },
-            "Getting NcMenuItem description"
-        ]
     }
 
     /// Moves to the next item within the currently unrolled section.
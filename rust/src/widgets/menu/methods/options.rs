@@ -1,4 +1,4 @@
-use crate::{NcChannelPair, NcMenuOptions, NcMenuSection};
+use crate::{NcChannelPair, NcMenuOptions, NcMenuSection, NCMENU_OPTION_BOTTOM};
 
 /// # `NcMenuOptions` constructors
 impl NcMenuOptions {
@@ -47,7 +47,7 @@ impl NcMenuOptions {
         self.headerchannels
     }
 
-    /// Returns a mutable reference of the styling for the sections.
+    /// Returns a mutable reference of the styling for the header.
     ///
     /// *(No equivalent C style function)*
     pub fn header_channels_mut<'a>(&'a mut self) -> &'a mut NcChannelPair {
@@ -67,4 +67,51 @@ impl NcMenuOptions {
     pub fn section_channels_mut<'a>(&'a mut self) -> &'a mut NcChannelPair {
         &mut self.sectionchannels
     }
+
+    /// Returns true if the menu is going to be placed at the bottom row.
+    ///
+    /// *(No equivalent C style function)*
+    pub const fn bottom(&self) -> bool {
+        self.flags & NCMENU_OPTION_BOTTOM as u64 != 0
+    }
+}
+
+/// # `NcMenuOptions` builder methods
+///
+/// There's no setter for the styling of the disabled items, since the
+/// `ncmenu_options` of this version of notcurses has no field for it:
+/// they're drawn with the section channels and a light grey foreground
+/// (`0xdddddd`), which can't be configured.
+impl NcMenuOptions {
+    /// Sets the styling for the header.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_header_channels(mut self, channels: NcChannelPair) -> Self {
+        self.headerchannels = channels;
+        self
+    }
+
+    /// Sets the styling for the sections.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_section_channels(mut self, channels: NcChannelPair) -> Self {
+        self.sectionchannels = channels;
+        self
+    }
+
+    /// Places the menu on the bottom row if `bottom` is true,
+    /// or on the top row otherwise.
+    ///
+    /// Note that a bottom menu will be scrolled along with the standard plane
+    /// if scrolling is enabled on it.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_bottom(mut self, bottom: bool) -> Self {
+        if bottom {
+            self.flags |= NCMENU_OPTION_BOTTOM as u64;
+        } else {
+            self.flags &= !(NCMENU_OPTION_BOTTOM as u64);
+        }
+        self
+    }
 }
@@ -19,8 +19,14 @@
 //W ncmenu_selected
 //W ncmenu_unroll
 
+mod actions;
 mod methods;
 
+pub use actions::*;
+
+#[cfg(test)]
+mod test;
+
 /// menus on the top or bottom rows
 ///
 /// A [Notcurses][crate::Notcurses] instance supports menu bars
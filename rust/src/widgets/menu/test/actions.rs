@@ -0,0 +1,55 @@
+//! Test `NcActionMenu` methods.
+
+use crate::{NcActionMenu, NcActionSection, NcInput, Notcurses, NCKEY_ENTER};
+
+use serial_test::serial;
+
+/// An action type that doesn't implement Clone.
+#[derive(Debug, PartialEq)]
+enum Action {
+    Open,
+    Quit,
+    About,
+}
+
+#[test]
+#[serial]
+fn actions() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = nc.stdplane()?;
+    let mut menu = NcActionMenu::new(
+        plane,
+        vec![
+            NcActionSection::new("File", NcInput::with_alt('f'))
+                .item("Open", NcInput::with_ctrl('o'), Action::Open)
+                .separator()
+                .item("Quit", NcInput::with_ctrl('q'), Action::Quit),
+            NcActionSection::new("Help", NcInput::with_alt('h')).item(
+                "About",
+                NcInput::with_ctrl('a'),
+                Action::About,
+            ),
+        ],
+    )?;
+
+    assert_eq![Some(&Action::Open), menu.action_at(0, 0)];
+    assert_eq![None, menu.action_at(0, 1)];
+    assert_eq![Some(&Action::Quit), menu.action_at(0, 2)];
+    assert_eq![Some(&Action::About), menu.action("About")];
+    assert_eq![None, menu.action_at(2, 0)];
+
+    // shortcuts
+    assert_eq![Some(&Action::Quit), menu.selected(&NcInput::with_ctrl('q'))];
+    assert_eq![None, menu.selected(&NcInput::with_ctrl('x'))];
+
+    // enter on an unrolled section
+    menu.menu().unroll(1)?;
+    let enter = NcInput::with_all_args(NCKEY_ENTER, None, None, false, false, false, 0);
+    assert_eq![Some(&Action::About), menu.selected(&enter)];
+    // the menu was rolled up
+    assert_eq![None, menu.selected(&enter)];
+
+    menu.destroy()?;
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! Test `NcMenu*` methods and associated functions.
+
+use crate::{NcInput, NcMenuItem, NcMenuOptions, NcMenuSection, NCMENU_OPTION_BOTTOM};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn options_builder() {
+    let mut items = [NcMenuItem::new("Quit", NcInput::with_ctrl('q'))];
+    let mut sections = [NcMenuSection::new(
+        "File",
+        &mut items,
+        NcInput::with_alt('f'),
+    )];
+
+    let mopts = NcMenuOptions::new(&mut sections);
+    assert_eq![0, mopts.header_channels()];
+    assert_eq![0, mopts.section_channels()];
+    assert_eq![false, mopts.bottom()];
+
+    let mopts = mopts
+        .set_header_channels(0x00ff0000_00440000)
+        .set_section_channels(0x00b0d700_00002000)
+        .set_bottom(true);
+    assert_eq![0x00ff0000_00440000, mopts.header_channels()];
+    assert_eq![0x00b0d700_00002000, mopts.section_channels()];
+    assert_eq![NCMENU_OPTION_BOTTOM as u64, mopts.flags];
+    assert_eq![true, mopts.bottom()];
+
+    let mopts = mopts.set_bottom(false);
+    assert_eq![0, mopts.flags];
+}
@@ -0,0 +1,7 @@
+//! `NcMenu` tests
+
+#[cfg(test)]
+mod methods;
+
+#[cfg(test)]
+mod actions;
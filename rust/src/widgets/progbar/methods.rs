@@ -37,6 +37,7 @@ impl NcProgBar {
     ///
     /// *C style function: [ncprogbar_destroy()][crate::ncprogbar_destroy].*
     pub fn destroy(&mut self) {
+        crate::plane::forget_plane_state(unsafe { crate::ncprogbar_plane(self) });
         unsafe {
             crate::ncprogbar_destroy(self);
         }
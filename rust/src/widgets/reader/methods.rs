@@ -1,6 +1,11 @@
 //! `NcReader*` methods and associated functions.
 
-use crate::{error_ref_mut, ncreader_create, NcPlane, NcReader, NcReaderOptions, NcResult};
+use core::ptr::null_mut;
+
+use crate::{
+    cstring, error, error_ref_mut, ncreader_create, rstring, NcInput, NcPlane, NcReader,
+    NcReaderOptions, NcResult, NCRESULT_ERR,
+};
 
 /// # `NcReaderOptions` Constructors
 impl NcReaderOptions {
@@ -31,4 +36,127 @@ impl NcReader {
     ) -> NcResult<&'a mut Self> {
         error_ref_mut![unsafe { ncreader_create(plane, &options) }]
     }
+
+    /// Destroys this NcReader and its bound [NcPlane].
+    ///
+    /// *C style function: [ncreader_destroy()][crate::ncreader_destroy].*
+    pub fn destroy(&mut self) {
+        crate::plane::forget_plane_state(unsafe { crate::ncreader_plane(self) });
+        unsafe {
+            crate::ncreader_destroy(self, null_mut());
+        }
+    }
+}
+
+/// # `NcReader` Methods
+impl NcReader {
+    /// Empties this NcReader of any user input, and homes the cursor.
+    ///
+    /// *C style function: [ncreader_clear()][crate::ncreader_clear].*
+    pub fn clear(&mut self) -> NcResult<()> {
+        error![unsafe { crate::ncreader_clear(self) }]
+    }
+
+    /// Returns a copy of the current (UTF-8) contents.
+    ///
+    /// *C style function: [ncreader_contents()][crate::ncreader_contents].*
+    pub fn contents(&self) -> NcResult<String> {
+        let cstr = unsafe { crate::ncreader_contents(self) };
+        if cstr.is_null() {
+            return Err(crate::NcError::with_kind(
+                NCRESULT_ERR,
+                crate::NcErrorKind::NullReturn,
+                "Getting the NcReader contents",
+            ));
+        }
+        let contents = rstring![cstr].to_string();
+        unsafe { libc::free(cstr as *mut libc::c_void) };
+        Ok(contents)
+    }
+
+    /// Attempts to move the cursor left.
+    ///
+    /// Returns an error if the move couldn't be executed.
+    /// Scrolling is taken into account.
+    ///
+    /// *C style function: [ncreader_move_left()][crate::ncreader_move_left].*
+    pub fn move_left(&mut self) -> NcResult<()> {
+        error![unsafe { crate::ncreader_move_left(self) }]
+    }
+
+    /// Attempts to move the cursor right.
+    ///
+    /// Returns an error if the move couldn't be executed.
+    /// Scrolling is taken into account.
+    ///
+    /// *C style function: [ncreader_move_right()][crate::ncreader_move_right].*
+    pub fn move_right(&mut self) -> NcResult<()> {
+        error![unsafe { crate::ncreader_move_right(self) }]
+    }
+
+    /// Attempts to move the cursor up.
+    ///
+    /// Returns an error if the move couldn't be executed.
+    /// Scrolling is taken into account.
+    ///
+    /// *C style function: [ncreader_move_up()][crate::ncreader_move_up].*
+    pub fn move_up(&mut self) -> NcResult<()> {
+        error![unsafe { crate::ncreader_move_up(self) }]
+    }
+
+    /// Attempts to move the cursor down.
+    ///
+    /// Returns an error if the move couldn't be executed.
+    /// Scrolling is taken into account.
+    ///
+    /// *C style function: [ncreader_move_down()][crate::ncreader_move_down].*
+    pub fn move_down(&mut self) -> NcResult<()> {
+        error![unsafe { crate::ncreader_move_down(self) }]
+    }
+
+    /// Offers the `input` to this NcReader.
+    ///
+    /// If it's relevant, this function returns true, and the input ought not
+    /// be processed further. Almost all inputs are relevant to an NcReader,
+    /// save synthesized ones.
+    ///
+    /// *C style function: [ncreader_offer_input()][crate::ncreader_offer_input].*
+    pub fn offer_input(&mut self, input: &NcInput) -> bool {
+        unsafe { crate::ncreader_offer_input(self, input) }
+    }
+
+    /// Returns the [NcPlane] backing this NcReader.
+    ///
+    /// *C style function: [ncreader_plane()][crate::ncreader_plane].*
+    pub fn plane<'a>(&mut self) -> NcResult<&'a mut NcPlane> {
+        error_ref_mut![
+            unsafe { crate::ncreader_plane(self) },
+            "Getting the backing NcPlane"
+        ]
+    }
+
+    /// Destructively writes the provided `egc` to the current cursor location.
+    ///
+    /// Moves the cursor as necessary, scrolling if applicable.
+    ///
+    /// *C style function: [ncreader_write_egc()][crate::ncreader_write_egc].*
+    pub fn write_egc(&mut self, egc: &str) -> NcResult<()> {
+        error![unsafe { crate::ncreader_write_egc(self, cstring![egc]) }]
+    }
+
+    /// Writes the provided `string` at the current cursor location,
+    /// one [NcEgc][crate::NcEgc] at a time.
+    ///
+    /// If the text is longer than the visible width of the reader, it will
+    /// only keep going past it if horizontal scrolling is enabled with
+    /// [NCREADER_OPTION_HORSCROLL][crate::NCREADER_OPTION_HORSCROLL].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn write_str(&mut self, string: &str) -> NcResult<()> {
+        let mut buf = [0u8; 4];
+        for ch in string.chars() {
+            self.write_egc(ch.encode_utf8(&mut buf))?;
+        }
+        Ok(())
+    }
 }
@@ -2,20 +2,26 @@
 
 // functions already exported by bindgen : 11
 // ------------------------------------------
-//  ncreader_clear
-//  ncreader_contents
-//  ncreader_create
-//  ncreader_destroy
-//  ncreader_move_down
-//  ncreader_move_left
-//  ncreader_move_right
-//  ncreader_move_up
-//  ncreader_offer_input
-//  ncreader_plane
-//  ncreader_write_egc
+// (#) test:  1
+// (W) wrap: 11
+// ------------------------------------------
+//W  ncreader_clear
+//W# ncreader_contents
+//W  ncreader_create
+//W  ncreader_destroy
+//W  ncreader_move_down
+//W  ncreader_move_left
+//W  ncreader_move_right
+//W  ncreader_move_up
+//W  ncreader_offer_input
+//W  ncreader_plane
+//W  ncreader_write_egc
 
 mod methods;
 
+#[cfg(test)]
+mod test;
+
 /// Provides a freeform input in a (possibly multiline) region
 ///
 /// Supports optional readline keybindings (opt out using
@@ -0,0 +1,44 @@
+//! Test `NcReader` methods and associated functions.
+
+use crate::{NcPlane, NcReader, NcReaderOptions, Notcurses, NCREADER_OPTION_HORSCROLL};
+
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn write_and_contents() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 20)?;
+    let reader = NcReader::new(plane)?;
+
+    reader.write_str("hello")?;
+    assert_eq!["hello", reader.contents()?];
+
+    reader.move_left()?;
+    reader.write_egc("O")?;
+    assert_eq!["hellO", reader.contents()?];
+
+    reader.clear()?;
+    assert_eq!["", reader.contents()?];
+
+    reader.destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn write_past_visible_width() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 1, 5)?;
+    let mut options = NcReaderOptions::new();
+    options.flags = NCREADER_OPTION_HORSCROLL as u64;
+    let reader = NcReader::with_options(plane, options)?;
+
+    reader.write_str("longer than five")?;
+    assert_eq!["longer than five", reader.contents()?];
+
+    reader.destroy();
+    nc.stop()?;
+    Ok(())
+}
@@ -0,0 +1,4 @@
+//! `NcReader` tests
+
+#[cfg(test)]
+mod methods;
@@ -0,0 +1,91 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{NcReel, NcTablet};
+
+/// The state kept by the Rust bindings for each [NcReel], which is opaque.
+///
+/// This version of notcurses ignores the scrolling flags, and its tablets
+/// form a ring without ends, so the order of the tablets is mirrored here,
+/// starting from the first one, in order to stop at the ends of the reels
+/// that are not circular.
+#[derive(Debug, Default)]
+struct ReelState {
+    circular: bool,
+    // the addresses of the tablets, in order
+    tablets: Vec<usize>,
+}
+
+thread_local! {
+    // the state of each NcReel, by its address
+    static REEL_STATE: RefCell<HashMap<usize, ReelState>> = RefCell::new(HashMap::new());
+}
+
+/// Helper function that updates the state of the `reel` with `f`,
+/// and returns its result.
+fn update_reel_state<T>(reel: *const NcReel, f: impl FnOnce(&mut ReelState) -> T) -> T {
+    REEL_STATE.with(|states| f(states.borrow_mut().entry(reel as usize).or_default()))
+}
+
+/// Helper function that starts tracking the state of a new `reel`.
+pub(crate) fn init_reel_state(reel: *const NcReel, circular: bool) {
+    update_reel_state(reel, |state| {
+        *state = ReelState {
+            circular,
+            tablets: Vec::new(),
+        }
+    });
+}
+
+/// Helper function that forgets the state of the `reel`, when it's destroyed.
+pub(crate) fn forget_reel_state(reel: *const NcReel) {
+    REEL_STATE.with(|states| states.borrow_mut().remove(&(reel as usize)));
+}
+
+/// Helper function that records the `tablet` added to the `reel`, either
+/// `after` or `before` another tablet, or else before the `focused` one.
+///
+/// Added before the first tablet, the new one goes to the end of the ring,
+/// except when it's explicitly `before` it.
+pub(crate) fn reel_tablet_added(
+    reel: *const NcReel,
+    tablet: *const NcTablet,
+    after: *const NcTablet,
+    before: *const NcTablet,
+    focused: *const NcTablet,
+) {
+    update_reel_state(reel, |state| {
+        let position = |t: *const NcTablet| state.tablets.iter().position(|&a| a == t as usize);
+        let index = if let Some(i) = position(after) {
+            i + 1
+        } else if let Some(i) = position(before) {
+            i
+        } else {
+            match position(focused) {
+                Some(i) if i > 0 => i,
+                _ => state.tablets.len(),
+            }
+        };
+        state.tablets.insert(index, tablet as usize);
+    });
+}
+
+/// Helper function that forgets the `tablet` deleted from the `reel`.
+pub(crate) fn reel_tablet_deleted(reel: *const NcReel, tablet: *const NcTablet) {
+    update_reel_state(reel, |state| {
+        state.tablets.retain(|&a| a != tablet as usize)
+    });
+}
+
+/// Helper function that returns true if the focus can move from the `focused`
+/// tablet of the `reel`, forward or backward, i.e. if the reel is circular,
+/// or the tablet is not at that end.
+pub(crate) fn reel_can_move(reel: *const NcReel, focused: *const NcTablet, forward: bool) -> bool {
+    update_reel_state(reel, |state| {
+        let end = if forward {
+            state.tablets.last()
+        } else {
+            state.tablets.first()
+        };
+        state.circular || end != Some(&(focused as usize))
+    })
+}
@@ -0,0 +1,291 @@
+//! `NcReel*` methods and associated functions.
+
+use core::ptr::null_mut;
+
+use crate::{
+    error, error_ref_mut, NcBoxMask, NcChannelPair, NcInput, NcPlane, NcReel, NcReelOptions,
+    NcResult, NcTablet, NcTabletCb, NCKEY_DOWN, NCKEY_SCROLL_DOWN, NCKEY_SCROLL_UP, NCKEY_UP,
+    NCREEL_OPTION_CIRCULAR, NCREEL_OPTION_INFINITESCROLL,
+};
+
+/// # `NcReelOptions` Constructors
+impl NcReelOptions {
+    /// `NcReelOptions` simple constructor, with all the borders drawn.
+    pub const fn new() -> Self {
+        Self::with_flags(0)
+    }
+
+    /// `NcReelOptions` constructor with flags, a bitfield of
+    /// [NCREEL_OPTION_*][crate::NCREEL_OPTION_INFINITESCROLL].
+    pub const fn with_flags(flags: u64) -> Self {
+        Self {
+            // bitfield; 1s will not be drawn
+            bordermask: 0,
+            // attributes used for the reel border
+            borderchan: 0,
+            // bitfield; same as bordermask but for the tablet borders
+            tabletmask: 0,
+            // tablet border styling channels
+            tabletchan: 0,
+            // focused tablet border styling channels
+            focusedchan: 0,
+            // bitfield over NCREEL_OPTION_*
+            flags,
+        }
+    }
+
+    /// Sets the channels of the border of the focused tablet.
+    pub fn set_focused_channels(mut self, channels: NcChannelPair) -> Self {
+        self.focusedchan = channels;
+        self
+    }
+
+    /// Sets the [NcBoxMask] of the reel border. 1s will not be drawn.
+    ///
+    /// Only the top, right, bottom and left bits are valid.
+    pub fn set_bordermask(mut self, mask: NcBoxMask) -> Self {
+        self.bordermask = mask;
+        self
+    }
+
+    /// Sets the channels of the reel border.
+    pub fn set_border_channels(mut self, channels: NcChannelPair) -> Self {
+        self.borderchan = channels;
+        self
+    }
+
+    /// Sets the [NcBoxMask] of the tablet borders. 1s will not be drawn.
+    ///
+    /// Only the top, right, bottom and left bits are valid.
+    pub fn set_tabletmask(mut self, mask: NcBoxMask) -> Self {
+        self.tabletmask = mask;
+        self
+    }
+
+    /// Sets the channels of the border of the non-focused tablets.
+    pub fn set_tablet_channels(mut self, channels: NcChannelPair) -> Self {
+        self.tabletchan = channels;
+        self
+    }
+
+    /// Sets whether scrolling is infinite, i.e. whether one can move up or
+    /// down forever, instead of reaching an end.
+    ///
+    /// Disabling it also disables [circular][NcReelOptions#method.set_circular]
+    /// scrolling.
+    pub fn set_infinite_scroll(mut self, infinite: bool) -> Self {
+        if infinite {
+            self.flags |= NCREEL_OPTION_INFINITESCROLL as u64;
+        } else {
+            self.flags &= !((NCREEL_OPTION_INFINITESCROLL | NCREEL_OPTION_CIRCULAR) as u64);
+        }
+        self
+    }
+
+    /// Sets whether navigation is circular, i.e. whether moving down from
+    /// the last tablet moves to the first one, and vice versa.
+    ///
+    /// Enabling it also enables [infinite][NcReelOptions#method.set_infinite_scroll]
+    /// scrolling, which it requires.
+    pub fn set_circular(mut self, circular: bool) -> Self {
+        if circular {
+            self.flags |= (NCREEL_OPTION_INFINITESCROLL | NCREEL_OPTION_CIRCULAR) as u64;
+        } else {
+            self.flags &= !(NCREEL_OPTION_CIRCULAR as u64);
+        }
+        self
+    }
+
+    /// Returns true if scrolling is infinite.
+    pub const fn is_infinite_scroll(&self) -> bool {
+        self.flags & NCREEL_OPTION_INFINITESCROLL as u64 != 0
+    }
+
+    /// Returns true if navigation is circular.
+    pub const fn is_circular(&self) -> bool {
+        self.flags & NCREEL_OPTION_CIRCULAR as u64 != 0
+    }
+}
+
+/// # `NcReel` Constructors & destructors
+impl NcReel {
+    /// `NcReel` simple constructor.
+    ///
+    /// Takes ownership of the `plane`, which is destroyed along with the reel.
+    pub fn new<'a>(plane: &mut NcPlane) -> NcResult<&'a mut Self> {
+        Self::with_options(plane, NcReelOptions::new())
+    }
+
+    /// `NcReel` constructor with options.
+    ///
+    /// Takes ownership of the `plane`, which is destroyed along with the reel.
+    ///
+    /// *C style function: [ncreel_create()][crate::ncreel_create].*
+    pub fn with_options<'a>(plane: &mut NcPlane, options: NcReelOptions) -> NcResult<&'a mut Self> {
+        let res = unsafe { crate::ncreel_create(plane, &options) };
+        if !res.is_null() {
+            crate::widgets::reel::init_reel_state(res, options.is_circular());
+        }
+        error_ref_mut![res, "Creating NcReel"]
+    }
+
+    /// Destroys this NcReel and its bound [NcPlane].
+    ///
+    /// *C style function: [ncreel_destroy()][crate::ncreel_destroy].*
+    pub fn destroy(&mut self) {
+        // including the planes of the tablets
+        crate::plane::forget_family_state(unsafe { &mut *crate::ncreel_plane(self) });
+        crate::widgets::reel::forget_reel_state(self);
+        unsafe {
+            crate::ncreel_destroy(self);
+        }
+    }
+}
+
+/// # `NcReel` Methods
+impl NcReel {
+    /// Adds a new [NcTablet] drawn by the callback `cb`, with the user
+    /// pointer `opaque`.
+    ///
+    /// Neither, either, or both of `after` and `before` may be specified.
+    /// If neither is specified, the new tablet is added after the focused one.
+    /// If both are specified, they must be adjacent.
+    ///
+    /// *C style function: [ncreel_add()][crate::ncreel_add].*
+    pub fn add<'a>(
+        &mut self,
+        after: Option<&mut NcTablet>,
+        before: Option<&mut NcTablet>,
+        cb: NcTabletCb,
+        opaque: *mut core::ffi::c_void,
+    ) -> NcResult<&'a mut NcTablet> {
+        let after = after.map_or(null_mut(), |t| t as *mut NcTablet);
+        let before = before.map_or(null_mut(), |t| t as *mut NcTablet);
+        let focused = unsafe { crate::ncreel_focused(self) };
+        let res = unsafe { crate::ncreel_add(self, after, before, Some(cb), opaque) };
+        if !res.is_null() {
+            crate::widgets::reel::reel_tablet_added(self, res, after, before, focused);
+        }
+        error_ref_mut![res, "Adding an NcTablet"]
+    }
+
+    /// Deletes the `tablet` from this NcReel.
+    ///
+    /// Returns an error if the tablet can't be found.
+    ///
+    /// *C style function: [ncreel_del()][crate::ncreel_del].*
+    pub fn del(&mut self, tablet: &mut NcTablet) -> NcResult<()> {
+        let res = unsafe { crate::ncreel_del(self, tablet) };
+        if res >= crate::NCRESULT_OK {
+            crate::widgets::reel::reel_tablet_deleted(self, tablet);
+        }
+        error![res]
+    }
+
+    /// Returns the focused [NcTablet], or None if the reel is empty.
+    ///
+    /// *C style function: [ncreel_focused()][crate::ncreel_focused].*
+    pub fn focused<'a>(&mut self) -> Option<&'a mut NcTablet> {
+        unsafe { crate::ncreel_focused(self).as_mut() }
+    }
+
+    /// Changes the focus to the next [NcTablet], and returns it.
+    ///
+    /// From the last tablet, it moves to the first one only if the reel is
+    /// [circular][NcReelOptions#method.set_circular], and otherwise the focus
+    /// stays on the last one.
+    ///
+    /// Returns None, doing nothing, if the reel is empty.
+    ///
+    /// *C style function: [ncreel_next()][crate::ncreel_next].*
+    pub fn next<'a>(&mut self) -> Option<&'a mut NcTablet> {
+        let focused = unsafe { crate::ncreel_focused(self) };
+        if !crate::widgets::reel::reel_can_move(self, focused, true) {
+            return unsafe { focused.as_mut() };
+        }
+        unsafe { crate::ncreel_next(self).as_mut() }
+    }
+
+    /// Changes the focus to the previous [NcTablet], and returns it.
+    ///
+    /// From the first tablet, it moves to the last one only if the reel is
+    /// [circular][NcReelOptions#method.set_circular], and otherwise the focus
+    /// stays on the first one.
+    ///
+    /// Returns None, doing nothing, if the reel is empty.
+    ///
+    /// *C style function: [ncreel_prev()][crate::ncreel_prev].*
+    pub fn prev<'a>(&mut self) -> Option<&'a mut NcTablet> {
+        let focused = unsafe { crate::ncreel_focused(self) };
+        if !crate::widgets::reel::reel_can_move(self, focused, false) {
+            return unsafe { focused.as_mut() };
+        }
+        unsafe { crate::ncreel_prev(self).as_mut() }
+    }
+
+    /// Offers the `input` to this NcReel.
+    ///
+    /// If it's relevant, this function returns true, and the input ought not
+    /// be processed further. Relevant inputs include a mouse click on a tablet,
+    /// the mouse scrollwheel, and the up, down, pgup and pgdown keys.
+    ///
+    /// *C style function: [ncreel_offer_input()][crate::ncreel_offer_input].*
+    pub fn offer_input(&mut self, input: &NcInput) -> bool {
+        // moves the focus like next() and prev(), which stop at the ends
+        match core::char::from_u32(input.id) {
+            Some(NCKEY_UP) | Some(NCKEY_SCROLL_UP) => {
+                self.prev();
+                true
+            }
+            Some(NCKEY_DOWN) | Some(NCKEY_SCROLL_DOWN) => {
+                self.next();
+                true
+            }
+            _ => unsafe { crate::ncreel_offer_input(self, input) },
+        }
+    }
+
+    /// Returns the [NcPlane] on which this NcReel lives.
+    ///
+    /// *C style function: [ncreel_plane()][crate::ncreel_plane].*
+    pub fn plane<'a>(&mut self) -> NcResult<&'a mut NcPlane> {
+        error_ref_mut![
+            unsafe { crate::ncreel_plane(self) },
+            "Getting the backing NcPlane"
+        ]
+    }
+
+    /// Redraws this NcReel in its entirety.
+    ///
+    /// The reel is cleared, and the tablets are laid out around the focused
+    /// one, invoking the draw callback of each visible tablet.
+    ///
+    /// *C style function: [ncreel_redraw()][crate::ncreel_redraw].*
+    pub fn redraw(&mut self) -> NcResult<()> {
+        error![unsafe { crate::ncreel_redraw(self) }]
+    }
+
+    /// Returns the number of [NcTablet]s in this NcReel.
+    ///
+    /// *C style function: [ncreel_tabletcount()][crate::ncreel_tabletcount].*
+    pub fn tabletcount(&self) -> usize {
+        unsafe { crate::ncreel_tabletcount(self) as usize }
+    }
+}
+
+/// # `NcTablet` Methods
+impl NcTablet {
+    /// Returns the [NcPlane] of this NcTablet, if it's currently visible.
+    ///
+    /// *C style function: [nctablet_plane()][crate::nctablet_plane].*
+    pub fn plane<'a>(&mut self) -> Option<&'a mut NcPlane> {
+        unsafe { crate::nctablet_plane(self).as_mut() }
+    }
+
+    /// Returns the user pointer associated with this NcTablet.
+    ///
+    /// *C style function: [nctablet_userptr()][crate::nctablet_userptr].*
+    pub fn userptr(&mut self) -> *mut core::ffi::c_void {
+        unsafe { crate::nctablet_userptr(self) }
+    }
+}
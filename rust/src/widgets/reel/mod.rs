@@ -1,5 +1,35 @@
 //! `NcReel` widget.
 
+// functions already exported by bindgen : 14
+// ------------------------------------------
+// (#) test:  5
+// (W) wrap: 12
+// ------------------------------------------
+//W  ncreel_add
+//W# ncreel_create
+//W  ncreel_del
+//W  ncreel_destroy
+//W# ncreel_focused
+//W# ncreel_next
+//W  ncreel_offer_input
+//W  ncreel_plane
+//W# ncreel_prev
+//W  ncreel_redraw
+//W# ncreel_tabletcount
+//   nctablet_ncplane
+//W  nctablet_plane
+//W  nctablet_userptr
+
+mod helpers;
+mod methods;
+
+#[cfg(test)]
+mod test;
+
+pub(crate) use helpers::*;
+
+use crate::NcIntResult;
+
 /// A wheel with `NcTablet`s on the outside
 ///
 /// An `NcReel` is projected onto the 2d rendering area, showing some portion of
@@ -21,6 +51,16 @@ pub type NcReelOptions = crate::bindings::ffi::ncreel_options;
 /// Visual tablet for [`NcReel`]
 pub type NcTablet = crate::bindings::ffi::nctablet;
 
+/// Tablet draw callback for [`NcReel`]
+///
+/// It's provided an [`NcTablet`] (from which the plane and the user pointer
+/// may be extracted), and a bool indicating whether output ought be drawn from
+/// the top (true) or bottom (false).
+///
+/// Returns the non-negative count of output lines, which must be less than or
+/// equal to the number of rows of the tablet's plane.
+pub type NcTabletCb = unsafe extern "C" fn(*mut NcTablet, bool) -> NcIntResult;
+
 /// is navigation circular (does moving down from the last tablet move to the
 /// first, and vice versa)? only meaningful when infinitescroll is true. if
 /// infinitescroll is false, this must be false.
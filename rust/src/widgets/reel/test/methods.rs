@@ -0,0 +1,170 @@
+//! Test `NcReel*` methods and associated functions.
+
+use core::ptr::null_mut;
+
+use crate::{NcIntResult, NcPlane, NcReel, NcReelOptions, NcTablet, Notcurses};
+
+use serial_test::serial;
+
+unsafe extern "C" fn draw_nothing(_tablet: *mut NcTablet, _drawfromtop: bool) -> NcIntResult {
+    0
+}
+
+#[test]
+#[serial]
+fn options() {
+    let options = NcReelOptions::new();
+    assert_eq![0, options.flags];
+    assert_eq![0, options.bordermask];
+
+    let options = NcReelOptions::with_flags(crate::NCREEL_OPTION_INFINITESCROLL as u64)
+        .set_focused_channels(0x00ff0000_00000000);
+    assert_eq![crate::NCREEL_OPTION_INFINITESCROLL as u64, options.flags];
+    assert_eq![0x00ff0000_00000000, options.focusedchan];
+}
+
+#[test]
+#[serial]
+fn empty_reel() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 20, 20)?;
+    let reel = NcReel::new(plane)?;
+
+    assert_eq![0, reel.tabletcount()];
+    assert![reel.focused().is_none()];
+    assert![reel.next().is_none()];
+    assert![reel.prev().is_none()];
+    assert![reel.focused().is_none()];
+
+    reel.destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn focus_navigation() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+    let plane = NcPlane::new(nc, 0, 0, 20, 20)?;
+    let reel = NcReel::new(plane)?;
+
+    let first = reel.add(None, None, draw_nothing, null_mut())? as *mut NcTablet;
+    // the first tablet gets the focus
+    assert_eq![Some(first), reel.focused().map(|t| t as *mut NcTablet)];
+
+    let last = reel.add(None, None, draw_nothing, null_mut())? as *mut NcTablet;
+    reel.add(Some(unsafe { &mut *first }), None, draw_nothing, null_mut())?;
+    assert_eq![3, reel.tabletcount()];
+    assert_eq![Some(first), reel.focused().map(|t| t as *mut NcTablet)];
+
+    // next and prev return the newly focused tablet
+    let second = reel.next().map(|t| t as *mut NcTablet);
+    assert![second.is_some()];
+    assert_ne![Some(first), second];
+    assert_eq![second, reel.focused().map(|t| t as *mut NcTablet)];
+    assert_eq![Some(first), reel.prev().map(|t| t as *mut NcTablet)];
+
+    // the reel is not circular, so the focus stops at the ends
+    assert_eq![Some(first), reel.prev().map(|t| t as *mut NcTablet)];
+    reel.next();
+    assert_eq![Some(last), reel.next().map(|t| t as *mut NcTablet)];
+    assert_eq![Some(last), reel.next().map(|t| t as *mut NcTablet)];
+    for _ in 0..3 {
+        reel.prev();
+    }
+    assert_eq![Some(first), reel.focused().map(|t| t as *mut NcTablet)];
+
+    reel.redraw()?;
+
+    // deleting the focused tablet moves the focus
+    reel.del(unsafe { &mut *first })?;
+    assert_eq![2, reel.tabletcount()];
+    assert_ne![Some(first), reel.focused().map(|t| t as *mut NcTablet)];
+    reel.del(unsafe { &mut *last })?;
+    assert_eq![1, reel.tabletcount()];
+
+    reel.destroy();
+    nc.stop()?;
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn options_builder() {
+    let options = NcReelOptions::new()
+        .set_bordermask(crate::NCBOXMASK_TOP | crate::NCBOXMASK_BOTTOM)
+        .set_border_channels(0x00ff0000_00000000)
+        .set_tabletmask(crate::NCBOXMASK_LEFT)
+        .set_tablet_channels(0x0000ff00_00000000);
+    assert_eq![
+        crate::NCBOXMASK_TOP | crate::NCBOXMASK_BOTTOM,
+        options.bordermask
+    ];
+    assert_eq![0x00ff0000_00000000, options.borderchan];
+    assert_eq![crate::NCBOXMASK_LEFT, options.tabletmask];
+    assert_eq![0x0000ff00_00000000, options.tabletchan];
+    assert_eq![0, options.flags];
+
+    // circular scrolling requires infinite scrolling
+    let options = NcReelOptions::new().set_circular(true);
+    assert![options.is_circular()];
+    assert![options.is_infinite_scroll()];
+    let options = options.set_circular(false);
+    assert![!options.is_circular()];
+    assert![options.is_infinite_scroll()];
+    let options = options.set_circular(true).set_infinite_scroll(false);
+    assert_eq![0, options.flags];
+}
+
+#[test]
+#[serial]
+fn circular_focus() -> crate::NcResult<()> {
+    let nc = Notcurses::new()?;
+
+    for &circular in [true, false].iter() {
+        let plane = NcPlane::new(nc, 0, 0, 20, 20)?;
+        let reel = NcReel::with_options(plane, NcReelOptions::new().set_circular(circular))?;
+
+        // with a single tablet the focus stays on it
+        let first = reel.add(None, None, draw_nothing, null_mut())? as *mut NcTablet;
+        assert_eq![Some(first), reel.next().map(|t| t as *mut NcTablet)];
+        assert_eq![Some(first), reel.prev().map(|t| t as *mut NcTablet)];
+
+        reel.add(None, None, draw_nothing, null_mut())?;
+        let last = reel.add(None, None, draw_nothing, null_mut())? as *mut NcTablet;
+        reel.redraw()?;
+
+        // moving back from the first tablet wraps to the last one, and forward
+        // from the last one wraps to the first, only if the reel is circular
+        let (wrapped_back, wrapped_forward) = if circular {
+            (last, first)
+        } else {
+            (first, last)
+        };
+        assert_eq![Some(wrapped_back), reel.prev().map(|t| t as *mut NcTablet)];
+        if !circular {
+            reel.next();
+            reel.next();
+        }
+        assert_eq![
+            Some(wrapped_forward),
+            reel.next().map(|t| t as *mut NcTablet)
+        ];
+
+        // the input moves the focus the same way: up from the first tablet
+        // wraps, and down from the last one doesn't
+        let key = if circular {
+            crate::NCKEY_UP
+        } else {
+            crate::NCKEY_DOWN
+        };
+        let input = crate::NcInput::with_all_args(key, None, None, false, false, false, 0);
+        assert![reel.offer_input(&input)];
+        assert_eq![Some(last), reel.focused().map(|t| t as *mut NcTablet)];
+
+        reel.destroy();
+    }
+
+    nc.stop()?;
+    Ok(())
+}